@@ -41,12 +41,42 @@ pub enum Action {
     Undo,
     Redo,
     ClearAllToasts,
+    JumpToCard,
+    DismissTopToast,
+    ToggleBoardFocusMode,
+    CycleCardStatus,
+    ResetBoardView,
+    ToggleCardSelection,
+    ShowQuickHelp,
+    MoveCardToTop,
+    MoveCardToBottom,
+    ToggleSplitView,
+    SwitchSplitViewPane,
+    ClearBoard,
+    ToggleFocusTimer,
+    ToggleLogPanel,
+    ChangeCardRecurrence,
+    GrowPanel,
+    ShrinkPanel,
+    ExportBoardAndCopyPath,
+    ToggleExpandedCard,
+    ToggleHideCompletedCards,
+    ToggleRelativeDueDates,
+    NextTheme,
+    PrvTheme,
+    NavigateBack,
+    NavigateForward,
+    ToggleBoardHideCompletedCards,
+    ToggleOverdueQuickFilter,
+    ToggleWatchedQuickFilter,
+    ToggleBlockedQuickFilter,
+    ToggleHighPriorityQuickFilter,
 }
 
 impl Action {
     /// All available actions
     pub fn iterator() -> Iter<'static, Action> {
-        static ACTIONS: [Action; 31] = [
+        static ACTIONS: [Action; 61] = [
             Action::Quit,
             Action::NextFocus,
             Action::PrvFocus,
@@ -78,6 +108,36 @@ impl Action {
             Action::Undo,
             Action::Redo,
             Action::ClearAllToasts,
+            Action::JumpToCard,
+            Action::DismissTopToast,
+            Action::ToggleBoardFocusMode,
+            Action::CycleCardStatus,
+            Action::ResetBoardView,
+            Action::ToggleCardSelection,
+            Action::ShowQuickHelp,
+            Action::MoveCardToTop,
+            Action::MoveCardToBottom,
+            Action::ToggleSplitView,
+            Action::SwitchSplitViewPane,
+            Action::ClearBoard,
+            Action::ToggleFocusTimer,
+            Action::ToggleLogPanel,
+            Action::ChangeCardRecurrence,
+            Action::GrowPanel,
+            Action::ShrinkPanel,
+            Action::ExportBoardAndCopyPath,
+            Action::ToggleExpandedCard,
+            Action::ToggleHideCompletedCards,
+            Action::ToggleRelativeDueDates,
+            Action::NextTheme,
+            Action::PrvTheme,
+            Action::NavigateBack,
+            Action::NavigateForward,
+            Action::ToggleBoardHideCompletedCards,
+            Action::ToggleOverdueQuickFilter,
+            Action::ToggleWatchedQuickFilter,
+            Action::ToggleBlockedQuickFilter,
+            Action::ToggleHighPriorityQuickFilter,
         ];
         ACTIONS.iter()
     }
@@ -116,12 +176,109 @@ impl Action {
             Action::Undo => &[Key::Ctrl('z')],
             Action::Redo => &[Key::Ctrl('y')],
             Action::ClearAllToasts => &[Key::Char('t')],
+            Action::JumpToCard => &[Key::Char('f')],
+            Action::DismissTopToast => &[Key::Char('x')],
+            Action::ToggleBoardFocusMode => &[Key::Char('o')],
+            Action::CycleCardStatus => &[Key::Char('s')],
+            Action::ResetBoardView => &[Key::Char('R')],
+            Action::ToggleCardSelection => &[Key::Char('v')],
+            Action::ShowQuickHelp => &[Key::Char('?')],
+            Action::MoveCardToTop => &[Key::Home],
+            Action::MoveCardToBottom => &[Key::End],
+            Action::ToggleSplitView => &[Key::Char('|')],
+            Action::SwitchSplitViewPane => &[Key::Char('w')],
+            Action::ClearBoard => &[Key::Char('C')],
+            Action::ToggleFocusTimer => &[Key::Char('p')],
+            Action::ToggleLogPanel => &[Key::Char('l')],
+            Action::ChangeCardRecurrence => &[Key::Char('e')],
+            Action::GrowPanel => &[Key::Char('+')],
+            Action::ShrinkPanel => &[Key::Char('-')],
+            Action::ExportBoardAndCopyPath => &[Key::Char('y')],
+            Action::ToggleExpandedCard => &[Key::Char('z')],
+            Action::ToggleHideCompletedCards => &[Key::Char('H')],
+            Action::ToggleRelativeDueDates => &[Key::Char('u')],
+            Action::NextTheme => &[Key::Char(']')],
+            Action::PrvTheme => &[Key::Char('[')],
+            Action::NavigateBack => &[Key::Char('<')],
+            Action::NavigateForward => &[Key::Char('>')],
+            Action::ToggleBoardHideCompletedCards => &[Key::Char('K')],
+            Action::ToggleOverdueQuickFilter => &[Key::Char('O')],
+            Action::ToggleWatchedQuickFilter => &[Key::Char('W')],
+            Action::ToggleBlockedQuickFilter => &[Key::Char('B')],
+            Action::ToggleHighPriorityQuickFilter => &[Key::Char('P')],
         }
     }
 
     pub fn all() -> Vec<Action> {
         Action::iterator().cloned().collect()
     }
+
+    /// Heading the action is listed under in the keybinding cheatsheet
+    pub fn category(&self) -> &'static str {
+        match self {
+            Action::Quit => "General",
+            Action::NextFocus => "Navigation",
+            Action::PrvFocus => "Navigation",
+            Action::OpenConfigMenu => "General",
+            Action::Up => "Navigation",
+            Action::Down => "Navigation",
+            Action::Right => "Navigation",
+            Action::Left => "Navigation",
+            Action::MoveCardUp => "Cards",
+            Action::MoveCardDown => "Cards",
+            Action::MoveCardRight => "Cards",
+            Action::MoveCardLeft => "Cards",
+            Action::TakeUserInput => "General",
+            Action::StopUserInput => "General",
+            Action::GoToPreviousUIMode => "General",
+            Action::Enter => "General",
+            Action::HideUiElement => "View",
+            Action::SaveState => "General",
+            Action::NewBoard => "Boards",
+            Action::NewCard => "Cards",
+            Action::DeleteCard => "Cards",
+            Action::DeleteBoard => "Boards",
+            Action::ChangeCardStatusToCompleted => "Cards",
+            Action::ChangeCardStatusToActive => "Cards",
+            Action::ChangeCardStatusToStale => "Cards",
+            Action::ResetUI => "General",
+            Action::GoToMainMenu => "General",
+            Action::ToggleCommandPalette => "General",
+            Action::Undo => "General",
+            Action::Redo => "General",
+            Action::ClearAllToasts => "General",
+            Action::JumpToCard => "Navigation",
+            Action::DismissTopToast => "General",
+            Action::ToggleBoardFocusMode => "Boards",
+            Action::CycleCardStatus => "Cards",
+            Action::ResetBoardView => "Boards",
+            Action::ToggleCardSelection => "Cards",
+            Action::ShowQuickHelp => "General",
+            Action::MoveCardToTop => "Cards",
+            Action::MoveCardToBottom => "Cards",
+            Action::ToggleSplitView => "View",
+            Action::SwitchSplitViewPane => "View",
+            Action::ClearBoard => "Boards",
+            Action::ToggleFocusTimer => "Cards",
+            Action::ToggleLogPanel => "View",
+            Action::ChangeCardRecurrence => "Cards",
+            Action::GrowPanel => "View",
+            Action::ShrinkPanel => "View",
+            Action::ExportBoardAndCopyPath => "General",
+            Action::ToggleExpandedCard => "Cards",
+            Action::ToggleHideCompletedCards => "View",
+            Action::ToggleRelativeDueDates => "View",
+            Action::NextTheme => "General",
+            Action::PrvTheme => "General",
+            Action::NavigateBack => "Navigation",
+            Action::NavigateForward => "Navigation",
+            Action::ToggleBoardHideCompletedCards => "View",
+            Action::ToggleOverdueQuickFilter => "View",
+            Action::ToggleWatchedQuickFilter => "View",
+            Action::ToggleBlockedQuickFilter => "View",
+            Action::ToggleHighPriorityQuickFilter => "View",
+        }
+    }
 }
 
 /// Could display a user friendly short description of action
@@ -159,6 +316,38 @@ impl Display for Action {
             Action::Undo => "Undo",
             Action::Redo => "Redo",
             Action::ClearAllToasts => "Clear all toasts",
+            Action::JumpToCard => "Jump to a card by label",
+            Action::DismissTopToast => "Dismiss latest toast",
+            Action::ToggleBoardFocusMode => "Toggle focus mode for the current board",
+            Action::CycleCardStatus => "Cycle card status",
+            Action::ResetBoardView => "Reset Board View",
+            Action::ToggleCardSelection => "Toggle Card Selection",
+            Action::ShowQuickHelp => "Show quick help",
+            Action::MoveCardToTop => "Move card to top of board",
+            Action::MoveCardToBottom => "Move card to bottom of board",
+            Action::ToggleSplitView => "Toggle split view",
+            Action::SwitchSplitViewPane => "Switch split view pane",
+            Action::ClearBoard => "Remove all cards from the current board",
+            Action::ToggleFocusTimer => "Start or stop the focus timer",
+            Action::ToggleLogPanel => "Show or hide the log panel",
+            Action::ChangeCardRecurrence => "Change card recurrence",
+            Action::GrowPanel => "Grow the focused panel",
+            Action::ShrinkPanel => "Shrink the focused panel",
+            Action::ExportBoardAndCopyPath => "Export board and copy its path",
+            Action::ToggleExpandedCard => "Expand or collapse card",
+            Action::ToggleHideCompletedCards => "Hide or show completed cards",
+            Action::ToggleRelativeDueDates => "Toggle relative due dates",
+            Action::NextTheme => "Switch to the next theme",
+            Action::PrvTheme => "Switch to the previous theme",
+            Action::NavigateBack => "Go back to the previous position",
+            Action::NavigateForward => "Go forward to the next position",
+            Action::ToggleBoardHideCompletedCards => "Hide or show completed cards on this board",
+            Action::ToggleOverdueQuickFilter => "Show only overdue cards or clear that filter",
+            Action::ToggleWatchedQuickFilter => "Show only watched cards or clear that filter",
+            Action::ToggleBlockedQuickFilter => "Show only blocked cards or clear that filter",
+            Action::ToggleHighPriorityQuickFilter => {
+                "Show only high priority cards or clear that filter"
+            }
         };
         write!(f, "{}", str)
     }
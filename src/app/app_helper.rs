@@ -1,22 +1,40 @@
-use chrono::Utc;
+use chrono::{Local, NaiveDate, NaiveDateTime, Utc};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::{style::Color, widgets::ListState};
-use std::{str::FromStr, time::Duration};
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 use crate::{
-    app::{state::KeyBindings, ActionHistory, AppConfig},
+    app::{
+        state::{KeyBindings, KeyChord},
+        ActionHistory, AppConfig,
+    },
     constants::{
-        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES,
-        NEW_BOARD_FORM_DEFAULT_STATE, NEW_CARD_FORM_DEFAULT_STATE,
+        CARD_JUMP_LABEL_CHARS, CARD_POINTS_OPTIONS, CLEAR_ALL_BOARDS_CONFIRM_WINDOW,
+        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, KEY_CHORD_TIMEOUT,
+        MAX_HELP_PANEL_HEIGHT, MAX_LOG_PANEL_HEIGHT, MAX_NAVIGATION_HISTORY_ENTRIES,
+        MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_SPLIT_VIEW_RATIO,
+        MIN_HELP_PANEL_HEIGHT, MIN_LOG_PANEL_HEIGHT, MIN_NO_BOARDS_PER_PAGE,
+        MIN_NO_CARDS_PER_BOARD, MIN_SPLIT_VIEW_RATIO, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        NAVIGATION_PRESSES_PER_STEP, NAVIGATION_REPEAT_WINDOW, NEW_BOARD_FORM_DEFAULT_STATE,
+        NEW_CARD_FORM_DEFAULT_STATE, RECURRENCE_CHECK_INTERVAL, RESET_UI_CONFIRM_WINDOW,
+        SPLIT_VIEW_RATIO_STEP, STALE_CHECK_INTERVAL, TAG_FILTER_NAME, TOAST_FADE_OUT_TIME,
     },
+    error::KanbanError,
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
+        data_handler::{
+            copy_to_clipboard, expand_path, export_kanban_to_json, get_available_local_savefiles,
+            get_config, get_config_with_warnings, import_config, save_theme, write_config,
+        },
         handler::refresh_visible_boards_and_cards,
         IoEvent,
     },
     ui::{
+        parse_accent_color, parse_custom_color,
         widgets::{CommandPaletteWidget, ToastType, ToastWidget},
         TextColorOptions, TextModifierOptions, Theme,
     },
@@ -24,13 +42,25 @@ use crate::{
 
 use super::{
     actions::Action,
-    date_format_converter, handle_exit,
-    kanban::{Board, Card, CardPriority, CardStatus},
+    board_days_until_due, date_format_converter, date_format_finder, days_until_stale, handle_exit,
+    is_navigation_jump,
+    kanban::{
+        assign_unused_ids, diff_boards, migrate_cards_to_workflow_stages, parse_markdown_checklist,
+        prune_archived_cards, sort_boards_by_done_ratio, Board, Card, CardLink, CardPriority,
+        CardRecurrence, CardStatus,
+    },
+    parse_localized_date, recently_modified_cards, sort_cards_by_due_date,
     state::{AppStatus, Focus, UiMode},
-    App, AppReturn, AppState, DateFormat, MainMenu, MainMenuItem, PopupMode,
+    App, AppReturn, AppState, CardEnterAction, CardIdScheme, ConfirmationStyle, DateFormat,
+    FilterPreset, FocusTimer, FocusTimerPhase, MainMenu, MainMenuItem, PopupMode, QuickFilter,
+    SplitViewPane,
 };
 
 pub fn go_right(app: &mut App) {
+    if app.state.status_column_view {
+        status_column_go_right(app);
+        return;
+    }
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
         &app.boards
@@ -40,9 +70,9 @@ pub fn go_right(app: &mut App) {
     let current_board_id = app.state.current_board_id;
     // check if current_board_id is set, if not assign to the first board
     // check if all_boards is empty, if so, return
-    if boards.is_empty() {
-        error!("Cannot go right: no boards found");
-        app.send_error_toast("Cannot go right: no boards found", None);
+    // there is nothing to move to without boards, the body shows how to create one
+    if boards.is_empty() || current_visible_boards.is_empty() {
+        debug!("Cannot go right: no boards found");
         return;
     }
     let current_board_id = if let Some(current_board_id) = current_board_id {
@@ -54,17 +84,16 @@ pub fn go_right(app: &mut App) {
     let current_board_index = current_visible_boards
         .iter()
         .position(|(board_id, _)| *board_id == current_board_id);
-    if current_board_index.is_none() {
-        debug!("Cannot go right: current board not found, trying to assign to the first board");
-        if current_visible_boards.is_empty() {
-            debug!("Cannot go right: current board not found, no visible boards found");
-            app.send_error_toast("Cannot go right: Something went wrong", None);
+    let current_board_index = match current_board_index {
+        Some(current_board_index) => current_board_index,
+        None => {
+            debug!("Cannot go right: current board not found, assigning the first board");
+            let (first_board_id, first_card_ids) = current_visible_boards.iter().next().unwrap();
+            app.state.current_board_id = Some(*first_board_id);
+            app.state.current_card_id = first_card_ids.first().copied();
             return;
-        } else {
-            app.state.current_board_id = Some(*current_visible_boards.keys().next().unwrap());
         }
-    }
-    let current_board_index = current_board_index.unwrap();
+    };
     if current_board_index == current_visible_boards.len() - 1 {
         // we are at the last board, check the index for the current board in all boards, if it is the last one, we cannot go right
         let current_board_index_in_all_boards =
@@ -76,8 +105,12 @@ pub fn go_right(app: &mut App) {
         }
         let current_board_index_in_all_boards = current_board_index_in_all_boards.unwrap();
         if current_board_index_in_all_boards == boards.len() - 1 {
-            // we are at the last board, we cannot go right
-            app.send_error_toast("Cannot go right: Already at the last board", None);
+            if app.config.wrap_navigation {
+                wrap_to_board(app, false);
+            } else {
+                // we are at the last board, we cannot go right
+                app.send_error_toast("Cannot go right: Already at the last board", None);
+            }
             return;
         }
         // we are not at the last board, we can go right
@@ -126,6 +159,10 @@ pub fn go_right(app: &mut App) {
 }
 
 pub fn go_left(app: &mut App) {
+    if app.state.status_column_view {
+        status_column_go_left(app);
+        return;
+    }
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
         &app.boards
@@ -135,9 +172,9 @@ pub fn go_left(app: &mut App) {
     let current_board_id = app.state.current_board_id;
     // check if current_board_id is set, if not assign to the first board
     // check if all_boards is empty, if so, return
-    if boards.is_empty() {
-        error!("Cannot go left: no boards");
-        app.send_error_toast("Cannot go left: no boards", None);
+    // there is nothing to move to without boards, the body shows how to create one
+    if boards.is_empty() || current_visible_boards.is_empty() {
+        debug!("Cannot go left: no boards");
         return;
     }
     let current_board_id = if let Some(current_board_id) = current_board_id {
@@ -167,7 +204,11 @@ pub fn go_left(app: &mut App) {
         let current_board_index_in_all_boards = current_board_index_in_all_boards.unwrap();
         if current_board_index_in_all_boards == 0 {
             // we are at the first board, we cannot go left
-            app.send_error_toast("Cannot go left: Already at the first board", None);
+            if app.config.wrap_navigation {
+                wrap_to_board(app, true);
+            } else {
+                app.send_error_toast("Cannot go left: Already at the first board", None);
+            }
             return;
         }
         // we are not at the first board, we can go left
@@ -220,6 +261,10 @@ pub fn go_left(app: &mut App) {
 }
 
 pub fn go_up(app: &mut App) {
+    if app.state.status_column_view {
+        status_column_go_up(app);
+        return;
+    }
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let current_board_id = app.state.current_board_id;
     let current_card_id = app.state.current_card_id;
@@ -229,7 +274,7 @@ pub fn go_up(app: &mut App) {
         &app.filtered_boards
     };
     // check if app.board is empty, if so, return
-    if current_visible_boards.is_empty() {
+    if current_visible_boards.is_empty() || boards.is_empty() {
         return;
     }
     let current_board_id = if let Some(current_board_id) = current_board_id {
@@ -258,10 +303,11 @@ pub fn go_up(app: &mut App) {
     let current_card_index = current_visible_boards
         .iter()
         .find(|(board_id, _)| **board_id == current_board_id)
-        .unwrap()
-        .1
-        .iter()
-        .position(|card_id| *card_id == current_card_id);
+        .and_then(|(_, card_ids)| {
+            card_ids
+                .iter()
+                .position(|card_id| *card_id == current_card_id)
+        });
     if current_card_index.is_none() {
         debug!("Cannot go up: current card not found");
         app.send_error_toast("Cannot go up: Something went wrong", None);
@@ -283,8 +329,12 @@ pub fn go_up(app: &mut App) {
         }
         let current_card_index_in_all_cards = current_card_index_in_all_cards.unwrap();
         if current_card_index_in_all_cards == 0 {
-            // we are at the first card, we cannot go up
-            app.send_error_toast("Cannot go up: Already at the first card", None);
+            if app.config.wrap_navigation {
+                wrap_to_card(app, current_board_id, true);
+            } else {
+                // we are at the first card, we cannot go up
+                app.send_error_toast("Cannot go up: Already at the first card", None);
+            }
             return;
         }
         // we are not at the first card, we can go up
@@ -335,6 +385,10 @@ pub fn go_up(app: &mut App) {
 }
 
 pub fn go_down(app: &mut App) {
+    if app.state.status_column_view {
+        status_column_go_down(app);
+        return;
+    }
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let current_board_id = app.state.current_board_id;
     let current_card_id = app.state.current_card_id;
@@ -344,7 +398,7 @@ pub fn go_down(app: &mut App) {
         &app.filtered_boards
     };
     // check if app.board is empty, if so, return
-    if current_visible_boards.is_empty() {
+    if current_visible_boards.is_empty() || boards.is_empty() {
         return;
     }
     let current_board_id = if let Some(current_board_id) = current_board_id {
@@ -365,9 +419,10 @@ pub fn go_down(app: &mut App) {
                 app.send_error_toast("Cannot go down: Something went wrong", None);
                 return;
             } else {
-                app.state.current_board_id = Some(*current_visible_boards.keys().next().unwrap());
-                app.state.current_card_id =
-                    Some(current_visible_boards.values().next().unwrap()[0]);
+                let (first_board_id, first_card_ids) =
+                    current_visible_boards.iter().next().unwrap();
+                app.state.current_board_id = Some(*first_board_id);
+                app.state.current_card_id = first_card_ids.first().copied();
                 return;
             }
         }
@@ -382,10 +437,11 @@ pub fn go_down(app: &mut App) {
     let current_card_index = current_visible_boards
         .iter()
         .find(|(board_id, _)| **board_id == current_board_id)
-        .unwrap()
-        .1
-        .iter()
-        .position(|card_id| *card_id == current_card_id);
+        .and_then(|(_, card_ids)| {
+            card_ids
+                .iter()
+                .position(|card_id| *card_id == current_card_id)
+        });
     if current_card_index.is_none() {
         debug!("Cannot go down: current card not found");
         app.send_error_toast("Cannot go down: Something went wrong", None);
@@ -415,8 +471,12 @@ pub fn go_down(app: &mut App) {
                 .len()
                 - 1
         {
-            // we are at the last card, we cannot go down
-            app.send_error_toast("Cannot go down: Already at the last card", None);
+            if app.config.wrap_navigation {
+                wrap_to_card(app, current_board_id, false);
+            } else {
+                // we are at the last card, we cannot go down
+                app.send_error_toast("Cannot go down: Already at the last card", None);
+            }
             return;
         }
         // we are not at the last card, we can go down
@@ -493,164 +553,1015 @@ pub fn go_down(app: &mut App) {
         // check if next_card_id is not 0
         if next_card_id != 0 {
             app.state.current_card_id = Some(next_card_id);
+        } else if app.config.wrap_navigation {
+            // all the cards of the board fit on one page, so this is the last card
+            wrap_to_card(app, current_board_id, false);
         }
     }
 }
 
-pub fn prepare_config_for_new_app(state: &mut AppState, theme: Theme) -> AppConfig {
-    let get_config_status = get_config(false);
-    if let Err(config_error_msg) = get_config_status {
-        if config_error_msg.contains("Overlapped keybinds found") {
-            error!("Keybinds overlap detected. Please check your config file and fix the keybinds. Using default keybinds for now.");
-            state.toasts.push(ToastWidget::new(
-                config_error_msg,
-                Duration::from_secs(DEFAULT_TOAST_DURATION) * 3,
-                ToastType::Error,
-                theme.clone(),
-            ));
-            state.toasts.push(ToastWidget::new("Please check your config file and fix the keybinds. Using default keybinds for now.".to_owned(),
-                Duration::from_secs(DEFAULT_TOAST_DURATION), ToastType::Warning, theme.clone()));
-            let new_config = get_config(true);
-            if let Err(new_config_error) = new_config {
-                error!("Unable to fix keybinds. Please check your config file. Using default config for now.");
-                state.toasts.push(ToastWidget::new(
-                    new_config_error,
-                    Duration::from_secs(DEFAULT_TOAST_DURATION) * 3,
-                    ToastType::Error,
-                    theme.clone(),
-                ));
-                state.toasts.push(ToastWidget::new(
-                    "Using default config for now.".to_owned(),
-                    Duration::from_secs(DEFAULT_TOAST_DURATION),
-                    ToastType::Warning,
-                    theme,
+/// Shows the first (or last) page of boards and selects the first (or last) board,
+/// used when board navigation wraps around
+fn wrap_to_board(app: &mut App, to_last: bool) {
+    let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    if boards.is_empty() {
+        return;
+    }
+    let no_of_boards_to_show = app.config.no_of_boards_to_show as usize;
+    let no_of_cards_to_show = app.config.no_of_cards_to_show as usize;
+    let start_index = if to_last {
+        boards.len().saturating_sub(no_of_boards_to_show)
+    } else {
+        0
+    };
+    let mut visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>> = LinkedHashMap::new();
+    for board in boards.iter().skip(start_index).take(no_of_boards_to_show) {
+        let visible_cards = board
+            .cards
+            .iter()
+            .take(no_of_cards_to_show)
+            .map(|card| card.id)
+            .collect::<Vec<u128>>();
+        visible_boards_and_cards.insert(board.id, visible_cards);
+    }
+    let selected_board = if to_last {
+        visible_boards_and_cards.iter().last()
+    } else {
+        visible_boards_and_cards.iter().next()
+    };
+    if let Some((board_id, card_ids)) = selected_board {
+        app.state.current_board_id = Some(*board_id);
+        app.state.current_card_id = card_ids.first().copied();
+    }
+    app.visible_boards_and_cards = visible_boards_and_cards;
+}
+
+/// Switches to the log variant of the current view with the log focused, pressing it again
+/// restores the exact view and focus from before
+fn handle_toggle_log_panel(app: &mut App) {
+    let current_mode = app.state.ui_mode;
+    let log_mode = if let Some(log_mode) = current_mode.with_log_panel() {
+        log_mode
+    } else {
+        app.send_warning_toast("The log panel can only be shown in the board views", None);
+        return;
+    };
+    if let Some((return_mode, return_focus)) = app.state.log_panel_return.take() {
+        // the view may have been changed by hand since the log panel was opened
+        if current_mode == log_mode {
+            app.set_ui_mode(return_mode);
+            if return_mode.get_available_targets().contains(&return_focus) {
+                app.state.focus = return_focus;
+            }
+            info!("Closed the log panel");
+            return;
+        }
+    }
+    if current_mode == log_mode {
+        // the view already shows the log, only move the focus to it and back
+        app.state.focus = if app.state.focus == Focus::Log {
+            Focus::Body
+        } else {
+            Focus::Log
+        };
+        return;
+    }
+    app.state.log_panel_return = Some((current_mode, app.state.focus));
+    app.set_ui_mode(log_mode);
+    app.state.focus = Focus::Log;
+    info!("Opened the log panel");
+}
+
+/// Starts the focus timer for the current card, or stops the running timer and logs the whole
+/// minutes worked so far
+fn handle_toggle_focus_timer(app: &mut App) {
+    if let Some(focus_timer) = app.state.focus_timer.take() {
+        if focus_timer.phase == FocusTimerPhase::Work {
+            let minutes = (focus_timer.started_at.elapsed().as_secs() / 60) as u32;
+            if minutes > 0 {
+                log_minutes_on_card(app, focus_timer.card_id, minutes);
+            }
+            info!(
+                "Stopped focus timer for '{}' after {} minutes",
+                focus_timer.card_name, minutes
+            );
+            app.send_info_toast(
+                &format!(
+                    "Stopped focus timer, logged {} minutes on '{}'",
+                    minutes, focus_timer.card_name
+                ),
+                None,
+            );
+        } else {
+            app.send_info_toast("Skipped the rest of the break", None);
+        }
+        return;
+    }
+    let current_card = app
+        .boards
+        .iter()
+        .find(|board| Some(board.id) == app.state.current_board_id)
+        .and_then(|board| board.get_card(app.state.current_card_id.unwrap_or(0)))
+        .map(|card| (card.id, card.name.clone()));
+    let (card_id, card_name) = if let Some(current_card) = current_card {
+        current_card
+    } else {
+        app.send_warning_toast("Select a card to start the focus timer", None);
+        return;
+    };
+    let work_minutes = app.config.focus_timer_work_minutes.max(1);
+    info!(
+        "Started a {} minute focus timer for '{}'",
+        work_minutes, card_name
+    );
+    app.send_info_toast(
+        &format!(
+            "Started a {} minute focus timer for '{}'",
+            work_minutes, card_name
+        ),
+        None,
+    );
+    app.state.focus_timer = Some(FocusTimer::new(
+        card_id,
+        card_name,
+        FocusTimerPhase::Work,
+        work_minutes,
+    ));
+}
+
+/// Moves the focus timer to its next phase once the current one is over, called on every tick
+pub fn update_focus_timer(app: &mut App) {
+    let focus_timer = match &app.state.focus_timer {
+        Some(focus_timer) if focus_timer.is_finished() => focus_timer.clone(),
+        _ => return,
+    };
+    match focus_timer.phase {
+        FocusTimerPhase::Work => {
+            let minutes = (focus_timer.duration.as_secs() / 60) as u32;
+            log_minutes_on_card(app, focus_timer.card_id, minutes);
+            let break_minutes = app.config.focus_timer_break_minutes;
+            info!(
+                "Focus session on '{}' done, logged {} minutes",
+                focus_timer.card_name, minutes
+            );
+            if break_minutes > 0 {
+                app.send_info_toast(
+                    &format!(
+                        "Focus session done, logged {} minutes on '{}'. Take a {} minute break",
+                        minutes, focus_timer.card_name, break_minutes
+                    ),
+                    Some(Duration::from_secs(10)),
+                );
+                app.state.focus_timer = Some(FocusTimer::new(
+                    focus_timer.card_id,
+                    focus_timer.card_name,
+                    FocusTimerPhase::Break,
+                    break_minutes,
                 ));
-                AppConfig::default()
             } else {
-                let mut unwrapped_new_config = new_config.unwrap();
-                unwrapped_new_config.keybindings = KeyBindings::default();
-                unwrapped_new_config
+                app.send_info_toast(
+                    &format!(
+                        "Focus session done, logged {} minutes on '{}'",
+                        minutes, focus_timer.card_name
+                    ),
+                    Some(Duration::from_secs(10)),
+                );
+                app.state.focus_timer = None;
             }
-        } else {
-            state.toasts.push(ToastWidget::new(
-                config_error_msg,
-                Duration::from_secs(DEFAULT_TOAST_DURATION),
-                ToastType::Error,
-                theme.clone(),
-            ));
-            state.toasts.push(ToastWidget::new(
-                "Using default config for now.".to_owned(),
-                Duration::from_secs(DEFAULT_TOAST_DURATION),
-                ToastType::Info,
-                theme,
-            ));
-            AppConfig::default()
         }
+        FocusTimerPhase::Break => {
+            info!("Focus timer break is over");
+            app.send_info_toast("Break is over", Some(Duration::from_secs(10)));
+            app.state.focus_timer = None;
+        }
+    }
+}
+
+/// Adds minutes to the time spent on a card wherever it is now, recorded as an undoable edit
+fn log_minutes_on_card(app: &mut App, card_id: u128, minutes: u32) {
+    let board = app
+        .boards
+        .iter_mut()
+        .find(|board| board.cards.iter().any(|card| card.id == card_id));
+    let board = if let Some(board) = board {
+        board
+    } else {
+        warn!("Could not log focus time, the card no longer exists");
+        app.send_warning_toast("Could not log focus time, the card no longer exists", None);
+        return;
+    };
+    let board_id = board.id;
+    let card = if let Some(card) = board.cards.iter_mut().find(|card| card.id == card_id) {
+        card
     } else {
-        get_config_status.unwrap()
+        return;
+    };
+    let old_card = card.clone();
+    card.minutes_spent += minutes;
+    card.date_modified = Utc::now().to_string();
+    let new_card = card.clone();
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .iter_mut()
+        .flat_map(|board| board.cards.iter_mut())
+        .find(|card| card.id == card_id)
+    {
+        *filtered_card = new_card.clone();
     }
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, new_card, board_id));
 }
 
-pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
-    // append to current user input if key is not enter else change state to Initialized
-    reset_mouse(app);
-    if key == Key::Esc {
-        // cancelling changes
-        match app.state.focus {
-            Focus::NewBoardName => app.state.new_board_form[0] = "".to_string(),
-            Focus::NewBoardDescription => app.state.new_board_form[1] = "".to_string(),
-            Focus::CardName => app.state.new_card_form[0] = "".to_string(),
-            Focus::CardDescription => app.state.new_card_form[1] = "".to_string(),
-            Focus::CardDueDate => app.state.new_card_form[2] = "".to_string(),
-            _ => app.state.current_user_input = "".to_string(),
+/// Moves the due dates of cards that repeat on the calendar forward once they have passed, called
+/// on every tick but only checks once every RECURRENCE_CHECK_INTERVAL
+pub fn advance_recurring_due_dates(app: &mut App) {
+    if let Some(last_check) = app.state.last_recurrence_check {
+        if last_check.elapsed() < Duration::from_secs(RECURRENCE_CHECK_INTERVAL) {
+            return;
         }
-        if app.state.popup_mode.is_some() {
-            match app.state.popup_mode.unwrap() {
-                PopupMode::CommandPalette => {
-                    app.state.popup_mode = None;
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                PopupMode::ConfirmDiscardCardChanges => {
-                    if app.card_being_edited.is_some() {
-                        warn!(
-                            "Discarding changes to card '{}'",
-                            app.card_being_edited.as_ref().unwrap().1.name
-                        );
-                        app.send_warning_toast(
-                            &format!(
-                                "Discarding changes to card '{}'",
-                                app.card_being_edited.as_ref().unwrap().1.name
-                            ),
-                            None,
-                        );
-                    }
-                    app.state.popup_mode = None;
-                    app.card_being_edited = None;
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                PopupMode::ViewCard => {
-                    if app.card_being_edited.is_some() {
-                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
-                    }
-                }
-                PopupMode::CardPrioritySelector => {
-                    if app.card_being_edited.is_some() {
-                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
-                    } else {
-                        app.state.popup_mode = None;
-                    }
-                }
-                PopupMode::CardStatusSelector => {
-                    if app.card_being_edited.is_some() {
-                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
-                    } else {
-                        app.state.popup_mode = None;
-                    }
+    }
+    app.state.last_recurrence_check = Some(Instant::now());
+    let now = Local::now().naive_local();
+    let mut advanced_cards = Vec::new();
+    for board in app.boards.iter_mut().chain(app.filtered_boards.iter_mut()) {
+        for card in board.cards.iter_mut() {
+            let recurrence = if let Some(recurrence) = card.recurrence {
+                recurrence
+            } else {
+                continue;
+            };
+            if let Some((next_due_date, anchor_day)) =
+                next_recurring_due_date(card, recurrence, now)
+            {
+                card.date_due = next_due_date;
+                card.recurrence_day = anchor_day;
+                card.date_modified = Utc::now().to_string();
+                if !advanced_cards.contains(&card.id) {
+                    advanced_cards.push(card.id);
                 }
-                _ => {}
             }
         }
-        app.state.app_status = AppStatus::Initialized;
-        app.state.current_cursor_position = None;
-        info!("Exiting user input mode");
-    } else {
-        // parsing keys to account for actions
+    }
+    if !advanced_cards.is_empty() {
+        info!(
+            "Moved the due date of {} repeating cards forward",
+            advanced_cards.len()
+        );
+    }
+}
 
-        if app.config.keybindings.toggle_command_palette.contains(&key) {
-            app.state.app_status = AppStatus::Initialized;
-            app.state.popup_mode = None;
-            return AppReturn::Continue;
+/// True once every `auto_save_interval_secs` while the boards have unsaved changes, the interval
+/// starts on the first tick after launch or after the interval is changed
+pub fn take_periodic_save_due(app: &mut App) -> bool {
+    let interval = match app.config.auto_save_interval_secs {
+        Some(interval) if interval > 0 => interval,
+        _ => return false,
+    };
+    match app.state.last_periodic_save {
+        Some(last_save) if last_save.elapsed() < Duration::from_secs(interval) => return false,
+        Some(_) => {}
+        None => {
+            app.state.last_periodic_save = Some(Instant::now());
+            return false;
         }
-        if app.state.popup_mode.is_some() {
-            let stop_input_mode_keys = &app.config.keybindings.stop_user_input;
-            match app.state.popup_mode.unwrap() {
-                PopupMode::CommandPalette => match key {
-                    Key::Up => {
-                        match app.state.focus {
-                            Focus::CommandPaletteCommand => {
-                                app.command_palette_command_search_prv();
-                            }
-                            Focus::CommandPaletteCard => {
-                                app.command_palette_card_search_prv();
-                            }
-                            Focus::CommandPaletteBoard => {
-                                app.command_palette_board_search_prv();
-                            }
-                            _ => {}
-                        }
-                        return AppReturn::Continue;
-                    }
-                    Key::Down => {
-                        match app.state.focus {
-                            Focus::CommandPaletteCommand => {
-                                app.command_palette_command_search_next();
-                            }
-                            Focus::CommandPaletteCard => {
-                                app.command_palette_card_search_next();
-                            }
-                            Focus::CommandPaletteBoard => {
-                                app.command_palette_board_search_next();
-                            }
+    }
+    app.state.last_periodic_save = Some(Instant::now());
+    app.action_history_manager.changes_since_save > 0
+}
+
+/// Marks active cards that were not changed for `stale_after_days` as stale, called on every tick
+/// but only checks once every STALE_CHECK_INTERVAL. The cards marked in one check are undone
+/// together
+pub fn mark_stale_cards(app: &mut App) {
+    if app.config.stale_after_days == 0 {
+        return;
+    }
+    if let Some(last_check) = app.state.last_stale_check {
+        if last_check.elapsed() < Duration::from_secs(STALE_CHECK_INTERVAL) {
+            return;
+        }
+    }
+    app.state.last_stale_check = Some(Instant::now());
+    let now = Utc::now().naive_utc();
+    let stale_after_days = app.config.stale_after_days;
+    let mut stale_cards = Vec::new();
+    let mut marked_cards = Vec::new();
+    for board in app.boards.iter_mut() {
+        for card in board.cards.iter_mut() {
+            if !days_until_stale(card, stale_after_days, now).map_or(false, |days| days <= 0) {
+                continue;
+            }
+            let old_card = card.clone();
+            card.card_status = CardStatus::Stale;
+            card.sync_workflow_stage(&app.config.workflow_stages);
+            stale_cards.push(ActionHistory::EditCard(old_card, card.clone(), board.id));
+            marked_cards.push(card.clone());
+        }
+    }
+    if stale_cards.is_empty() {
+        return;
+    }
+    for filtered_board in app.filtered_boards.iter_mut() {
+        for card in filtered_board.cards.iter_mut() {
+            if let Some(stale_card) = marked_cards.iter().find(|marked| marked.id == card.id) {
+                *card = stale_card.clone();
+            }
+        }
+    }
+    let stale_count = stale_cards.len();
+    app.action_history_manager
+        .new_action(ActionHistory::CompoundOperation(
+            format!("Marked {} cards as stale", stale_count),
+            stale_cards,
+        ));
+    refresh_visible_boards_and_cards(app);
+    info!(
+        "Marked {} cards as stale after {} days without changes",
+        stale_count, stale_after_days
+    );
+    app.send_info_toast(
+        &format!(
+            "Marked {} cards as stale after {} days without changes",
+            stale_count, stale_after_days
+        ),
+        None,
+    );
+}
+
+/// The next due date of the card after `now` in the same format as its due date and the day of
+/// the month it repeats on, None when the due date has not passed or cannot be read
+fn next_recurring_due_date(
+    card: &Card,
+    recurrence: CardRecurrence,
+    now: NaiveDateTime,
+) -> Option<(String, u32)> {
+    let date_due = card.date_due.trim();
+    let date_format = date_format_finder(date_due).ok()?;
+    let parser_string = date_format.to_parser_string();
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        let due_date = NaiveDateTime::parse_from_str(date_due, parser_string).ok()?;
+        let anchor_day = card.recurrence_anchor_day(due_date.date());
+        let next_due_date = recurrence.advance_past(due_date, now, anchor_day)?;
+        Some((next_due_date.format(parser_string).to_string(), anchor_day))
+    } else {
+        // a due date without a time is due for the whole day
+        let due_date = NaiveDate::parse_from_str(date_due, parser_string)
+            .ok()?
+            .and_hms_opt(0, 0, 0)?;
+        let anchor_day = card.recurrence_anchor_day(due_date.date());
+        let today = now.date().and_hms_opt(0, 0, 0)?;
+        let next_due_date = recurrence.advance_past(due_date, today, anchor_day)?;
+        Some((
+            next_due_date.date().format(parser_string).to_string(),
+            anchor_day,
+        ))
+    }
+}
+
+fn handle_card_recurrence_selection(app: &mut App) {
+    // index 0 is "Not Set", the rest map onto CardRecurrence::all
+    let selected_index = app.state.card_recurrence_list_state.selected().unwrap_or(0);
+    let selected_recurrence = if selected_index == 0 {
+        None
+    } else {
+        CardRecurrence::all().get(selected_index - 1).copied()
+    };
+    app.state.popup_mode = None;
+    let current_card_id = app.state.current_card_id.unwrap_or(0);
+    let board = app
+        .boards
+        .iter_mut()
+        .find(|board| Some(board.id) == app.state.current_board_id);
+    let (board_id, card) = if let Some(board) = board {
+        let board_id = board.id;
+        match board
+            .cards
+            .iter_mut()
+            .find(|card| card.id == current_card_id)
+        {
+            Some(card) => (board_id, card),
+            None => {
+                app.send_error_toast("Error Could not find current card", None);
+                return;
+            }
+        }
+    } else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    let old_card = card.clone();
+    card.recurrence = selected_recurrence;
+    card.date_modified = Utc::now().to_string();
+    let new_card = card.clone();
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .iter_mut()
+        .flat_map(|board| board.cards.iter_mut())
+        .find(|card| card.id == current_card_id)
+    {
+        *filtered_card = new_card.clone();
+    }
+    let message = match selected_recurrence {
+        Some(recurrence) => {
+            if new_card.date_due == FIELD_NOT_SET {
+                app.send_warning_toast(
+                    "The card has no due date, it repeats once a due date is set",
+                    None,
+                );
+            }
+            format!(
+                "'{}' now repeats {}",
+                new_card.name,
+                recurrence.to_string().to_lowercase()
+            )
+        }
+        None => format!("'{}' no longer repeats", new_card.name),
+    };
+    info!("{}", message);
+    app.send_info_toast(&message, None);
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, new_card, board_id));
+    // an overdue card moves to its next due date straight away
+    app.state.last_recurrence_check = None;
+    advance_recurring_due_dates(app);
+}
+
+/// Grows or shrinks the focused panel and saves the new size in the config. Growing the body
+/// widens the active pane in split view and otherwise shrinks the log or help panel below it
+fn handle_resize_panel(app: &mut App, grow: bool) {
+    let available_targets = app.state.ui_mode.get_available_targets();
+    let (config_key, current, min, max, step, grow) = match app.state.focus {
+        Focus::Help => (
+            "Help Panel Height",
+            app.config.help_panel_height,
+            MIN_HELP_PANEL_HEIGHT,
+            MAX_HELP_PANEL_HEIGHT,
+            1,
+            grow,
+        ),
+        Focus::Log => (
+            "Log Panel Height",
+            app.config.log_panel_height,
+            MIN_LOG_PANEL_HEIGHT,
+            MAX_LOG_PANEL_HEIGHT,
+            1,
+            grow,
+        ),
+        Focus::Body if app.state.split_view.is_some() => {
+            // the ratio is the width of the left pane, the active pane is on the left when the
+            // inactive one is not
+            let active_pane_is_left = app
+                .state
+                .split_view
+                .as_ref()
+                .map(|pane| !pane.is_left)
+                .unwrap_or(true);
+            (
+                "Split View Ratio",
+                app.config.split_view_ratio,
+                MIN_SPLIT_VIEW_RATIO,
+                MAX_SPLIT_VIEW_RATIO,
+                SPLIT_VIEW_RATIO_STEP,
+                grow == active_pane_is_left,
+            )
+        }
+        Focus::Body if available_targets.contains(&Focus::Log) => (
+            "Log Panel Height",
+            app.config.log_panel_height,
+            MIN_LOG_PANEL_HEIGHT,
+            MAX_LOG_PANEL_HEIGHT,
+            1,
+            !grow,
+        ),
+        Focus::Body if available_targets.contains(&Focus::Help) => (
+            "Help Panel Height",
+            app.config.help_panel_height,
+            MIN_HELP_PANEL_HEIGHT,
+            MAX_HELP_PANEL_HEIGHT,
+            1,
+            !grow,
+        ),
+        _ => {
+            app.send_warning_toast(
+                "Focus the body, help or log panel in a view with more than one panel to resize it",
+                None,
+            );
+            return;
+        }
+    };
+    let new_value = if grow {
+        current.saturating_add(step).min(max)
+    } else {
+        current.saturating_sub(step).max(min)
+    };
+    if new_value == current {
+        app.send_info_toast(
+            &format!(
+                "The panel is already as {} as it can be",
+                if grow { "large" } else { "small" }
+            ),
+            None,
+        );
+        return;
+    }
+    let config_string = format!("{}: {}", config_key, new_value);
+    let app_config = AppConfig::edit_with_string(&config_string, app);
+    app.config = app_config.clone();
+    if let Err(e) = write_config(&app_config) {
+        error!("Error writing config file: {}", e);
+        app.send_error_toast(&format!("Error writing config file: {}", e), None);
+    }
+}
+
+/// Removes every card from the current board as a single undoable action
+fn handle_clear_board(app: &mut App) {
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_warning_toast("No board selected", None);
+        return;
+    };
+    let board = if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+        board
+    } else {
+        app.send_error_toast("Error Could not find current board", None);
+        return;
+    };
+    if board.cards.is_empty() {
+        app.send_warning_toast(&format!("Board '{}' has no cards", board.name), None);
+        return;
+    }
+    let board_name = board.name.clone();
+    let removed_cards = std::mem::take(&mut board.cards);
+    let removed_count = removed_cards.len();
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        filtered_board.cards.clear();
+    }
+    app.state
+        .selected_card_ids
+        .retain(|card_id| !removed_cards.iter().any(|card| card.id == *card_id));
+    if let Some(card_ids) = app.visible_boards_and_cards.get_mut(&current_board_id) {
+        card_ids.clear();
+    }
+    app.state.current_card_id = None;
+    app.action_history_manager
+        .new_action(ActionHistory::ClearBoard(removed_cards, current_board_id));
+    warn!("Removed {} cards from board: {}", removed_count, board_name);
+    app.send_warning_toast(
+        &format!(
+            "Removed {} cards from board '{}'",
+            removed_count, board_name
+        ),
+        None,
+    );
+}
+
+/// Exports every board to JSON and then removes all cards from all boards as a single undoable
+/// action, the boards themselves are kept. Has to be run twice within
+/// CLEAR_ALL_BOARDS_CONFIRM_WINDOW, nothing is cleared when the export fails
+pub fn handle_export_and_clear_all_boards(app: &mut App) {
+    let card_count = app
+        .boards
+        .iter()
+        .map(|board| board.cards.len())
+        .sum::<usize>();
+    if card_count == 0 {
+        app.send_warning_toast("There are no cards to clear", None);
+        return;
+    }
+    let confirm_window = Duration::from_millis(CLEAR_ALL_BOARDS_CONFIRM_WINDOW);
+    let confirmed = app
+        .state
+        .clear_all_boards_requested_at
+        .map_or(false, |requested_at| {
+            requested_at.elapsed() < confirm_window
+        });
+    if !confirmed {
+        app.state.clear_all_boards_requested_at = Some(Instant::now());
+        app.send_warning_toast(
+            &format!(
+                "Run the command again to export and clear {} cards from {} boards",
+                card_count,
+                app.boards.len()
+            ),
+            Some(confirm_window),
+        );
+        return;
+    }
+    app.state.clear_all_boards_requested_at = None;
+    let export_path = match export_kanban_to_json(&app.boards) {
+        Ok(export_path) => export_path,
+        Err(e) => {
+            error!("Failed to export JSON, boards were not cleared: {}", e);
+            app.send_error_toast(
+                &format!("Failed to export JSON, boards were not cleared: {}", e),
+                None,
+            );
+            return;
+        }
+    };
+    info!("Exported JSON to {}", export_path);
+    app.send_info_toast(&format!("Exported JSON to {}", export_path), None);
+    let cleared_boards = app
+        .boards
+        .iter_mut()
+        .filter(|board| !board.cards.is_empty())
+        .map(|board| (std::mem::take(&mut board.cards), board.id))
+        .collect::<Vec<(Vec<Card>, u128)>>();
+    for filtered_board in app.filtered_boards.iter_mut() {
+        filtered_board.cards.clear();
+    }
+    for card_ids in app.visible_boards_and_cards.values_mut() {
+        card_ids.clear();
+    }
+    app.state.selected_card_ids.clear();
+    app.state.current_card_id = None;
+    let board_count = cleared_boards.len();
+    app.action_history_manager
+        .new_action(ActionHistory::CompoundOperation(
+            format!(
+                "Removed all {} cards from {} boards",
+                card_count, board_count
+            ),
+            cleared_boards
+                .into_iter()
+                .map(|(cards, board_id)| ActionHistory::ClearBoard(cards, board_id))
+                .collect(),
+        ));
+    warn!("Removed {} cards from {} boards", card_count, board_count);
+    app.send_warning_toast(
+        &format!("Removed {} cards from {} boards", card_count, board_count),
+        None,
+    );
+}
+
+/// Splits the body to show the board next to the current one in a second pane, or closes the split
+fn toggle_split_view(app: &mut App) {
+    if app.state.split_view.is_some() {
+        app.state.split_view = None;
+        info!("Closed split view");
+        app.send_info_toast("Closed split view", None);
+        return;
+    }
+    if app.state.status_column_view {
+        app.send_warning_toast(
+            "Split view is not available in the status column view",
+            None,
+        );
+        return;
+    }
+    let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    if boards.len() < 2 {
+        app.send_warning_toast("Split view needs at least two boards", None);
+        return;
+    }
+    let current_board_index = app
+        .state
+        .current_board_id
+        .and_then(|current_board_id| boards.iter().position(|board| board.id == current_board_id));
+    let current_board_index = if let Some(current_board_index) = current_board_index {
+        current_board_index
+    } else {
+        app.send_warning_toast("Select a board to split the view", None);
+        return;
+    };
+    let other_board_index = if current_board_index + 1 < boards.len() {
+        current_board_index + 1
+    } else {
+        current_board_index - 1
+    };
+    let other_board = &boards[other_board_index];
+    let visible_card_ids = other_board
+        .cards
+        .iter()
+        .take(app.config.no_of_cards_to_show as usize)
+        .map(|card| card.id)
+        .collect::<Vec<u128>>();
+    let other_board_name = other_board.name.clone();
+    app.state.split_view = Some(SplitViewPane {
+        board_id: other_board.id,
+        card_id: visible_card_ids.first().copied(),
+        visible_card_ids,
+        is_left: other_board_index < current_board_index,
+    });
+    app.state.focus = Focus::Body;
+    info!("Opened split view with board '{}'", other_board_name);
+    app.send_info_toast(
+        &format!("Opened split view with board '{}'", other_board_name),
+        None,
+    );
+}
+
+/// Moves navigation to the other pane of the split view, the pane that is left keeps its board
+/// and selected card
+fn switch_split_view_pane(app: &mut App) {
+    let inactive_pane = if let Some(inactive_pane) = app.state.split_view.clone() {
+        inactive_pane
+    } else {
+        app.send_warning_toast("Split view is not open", None);
+        return;
+    };
+    let no_of_boards_to_show = app.config.no_of_boards_to_show as usize;
+    let no_of_cards_to_show = app.config.no_of_cards_to_show as usize;
+    let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board_index = boards
+        .iter()
+        .position(|board| board.id == inactive_pane.board_id);
+    let board_index = if let Some(board_index) = board_index {
+        board_index
+    } else {
+        app.state.split_view = None;
+        app.send_warning_toast(
+            "The board in the other pane is no longer shown, closed split view",
+            None,
+        );
+        return;
+    };
+    let board = &boards[board_index];
+    // cards may have been moved or deleted while the pane was inactive
+    let mut visible_card_ids = inactive_pane
+        .visible_card_ids
+        .iter()
+        .filter(|card_id| board.cards.iter().any(|card| card.id == **card_id))
+        .copied()
+        .collect::<Vec<u128>>();
+    if visible_card_ids.is_empty() {
+        visible_card_ids = board
+            .cards
+            .iter()
+            .take(no_of_cards_to_show)
+            .map(|card| card.id)
+            .collect();
+    }
+    let card_id = inactive_pane
+        .card_id
+        .filter(|card_id| visible_card_ids.contains(card_id))
+        .or_else(|| visible_card_ids.first().copied());
+    // navigation only works on visible boards, so show a page that starts with the board
+    let page_of_boards = if app.visible_boards_and_cards.contains_key(&board.id) {
+        None
+    } else {
+        let start_index = board_index.min(boards.len().saturating_sub(no_of_boards_to_show));
+        let mut visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>> = LinkedHashMap::new();
+        for board in boards.iter().skip(start_index).take(no_of_boards_to_show) {
+            let visible_cards = board
+                .cards
+                .iter()
+                .take(no_of_cards_to_show)
+                .map(|card| card.id)
+                .collect::<Vec<u128>>();
+            visible_boards_and_cards.insert(board.id, visible_cards);
+        }
+        Some(visible_boards_and_cards)
+    };
+    let active_pane = app.state.current_board_id.map(|board_id| SplitViewPane {
+        board_id,
+        card_id: app.state.current_card_id,
+        visible_card_ids: app
+            .visible_boards_and_cards
+            .get(&board_id)
+            .cloned()
+            .unwrap_or_default(),
+        is_left: !inactive_pane.is_left,
+    });
+    if let Some(page_of_boards) = page_of_boards {
+        app.visible_boards_and_cards = page_of_boards;
+    }
+    if let Some(card_ids) = app
+        .visible_boards_and_cards
+        .get_mut(&inactive_pane.board_id)
+    {
+        *card_ids = visible_card_ids;
+    }
+    app.state.current_board_id = Some(inactive_pane.board_id);
+    app.state.current_card_id = card_id;
+    app.state.split_view = active_pane;
+    app.state.focus = Focus::Body;
+}
+
+/// Shows the first (or last) page of cards of a board and selects the first (or last) card,
+/// used when card navigation wraps around
+fn wrap_to_card(app: &mut App, board_id: u128, to_last: bool) {
+    let boards: &Vec<Board> = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board = if let Some(board) = boards.iter().find(|board| board.id == board_id) {
+        board
+    } else {
+        return;
+    };
+    if board.cards.is_empty() {
+        return;
+    }
+    let no_of_cards_to_show = app.config.no_of_cards_to_show as usize;
+    let start_index = if to_last {
+        board.cards.len().saturating_sub(no_of_cards_to_show)
+    } else {
+        0
+    };
+    let visible_cards = board
+        .cards
+        .iter()
+        .skip(start_index)
+        .take(no_of_cards_to_show)
+        .map(|card| card.id)
+        .collect::<Vec<u128>>();
+    app.state.current_card_id = if to_last {
+        visible_cards.last().copied()
+    } else {
+        visible_cards.first().copied()
+    };
+    app.visible_boards_and_cards
+        .entry(board_id)
+        .and_modify(|cards| *cards = visible_cards);
+}
+
+pub fn prepare_config_for_new_app(state: &mut AppState, theme: Theme) -> AppConfig {
+    if state.safe_mode {
+        info!("Safe mode, not reading the config file");
+        return AppConfig::default();
+    }
+    let get_config_status = get_config_with_warnings(false);
+    if let Err(config_error) = get_config_status {
+        if let KanbanError::Overlap(_) = config_error {
+            error!("Keybinds overlap detected. Please check your config file and fix the keybinds. Using default keybinds for now.");
+            state.toasts.push(ToastWidget::new(
+                config_error.to_string(),
+                Duration::from_secs(DEFAULT_TOAST_DURATION) * 3,
+                ToastType::Error,
+                theme.clone(),
+            ));
+            state.toasts.push(ToastWidget::new("Please check your config file and fix the keybinds. Using default keybinds for now.".to_owned(),
+                Duration::from_secs(DEFAULT_TOAST_DURATION), ToastType::Warning, theme.clone()));
+            let new_config = get_config(true);
+            if let Err(new_config_error) = new_config {
+                error!("Unable to fix keybinds. Please check your config file. Using default config for now.");
+                state.toasts.push(ToastWidget::new(
+                    new_config_error.to_string(),
+                    Duration::from_secs(DEFAULT_TOAST_DURATION) * 3,
+                    ToastType::Error,
+                    theme.clone(),
+                ));
+                state.toasts.push(ToastWidget::new(
+                    "Using default config for now.".to_owned(),
+                    Duration::from_secs(DEFAULT_TOAST_DURATION),
+                    ToastType::Warning,
+                    theme,
+                ));
+                AppConfig::default()
+            } else {
+                let mut unwrapped_new_config = new_config.unwrap();
+                unwrapped_new_config.keybindings = KeyBindings::default();
+                unwrapped_new_config
+            }
+        } else {
+            state.toasts.push(ToastWidget::new(
+                config_error.to_string(),
+                Duration::from_secs(DEFAULT_TOAST_DURATION),
+                ToastType::Error,
+                theme.clone(),
+            ));
+            state.toasts.push(ToastWidget::new(
+                "Using default config for now.".to_owned(),
+                Duration::from_secs(DEFAULT_TOAST_DURATION),
+                ToastType::Info,
+                theme,
+            ));
+            AppConfig::default()
+        }
+    } else {
+        let (config, warnings) = get_config_status.unwrap();
+        for warning in warnings {
+            warn!("{}", warning);
+            state.toasts.push(ToastWidget::new(
+                warning,
+                Duration::from_secs(DEFAULT_TOAST_DURATION) * 2,
+                ToastType::Warning,
+                theme.clone(),
+            ));
+        }
+        config
+    }
+}
+
+pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
+    // append to current user input if key is not enter else change state to Initialized
+    reset_mouse(app);
+    if key == Key::Esc {
+        // cancelling changes
+        match app.state.focus {
+            Focus::NewBoardName => app.state.new_board_form[0] = "".to_string(),
+            Focus::NewBoardDescription => app.state.new_board_form[1] = "".to_string(),
+            Focus::CardName => app.state.new_card_form[0] = "".to_string(),
+            Focus::CardDescription => app.state.new_card_form[1] = "".to_string(),
+            Focus::CardDueDate => app.state.new_card_form[2] = "".to_string(),
+            _ => app.state.current_user_input = "".to_string(),
+        }
+        if app.state.popup_mode.is_some() {
+            match app.state.popup_mode.unwrap() {
+                PopupMode::CommandPalette => {
+                    app.state.popup_mode = None;
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                PopupMode::ConfirmDiscardCardChanges => {
+                    if app.card_being_edited.is_some() {
+                        warn!(
+                            "Discarding changes to card '{}'",
+                            app.card_being_edited.as_ref().unwrap().1.name
+                        );
+                        app.send_warning_toast(
+                            &format!(
+                                "Discarding changes to card '{}'",
+                                app.card_being_edited.as_ref().unwrap().1.name
+                            ),
+                            None,
+                        );
+                    }
+                    app.state.popup_mode = None;
+                    app.card_being_edited = None;
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                PopupMode::ViewCard => {
+                    if app.card_being_edited.is_some() {
+                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
+                    }
+                }
+                PopupMode::CardPrioritySelector | PopupMode::CardPointsSelector => {
+                    if app.card_being_edited.is_some() {
+                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
+                    } else {
+                        app.state.popup_mode = None;
+                    }
+                }
+                PopupMode::CardStatusSelector => {
+                    if app.card_being_edited.is_some() {
+                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
+                    } else {
+                        app.state.popup_mode = None;
+                    }
+                }
+                _ => {}
+            }
+        }
+        app.state.app_status = AppStatus::Initialized;
+        app.state.current_cursor_position = None;
+        info!("Exiting user input mode");
+    } else {
+        // parsing keys to account for actions
+
+        if app.config.keybindings.toggle_command_palette.contains(&key) {
+            app.state.app_status = AppStatus::Initialized;
+            app.state.popup_mode = None;
+            return AppReturn::Continue;
+        }
+        if app.state.popup_mode.is_some() {
+            let stop_input_mode_keys = &app.config.keybindings.stop_user_input;
+            match app.state.popup_mode.unwrap() {
+                PopupMode::CommandPalette => match key {
+                    Key::Up => {
+                        match app.state.focus {
+                            Focus::CommandPaletteCommand => {
+                                app.command_palette_command_search_prv();
+                            }
+                            Focus::CommandPaletteCard => {
+                                app.command_palette_card_search_prv();
+                            }
+                            Focus::CommandPaletteBoard => {
+                                app.command_palette_board_search_prv();
+                            }
+                            _ => {}
+                        }
+                        return AppReturn::Continue;
+                    }
+                    Key::Down => {
+                        match app.state.focus {
+                            Focus::CommandPaletteCommand => {
+                                app.command_palette_command_search_next();
+                            }
+                            Focus::CommandPaletteCard => {
+                                app.command_palette_card_search_next();
+                            }
+                            Focus::CommandPaletteBoard => {
+                                app.command_palette_board_search_next();
+                            }
                             _ => {}
                         }
                         return AppReturn::Continue;
@@ -751,6 +1662,10 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                                 app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
                                 return AppReturn::Continue;
                             }
+                            Focus::CardPoints => {
+                                app.state.popup_mode = Some(PopupMode::CardPointsSelector);
+                                return AppReturn::Continue;
+                            }
                             Focus::SubmitButton => {
                                 return handle_edit_card_submit(app);
                             }
@@ -763,2165 +1678,3995 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                             app.state.current_cursor_position = None;
                             return AppReturn::Continue;
                         }
-                        Key::BackTab => {
-                            handle_prv_focus(app);
-                            app.state.card_view_comment_list_state.select(None);
-                            app.state.card_view_tag_list_state.select(None);
-                            app.state.current_cursor_position = None;
+                        Key::BackTab => {
+                            handle_prv_focus(app);
+                            app.state.card_view_comment_list_state.select(None);
+                            app.state.card_view_tag_list_state.select(None);
+                            app.state.current_cursor_position = None;
+                            return AppReturn::Continue;
+                        }
+                        Key::Backspace => {
+                            match app.state.focus {
+                                Focus::CardName => {
+                                    if app.state.current_cursor_position.is_some() {
+                                        let current_cursor_position =
+                                            app.state.current_cursor_position.unwrap();
+                                        if current_cursor_position > 0 {
+                                            card_being_edited
+                                                .1
+                                                .name
+                                                .remove(current_cursor_position - 1);
+                                            app.state.current_cursor_position =
+                                                Some(current_cursor_position - 1);
+                                        }
+                                    } else {
+                                        card_being_edited
+                                            .1
+                                            .name
+                                            .remove(card_being_edited.1.name.len() - 1);
+                                        app.state.current_cursor_position =
+                                            Some(card_being_edited.1.name.len());
+                                    }
+                                }
+                                Focus::CardDescription => {
+                                    if app.state.current_cursor_position.is_some() {
+                                        let current_cursor_position =
+                                            app.state.current_cursor_position.unwrap();
+                                        if current_cursor_position > 0 {
+                                            card_being_edited
+                                                .1
+                                                .description
+                                                .remove(current_cursor_position - 1);
+                                            app.state.current_cursor_position =
+                                                Some(current_cursor_position - 1);
+                                        }
+                                    } else {
+                                        card_being_edited
+                                            .1
+                                            .description
+                                            .remove(card_being_edited.1.description.len() - 1);
+                                        app.state.current_cursor_position =
+                                            Some(card_being_edited.1.description.len());
+                                    }
+                                }
+                                Focus::CardDueDate => {
+                                    if app.state.current_cursor_position.is_some() {
+                                        let current_cursor_position =
+                                            app.state.current_cursor_position.unwrap();
+                                        if current_cursor_position > 0 {
+                                            card_being_edited
+                                                .1
+                                                .date_due
+                                                .remove(current_cursor_position - 1);
+                                            app.state.current_cursor_position =
+                                                Some(current_cursor_position - 1);
+                                        }
+                                    } else {
+                                        card_being_edited
+                                            .1
+                                            .date_due
+                                            .remove(card_being_edited.1.date_due.len() - 1);
+                                        app.state.current_cursor_position =
+                                            Some(card_being_edited.1.date_due.len());
+                                    }
+                                }
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag_index =
+                                            app.state.card_view_tag_list_state.selected();
+                                        if selected_tag_index.is_some() {
+                                            // check if the card has a tag at the selected index
+                                            if card_being_edited
+                                                .1
+                                                .tags
+                                                .get(selected_tag_index.unwrap())
+                                                .is_none()
+                                            {
+                                                // select the last available tag
+                                                app.state.card_view_tag_list_state.select(Some(
+                                                    card_being_edited.1.tags.len() - 1,
+                                                ));
+                                                return AppReturn::Continue;
+                                            }
+                                        }
+                                        let selected_tag_index = selected_tag_index.unwrap();
+                                        let current_cursor_position =
+                                            if app.state.current_cursor_position.is_some() {
+                                                app.state.current_cursor_position.unwrap()
+                                            } else {
+                                                app.state.current_cursor_position = Some(0);
+                                                0
+                                            };
+                                        if current_cursor_position > 0 {
+                                            // check if selected_tag_index is valid
+                                            if card_being_edited
+                                                .1
+                                                .tags
+                                                .get(selected_tag_index)
+                                                .is_none()
+                                            {
+                                                if card_being_edited.1.tags.is_empty() {
+                                                    app.state.card_view_tag_list_state.select(None);
+                                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                                } else {
+                                                    app.state.card_view_tag_list_state.select(
+                                                        Some(card_being_edited.1.tags.len() - 1),
+                                                    );
+                                                }
+                                                return AppReturn::Continue;
+                                            }
+                                            card_being_edited
+                                                .1
+                                                .tags
+                                                .get_mut(selected_tag_index)
+                                                .unwrap()
+                                                .remove(current_cursor_position - 1);
+                                            app.state.current_cursor_position =
+                                                Some(current_cursor_position - 1);
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                    }
+                                }
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment_index =
+                                            app.state.card_view_comment_list_state.selected();
+                                        if selected_comment_index.is_some() {
+                                            // check if the card has a comment at the selected index
+                                            if card_being_edited
+                                                .1
+                                                .comments
+                                                .get(selected_comment_index.unwrap())
+                                                .is_none()
+                                            {
+                                                if card_being_edited.1.comments.is_empty() {
+                                                    app.state
+                                                        .card_view_comment_list_state
+                                                        .select(None);
+                                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                                } else {
+                                                    app.state.card_view_comment_list_state.select(
+                                                        Some(
+                                                            card_being_edited.1.comments.len() - 1,
+                                                        ),
+                                                    );
+                                                }
+                                                return AppReturn::Continue;
+                                            }
+                                        }
+                                        let selected_comment_index =
+                                            selected_comment_index.unwrap();
+                                        let current_cursor_position =
+                                            if app.state.current_cursor_position.is_some() {
+                                                app.state.current_cursor_position.unwrap()
+                                            } else {
+                                                app.state.current_cursor_position = Some(0);
+                                                0
+                                            };
+                                        if current_cursor_position > 0 {
+                                            // remove the char from the selected comment
+                                            card_being_edited
+                                                .1
+                                                .comments
+                                                .get_mut(selected_comment_index)
+                                                .unwrap()
+                                                .remove(current_cursor_position - 1);
+                                            app.state.current_cursor_position =
+                                                Some(current_cursor_position - 1);
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                    }
+                                }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Key::Left => {
+                            match app.state.focus {
+                                Focus::CardName => {
+                                    if !card_being_edited.1.name.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position =
+                                                Some(card_being_edited.1.name.len());
+                                        } else if app.state.current_cursor_position.unwrap() > 0 {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() - 1,
+                                            );
+                                        } else {
+                                            app.state.current_cursor_position = Some(0);
+                                        }
+                                    }
+                                }
+                                Focus::CardDescription => {
+                                    if !card_being_edited.1.description.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position =
+                                                Some(card_being_edited.1.description.len());
+                                        } else if app.state.current_cursor_position.unwrap() > 0 {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() - 1,
+                                            );
+                                        } else {
+                                            app.state.current_cursor_position = Some(0);
+                                        }
+                                    }
+                                }
+                                Focus::CardDueDate => {
+                                    if !card_being_edited.1.date_due.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position =
+                                                Some(card_being_edited.1.date_due.len());
+                                        } else if app.state.current_cursor_position.unwrap() > 0 {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() - 1,
+                                            );
+                                        } else {
+                                            app.state.current_cursor_position = Some(0);
+                                        }
+                                    }
+                                }
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
+                                        if tag.is_some() {
+                                            let tag = tag.unwrap();
+                                            if app.state.current_cursor_position.is_none() {
+                                                app.state.current_cursor_position = Some(tag.len());
+                                            } else if app.state.current_cursor_position.unwrap() > 0
+                                            {
+                                                if app.state.current_cursor_position.unwrap()
+                                                    > tag.len()
+                                                {
+                                                    app.state.current_cursor_position =
+                                                        Some(tag.len());
+                                                }
+                                                app.state.current_cursor_position = Some(
+                                                    app.state.current_cursor_position.unwrap() - 1,
+                                                );
+                                            } else {
+                                                app.state.current_cursor_position = Some(0);
+                                            }
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                    }
+                                }
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        let comment =
+                                            card_being_edited.1.comments.get_mut(selected_comment);
+                                        if comment.is_some() {
+                                            let comment = comment.unwrap();
+                                            if app.state.current_cursor_position.is_none() {
+                                                app.state.current_cursor_position =
+                                                    Some(comment.len());
+                                            } else if app.state.current_cursor_position.unwrap() > 0
+                                            {
+                                                if app.state.current_cursor_position.unwrap()
+                                                    > comment.len()
+                                                {
+                                                    app.state.current_cursor_position =
+                                                        Some(comment.len());
+                                                }
+                                                app.state.current_cursor_position = Some(
+                                                    app.state.current_cursor_position.unwrap() - 1,
+                                                );
+                                            } else {
+                                                app.state.current_cursor_position = Some(0);
+                                            }
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                    }
+                                }
+                                _ => {}
+                            }
                             return AppReturn::Continue;
                         }
-                        Key::Backspace => {
+                        Key::Right => {
                             match app.state.focus {
                                 Focus::CardName => {
-                                    if app.state.current_cursor_position.is_some() {
-                                        let current_cursor_position =
-                                            app.state.current_cursor_position.unwrap();
-                                        if current_cursor_position > 0 {
-                                            card_being_edited
-                                                .1
-                                                .name
-                                                .remove(current_cursor_position - 1);
+                                    if !card_being_edited.1.name.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position = Some(0);
+                                        } else if app.state.current_cursor_position.unwrap()
+                                            < card_being_edited.1.name.len()
+                                        {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() + 1,
+                                            );
+                                        } else {
                                             app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
+                                                Some(card_being_edited.1.name.len());
+                                        }
+                                    }
+                                }
+                                Focus::CardDescription => {
+                                    if !card_being_edited.1.description.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position = Some(0);
+                                        } else if app.state.current_cursor_position.unwrap()
+                                            < card_being_edited.1.description.len()
+                                        {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() + 1,
+                                            );
+                                        } else {
+                                            app.state.current_cursor_position =
+                                                Some(card_being_edited.1.description.len());
+                                        }
+                                    }
+                                }
+                                Focus::CardDueDate => {
+                                    if !card_being_edited.1.date_due.is_empty() {
+                                        if app.state.current_cursor_position.is_none() {
+                                            app.state.current_cursor_position = Some(0);
+                                        } else if app.state.current_cursor_position.unwrap()
+                                            < card_being_edited.1.date_due.len()
+                                        {
+                                            app.state.current_cursor_position = Some(
+                                                app.state.current_cursor_position.unwrap() + 1,
+                                            );
+                                        } else {
+                                            app.state.current_cursor_position =
+                                                Some(card_being_edited.1.date_due.len());
+                                        }
+                                    }
+                                }
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
+                                        if tag.is_some() {
+                                            let tag = tag.unwrap();
+                                            if app.state.current_cursor_position.is_none() {
+                                                app.state.current_cursor_position = Some(0);
+                                            } else if app.state.current_cursor_position.unwrap()
+                                                < tag.len()
+                                            {
+                                                app.state.current_cursor_position = Some(
+                                                    app.state.current_cursor_position.unwrap() + 1,
+                                                );
+                                            } else {
+                                                app.state.current_cursor_position = Some(tag.len());
+                                            }
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                    }
+                                }
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        let comment =
+                                            card_being_edited.1.comments.get_mut(selected_comment);
+                                        if comment.is_some() {
+                                            let comment = comment.unwrap();
+                                            if app.state.current_cursor_position.is_none() {
+                                                app.state.current_cursor_position = Some(0);
+                                            } else if app.state.current_cursor_position.unwrap()
+                                                < comment.len()
+                                            {
+                                                app.state.current_cursor_position = Some(
+                                                    app.state.current_cursor_position.unwrap() + 1,
+                                                );
+                                            } else {
+                                                app.state.current_cursor_position =
+                                                    Some(comment.len());
+                                            }
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                    }
+                                }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Key::Home => {
+                            match app.state.focus {
+                                Focus::CardDescription | Focus::CardDueDate | Focus::CardName => {
+                                    app.state.current_cursor_position = Some(0);
+                                }
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        let tag = card_being_edited.1.tags.get(selected_tag);
+                                        if tag.is_some() {
+                                            app.state.current_cursor_position = Some(0);
+                                        }
+                                    } else {
+                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                    }
+                                }
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        let comment =
+                                            card_being_edited.1.comments.get(selected_comment);
+                                        if comment.is_some() {
+                                            app.state.current_cursor_position = Some(0);
                                         }
                                     } else {
-                                        card_being_edited
-                                            .1
-                                            .name
-                                            .remove(card_being_edited.1.name.len() - 1);
-                                        app.state.current_cursor_position =
-                                            Some(card_being_edited.1.name.len());
+                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
                                     }
                                 }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Key::End => {
+                            match app.state.focus {
+                                Focus::CardName => {
+                                    app.state.current_cursor_position =
+                                        Some(card_being_edited.1.name.len());
+                                }
                                 Focus::CardDescription => {
-                                    if app.state.current_cursor_position.is_some() {
-                                        let current_cursor_position =
-                                            app.state.current_cursor_position.unwrap();
-                                        if current_cursor_position > 0 {
-                                            card_being_edited
-                                                .1
-                                                .description
-                                                .remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(card_being_edited.1.description.len());
+                                }
+                                Focus::CardDueDate => {
+                                    app.state.current_cursor_position =
+                                        Some(card_being_edited.1.date_due.len());
+                                }
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        let tag = card_being_edited.1.tags.get(selected_tag);
+                                        if tag.is_some() {
                                             app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
+                                                Some(tag.unwrap().len());
                                         }
                                     } else {
-                                        card_being_edited
-                                            .1
-                                            .description
-                                            .remove(card_being_edited.1.description.len() - 1);
-                                        app.state.current_cursor_position =
-                                            Some(card_being_edited.1.description.len());
+                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
                                     }
                                 }
-                                Focus::CardDueDate => {
-                                    if app.state.current_cursor_position.is_some() {
-                                        let current_cursor_position =
-                                            app.state.current_cursor_position.unwrap();
-                                        if current_cursor_position > 0 {
-                                            card_being_edited
-                                                .1
-                                                .date_due
-                                                .remove(current_cursor_position - 1);
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        let comment =
+                                            card_being_edited.1.comments.get(selected_comment);
+                                        if comment.is_some() {
                                             app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
+                                                Some(comment.unwrap().len());
                                         }
                                     } else {
-                                        card_being_edited
-                                            .1
-                                            .date_due
-                                            .remove(card_being_edited.1.date_due.len() - 1);
-                                        app.state.current_cursor_position =
-                                            Some(card_being_edited.1.date_due.len());
+                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
                                     }
                                 }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Key::ShiftRight => {
+                            match app.state.focus {
                                 Focus::CardTags => {
                                     if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected();
-                                        if selected_tag_index.is_some() {
-                                            // check if the card has a tag at the selected index
-                                            if card_being_edited
-                                                .1
-                                                .tags
-                                                .get(selected_tag_index.unwrap())
-                                                .is_none()
-                                            {
-                                                // select the last available tag
-                                                app.state.card_view_tag_list_state.select(Some(
-                                                    card_being_edited.1.tags.len() - 1,
-                                                ));
-                                                return AppReturn::Continue;
-                                            }
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if card_being_edited.1.tags.is_empty() {
+                                            return AppReturn::Continue;
                                         }
-                                        let selected_tag_index = selected_tag_index.unwrap();
-                                        let current_cursor_position =
-                                            if app.state.current_cursor_position.is_some() {
-                                                app.state.current_cursor_position.unwrap()
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                                0
-                                            };
-                                        if current_cursor_position > 0 {
-                                            // check if selected_tag_index is valid
-                                            if card_being_edited
-                                                .1
-                                                .tags
-                                                .get(selected_tag_index)
-                                                .is_none()
-                                            {
-                                                if card_being_edited.1.tags.is_empty() {
-                                                    app.state.card_view_tag_list_state.select(None);
-                                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                                } else {
-                                                    app.state.card_view_tag_list_state.select(
-                                                        Some(card_being_edited.1.tags.len() - 1),
-                                                    );
-                                                }
-                                                return AppReturn::Continue;
-                                            }
-                                            card_being_edited
-                                                .1
-                                                .tags
-                                                .get_mut(selected_tag_index)
-                                                .unwrap()
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
+                                        let selected_tag_index =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        if selected_tag_index < card_being_edited.1.tags.len() - 1 {
+                                            app.state
+                                                .card_view_tag_list_state
+                                                .select(Some(selected_tag_index + 1));
                                         }
                                     } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if !card_being_edited.1.tags.is_empty() {
+                                            app.state.card_view_tag_list_state.select(Some(0));
+                                        }
                                     }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
                                 }
                                 Focus::CardComments => {
                                     if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment_index =
-                                            app.state.card_view_comment_list_state.selected();
-                                        if selected_comment_index.is_some() {
-                                            // check if the card has a comment at the selected index
-                                            if card_being_edited
-                                                .1
-                                                .comments
-                                                .get(selected_comment_index.unwrap())
-                                                .is_none()
-                                            {
-                                                if card_being_edited.1.comments.is_empty() {
-                                                    app.state
-                                                        .card_view_comment_list_state
-                                                        .select(None);
-                                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                                } else {
-                                                    app.state.card_view_comment_list_state.select(
-                                                        Some(
-                                                            card_being_edited.1.comments.len() - 1,
-                                                        ),
-                                                    );
-                                                }
-                                                return AppReturn::Continue;
-                                            }
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if card_being_edited.1.comments.is_empty() {
+                                            return AppReturn::Continue;
                                         }
-                                        let selected_comment_index =
-                                            selected_comment_index.unwrap();
-                                        let current_cursor_position =
-                                            if app.state.current_cursor_position.is_some() {
-                                                app.state.current_cursor_position.unwrap()
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                                0
-                                            };
-                                        if current_cursor_position > 0 {
-                                            // remove the char from the selected comment
-                                            card_being_edited
-                                                .1
-                                                .comments
-                                                .get_mut(selected_comment_index)
-                                                .unwrap()
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
+                                        let selected_comment_index = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        if selected_comment_index
+                                            < card_being_edited.1.comments.len() - 1
+                                        {
+                                            app.state
+                                                .card_view_comment_list_state
+                                                .select(Some(selected_comment_index + 1));
+                                        }
+                                    } else {
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if !card_being_edited.1.comments.is_empty() {
+                                            app.state.card_view_comment_list_state.select(Some(0));
+                                        }
+                                    }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
+                                }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Key::ShiftLeft => {
+                            match app.state.focus {
+                                Focus::CardTags => {
+                                    if app.state.card_view_tag_list_state.selected().is_some() {
+                                        let selected_tag_index =
+                                            app.state.card_view_tag_list_state.selected().unwrap();
+                                        if selected_tag_index > 0 {
+                                            app.state
+                                                .card_view_tag_list_state
+                                                .select(Some(selected_tag_index - 1));
+                                        }
+                                    } else {
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if !card_being_edited.1.tags.is_empty() {
+                                            app.state
+                                                .card_view_tag_list_state
+                                                .select(Some(card_being_edited.1.tags.len() - 1));
+                                        }
+                                    }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
+                                }
+                                Focus::CardComments => {
+                                    if app.state.card_view_comment_list_state.selected().is_some() {
+                                        let selected_comment_index = app
+                                            .state
+                                            .card_view_comment_list_state
+                                            .selected()
+                                            .unwrap();
+                                        if selected_comment_index > 0 {
+                                            app.state
+                                                .card_view_comment_list_state
+                                                .select(Some(selected_comment_index - 1));
                                         }
                                     } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        if !card_being_edited.1.comments.is_empty() {
+                                            app.state.card_view_comment_list_state.select(Some(
+                                                card_being_edited.1.comments.len() - 1,
+                                            ));
+                                        }
                                     }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
                                 }
                                 _ => {}
                             }
                             return AppReturn::Continue;
                         }
-                        Key::Left => {
+                        Key::Delete => {
                             match app.state.focus {
-                                Focus::CardName => {
-                                    if !card_being_edited.1.name.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.name.len());
-                                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() - 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    }
-                                }
-                                Focus::CardDescription => {
-                                    if !card_being_edited.1.description.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.description.len());
-                                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() - 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    }
-                                }
-                                Focus::CardDueDate => {
-                                    if !card_being_edited.1.date_due.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.date_due.len());
-                                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() - 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    }
-                                }
                                 Focus::CardTags => {
                                     if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        let selected_tag_index =
                                             app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                        if tag.is_some() {
-                                            let tag = tag.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(tag.len());
-                                            } else if app.state.current_cursor_position.unwrap() > 0
-                                            {
-                                                if app.state.current_cursor_position.unwrap()
-                                                    > tag.len()
-                                                {
-                                                    app.state.current_cursor_position =
-                                                        Some(tag.len());
-                                                }
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() - 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                            }
+                                        card_being_edited.1.tags.remove(selected_tag_index);
+                                        if selected_tag_index < card_being_edited.1.tags.len() {
+                                            app.state
+                                                .card_view_tag_list_state
+                                                .select(Some(selected_tag_index));
+                                        } else if selected_tag_index > 0 {
+                                            app.state
+                                                .card_view_tag_list_state
+                                                .select(Some(selected_tag_index - 1));
+                                        } else {
+                                            app.state.card_view_tag_list_state.select(None);
                                         }
                                     } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                        app.send_warning_toast("No tag selected, press <Shift+Right> or <Shift+Left> to select a tag", None);
                                     }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
                                 }
                                 Focus::CardComments => {
                                     if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
+                                        let card_being_edited =
+                                            app.card_being_edited.as_mut().unwrap();
+                                        let selected_comment_index = app
                                             .state
                                             .card_view_comment_list_state
                                             .selected()
                                             .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
-                                        if comment.is_some() {
-                                            let comment = comment.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position =
-                                                    Some(comment.len());
-                                            } else if app.state.current_cursor_position.unwrap() > 0
-                                            {
-                                                if app.state.current_cursor_position.unwrap()
-                                                    > comment.len()
-                                                {
-                                                    app.state.current_cursor_position =
-                                                        Some(comment.len());
-                                                }
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() - 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                            }
+                                        card_being_edited.1.comments.remove(selected_comment_index);
+                                        if selected_comment_index
+                                            < card_being_edited.1.comments.len()
+                                        {
+                                            app.state
+                                                .card_view_comment_list_state
+                                                .select(Some(selected_comment_index));
+                                        } else if selected_comment_index > 0 {
+                                            app.state
+                                                .card_view_comment_list_state
+                                                .select(Some(selected_comment_index - 1));
+                                        } else {
+                                            app.state.card_view_comment_list_state.select(None);
                                         }
                                     } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                        app.send_warning_toast("No comment selected, press <Shift+Right> or <Shift+Left> to select a comment", None);
                                     }
+                                    app.state.current_cursor_position = None;
+                                    return AppReturn::Continue;
+                                }
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        _ => {
+                            if stop_input_mode_keys.contains(&key) {
+                                app.state.current_cursor_position = None;
+                                if app.card_being_edited.is_some() {
+                                    app.state.popup_mode =
+                                        Some(PopupMode::ConfirmDiscardCardChanges);
+                                    app.state.app_status = AppStatus::Initialized;
+                                }
+                                return AppReturn::Continue;
+                            }
+                        }
+                    }
+                }
+                PopupMode::ConfirmLoadSave
+                | PopupMode::ConfirmPruneArchivedCards
+                | PopupMode::ConfirmDeleteBoard
+                    if key == Key::Enter =>
+                {
+                    // typing is done, the next enter presses the focused button
+                    app.state.app_status = AppStatus::Initialized;
+                    app.state.current_cursor_position = None;
+                    return AppReturn::Continue;
+                }
+                PopupMode::ConfirmDiscardCardChanges => {
+                    match key {
+                        Key::Tab => handle_next_focus(app),
+                        Key::BackTab => handle_prv_focus(app),
+                        Key::Enter => {
+                            if app.state.focus == Focus::SubmitButton {
+                                handle_edit_card_submit(app);
+                                app.state.popup_mode = None;
+                                app.state.app_status = AppStatus::Initialized;
+                            } else {
+                                if app.card_being_edited.is_some() {
+                                    warn!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    );
+                                }
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    ),
+                                    None,
+                                );
+                                app.state.popup_mode = None;
+                                app.card_being_edited = None;
+                                app.state.app_status = AppStatus::Initialized;
+                            }
+                        }
+                        _ => {}
+                    }
+                    return AppReturn::Continue;
+                }
+                PopupMode::CardPrioritySelector => match key {
+                    Key::Up => {
+                        app.select_card_priority_prv();
+                    }
+                    Key::Down => {
+                        app.select_card_priority_next();
+                    }
+                    Key::Enter => {
+                        handle_change_card_priority(app);
+                    }
+                    _ => {}
+                },
+                PopupMode::CardPointsSelector => match key {
+                    Key::Up => {
+                        app.select_card_points_prv();
+                    }
+                    Key::Down => {
+                        app.select_card_points_next();
+                    }
+                    Key::Enter => {
+                        handle_change_card_points(app);
+                    }
+                    _ => {}
+                },
+                PopupMode::CardStatusSelector => match key {
+                    Key::Up => {
+                        app.select_card_status_prv();
+                    }
+                    Key::Down => {
+                        app.select_card_status_next();
+                    }
+                    Key::Enter => {
+                        handle_change_card_status(app);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        let mut current_key = key.to_string();
+        match key {
+            Key::Char(' ') => current_key = " ".to_string(),
+            Key::Enter => current_key = "\n".to_string(),
+            Key::Tab => current_key = "  ".to_string(),
+            Key::BackTab => current_key = "".to_string(),
+            Key::Backspace => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => match app.state.focus {
+                        Focus::NewBoardName => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state.new_board_form[0].remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
+                                }
+                            } else {
+                                app.state.new_board_form[0].pop();
+                            }
+                        }
+                        Focus::NewBoardDescription => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state.new_board_form[1].remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                _ => {}
+                            } else {
+                                app.state.new_board_form[1].pop();
                             }
-                            return AppReturn::Continue;
                         }
-                        Key::Right => {
-                            match app.state.focus {
-                                Focus::CardName => {
-                                    if !card_being_edited.1.name.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position = Some(0);
-                                        } else if app.state.current_cursor_position.unwrap()
-                                            < card_being_edited.1.name.len()
-                                        {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() + 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.name.len());
-                                        }
-                                    }
+                        _ => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state
+                                        .current_user_input
+                                        .remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                Focus::CardDescription => {
-                                    if !card_being_edited.1.description.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position = Some(0);
-                                        } else if app.state.current_cursor_position.unwrap()
-                                            < card_being_edited.1.description.len()
-                                        {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() + 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.description.len());
-                                        }
-                                    }
+                            } else {
+                                app.state.current_user_input.pop();
+                            }
+                        }
+                    },
+                    UiMode::NewCard => match app.state.focus {
+                        Focus::CardName => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state.new_card_form[0].remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                Focus::CardDueDate => {
-                                    if !card_being_edited.1.date_due.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position = Some(0);
-                                        } else if app.state.current_cursor_position.unwrap()
-                                            < card_being_edited.1.date_due.len()
-                                        {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() + 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.date_due.len());
-                                        }
-                                    }
+                            } else {
+                                app.state.new_card_form[0].pop();
+                            }
+                        }
+                        Focus::CardDescription => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state.new_card_form[1].remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                        if tag.is_some() {
-                                            let tag = tag.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(0);
-                                            } else if app.state.current_cursor_position.unwrap()
-                                                < tag.len()
-                                            {
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() + 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(tag.len());
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                    }
+                            } else {
+                                app.state.new_card_form[1].pop();
+                            }
+                        }
+                        Focus::CardDueDate => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state.new_card_form[2].remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
-                                        if comment.is_some() {
-                                            let comment = comment.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(0);
-                                            } else if app.state.current_cursor_position.unwrap()
-                                                < comment.len()
-                                            {
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() + 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position =
-                                                    Some(comment.len());
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                    }
+                            } else {
+                                app.state.new_card_form[2].pop();
+                            }
+                        }
+                        _ => {
+                            if app.state.current_cursor_position.is_some() {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap();
+                                if current_cursor_position > 0 {
+                                    app.state
+                                        .current_user_input
+                                        .remove(current_cursor_position - 1);
+                                    app.state.current_cursor_position =
+                                        Some(current_cursor_position - 1);
                                 }
-                                _ => {}
+                            } else {
+                                app.state.current_user_input.pop();
+                            }
+                        }
+                    },
+                    _ => {
+                        if app.state.current_cursor_position.is_some() {
+                            let current_cursor_position =
+                                app.state.current_cursor_position.unwrap();
+                            if current_cursor_position > 0 {
+                                app.state
+                                    .current_user_input
+                                    .remove(current_cursor_position - 1);
+                                app.state.current_cursor_position =
+                                    Some(current_cursor_position - 1);
+                            }
+                        } else {
+                            app.state.current_user_input.pop();
+                        }
+                    }
+                };
+                current_key = "".to_string();
+            }
+            Key::Left => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => match app.state.focus {
+                        Focus::NewBoardName => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_board_form[0].len());
+                            } else if app.state.current_cursor_position.unwrap() > 0 {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() - 1);
+                            } else {
+                                app.state.current_cursor_position = Some(0);
+                            }
+                        }
+                        Focus::NewBoardDescription => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_board_form[1].len());
+                            } else if app.state.current_cursor_position.unwrap() > 0 {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() - 1);
+                            } else {
+                                app.state.current_cursor_position = Some(0);
+                            }
+                        }
+                        _ => {}
+                    },
+                    UiMode::NewCard => match app.state.focus {
+                        Focus::CardName => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[0].len());
+                            } else if app.state.current_cursor_position.unwrap() > 0 {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() - 1);
+                            } else {
+                                app.state.current_cursor_position = Some(0);
+                            }
+                        }
+                        Focus::CardDescription => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[1].len());
+                            } else if app.state.current_cursor_position.unwrap() > 0 {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() - 1);
+                            } else {
+                                app.state.current_cursor_position = Some(0);
+                            }
+                        }
+                        Focus::CardDueDate => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[2].len());
+                            } else if app.state.current_cursor_position.unwrap() > 0 {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() - 1);
+                            } else {
+                                app.state.current_cursor_position = Some(0);
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ => {
+                        if app.state.current_cursor_position.is_none() {
+                            app.state.current_cursor_position =
+                                Some(app.state.current_user_input.len());
+                        } else if app.state.current_cursor_position.unwrap() > 0 {
+                            app.state.current_cursor_position =
+                                Some(app.state.current_cursor_position.unwrap() - 1);
+                        } else {
+                            app.state.current_cursor_position = Some(0);
+                        }
+                    }
+                };
+                current_key = "".to_string();
+            }
+            Key::Right => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => match app.state.focus {
+                        Focus::NewBoardName => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position = Some(0);
+                            } else if app.state.current_cursor_position.unwrap()
+                                < app.state.new_board_form[0].len()
+                            {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() + 1);
+                            } else {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_board_form[0].len());
                             }
-                            return AppReturn::Continue;
                         }
-                        Key::Home => {
-                            match app.state.focus {
-                                Focus::CardDescription | Focus::CardDueDate | Focus::CardName => {
-                                    app.state.current_cursor_position = Some(0);
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get(selected_tag);
-                                        if tag.is_some() {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                    }
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get(selected_comment);
-                                        if comment.is_some() {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                    }
-                                }
-                                _ => {}
+                        Focus::NewBoardDescription => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position = Some(0);
+                            } else if app.state.current_cursor_position.unwrap()
+                                < app.state.new_board_form[1].len()
+                            {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() + 1);
+                            } else {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_board_form[1].len());
                             }
-                            return AppReturn::Continue;
                         }
-                        Key::End => {
-                            match app.state.focus {
-                                Focus::CardName => {
-                                    app.state.current_cursor_position =
-                                        Some(card_being_edited.1.name.len());
-                                }
-                                Focus::CardDescription => {
-                                    app.state.current_cursor_position =
-                                        Some(card_being_edited.1.description.len());
+                        _ => {}
+                    },
+                    UiMode::NewCard => match app.state.focus {
+                        Focus::CardName => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position = Some(0);
+                            } else if app.state.current_cursor_position.unwrap()
+                                < app.state.new_card_form[0].len()
+                            {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() + 1);
+                            } else {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[0].len());
+                            }
+                        }
+                        Focus::CardDescription => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position = Some(0);
+                            } else if app.state.current_cursor_position.unwrap()
+                                < app.state.new_card_form[1].len()
+                            {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() + 1);
+                            } else {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[1].len());
+                            }
+                        }
+                        Focus::CardDueDate => {
+                            if app.state.current_cursor_position.is_none() {
+                                app.state.current_cursor_position = Some(0);
+                            } else if app.state.current_cursor_position.unwrap()
+                                < app.state.new_card_form[2].len()
+                            {
+                                app.state.current_cursor_position =
+                                    Some(app.state.current_cursor_position.unwrap() + 1);
+                            } else {
+                                app.state.current_cursor_position =
+                                    Some(app.state.new_card_form[2].len());
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ => {
+                        if app.state.current_cursor_position.is_none() {
+                            app.state.current_cursor_position = Some(0);
+                        } else if app.state.current_cursor_position.unwrap()
+                            < app.state.current_user_input.len()
+                        {
+                            app.state.current_cursor_position =
+                                Some(app.state.current_cursor_position.unwrap() + 1);
+                        } else {
+                            app.state.current_cursor_position =
+                                Some(app.state.current_user_input.len());
+                        }
+                    }
+                };
+                current_key = "".to_string();
+            }
+            Key::Home => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => match app.state.focus {
+                        Focus::NewBoardName | Focus::NewBoardDescription => {
+                            app.state.current_cursor_position = Some(0);
+                        }
+                        _ => {}
+                    },
+                    UiMode::NewCard => match app.state.focus {
+                        Focus::CardName | Focus::CardDescription | Focus::CardDueDate => {
+                            app.state.current_cursor_position = Some(0);
+                        }
+                        _ => {}
+                    },
+                    _ => {
+                        app.state.current_cursor_position = Some(0);
+                    }
+                };
+                current_key = "".to_string();
+            }
+            Key::End => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => match app.state.focus {
+                        Focus::NewBoardName => {
+                            app.state.current_cursor_position =
+                                Some(app.state.new_board_form[0].len());
+                        }
+                        Focus::NewBoardDescription => {
+                            app.state.current_cursor_position =
+                                Some(app.state.new_board_form[1].len());
+                        }
+                        _ => {}
+                    },
+                    UiMode::NewCard => match app.state.focus {
+                        Focus::CardName => {
+                            app.state.current_cursor_position =
+                                Some(app.state.new_card_form[0].len());
+                        }
+                        Focus::CardDescription => {
+                            app.state.current_cursor_position =
+                                Some(app.state.new_card_form[1].len());
+                        }
+                        Focus::CardDueDate => {
+                            app.state.current_cursor_position =
+                                Some(app.state.new_card_form[2].len());
+                        }
+                        _ => {}
+                    },
+                    _ => {
+                        app.state.current_cursor_position =
+                            Some(app.state.current_user_input.len());
+                    }
+                };
+                current_key = "".to_string();
+            }
+            _ => {
+                if app.config.keybindings.stop_user_input.contains(&key) {
+                    app.state.app_status = AppStatus::Initialized;
+                    app.state.current_cursor_position = None;
+                    info!("Exiting User Input Mode");
+                    return AppReturn::Continue;
+                }
+                if current_key.starts_with('<') && current_key.ends_with('>') {
+                    current_key = current_key[1..current_key.len() - 1].to_string();
+                }
+                if current_key.is_empty() {
+                    return AppReturn::Continue;
+                }
+            }
+        }
+
+        // Actually appending input to the current user input* (depending on the current mode)
+
+        if current_key.chars().next().is_some() {
+            if app.state.popup_mode.is_some() {
+                match app.state.popup_mode.unwrap() {
+                    PopupMode::ViewCard => {
+                        let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                        match app.state.focus {
+                            Focus::CardName => {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap_or(0);
+                                for (i, char) in current_key.chars().enumerate() {
+                                    card_being_edited
+                                        .1
+                                        .name
+                                        .insert(current_cursor_position + i, char);
                                 }
-                                Focus::CardDueDate => {
-                                    app.state.current_cursor_position =
-                                        Some(card_being_edited.1.date_due.len());
+                                app.state.current_cursor_position =
+                                    Some(current_cursor_position + 1);
+                            }
+                            Focus::CardDescription => {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap_or(0);
+                                for (i, char) in current_key.chars().enumerate() {
+                                    card_being_edited
+                                        .1
+                                        .description
+                                        .insert(current_cursor_position + i, char);
                                 }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get(selected_tag);
-                                        if tag.is_some() {
-                                            app.state.current_cursor_position =
-                                                Some(tag.unwrap().len());
+                                app.state.current_cursor_position =
+                                    Some(current_cursor_position + 1);
+                            }
+                            Focus::CardDueDate => {
+                                let current_cursor_position =
+                                    app.state.current_cursor_position.unwrap_or(0);
+                                for (i, char) in current_key.chars().enumerate() {
+                                    card_being_edited
+                                        .1
+                                        .date_due
+                                        .insert(current_cursor_position + i, char);
+                                }
+                                app.state.current_cursor_position =
+                                    Some(current_cursor_position + 1);
+                            }
+                            Focus::CardTags => {
+                                let mut current_cursor_position =
+                                    app.state.current_cursor_position.unwrap_or(0);
+                                if app.state.card_view_tag_list_state.selected().is_some() {
+                                    let selected_tag =
+                                        app.state.card_view_tag_list_state.selected().unwrap();
+                                    let mut tag = card_being_edited.1.tags.get_mut(selected_tag);
+                                    if tag.is_some() {
+                                        let tag = tag.as_mut().unwrap();
+                                        if current_cursor_position > tag.len() {
+                                            current_cursor_position = tag.len();
                                         }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                        for (i, char) in current_key.chars().enumerate() {
+                                            tag.insert(current_cursor_position + i, char);
+                                        }
+                                        app.state.current_cursor_position =
+                                            Some(current_cursor_position + 1);
                                     }
+                                } else {
+                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get(selected_comment);
-                                        if comment.is_some() {
-                                            app.state.current_cursor_position =
-                                                Some(comment.unwrap().len());
+                            }
+                            Focus::CardComments => {
+                                let mut current_cursor_position =
+                                    app.state.current_cursor_position.unwrap_or(0);
+                                if app.state.card_view_comment_list_state.selected().is_some() {
+                                    let selected_comment =
+                                        app.state.card_view_comment_list_state.selected().unwrap();
+                                    let mut comment =
+                                        card_being_edited.1.comments.get_mut(selected_comment);
+                                    if comment.is_some() {
+                                        let comment = comment.as_mut().unwrap();
+                                        if current_cursor_position > comment.len() {
+                                            current_cursor_position = comment.len();
                                         }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                        for (i, char) in current_key.chars().enumerate() {
+                                            comment.insert(current_cursor_position + i, char);
+                                        }
+                                        app.state.current_cursor_position =
+                                            Some(current_cursor_position + 1);
                                     }
+                                } else {
+                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
                                 }
-                                _ => {}
                             }
-                            return AppReturn::Continue;
+                            _ => {}
+                        }
+                    }
+                    PopupMode::CommandPalette
+                    | PopupMode::CustomRGBPromptFG
+                    | PopupMode::CustomRGBPromptBG
+                    | PopupMode::EditGeneralConfig
+                    | PopupMode::EditBoardDescription
+                    | PopupMode::SaveNote
+                    | PopupMode::ImportMarkdown
+                    | PopupMode::ImportJson
+                    | PopupMode::ImportTrello
+                    | PopupMode::EditBoardCardLimit
+                    | PopupMode::EditBoardDueDate
+                    | PopupMode::EditCardProgress
+                    | PopupMode::EditBoardAccent
+                    | PopupMode::NewCardOnAllBoards
+                    | PopupMode::ConfirmLoadSave
+                    | PopupMode::ConfirmPruneArchivedCards
+                    | PopupMode::ConfirmDeleteBoard => {
+                        let current_cursor_position =
+                            app.state.current_cursor_position.unwrap_or(0);
+                        for (i, char) in current_key.chars().enumerate() {
+                            app.state
+                                .current_user_input
+                                .insert(current_cursor_position + i, char);
+                        }
+                        app.state.current_cursor_position = Some(current_cursor_position + 1);
+                    }
+                    _ => {
+                        debug!(
+                            "Invalid popup mode '{}' for user input",
+                            app.state.popup_mode.unwrap()
+                        );
+                    }
+                }
+                return AppReturn::Continue;
+            }
+            match app.state.focus {
+                Focus::NewBoardName => {
+                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state.new_board_form[0].insert(cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(cursor_position + 1);
+                }
+                Focus::NewBoardDescription => {
+                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state.new_board_form[1].insert(cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(cursor_position + 1);
+                }
+                Focus::CardName => {
+                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state.new_card_form[0].insert(cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(cursor_position + 1);
+                }
+                Focus::CardDescription => {
+                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state.new_card_form[1].insert(current_cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                }
+                Focus::CardDueDate => {
+                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state.new_card_form[2].insert(current_cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                }
+                _ => {
+                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
+                    for (i, char) in current_key.chars().enumerate() {
+                        app.state
+                            .current_user_input
+                            .insert(current_cursor_position + i, char);
+                    }
+                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                }
+            }
+        }
+    }
+    AppReturn::Continue
+}
+
+pub async fn handle_keybind_mode(app: &mut App, key: Key) -> AppReturn {
+    match key {
+        Key::Esc => {
+            app.state.app_status = AppStatus::Initialized;
+            app.state.edited_keybinding = None;
+            info!("Exiting user keybind input mode");
+        }
+        _ => {
+            if app.config.keybindings.stop_user_input.contains(&key) {
+                app.state.app_status = AppStatus::Initialized;
+                info!("Exiting user keybind input mode");
+                return AppReturn::Continue;
+            }
+            if app.state.edited_keybinding.is_some() {
+                let keybinding = app.state.edited_keybinding.as_mut().unwrap();
+                keybinding.push(key);
+            } else {
+                app.state.edited_keybinding = Some(vec![key]);
+            }
+        }
+    }
+    AppReturn::Continue
+}
+
+pub fn handle_card_jump_mode(app: &mut App, key: Key) -> AppReturn {
+    match key {
+        Key::Char(c) => {
+            app.state.card_jump_input.push(c);
+            let current_input = app.state.card_jump_input.clone();
+            let matching_labels = app
+                .state
+                .card_jump_labels
+                .iter()
+                .filter(|(label, _, _)| label.starts_with(&current_input))
+                .cloned()
+                .collect::<Vec<(String, u128, u128)>>();
+            if matching_labels.is_empty() {
+                warn!("No card found with label '{}'", current_input);
+                app.send_warning_toast(
+                    &format!("No card found with label '{}'", current_input),
+                    None,
+                );
+                exit_card_jump_mode(app);
+            } else if let Some((_, board_id, card_id)) = matching_labels
+                .iter()
+                .find(|(label, _, _)| *label == current_input)
+            {
+                app.state.current_board_id = Some(*board_id);
+                app.state.current_card_id = Some(*card_id);
+                app.state.focus = Focus::Body;
+                exit_card_jump_mode(app);
+            }
+        }
+        _ => {
+            // Esc or any other key cancels the jump
+            exit_card_jump_mode(app);
+        }
+    }
+    AppReturn::Continue
+}
+
+fn exit_card_jump_mode(app: &mut App) {
+    app.state.card_jump_labels = Vec::new();
+    app.state.card_jump_input = String::new();
+    app.state.app_status = AppStatus::Initialized;
+    info!("Exiting card jump mode");
+}
+
+/// Generates unique labels for the cards on screen, all labels share the shortest length that
+/// gives every card its own label so no label is a prefix of another
+fn generate_card_jump_labels(count: usize) -> Vec<String> {
+    let label_chars = CARD_JUMP_LABEL_CHARS.chars().collect::<Vec<char>>();
+    let mut labels = label_chars
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>();
+    while labels.len() < count {
+        labels = labels
+            .iter()
+            .flat_map(|prefix| label_chars.iter().map(move |c| format!("{}{}", prefix, c)))
+            .collect();
+    }
+    labels.truncate(count);
+    labels
+}
+
+/// Groups the cards of all boards by status, ignoring board boundaries. Columns are in the
+/// order of `CardStatus::all()` and hold (board_id, card_id) pairs
+pub fn get_status_columns(boards: &[Board]) -> Vec<(CardStatus, Vec<(u128, u128)>)> {
+    CardStatus::all()
+        .into_iter()
+        .map(|status| {
+            let cards = boards
+                .iter()
+                .flat_map(|board| {
+                    board
+                        .cards
+                        .iter()
+                        .filter(|card| card.card_status == status)
+                        .map(move |card| (board.id, card.id))
+                })
+                .collect();
+            (status, cards)
+        })
+        .collect()
+}
+
+/// Returns the (column, row) of the current card in the status column view
+fn get_status_column_position(
+    columns: &[(CardStatus, Vec<(u128, u128)>)],
+    current_card_id: Option<u128>,
+) -> Option<(usize, usize)> {
+    let current_card_id = current_card_id?;
+    columns
+        .iter()
+        .enumerate()
+        .find_map(|(column_index, (_, cards))| {
+            cards
+                .iter()
+                .position(|(_, card_id)| *card_id == current_card_id)
+                .map(|row_index| (column_index, row_index))
+        })
+}
+
+fn select_status_column_card(app: &mut App, board_id: u128, card_id: u128) {
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = Some(card_id);
+}
+
+/// Selects the first card of the first non empty column, returns false if there are no cards
+fn select_first_status_column_card(
+    app: &mut App,
+    columns: &[(CardStatus, Vec<(u128, u128)>)],
+) -> bool {
+    if let Some((board_id, card_id)) = columns.iter().find_map(|(_, cards)| cards.first()) {
+        select_status_column_card(app, *board_id, *card_id);
+        true
+    } else {
+        false
+    }
+}
+
+fn status_column_go_up(app: &mut App) {
+    let columns = get_status_columns(&app.boards);
+    if let Some((column_index, row_index)) =
+        get_status_column_position(&columns, app.state.current_card_id)
+    {
+        if row_index > 0 {
+            let (board_id, card_id) = columns[column_index].1[row_index - 1];
+            select_status_column_card(app, board_id, card_id);
+        } else if app.config.wrap_navigation {
+            if let Some((board_id, card_id)) = columns[column_index].1.last() {
+                select_status_column_card(app, *board_id, *card_id);
+            }
+        }
+    } else {
+        select_first_status_column_card(app, &columns);
+    }
+}
+
+fn status_column_go_down(app: &mut App) {
+    let columns = get_status_columns(&app.boards);
+    if let Some((column_index, row_index)) =
+        get_status_column_position(&columns, app.state.current_card_id)
+    {
+        if let Some((board_id, card_id)) = columns[column_index].1.get(row_index + 1) {
+            select_status_column_card(app, *board_id, *card_id);
+        } else if app.config.wrap_navigation {
+            if let Some((board_id, card_id)) = columns[column_index].1.first() {
+                select_status_column_card(app, *board_id, *card_id);
+            }
+        }
+    } else {
+        select_first_status_column_card(app, &columns);
+    }
+}
+
+fn status_column_go_right(app: &mut App) {
+    let columns = get_status_columns(&app.boards);
+    if let Some((column_index, row_index)) =
+        get_status_column_position(&columns, app.state.current_card_id)
+    {
+        let wrapped_columns = if app.config.wrap_navigation {
+            column_index
+        } else {
+            0
+        };
+        // skip over empty columns
+        if let Some((_, cards)) = columns
+            .iter()
+            .skip(column_index + 1)
+            .chain(columns.iter().take(wrapped_columns))
+            .find(|(_, cards)| !cards.is_empty())
+        {
+            let (board_id, card_id) = cards[row_index.min(cards.len() - 1)];
+            select_status_column_card(app, board_id, card_id);
+        }
+    } else {
+        select_first_status_column_card(app, &columns);
+    }
+}
+
+fn status_column_go_left(app: &mut App) {
+    let columns = get_status_columns(&app.boards);
+    if let Some((column_index, row_index)) =
+        get_status_column_position(&columns, app.state.current_card_id)
+    {
+        let wrapped_columns = if app.config.wrap_navigation {
+            columns.len()
+        } else {
+            0
+        };
+        if let Some((_, cards)) = columns
+            .iter()
+            .take(column_index)
+            .rev()
+            .chain(
+                columns
+                    .iter()
+                    .skip(column_index + 1)
+                    .rev()
+                    .take(wrapped_columns),
+            )
+            .find(|(_, cards)| !cards.is_empty())
+        {
+            let (board_id, card_id) = cards[row_index.min(cards.len() - 1)];
+            select_status_column_card(app, board_id, card_id);
+        }
+    } else {
+        select_first_status_column_card(app, &columns);
+    }
+}
+
+/// Moves the current card to the next or previous status column, which changes its status
+/// but keeps it in the same board
+fn move_card_to_status_column(app: &mut App, move_right: bool) {
+    let columns = get_status_columns(&app.boards);
+    let (column_index, row_index) =
+        match get_status_column_position(&columns, app.state.current_card_id) {
+            Some(position) => position,
+            None => return,
+        };
+    let target_column_index = if move_right {
+        column_index + 1
+    } else if column_index > 0 {
+        column_index - 1
+    } else {
+        return;
+    };
+    let new_status = match columns.get(target_column_index) {
+        Some((status, _)) => status.clone(),
+        None => return,
+    };
+    let (board_id, card_id) = columns[column_index].1[row_index];
+    let board = match app.boards.iter_mut().find(|board| board.id == board_id) {
+        Some(board) => board,
+        None => return,
+    };
+    let card = match board.cards.iter_mut().find(|card| card.id == card_id) {
+        Some(card) => card,
+        None => return,
+    };
+    let temp_old_card = card.clone();
+    card.date_completed = if new_status == CardStatus::Complete {
+        Utc::now().to_string()
+    } else {
+        "N/A".to_string()
+    };
+    card.card_status = new_status.clone();
+    card.date_modified = Utc::now().to_string();
+    card.sync_workflow_stage(&app.config.workflow_stages);
+    let new_card = card.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(
+            temp_old_card,
+            new_card.clone(),
+            board_id,
+        ));
+    info!(
+        "Changed status to {} for card {}",
+        new_status, new_card.name
+    );
+    app.send_info_toast(
+        &format!(
+            "Changed status to {} for card {}",
+            new_status, new_card.name
+        ),
+        None,
+    );
+}
+
+async fn handle_load_save(app: &mut App) {
+    if app.boards.is_empty() {
+        app.dispatch(IoEvent::LoadSave).await;
+        return;
+    }
+    if let Some(save_boards) = app.state.preview_boards_and_cards.as_ref() {
+        let diff = diff_boards(&app.boards, save_boards);
+        if diff.is_empty() {
+            app.dispatch(IoEvent::LoadSave).await;
+        } else {
+            app.state.load_save_diff = Some(diff);
+            let save_index = app.state.load_save_state.selected().unwrap_or(0);
+            let save_name = get_available_local_savefiles()
+                .and_then(|saves| saves.get(save_index).cloned())
+                .unwrap_or_default();
+            app.open_confirmation(
+                PopupMode::ConfirmLoadSave,
+                app.config.permanent_change_confirmation,
+                &save_name,
+            );
+        }
+    } else {
+        warn!("Save preview is not loaded yet, cannot compare with current boards");
+        app.send_warning_toast(
+            "Save preview is still loading or no save is selected, please try again",
+            None,
+        );
+    }
+}
+
+/// Deletes the board right away or asks first, depending on the confirmation style for changes
+/// that can be undone
+fn request_board_deletion(app: &mut App, board_id: u128) {
+    let board_name = if let Some(board) = app.boards.iter().find(|board| board.id == board_id) {
+        board.name.clone()
+    } else {
+        debug!("Board with id {} not found to delete", board_id);
+        return;
+    };
+    if app.config.undoable_change_confirmation == ConfirmationStyle::None {
+        delete_board(app, board_id);
+        return;
+    }
+    app.state.board_to_delete = Some(board_id);
+    app.open_confirmation(
+        PopupMode::ConfirmDeleteBoard,
+        app.config.undoable_change_confirmation,
+        &board_name,
+    );
+}
+
+fn delete_board(app: &mut App, board_id: u128) {
+    let index = if let Some(index) = app.boards.iter().position(|board| board.id == board_id) {
+        index
+    } else {
+        debug!("Board with id {} not found to delete", board_id);
+        return;
+    };
+    let board = app.boards.remove(index);
+    app.filtered_boards.retain(|board| board.id != board_id);
+    let board_name = board.name.clone();
+    // if index is > 0, set current board to previous board, else set to next board, else set to None
+    if index > 0 {
+        app.state.current_board_id = Some(app.boards[index - 1].id);
+    } else if index < app.boards.len() {
+        app.state.current_board_id = Some(app.boards[index].id);
+    } else {
+        app.state.current_board_id = None;
+    }
+    app.state.current_card_id = None;
+    app.visible_boards_and_cards.remove(&board_id);
+    warn!("Deleted board: {}", board_name);
+    app.action_history_manager
+        .new_action(ActionHistory::DeleteBoard(board));
+    app.send_warning_toast(&format!("Deleted board: {}", board_name), None);
+    refresh_visible_boards_and_cards(app);
+}
+
+fn confirm_delete_board(app: &mut App) {
+    if !check_confirmation_typed(app) {
+        return;
+    }
+    app.close_confirmation();
+    if let Some(board_id) = app.state.board_to_delete.take() {
+        delete_board(app, board_id);
+    }
+}
+
+fn cancel_delete_board(app: &mut App) {
+    app.close_confirmation();
+    app.state.board_to_delete = None;
+    info!("Kept the board");
+    app.send_info_toast("Kept the board", None);
+}
+
+/// False and a reminder of what to type when the open confirmation popup needs text that was not
+/// typed yet
+fn check_confirmation_typed(app: &mut App) -> bool {
+    if app.confirmation_typed() {
+        return true;
+    }
+    let confirmation_text = app.state.confirmation_text.clone().unwrap_or_default();
+    app.send_warning_toast(
+        &format!(
+            "Type '{}' exactly to confirm, press {} to start typing",
+            confirmation_text,
+            app.config
+                .keybindings
+                .take_user_input
+                .first()
+                .map_or(String::from("<i>"), |key| key.to_string())
+        ),
+        None,
+    );
+    false
+}
+
+async fn confirm_load_save(app: &mut App) {
+    if !check_confirmation_typed(app) {
+        return;
+    }
+    app.close_confirmation();
+    app.state.load_save_diff = None;
+    app.dispatch(IoEvent::LoadSave).await;
+}
+
+fn cancel_load_save(app: &mut App) {
+    app.close_confirmation();
+    app.state.load_save_diff = None;
+    info!("Cancelled loading save");
+    app.send_info_toast("Cancelled loading save", None);
+}
+
+fn confirm_prune_archived_cards(app: &mut App) {
+    if !check_confirmation_typed(app) {
+        return;
+    }
+    app.close_confirmation();
+    app.state.archived_cards_to_prune.clear();
+    let pruned_cards = prune_archived_cards(
+        &mut app.boards,
+        app.config.archive_retention_days,
+        Local::now().date_naive(),
+        false,
+    );
+    for (board_name, card_name) in &pruned_cards {
+        info!(
+            "Deleted archived card '{}' from board '{}'",
+            card_name, board_name
+        );
+    }
+    app.send_info_toast(
+        &format!("Deleted {} archived cards", pruned_cards.len()),
+        None,
+    );
+}
+
+fn cancel_prune_archived_cards(app: &mut App) {
+    app.close_confirmation();
+    app.state.archived_cards_to_prune.clear();
+    info!("Kept archived cards past the retention period");
+    app.send_info_toast(
+        "Kept archived cards, you will be asked again on the next start",
+        None,
+    );
+}
+
+/// Moves the current card to the top or bottom of its board in one step, the card stays focused
+/// and the board is scrolled so it is visible
+fn move_current_card_to_board_edge(app: &mut App, to_top: bool) -> AppReturn {
+    if !UiMode::view_modes().contains(&app.state.ui_mode)
+        || app.state.focus != Focus::Body
+        || app.state.popup_mode.is_some()
+    {
+        return AppReturn::Continue;
+    }
+    if app.state.status_column_view {
+        app.send_warning_toast("Cards cannot be reordered in the status column view", None);
+        return AppReturn::Continue;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(board_id), Some(card_id)) => (board_id, card_id),
+            _ => return AppReturn::Continue,
+        };
+    let edge_name = if to_top { "top" } else { "bottom" };
+    let board = match app.boards.iter_mut().find(|b| b.id == current_board_id) {
+        Some(board) => board,
+        None => {
+            debug!("Cannot move card to the {}: board not found", edge_name);
+            return AppReturn::Continue;
+        }
+    };
+    let card_index = match board.cards.iter().position(|c| c.id == current_card_id) {
+        Some(card_index) => card_index,
+        None => {
+            debug!("Cannot move card to the {}: card not found", edge_name);
+            return AppReturn::Continue;
+        }
+    };
+    let target_index = if to_top { 0 } else { board.cards.len() - 1 };
+    if card_index == target_index {
+        app.send_warning_toast(
+            &format!("Card is already at the {} of the board", edge_name),
+            None,
+        );
+        return AppReturn::Continue;
+    }
+    let card = board.cards.remove(card_index);
+    board.cards.insert(target_index, card);
+    let mut shown_card_ids = board.cards.iter().map(|c| c.id).collect::<Vec<u128>>();
+    // a filtered view shows a copy of the board, move the card there too
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        if let Some(filtered_index) = filtered_board
+            .cards
+            .iter()
+            .position(|c| c.id == current_card_id)
+        {
+            let card = filtered_board.cards.remove(filtered_index);
+            if to_top {
+                filtered_board.cards.insert(0, card);
+            } else {
+                filtered_board.cards.push(card);
+            }
+        }
+        shown_card_ids = filtered_board.cards.iter().map(|c| c.id).collect();
+    }
+    let no_of_cards_to_show = app.config.no_of_cards_to_show as usize;
+    let visible_cards = if to_top {
+        shown_card_ids
+            .into_iter()
+            .take(no_of_cards_to_show)
+            .collect::<Vec<u128>>()
+    } else {
+        let first_visible_index = shown_card_ids.len().saturating_sub(no_of_cards_to_show);
+        shown_card_ids.split_off(first_visible_index)
+    };
+    app.visible_boards_and_cards
+        .entry(current_board_id)
+        .and_modify(|cards| *cards = visible_cards);
+    app.action_history_manager
+        .new_action(ActionHistory::MoveCardWithinBoard(
+            current_board_id,
+            card_index,
+            target_index,
+        ));
+    AppReturn::Continue
+}
+
+/// Shows the least complete boards first, only the view is sorted until "Save Board Order" is used
+pub fn handle_sort_boards_by_done_ratio(app: &mut App) {
+    let mut sorted_boards = if app.filtered_boards.is_empty() {
+        app.boards.clone()
+    } else {
+        app.filtered_boards.clone()
+    };
+    if sorted_boards.is_empty() {
+        app.send_warning_toast("No boards to sort", None);
+        return;
+    }
+    sort_boards_by_done_ratio(&mut sorted_boards);
+    app.filtered_boards = sorted_boards;
+    refresh_visible_boards_and_cards(app);
+    app.send_info_toast("Sorted boards by done ratio", None);
+}
+
+/// Reorders the boards to match the order they are shown in, boards that are hidden by a filter
+/// keep their order after the shown ones
+pub fn handle_save_board_order(app: &mut App) {
+    if app.filtered_boards.is_empty() {
+        app.send_warning_toast("Boards are already shown in their saved order", None);
+        return;
+    }
+    let shown_board_ids = app
+        .filtered_boards
+        .iter()
+        .map(|board| board.id)
+        .collect::<Vec<u128>>();
+    app.boards.sort_by_key(|board| {
+        shown_board_ids
+            .iter()
+            .position(|id| *id == board.id)
+            .unwrap_or(shown_board_ids.len())
+    });
+    info!("Saved the shown board order");
+    app.send_info_toast("Saved the shown board order", None);
+}
+
+/// Sorts the cards of the current board by due date with undated cards last, the new order is kept
+/// when saving
+pub fn handle_sort_cards_by_due_date(app: &mut App) {
+    if !UiMode::view_modes().contains(&app.state.ui_mode) {
+        app.send_error_toast("Cannot sort cards in this view", None);
+        return;
+    }
+    let current_board = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter_mut().find(|board| board.id == board_id));
+    let current_board = if let Some(board) = current_board {
+        board
+    } else {
+        app.send_warning_toast("No board selected to sort", None);
+        return;
+    };
+    sort_cards_by_due_date(&mut current_board.cards, app.config.card_sort_tie_breaker);
+    let board_name = current_board.name.clone();
+    refresh_visible_boards_and_cards(app);
+    info!("Sorted the cards of board '{}' by due date", board_name);
+    app.send_info_toast(
+        &format!("Sorted the cards of board '{}' by due date", board_name),
+        None,
+    );
+}
+
+/// Keeps a copy of the current card in the config so it can be pasted into any save
+pub fn handle_copy_card(app: &mut App) {
+    if !UiMode::view_modes().contains(&app.state.ui_mode) {
+        app.send_error_toast("Cannot copy cards in this view", None);
+        return;
+    }
+    let card = app
+        .state
+        .current_board_id
+        .zip(app.state.current_card_id)
+        .and_then(|(board_id, card_id)| {
+            app.boards
+                .iter()
+                .find(|board| board.id == board_id)
+                .and_then(|board| board.get_card(card_id))
+        })
+        .cloned();
+    let card = if let Some(card) = card {
+        card
+    } else {
+        app.send_warning_toast("No card selected to copy", None);
+        return;
+    };
+    app.config.card_clipboard = Some(card.clone());
+    if let Err(e) = write_config(&app.config) {
+        error!("Error saving the copied card: {}", e);
+        app.send_error_toast(&format!("Error saving the copied card: {}", e), None);
+        return;
+    }
+    info!("Copied card '{}'", card.name);
+    app.send_info_toast(
+        &format!(
+            "Copied card '{}', use \"Paste Card\" on a board in any save",
+            card.name
+        ),
+        None,
+    );
+}
+
+/// Adds the copied card to the current board with a new id, the copy stays so it can be pasted
+/// again
+pub fn handle_paste_card(app: &mut App) {
+    if !UiMode::view_modes().contains(&app.state.ui_mode) {
+        app.send_error_toast("Cannot paste cards in this view", None);
+        return;
+    }
+    let copied_card = if let Some(card) = &app.config.card_clipboard {
+        card.clone()
+    } else {
+        app.send_warning_toast("No card copied, use \"Copy Card\" first", None);
+        return;
+    };
+    let current_board_id = if let Some(board_id) = app.state.current_board_id {
+        board_id
+    } else {
+        app.send_warning_toast("No board selected to paste the card on", None);
+        return;
+    };
+    let workflow_stages = app.config.workflow_stages.clone();
+    let sequential_ids = app.config.card_id_scheme == CardIdScheme::Sequential;
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return;
+        };
+    let mut new_card = copied_card.duplicate();
+    new_card.date_modified = Utc::now().to_string();
+    new_card.sync_workflow_stage(&workflow_stages);
+    if sequential_ids {
+        new_card.display_id = current_board.next_card_display_id();
+    }
+    current_board.cards.push(new_card.clone());
+    let board_name = current_board.name.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::CreateCard(
+            new_card.clone(),
+            current_board_id,
+        ));
+    refresh_visible_boards_and_cards(app);
+    info!("Pasted card '{}' on board '{}'", new_card.name, board_name);
+    app.send_info_toast(
+        &format!("Pasted card '{}' on board '{}'", new_card.name, board_name),
+        None,
+    );
+}
+
+/// Moves the current card into the archive of its board, archived cards are not shown and are
+/// deleted at startup once they are older than the configured retention period
+pub fn handle_archive_card(app: &mut App) {
+    if !UiMode::view_modes().contains(&app.state.ui_mode) {
+        app.send_error_toast("Cannot archive cards in this view", None);
+        return;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(board_id), Some(card_id)) => (board_id, card_id),
+            _ => {
+                app.send_warning_toast("No card selected to archive", None);
+                return;
+            }
+        };
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return;
+        };
+    let card_index = current_board
+        .cards
+        .iter()
+        .position(|card| card.id == current_card_id);
+    let archived_card = current_board.archive_card(current_card_id, Local::now().date_naive());
+    let archived_card = if let (Some(card_index), Some(archived_card)) = (card_index, archived_card)
+    {
+        // select the previous card, or the next one when the first card was archived
+        app.state.current_card_id = if card_index > 0 {
+            current_board.cards.get(card_index - 1).map(|card| card.id)
+        } else {
+            current_board.cards.first().map(|card| card.id)
+        };
+        archived_card
+    } else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        filtered_board
+            .cards
+            .retain(|card| card.id != current_card_id);
+    }
+    if let Some(visible_cards) = app.visible_boards_and_cards.get_mut(&current_board_id) {
+        visible_cards.retain(|card_id| *card_id != current_card_id);
+    }
+    app.state
+        .selected_card_ids
+        .retain(|id| *id != current_card_id);
+    refresh_visible_boards_and_cards(app);
+    info!("Archived card {}", archived_card.name);
+    app.send_info_toast(&format!("Archived card {}", archived_card.name), None);
+}
+
+enum KeyChordProgress {
+    Complete(&'static Action),
+    Pending,
+    NoChord,
+}
+
+/// Adds the key to the chord being typed, a chord that is not finished within
+/// KEY_CHORD_TIMEOUT is dropped and the key is tried as the start of a new one
+fn advance_key_chord(app: &mut App, key: Key) -> KeyChordProgress {
+    let timed_out = app.state.pending_chord_time.map_or(true, |pending_since| {
+        pending_since.elapsed() > Duration::from_millis(KEY_CHORD_TIMEOUT)
+    });
+    if timed_out {
+        app.state.pending_chord.clear();
+    }
+    let mut keys = app.state.pending_chord.clone();
+    keys.push(key);
+    let chords = &app.config.keybindings.chords;
+    if let Some(chord) = chords.iter().find(|chord| chord.keys == keys) {
+        let action_name = chord.action.clone();
+        app.state.pending_chord.clear();
+        app.state.pending_chord_time = None;
+        return match KeyBindings::str_to_action(app.config.keybindings.clone(), &action_name) {
+            Some(action) => KeyChordProgress::Complete(action),
+            None => {
+                warn!("Chord is bound to unknown action {}", action_name);
+                KeyChordProgress::NoChord
+            }
+        };
+    }
+    if chords.iter().any(|chord| chord.keys.starts_with(&keys)) {
+        debug!(
+            "Waiting for the rest of chord {}",
+            KeyChord::keys_to_string(&keys)
+        );
+        app.state.pending_chord = keys;
+        app.state.pending_chord_time = Some(Instant::now());
+        return KeyChordProgress::Pending;
+    }
+    app.state.pending_chord_time = None;
+    if app.state.pending_chord.is_empty() {
+        KeyChordProgress::NoChord
+    } else {
+        app.state.pending_chord.clear();
+        advance_key_chord(app, key)
+    }
+}
+
+pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
+    if app.state.popup_mode == Some(PopupMode::QuickHelp) {
+        // the quick help overlay is dismissed by any key without triggering its action
+        app.state.popup_mode = None;
+        return AppReturn::Continue;
+    }
+    if app.state.popup_mode == Some(PopupMode::ExpandedCard) {
+        handle_expanded_card_key(app, key);
+        return AppReturn::Continue;
+    }
+    let chord_action = match advance_key_chord(app, key) {
+        KeyChordProgress::Complete(action) => Some(action),
+        KeyChordProgress::Pending => return AppReturn::Continue,
+        KeyChordProgress::NoChord => None,
+    };
+    if let Some(action) = chord_action.or_else(|| app.actions.find(key, &app.config)) {
+        // check if the current focus is in the available focus list for the current ui mode if not assign it to the first
+        if app.state.popup_mode.is_some() {
+            if !PopupMode::get_available_targets(&app.state.popup_mode.unwrap())
+                .iter()
+                .any(|x| x == &app.state.focus)
+            {
+                let available_targets =
+                    PopupMode::get_available_targets(&app.state.popup_mode.unwrap());
+                if !available_targets.is_empty() {
+                    app.state.focus = available_targets[0];
+                }
+            }
+        } else if !UiMode::get_available_targets(&app.state.ui_mode)
+            .iter()
+            .any(|x| x == &app.state.focus)
+        {
+            let available_targets = UiMode::get_available_targets(&app.state.ui_mode);
+            if !available_targets.is_empty() {
+                app.state.focus = available_targets[0];
+            }
+        }
+        match action {
+            Action::Quit => {
+                handle_exit(app).await;
+                AppReturn::Exit
+            }
+            Action::NextFocus => {
+                handle_next_focus(app);
+                AppReturn::Continue
+            }
+            Action::PrvFocus => {
+                handle_prv_focus(app);
+                AppReturn::Continue
+            }
+            Action::ResetUI => {
+                // typing should never reset the ui, even if the key is shared with an input field
+                if app.state.app_status == AppStatus::UserInput {
+                    return AppReturn::Continue;
+                }
+                // in the keybinding editor the reset key only resets the selected keybinding
+                if app.state.ui_mode == UiMode::EditKeybindings
+                    && app.state.popup_mode.is_none()
+                    && app.state.focus == Focus::EditKeybindingsTable
+                    && app.state.edit_keybindings_state.selected().is_some()
+                {
+                    handle_reset_selected_keybinding(app);
+                    return AppReturn::Continue;
+                }
+                if app.config.confirm_reset_ui {
+                    let confirm_window = Duration::from_millis(RESET_UI_CONFIRM_WINDOW);
+                    let confirmed = app
+                        .state
+                        .reset_ui_requested_at
+                        .map_or(false, |requested_at| {
+                            requested_at.elapsed() < confirm_window
+                        });
+                    if !confirmed {
+                        app.state.reset_ui_requested_at = Some(Instant::now());
+                        let reset_keys = app
+                            .config
+                            .keybindings
+                            .reset_ui
+                            .iter()
+                            .map(|key| key.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" or ");
+                        app.send_warning_toast(
+                            &format!("Press {} again to reset the UI", reset_keys),
+                            Some(confirm_window),
+                        );
+                        return AppReturn::Continue;
+                    }
+                    app.state.reset_ui_requested_at = None;
+                }
+                let new_ui_mode = app.config.default_view;
+                let available_focus_targets = UiMode::get_available_targets(&new_ui_mode);
+                // check if focus is still available in the new ui_mode if not set it to the first available tab
+                if !available_focus_targets.contains(&app.state.focus) {
+                    // check if available focus targets is empty
+                    if available_focus_targets.is_empty() {
+                        app.state.focus = Focus::NoFocus;
+                    } else {
+                        app.state.focus = available_focus_targets[0];
+                    }
+                }
+                let default_theme = app.config.default_theme.clone();
+                for theme in app.all_themes.iter_mut() {
+                    if theme.name == default_theme {
+                        app.theme = theme.clone();
+                    }
+                }
+                app.state.toasts = vec![];
+                app.send_info_toast("UI reset, all toasts cleared", None);
+                app.state.ui_mode = new_ui_mode;
+                app.state.popup_mode = None;
+                refresh_visible_boards_and_cards(app);
+                AppReturn::Continue
+            }
+            Action::OpenConfigMenu => {
+                match app.state.ui_mode {
+                    UiMode::ConfigMenu => {
+                        // check if the prv ui mode is the same as the current ui mode
+                        if app.state.prev_ui_mode.is_some()
+                            && app.state.prev_ui_mode.as_ref().unwrap() == &UiMode::ConfigMenu
+                        {
+                            app.state.ui_mode = app.config.default_view;
+                        } else {
+                            app.state.ui_mode = *app
+                                .state
+                                .prev_ui_mode
+                                .as_ref()
+                                .unwrap_or(&app.config.default_view);
                         }
-                        Key::ShiftRight => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if card_being_edited.1.tags.is_empty() {
-                                            return AppReturn::Continue;
-                                        }
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        if selected_tag_index < card_being_edited.1.tags.len() - 1 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index + 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.tags.is_empty() {
-                                            app.state.card_view_tag_list_state.select(Some(0));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if card_being_edited.1.comments.is_empty() {
-                                            return AppReturn::Continue;
-                                        }
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        if selected_comment_index
-                                            < card_being_edited.1.comments.len() - 1
-                                        {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index + 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.comments.is_empty() {
-                                            app.state.card_view_comment_list_state.select(Some(0));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                _ => {}
-                            }
-                            return AppReturn::Continue;
+                    }
+                    _ => {
+                        app.state.prev_ui_mode = Some(app.state.ui_mode);
+                        app.state.ui_mode = UiMode::ConfigMenu;
+                        if app.state.config_state.selected().is_none() {
+                            app.config_next()
                         }
-                        Key::ShiftLeft => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        if selected_tag_index > 0 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index - 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.tags.is_empty() {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(card_being_edited.1.tags.len() - 1));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        if selected_comment_index > 0 {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index - 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.comments.is_empty() {
-                                            app.state.card_view_comment_list_state.select(Some(
-                                                card_being_edited.1.comments.len() - 1,
-                                            ));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                _ => {}
+                        let available_focus_targets = app.state.ui_mode.get_available_targets();
+                        if !available_focus_targets.contains(&app.state.focus) {
+                            // check if available focus targets is empty
+                            if available_focus_targets.is_empty() {
+                                app.state.focus = Focus::NoFocus;
+                            } else {
+                                app.state.focus = available_focus_targets[0];
                             }
-                            return AppReturn::Continue;
                         }
-                        Key::Delete => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        card_being_edited.1.tags.remove(selected_tag_index);
-                                        if selected_tag_index < card_being_edited.1.tags.len() {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index));
-                                        } else if selected_tag_index > 0 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index - 1));
-                                        } else {
-                                            app.state.card_view_tag_list_state.select(None);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected, press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        card_being_edited.1.comments.remove(selected_comment_index);
-                                        if selected_comment_index
-                                            < card_being_edited.1.comments.len()
-                                        {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index));
-                                        } else if selected_comment_index > 0 {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index - 1));
-                                        } else {
-                                            app.state.card_view_comment_list_state.select(None);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected, press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                _ => {}
+                    }
+                }
+                if app.state.popup_mode.is_some() {
+                    app.state.popup_mode = None;
+                }
+                AppReturn::Continue
+            }
+            Action::Up => {
+                reset_mouse(app);
+                if app.state.popup_mode.is_some() {
+                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
+                    match popup_mode {
+                        PopupMode::ChangeUIMode => app.select_default_view_prv(),
+                        PopupMode::CardStatusSelector | PopupMode::BoardStatusSelector => {
+                            app.select_card_status_prv()
+                        }
+                        PopupMode::SelectDefaultView => app.select_default_view_prv(),
+                        PopupMode::ChangeTheme => app.select_change_theme_prv(),
+                        PopupMode::EditThemeStyle => {
+                            if app.state.focus == Focus::StyleEditorFG {
+                                app.select_edit_style_fg_prv();
+                            } else if app.state.focus == Focus::StyleEditorBG {
+                                app.select_edit_style_bg_prv();
+                            } else if app.state.focus == Focus::StyleEditorModifier {
+                                app.select_edit_style_modifier_prv();
                             }
-                            return AppReturn::Continue;
                         }
-                        _ => {
-                            if stop_input_mode_keys.contains(&key) {
-                                app.state.current_cursor_position = None;
-                                if app.card_being_edited.is_some() {
-                                    app.state.popup_mode =
-                                        Some(PopupMode::ConfirmDiscardCardChanges);
-                                    app.state.app_status = AppStatus::Initialized;
-                                }
-                                return AppReturn::Continue;
+                        PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
+                        PopupMode::FilterByTag => app.filter_by_tag_popup_prv(),
+                        PopupMode::FilterPresetSelector => app.filter_preset_popup_prv(),
+                        PopupMode::CardRecurrenceSelector => app.card_recurrence_popup_prv(),
+                        PopupMode::ActivityLog => app.activity_log_popup_prv(),
+                        PopupMode::CardLinkSelector => app.card_link_popup_prv(),
+                        PopupMode::RecentCards => app.recent_cards_popup_prv(),
+                        _ => {}
+                    }
+                    return AppReturn::Continue;
+                }
+                match app.state.ui_mode {
+                    UiMode::ConfigMenu => {
+                        if app.state.focus == Focus::ConfigTable {
+                            app.config_prv();
+                        } else {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the Config Menu with {} or {}, to select a config option using the arrow keys",
+                                next_focus_key, prev_focus_key), None);
+                        }
+                    }
+                    UiMode::MainMenu => {
+                        if app.state.focus == Focus::MainMenu {
+                            app.main_menu_prv();
+                        } else if app.state.focus == Focus::MainMenuHelp {
+                            app.help_prv();
+                        } else {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the Main Menu with {} or {}, to navigate the menu using the arrow keys",
+                                next_focus_key, prev_focus_key), None);
+                        }
+                    }
+                    UiMode::LoadSave => {
+                        app.load_save_prv();
+                        app.dispatch(IoEvent::LoadPreview).await;
+                    }
+                    UiMode::EditKeybindings => {
+                        app.edit_keybindings_prv();
+                    }
+                    UiMode::CreateTheme => {
+                        if app.state.focus == Focus::ThemeEditor {
+                            app.select_create_theme_prv();
+                        } else if app.state.focus == Focus::SubmitButton {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the theme editor with {} or {}, to select a style to edit",
+                                next_focus_key, prev_focus_key), None);
+                        }
+                    }
+                    _ => {
+                        if app.state.focus == Focus::Body {
+                            for _ in 0..get_navigation_steps(app, Action::Up) {
+                                go_up(app);
                             }
+                        } else if app.state.focus == Focus::Help {
+                            app.help_prv();
                         }
                     }
                 }
-                PopupMode::ConfirmDiscardCardChanges => {
-                    match key {
-                        Key::Tab => handle_next_focus(app),
-                        Key::BackTab => handle_prv_focus(app),
-                        Key::Enter => {
-                            if app.state.focus == Focus::SubmitButton {
-                                handle_edit_card_submit(app);
-                                app.state.popup_mode = None;
-                                app.state.app_status = AppStatus::Initialized;
-                            } else {
-                                if app.card_being_edited.is_some() {
-                                    warn!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    );
-                                }
-                                app.send_warning_toast(
-                                    &format!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    ),
-                                    None,
-                                );
-                                app.state.popup_mode = None;
-                                app.card_being_edited = None;
-                                app.state.app_status = AppStatus::Initialized;
+                AppReturn::Continue
+            }
+            Action::Down => {
+                reset_mouse(app);
+                if app.state.popup_mode.is_some() {
+                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
+                    match popup_mode {
+                        PopupMode::ChangeUIMode => app.select_default_view_next(),
+                        PopupMode::CardStatusSelector | PopupMode::BoardStatusSelector => {
+                            app.select_card_status_next()
+                        }
+                        PopupMode::SelectDefaultView => app.select_default_view_next(),
+                        PopupMode::ChangeTheme => app.select_change_theme_next(),
+                        PopupMode::EditThemeStyle => {
+                            if app.state.focus == Focus::StyleEditorFG {
+                                app.select_edit_style_fg_next();
+                            } else if app.state.focus == Focus::StyleEditorBG {
+                                app.select_edit_style_bg_next();
+                            } else if app.state.focus == Focus::StyleEditorModifier {
+                                app.select_edit_style_modifier_next();
                             }
                         }
+                        PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_next(),
+                        PopupMode::FilterByTag => app.filter_by_tag_popup_next(),
+                        PopupMode::FilterPresetSelector => app.filter_preset_popup_next(),
+                        PopupMode::CardRecurrenceSelector => app.card_recurrence_popup_next(),
+                        PopupMode::ActivityLog => app.activity_log_popup_next(),
+                        PopupMode::CardLinkSelector => app.card_link_popup_next(),
+                        PopupMode::RecentCards => app.recent_cards_popup_next(),
                         _ => {}
                     }
                     return AppReturn::Continue;
                 }
-                PopupMode::CardPrioritySelector => match key {
-                    Key::Up => {
-                        app.select_card_priority_prv();
+                match app.state.ui_mode {
+                    UiMode::ConfigMenu => {
+                        if app.state.focus == Focus::ConfigTable {
+                            app.config_next();
+                        } else {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the Config Menu with {} or {}, to select a config option using the arrow keys",
+                                next_focus_key, prev_focus_key), None);
+                        }
                     }
-                    Key::Down => {
-                        app.select_card_priority_next();
+                    UiMode::MainMenu => {
+                        if app.state.focus == Focus::MainMenu {
+                            app.main_menu_next();
+                        } else if app.state.focus == Focus::MainMenuHelp {
+                            app.help_next();
+                        } else {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the Main Menu with {} or {}, to navigate the menu using the arrow keys",
+                                next_focus_key, prev_focus_key), None);
+                        }
                     }
-                    Key::Enter => {
-                        handle_change_card_priority(app);
+                    UiMode::LoadSave => {
+                        app.load_save_next();
+                        app.dispatch(IoEvent::LoadPreview).await;
                     }
-                    _ => {}
-                },
-                PopupMode::CardStatusSelector => match key {
-                    Key::Up => {
-                        app.select_card_status_prv();
+                    UiMode::EditKeybindings => {
+                        app.edit_keybindings_next();
                     }
-                    Key::Down => {
-                        app.select_card_status_next();
+                    UiMode::CreateTheme => {
+                        if app.state.focus == Focus::ThemeEditor {
+                            app.select_create_theme_next();
+                        } else if app.state.focus == Focus::SubmitButton {
+                            let next_focus_key = app
+                                .config
+                                .keybindings
+                                .next_focus
+                                .get(0)
+                                .unwrap_or(&Key::Tab);
+                            let prev_focus_key = app
+                                .config
+                                .keybindings
+                                .prev_focus
+                                .get(0)
+                                .unwrap_or(&Key::BackTab);
+                            app.send_warning_toast(&format!(
+                                "Move Focus to the theme editor with {} or {}, to select a style to edit",
+                                next_focus_key, prev_focus_key), None);
+                        }
                     }
-                    Key::Enter => {
-                        handle_change_card_status(app);
+                    _ => {
+                        if app.state.focus == Focus::Body {
+                            for _ in 0..get_navigation_steps(app, Action::Down) {
+                                go_down(app);
+                            }
+                        } else if app.state.focus == Focus::Help {
+                            app.help_next();
+                        }
                     }
-                    _ => {}
-                },
-                _ => {}
+                }
+                AppReturn::Continue
             }
-        }
-        let mut current_key = key.to_string();
-        match key {
-            Key::Char(' ') => current_key = " ".to_string(),
-            Key::Enter => current_key = "\n".to_string(),
-            Key::Tab => current_key = "  ".to_string(),
-            Key::BackTab => current_key = "".to_string(),
-            Key::Backspace => {
+            Action::Right => {
+                reset_mouse(app);
+                if app.state.focus == Focus::Body
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                    && app.state.popup_mode.is_none()
+                {
+                    go_right(app);
+                }
+                AppReturn::Continue
+            }
+            Action::Left => {
+                reset_mouse(app);
+                if app.state.focus == Focus::Body
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                    && app.state.popup_mode.is_none()
+                {
+                    go_left(app);
+                }
+                AppReturn::Continue
+            }
+            Action::TakeUserInput => {
                 match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_board_form[0].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
+                    UiMode::NewBoard | UiMode::NewCard => {
+                        app.state.app_status = AppStatus::UserInput;
+                        info!("Taking user input");
+                    }
+                    _ => {
+                        if app.state.popup_mode.is_some() {
+                            match app.state.popup_mode.unwrap() {
+                                PopupMode::EditGeneralConfig
+                                | PopupMode::EditBoardDescription
+                                | PopupMode::SaveNote
+                                | PopupMode::ImportMarkdown
+                                | PopupMode::ImportJson
+                                | PopupMode::ImportTrello
+                                | PopupMode::EditBoardCardLimit
+                                | PopupMode::EditBoardDueDate
+                                | PopupMode::EditCardProgress
+                                | PopupMode::EditBoardAccent
+                                | PopupMode::NewCardOnAllBoards
+                                | PopupMode::CustomRGBPromptFG
+                                | PopupMode::CustomRGBPromptBG => {
+                                    app.state.app_status = AppStatus::UserInput;
+                                    info!("Taking user input");
                                 }
-                            } else {
-                                app.state.new_board_form[0].pop();
-                            }
-                        }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_board_form[1].remove(current_cursor_position - 1);
+                                PopupMode::ConfirmLoadSave
+                                | PopupMode::ConfirmPruneArchivedCards
+                                | PopupMode::ConfirmDeleteBoard
+                                    if app.state.confirmation_text.is_some() =>
+                                {
                                     app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
+                                        Some(app.state.current_user_input.len());
+                                    app.state.app_status = AppStatus::UserInput;
+                                    info!("Taking user input");
                                 }
-                            } else {
-                                app.state.new_board_form[1].pop();
-                            }
-                        }
-                        _ => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state
-                                        .current_user_input
-                                        .remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
+                                PopupMode::EditSpecificKeyBinding => {
+                                    app.state.app_status = AppStatus::KeyBindMode;
+                                    info!("Taking user keybind input");
                                 }
-                            } else {
-                                app.state.current_user_input.pop();
-                            }
-                        }
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::CardName => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[0].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
+                                PopupMode::ViewCard => {
+                                    if app.state.current_board_id.is_some()
+                                        && app.state.current_card_id.is_some()
+                                    {
+                                        let board = app.boards.iter().find(|board| {
+                                            board.id == app.state.current_board_id.unwrap()
+                                        });
+                                        if board.is_some() {
+                                            let card = board.unwrap().cards.iter().find(|card| {
+                                                card.id == app.state.current_card_id.unwrap()
+                                            });
+                                            if card.is_some() {
+                                                app.card_being_edited = Some((
+                                                    app.state.current_board_id.unwrap(),
+                                                    card.unwrap().clone(),
+                                                ));
+                                            }
+                                        }
+                                        app.state.app_status = AppStatus::UserInput;
+                                    } else {
+                                        debug!("No current board or card id to edit card");
+                                    }
                                 }
-                            } else {
-                                app.state.new_card_form[0].pop();
+                                _ => {}
                             }
                         }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[1].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_card_form[1].pop();
-                            }
+                    }
+                }
+                AppReturn::Continue
+            }
+            Action::StopUserInput => {
+                if app.state.app_status == AppStatus::UserInput {
+                    app.state.app_status = AppStatus::Initialized;
+                    info!("Exiting user input mode");
+                }
+                AppReturn::Continue
+            }
+            Action::GoToPreviousUIMode => handle_go_to_previous_ui_mode(app).await,
+            Action::Enter => {
+                if app.state.popup_mode.is_some() {
+                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
+                    match popup_mode {
+                        PopupMode::ChangeUIMode => handle_change_ui_mode(app),
+                        PopupMode::CardStatusSelector => {
+                            return handle_change_card_status(app);
                         }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[2].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
+                        PopupMode::BoardStatusSelector => {
+                            return handle_change_board_cards_status(app);
+                        }
+                        PopupMode::EditGeneralConfig => {
+                            if app.state.ui_mode == UiMode::CreateTheme {
+                                handle_create_theme_action(app);
                             } else {
-                                app.state.new_card_form[2].pop();
+                                handle_edit_general_config(app);
                             }
                         }
-                        _ => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state
-                                        .current_user_input
-                                        .remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
+                        PopupMode::EditBoardDescription => handle_edit_board_description(app),
+                        PopupMode::SaveNote => handle_save_note(app).await,
+                        PopupMode::ImportMarkdown => handle_import_markdown(app),
+                        PopupMode::ImportJson => handle_import_json(app).await,
+                        PopupMode::ImportTrello => handle_import_trello(app).await,
+                        PopupMode::EditBoardCardLimit => handle_edit_board_card_limit(app),
+                        PopupMode::EditBoardDueDate => handle_edit_board_due_date(app),
+                        PopupMode::EditCardProgress => handle_edit_card_progress(app),
+                        PopupMode::EditBoardAccent => handle_edit_board_accent(app),
+                        PopupMode::NewCardOnAllBoards => handle_new_card_on_all_boards(app),
+                        PopupMode::QuickHelp | PopupMode::ExpandedCard => {}
+                        PopupMode::EditSpecificKeyBinding => handle_edit_specific_keybinding(app),
+                        PopupMode::SelectDefaultView => handle_default_view_selection(app),
+                        PopupMode::ChangeDateFormatPopup => handle_change_date_format(app),
+                        PopupMode::ChangeTheme => {
+                            return handle_change_theme(app, app.state.default_theme_mode)
+                        }
+                        PopupMode::EditThemeStyle => return handle_create_theme_action(app),
+                        PopupMode::SaveThemePrompt => handle_save_theme_prompt(app),
+                        PopupMode::CustomRGBPromptFG => return handle_custom_rgb_prompt(app, true),
+                        PopupMode::CustomRGBPromptBG => {
+                            return handle_custom_rgb_prompt(app, false)
+                        }
+                        PopupMode::ViewCard => match app.state.focus {
+                            Focus::CardPriority => {
+                                if app.card_being_edited.is_none() {
+                                    handle_edit_new_card(app);
                                 }
-                            } else {
-                                app.state.current_user_input.pop();
+                                app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
+                                return AppReturn::Continue;
                             }
-                        }
-                    },
-                    _ => {
-                        if app.state.current_cursor_position.is_some() {
-                            let current_cursor_position =
-                                app.state.current_cursor_position.unwrap();
-                            if current_cursor_position > 0 {
-                                app.state
-                                    .current_user_input
-                                    .remove(current_cursor_position - 1);
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position - 1);
+                            Focus::CardStatus => {
+                                if app.card_being_edited.is_none() {
+                                    handle_edit_new_card(app);
+                                }
+                                app.state.popup_mode = Some(PopupMode::CardStatusSelector);
+                                return AppReturn::Continue;
                             }
-                        } else {
-                            app.state.current_user_input.pop();
-                        }
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::Left => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[0].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
+                            Focus::CardPoints => {
+                                if app.card_being_edited.is_none() {
+                                    handle_edit_new_card(app);
+                                }
+                                app.state.popup_mode = Some(PopupMode::CardPointsSelector);
+                                return AppReturn::Continue;
                             }
-                        }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[1].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
+                            Focus::CardName
+                            | Focus::CardDescription
+                            | Focus::CardDueDate
+                            | Focus::CardTags
+                            | Focus::CardComments => return handle_edit_new_card(app),
+                            Focus::SubmitButton => {
+                                return handle_edit_card_submit(app);
                             }
+                            _ => {}
+                        },
+                        PopupMode::CommandPalette => {
+                            // not required to handle here as the command palette is handled in the user input mode
                         }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::CardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[0].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
+                        PopupMode::ConfirmDiscardCardChanges => match app.state.focus {
+                            Focus::SubmitButton => {
+                                handle_edit_card_submit(app);
+                                app.state.popup_mode = None;
                             }
-                        }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[1].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
+                            Focus::ExtraFocus => {
+                                if app.card_being_edited.is_some() {
+                                    warn!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    );
+                                    app.send_warning_toast(
+                                        &format!(
+                                            "Discarding changes to card '{}'",
+                                            app.card_being_edited.as_ref().unwrap().1.name
+                                        ),
+                                        None,
+                                    );
+                                }
+                                app.state.popup_mode = None;
+                                app.card_being_edited = None;
                             }
+                            _ => {}
+                        },
+                        PopupMode::CardPrioritySelector => {
+                            return handle_change_card_priority(app);
                         }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[2].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
+                        PopupMode::CardPointsSelector => {
+                            return handle_change_card_points(app);
                         }
-                        _ => {}
-                    },
-                    _ => {
-                        if app.state.current_cursor_position.is_none() {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_user_input.len());
-                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_cursor_position.unwrap() - 1);
-                        } else {
-                            app.state.current_cursor_position = Some(0);
+                        PopupMode::FilterByTag => {
+                            handle_filter_by_tag(app);
+                            return AppReturn::Continue;
                         }
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::Right => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_board_form[0].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[0].len());
-                            }
+                        PopupMode::FilterPresetSelector => handle_filter_preset_selection(app),
+                        PopupMode::CardRecurrenceSelector => handle_card_recurrence_selection(app),
+                        PopupMode::ActivityLog => {}
+                        PopupMode::CardLinkSelector => {
+                            handle_card_link_selection(app);
+                            return AppReturn::Continue;
                         }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_board_form[1].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[1].len());
-                            }
+                        PopupMode::RecentCards => {
+                            handle_recent_card_selection(app);
+                            return AppReturn::Continue;
                         }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::CardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[0].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[0].len());
+                        PopupMode::ConfirmLoadSave => {
+                            match app.state.focus {
+                                Focus::SubmitButton => confirm_load_save(app).await,
+                                Focus::ExtraFocus => cancel_load_save(app),
+                                _ => {}
                             }
+                            return AppReturn::Continue;
                         }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[1].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[1].len());
+                        PopupMode::ConfirmPruneArchivedCards => {
+                            match app.state.focus {
+                                Focus::SubmitButton => confirm_prune_archived_cards(app),
+                                Focus::ExtraFocus => cancel_prune_archived_cards(app),
+                                _ => {}
                             }
+                            return AppReturn::Continue;
                         }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[2].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[2].len());
+                        PopupMode::ConfirmDeleteBoard => {
+                            match app.state.focus {
+                                Focus::SubmitButton => confirm_delete_board(app),
+                                Focus::ExtraFocus => cancel_delete_board(app),
+                                _ => {}
                             }
+                            return AppReturn::Continue;
                         }
-                        _ => {}
+                    }
+                    app.state.popup_mode = None;
+                    return AppReturn::Continue;
+                }
+                match app.state.ui_mode {
+                    UiMode::ConfigMenu => handle_config_menu_action(app),
+                    UiMode::MainMenu => match app.state.focus {
+                        Focus::MainMenu => handle_main_menu_action(app).await,
+                        Focus::MainMenuHelp => {
+                            app.state.ui_mode = UiMode::HelpMenu;
+                            AppReturn::Continue
+                        }
+                        Focus::Log => {
+                            app.state.ui_mode = UiMode::LogsOnly;
+                            AppReturn::Continue
+                        }
+                        _ => AppReturn::Continue,
                     },
+                    UiMode::NewBoard => {
+                        handle_new_board_action(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::NewCard => handle_new_card_action(app),
+                    UiMode::LoadSave => {
+                        handle_load_save(app).await;
+                        AppReturn::Continue
+                    }
+                    UiMode::EditKeybindings => {
+                        handle_edit_keybindings_action(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::CreateTheme => {
+                        handle_create_theme_action(app);
+                        AppReturn::Continue
+                    }
                     _ => {
-                        if app.state.current_cursor_position.is_none() {
-                            app.state.current_cursor_position = Some(0);
-                        } else if app.state.current_cursor_position.unwrap()
-                            < app.state.current_user_input.len()
+                        match app.state.focus {
+                            Focus::Help => {
+                                app.state.prev_ui_mode = Some(app.state.ui_mode);
+                                app.state.ui_mode = UiMode::HelpMenu;
+                            }
+                            Focus::Log => {
+                                app.state.prev_ui_mode = Some(app.state.ui_mode);
+                                app.state.ui_mode = UiMode::LogsOnly;
+                            }
+                            _ => {}
+                        }
+                        if UiMode::view_modes().contains(&app.state.ui_mode)
+                            && app.state.focus == Focus::Body
                         {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_cursor_position.unwrap() + 1);
-                        } else {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_user_input.len());
+                            // check if there is a current card
+                            if let Some(current_card_id) = app.state.current_card_id {
+                                if let Some(current_board_id) = app.state.current_board_id {
+                                    // check if the current card is in the current board
+                                    let current_board = app
+                                        .boards
+                                        .iter()
+                                        .find(|board| board.id == current_board_id);
+                                    if let Some(current_board) = current_board {
+                                        let current_card = current_board
+                                            .cards
+                                            .iter()
+                                            .find(|card| card.id == current_card_id);
+                                        if current_card.is_some() {
+                                            match app.config.card_enter_action {
+                                                CardEnterAction::OpenDetail => {
+                                                    app.state.popup_mode =
+                                                        Some(PopupMode::ViewCard);
+                                                }
+                                                CardEnterAction::ChangeStatus => {
+                                                    app.state.popup_mode =
+                                                        Some(PopupMode::CardStatusSelector);
+                                                }
+                                                CardEnterAction::EditCard => {
+                                                    app.state.popup_mode =
+                                                        Some(PopupMode::ViewCard);
+                                                    app.state.focus = Focus::CardName;
+                                                    handle_edit_new_card(app);
+                                                }
+                                            }
+                                        } else {
+                                            // if the current card is not in the current board then set the current card to None
+                                            app.state.current_card_id = None;
+                                        }
+                                    } else {
+                                        // if the current board is not found then set the current card to None
+                                        app.state.current_card_id = None;
+                                    }
+                                } else {
+                                    // if the current board is not found then set the current card to None
+                                    app.state.current_card_id = None;
+                                }
+                            }
                         }
+                        AppReturn::Continue
                     }
-                };
-                current_key = "".to_string();
+                }
             }
-            Key::Home => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName | Focus::NewBoardDescription => {
-                            app.state.current_cursor_position = Some(0);
+            Action::HideUiElement => {
+                let current_focus =
+                    if let Ok(current_focus) = Focus::from_str(app.state.focus.to_str()) {
+                        current_focus
+                    } else {
+                        Focus::NoFocus
+                    };
+                let current_ui_mode = app.state.ui_mode;
+                // hide the current focus by switching to a view where it is not available
+                // for example if current uimode is Title and focus is on Title then switch to Zen
+                if current_ui_mode == UiMode::Zen {
+                    app.state.ui_mode = UiMode::MainMenu;
+                    if app.state.main_menu_state.selected().is_none() {
+                        app.main_menu_next();
+                    }
+                } else if current_ui_mode == UiMode::TitleBody {
+                    if current_focus == Focus::Title {
+                        app.state.ui_mode = UiMode::Zen;
+                        app.state.focus = Focus::Body;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::CardName | Focus::CardDescription | Focus::CardDueDate => {
-                            app.state.current_cursor_position = Some(0);
+                    }
+                } else if current_ui_mode == UiMode::BodyHelp {
+                    if current_focus == Focus::Help {
+                        app.state.ui_mode = UiMode::Zen;
+                        app.state.focus = Focus::Body;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        _ => {}
-                    },
-                    _ => {
-                        app.state.current_cursor_position = Some(0);
                     }
-                };
-                current_key = "".to_string();
-            }
-            Key::End => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_board_form[0].len());
+                } else if current_ui_mode == UiMode::BodyLog {
+                    if current_focus == Focus::Log {
+                        app.state.ui_mode = UiMode::Zen;
+                        app.state.focus = Focus::Body;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        Focus::NewBoardDescription => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_board_form[1].len());
+                    }
+                } else if current_ui_mode == UiMode::TitleBodyHelp {
+                    if current_focus == Focus::Title {
+                        app.state.ui_mode = UiMode::BodyHelp;
+                        app.state.focus = Focus::Body;
+                    } else if current_focus == Focus::Help {
+                        app.state.ui_mode = UiMode::TitleBody;
+                        app.state.focus = Focus::Title;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::CardName => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[0].len());
+                    }
+                } else if current_ui_mode == UiMode::TitleBodyLog {
+                    if current_focus == Focus::Title {
+                        app.state.ui_mode = UiMode::BodyLog;
+                        app.state.focus = Focus::Body;
+                    } else if current_focus == Focus::Log {
+                        app.state.ui_mode = UiMode::TitleBody;
+                        app.state.focus = Focus::Title;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        Focus::CardDescription => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[1].len());
+                    }
+                } else if current_ui_mode == UiMode::TitleBodyHelpLog {
+                    if current_focus == Focus::Title {
+                        app.state.ui_mode = UiMode::BodyHelpLog;
+                        app.state.focus = Focus::Body;
+                    } else if current_focus == Focus::Help {
+                        app.state.ui_mode = UiMode::TitleBodyLog;
+                        app.state.focus = Focus::Title;
+                    } else if current_focus == Focus::Log {
+                        app.state.ui_mode = UiMode::TitleBodyHelp;
+                        app.state.focus = Focus::Title;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        Focus::CardDueDate => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[2].len());
+                    }
+                } else if current_ui_mode == UiMode::BodyHelpLog {
+                    if current_focus == Focus::Help {
+                        app.state.ui_mode = UiMode::BodyLog;
+                        app.state.focus = Focus::Body;
+                    } else if current_focus == Focus::Log {
+                        app.state.ui_mode = UiMode::BodyHelp;
+                        app.state.focus = Focus::Body;
+                    } else {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next();
                         }
-                        _ => {}
-                    },
-                    _ => {
-                        app.state.current_cursor_position =
-                            Some(app.state.current_user_input.len());
                     }
-                };
-                current_key = "".to_string();
+                }
+                AppReturn::Continue
             }
-            _ => {
-                if app.config.keybindings.stop_user_input.contains(&key) {
-                    app.state.app_status = AppStatus::Initialized;
-                    app.state.current_cursor_position = None;
-                    info!("Exiting User Input Mode");
-                    return AppReturn::Continue;
+            Action::SaveState => {
+                if app.config.prompt_for_save_note && app.state.popup_mode.is_none() {
+                    open_save_note_prompt(app);
+                } else {
+                    app.dispatch(IoEvent::SaveLocalData).await;
                 }
-                if current_key.starts_with('<') && current_key.ends_with('>') {
-                    current_key = current_key[1..current_key.len() - 1].to_string();
+                AppReturn::Continue
+            }
+            Action::NewBoard => {
+                // check if current ui_mode is in UiMode::view_modes()
+                if UiMode::view_modes().contains(&app.state.ui_mode) {
+                    app.state.new_board_form = NEW_BOARD_FORM_DEFAULT_STATE
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect();
+                    app.set_ui_mode(UiMode::NewBoard);
+                    app.state.previous_focus = Some(app.state.focus);
                 }
-                if current_key.is_empty() {
-                    return AppReturn::Continue;
+                AppReturn::Continue
+            }
+            Action::NewCard => {
+                if UiMode::view_modes().contains(&app.state.ui_mode) {
+                    // check if current board is not empty
+                    if app.state.current_board_id.is_none() {
+                        warn!("No board available to add card to");
+                        app.send_warning_toast("No board available to add card to", None);
+                        return AppReturn::Continue;
+                    }
+                    app.state.new_card_form = NEW_CARD_FORM_DEFAULT_STATE
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect();
+                    app.set_ui_mode(UiMode::NewCard);
+                    app.state.previous_focus = Some(app.state.focus);
                 }
+                AppReturn::Continue
             }
-        }
-
-        // Actually appending input to the current user input* (depending on the current mode)
-
-        if current_key.chars().next().is_some() {
-            if app.state.popup_mode.is_some() {
-                match app.state.popup_mode.unwrap() {
-                    PopupMode::ViewCard => {
-                        let card_being_edited = app.card_being_edited.as_mut().unwrap();
+            Action::DeleteCard => {
+                match app.state.ui_mode {
+                    UiMode::LoadSave => {
+                        // run delete task in background
+                        app.dispatch(IoEvent::DeleteSave).await;
+                        tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
+                        app.dispatch(IoEvent::LoadPreview).await;
+                        AppReturn::Continue
+                    }
+                    _ => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            return AppReturn::Continue;
+                        }
                         match app.state.focus {
-                            Focus::CardName => {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                for (i, char) in current_key.chars().enumerate() {
-                                    card_being_edited
-                                        .1
-                                        .name
-                                        .insert(current_cursor_position + i, char);
-                                }
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position + 1);
-                            }
-                            Focus::CardDescription => {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                for (i, char) in current_key.chars().enumerate() {
-                                    card_being_edited
-                                        .1
-                                        .description
-                                        .insert(current_cursor_position + i, char);
-                                }
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position + 1);
-                            }
-                            Focus::CardDueDate => {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                for (i, char) in current_key.chars().enumerate() {
-                                    card_being_edited
-                                        .1
-                                        .date_due
-                                        .insert(current_cursor_position + i, char);
-                                }
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position + 1);
-                            }
-                            Focus::CardTags => {
-                                let mut current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                if app.state.card_view_tag_list_state.selected().is_some() {
-                                    let selected_tag =
-                                        app.state.card_view_tag_list_state.selected().unwrap();
-                                    let mut tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                    if tag.is_some() {
-                                        let tag = tag.as_mut().unwrap();
-                                        if current_cursor_position > tag.len() {
-                                            current_cursor_position = tag.len();
-                                        }
-                                        for (i, char) in current_key.chars().enumerate() {
-                                            tag.insert(current_cursor_position + i, char);
-                                        }
-                                        app.state.current_cursor_position =
-                                            Some(current_cursor_position + 1);
-                                    }
-                                } else {
-                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                }
-                            }
-                            Focus::CardComments => {
-                                let mut current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                if app.state.card_view_comment_list_state.selected().is_some() {
-                                    let selected_comment =
-                                        app.state.card_view_comment_list_state.selected().unwrap();
-                                    let mut comment =
-                                        card_being_edited.1.comments.get_mut(selected_comment);
-                                    if comment.is_some() {
-                                        let comment = comment.as_mut().unwrap();
-                                        if current_cursor_position > comment.len() {
-                                            current_cursor_position = comment.len();
-                                        }
-                                        for (i, char) in current_key.chars().enumerate() {
-                                            comment.insert(current_cursor_position + i, char);
+                            Focus::Body => {
+                                // delete the current card
+                                if let Some(current_board) = app.state.current_board_id {
+                                    // find index of current board id in app.boards
+                                    let index = app
+                                        .boards
+                                        .iter()
+                                        .position(|board| board.id == current_board);
+                                    if let Some(current_card) = app.state.current_card_id {
+                                        let card_index = app.boards[index.unwrap()]
+                                            .cards
+                                            .iter()
+                                            .position(|card| card.id == current_card);
+                                        if let Some(card_index) = card_index {
+                                            let card = app.boards[index.unwrap()].cards[card_index]
+                                                .clone();
+                                            let card_name = card.name.clone();
+                                            app.boards[index.unwrap()].cards.remove(card_index);
+                                            // if index is > 0, set current card to previous card, else set to next card, else set to None
+                                            if card_index > 0 {
+                                                app.state.current_card_id = Some(
+                                                    app.boards[index.unwrap()].cards
+                                                        [card_index - 1]
+                                                        .id,
+                                                );
+                                            } else if !app.boards[index.unwrap()].cards.is_empty() {
+                                                app.state.current_card_id =
+                                                    Some(app.boards[index.unwrap()].cards[0].id);
+                                            } else {
+                                                app.state.current_card_id = None;
+                                            }
+                                            warn!("Deleted card {}", card_name);
+                                            app.action_history_manager.new_action(
+                                                ActionHistory::DeleteCard(card, current_board),
+                                            );
+                                            app.send_warning_toast(
+                                                &format!("Deleted card {}", card_name),
+                                                None,
+                                            );
+                                            // remove card_id from app.visible_boards_and_cards if it is there, where visible_boards_and_cards is a LinkedHashMap of board_id to a vector of card_ids
+                                            if let Some(visible_cards) =
+                                                app.visible_boards_and_cards.get_mut(&current_board)
+                                            {
+                                                if let Some(card_index) = visible_cards
+                                                    .iter()
+                                                    .position(|card_id| *card_id == current_card)
+                                                {
+                                                    visible_cards.remove(card_index);
+                                                }
+                                            }
+                                            refresh_visible_boards_and_cards(app);
                                         }
-                                        app.state.current_cursor_position =
-                                            Some(current_cursor_position + 1);
+                                    } else if let Some(current_board) = app.state.current_board_id {
+                                        request_board_deletion(app, current_board);
                                     }
-                                } else {
-                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
                                 }
+                                AppReturn::Continue
                             }
-                            _ => {}
+                            _ => AppReturn::Continue,
                         }
                     }
-                    PopupMode::CommandPalette
-                    | PopupMode::CustomRGBPromptFG
-                    | PopupMode::CustomRGBPromptBG
-                    | PopupMode::EditGeneralConfig => {
-                        let current_cursor_position =
-                            app.state.current_cursor_position.unwrap_or(0);
-                        for (i, char) in current_key.chars().enumerate() {
-                            app.state
-                                .current_user_input
-                                .insert(current_cursor_position + i, char);
+                }
+            }
+            Action::DeleteBoard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
+                }
+                match app.state.focus {
+                    Focus::Body => {
+                        if let Some(current_board) = app.state.current_board_id {
+                            request_board_deletion(app, current_board);
                         }
-                        app.state.current_cursor_position = Some(current_cursor_position + 1);
-                    }
-                    _ => {
-                        debug!(
-                            "Invalid popup mode '{}' for user input",
-                            app.state.popup_mode.unwrap()
-                        );
+                        AppReturn::Continue
                     }
+                    _ => AppReturn::Continue,
                 }
-                return AppReturn::Continue;
             }
-            match app.state.focus {
-                Focus::NewBoardName => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_board_form[0].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
+            Action::ToggleLogPanel => {
+                if app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
                 }
-                Focus::NewBoardDescription => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_board_form[1].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
+                handle_toggle_log_panel(app);
+                AppReturn::Continue
+            }
+            Action::ChangeCardRecurrence => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
                 }
-                Focus::CardName => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[0].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
+                let current_card = app
+                    .boards
+                    .iter()
+                    .find(|board| Some(board.id) == app.state.current_board_id)
+                    .and_then(|board| board.get_card(app.state.current_card_id.unwrap_or(0)));
+                if let Some(current_card) = current_card {
+                    let selected_index = current_card
+                        .recurrence
+                        .and_then(|recurrence| {
+                            CardRecurrence::all().iter().position(|r| *r == recurrence)
+                        })
+                        .map(|index| index + 1)
+                        .unwrap_or(0);
+                    app.state
+                        .card_recurrence_list_state
+                        .select(Some(selected_index));
+                    app.state.popup_mode = Some(PopupMode::CardRecurrenceSelector);
+                } else {
+                    app.send_warning_toast("Select a card to change its recurrence", None);
                 }
-                Focus::CardDescription => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[1].insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                AppReturn::Continue
+            }
+            Action::NextTheme => {
+                if app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
                 }
-                Focus::CardDueDate => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[2].insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                handle_cycle_theme(app, true);
+                AppReturn::Continue
+            }
+            Action::PrvTheme => {
+                if app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
                 }
-                _ => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state
-                            .current_user_input
-                            .insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
+                handle_cycle_theme(app, false);
+                AppReturn::Continue
+            }
+            Action::NavigateBack => {
+                if app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
                 }
+                handle_navigate_history(app, true);
+                AppReturn::Continue
             }
-        }
-    }
-    AppReturn::Continue
-}
-
-pub async fn handle_keybind_mode(app: &mut App, key: Key) -> AppReturn {
-    match key {
-        Key::Esc => {
-            app.state.app_status = AppStatus::Initialized;
-            app.state.edited_keybinding = None;
-            info!("Exiting user keybind input mode");
-        }
-        _ => {
-            if app.config.keybindings.stop_user_input.contains(&key) {
-                app.state.app_status = AppStatus::Initialized;
-                info!("Exiting user keybind input mode");
-                return AppReturn::Continue;
+            Action::NavigateForward => {
+                if app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
+                }
+                handle_navigate_history(app, false);
+                AppReturn::Continue
             }
-            if app.state.edited_keybinding.is_some() {
-                let keybinding = app.state.edited_keybinding.as_mut().unwrap();
-                keybinding.push(key);
-            } else {
-                app.state.edited_keybinding = Some(vec![key]);
+            Action::ToggleRelativeDueDates => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                let relative_due_dates = !app.relative_due_dates();
+                app.state.relative_due_dates_override = Some(relative_due_dates);
+                if relative_due_dates {
+                    app.send_info_toast("Showing due dates relative to today", None);
+                } else {
+                    app.send_info_toast("Showing due dates as dates", None);
+                }
+                AppReturn::Continue
             }
-        }
-    }
-    AppReturn::Continue
-}
-
-pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
-    if let Some(action) = app.actions.find(key, &app.config) {
-        // check if the current focus is in the available focus list for the current ui mode if not assign it to the first
-        if app.state.popup_mode.is_some() {
-            if !PopupMode::get_available_targets(&app.state.popup_mode.unwrap())
-                .iter()
-                .any(|x| x == &app.state.focus)
-            {
-                let available_targets =
-                    PopupMode::get_available_targets(&app.state.popup_mode.unwrap());
-                if !available_targets.is_empty() {
-                    app.state.focus = available_targets[0];
+            Action::ToggleHideCompletedCards => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
                 }
+                handle_toggle_hide_completed_cards(app);
+                AppReturn::Continue
             }
-        } else if !UiMode::get_available_targets(&app.state.ui_mode)
-            .iter()
-            .any(|x| x == &app.state.focus)
-        {
-            let available_targets = UiMode::get_available_targets(&app.state.ui_mode);
-            if !available_targets.is_empty() {
-                app.state.focus = available_targets[0];
+            Action::ToggleBoardHideCompletedCards => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_toggle_board_hide_completed_cards(app);
+                AppReturn::Continue
             }
-        }
-        match action {
-            Action::Quit => {
-                handle_exit(app).await;
-                AppReturn::Exit
+            Action::ToggleOverdueQuickFilter => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_toggle_quick_filter(app, QuickFilter::Overdue);
+                AppReturn::Continue
             }
-            Action::NextFocus => {
-                handle_next_focus(app);
+            Action::ToggleWatchedQuickFilter => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_toggle_quick_filter(app, QuickFilter::Watched);
                 AppReturn::Continue
             }
-            Action::PrvFocus => {
-                handle_prv_focus(app);
+            Action::ToggleBlockedQuickFilter => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_toggle_quick_filter(app, QuickFilter::Blocked);
                 AppReturn::Continue
             }
-            Action::ResetUI => {
-                let new_ui_mode = app.config.default_view;
-                let available_focus_targets = UiMode::get_available_targets(&new_ui_mode);
-                // check if focus is still available in the new ui_mode if not set it to the first available tab
-                if !available_focus_targets.contains(&app.state.focus) {
-                    // check if available focus targets is empty
-                    if available_focus_targets.is_empty() {
-                        app.state.focus = Focus::NoFocus;
-                    } else {
-                        app.state.focus = available_focus_targets[0];
-                    }
+            Action::ToggleHighPriorityQuickFilter => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
                 }
-                let default_theme = app.config.default_theme.clone();
-                for theme in app.all_themes.iter_mut() {
-                    if theme.name == default_theme {
-                        app.theme = theme.clone();
-                    }
+                handle_toggle_quick_filter(app, QuickFilter::HighPriority);
+                AppReturn::Continue
+            }
+            Action::ToggleExpandedCard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
                 }
-                app.state.toasts = vec![];
-                app.send_info_toast("UI reset, all toasts cleared", None);
-                app.state.ui_mode = new_ui_mode;
-                app.state.popup_mode = None;
-                refresh_visible_boards_and_cards(app);
+                if app.state.current_card_id.is_none() {
+                    app.send_warning_toast("No card selected to expand", None);
+                    return AppReturn::Continue;
+                }
+                app.state.popup_mode = Some(PopupMode::ExpandedCard);
                 AppReturn::Continue
             }
-            Action::OpenConfigMenu => {
-                match app.state.ui_mode {
-                    UiMode::ConfigMenu => {
-                        // check if the prv ui mode is the same as the current ui mode
-                        if app.state.prev_ui_mode.is_some()
-                            && app.state.prev_ui_mode.as_ref().unwrap() == &UiMode::ConfigMenu
-                        {
-                            app.state.ui_mode = app.config.default_view;
-                        } else {
-                            app.state.ui_mode = *app
-                                .state
-                                .prev_ui_mode
-                                .as_ref()
-                                .unwrap_or(&app.config.default_view);
-                        }
-                    }
-                    _ => {
-                        app.state.prev_ui_mode = Some(app.state.ui_mode);
-                        app.state.ui_mode = UiMode::ConfigMenu;
-                        if app.state.config_state.selected().is_none() {
-                            app.config_next()
-                        }
-                        let available_focus_targets = app.state.ui_mode.get_available_targets();
-                        if !available_focus_targets.contains(&app.state.focus) {
-                            // check if available focus targets is empty
-                            if available_focus_targets.is_empty() {
-                                app.state.focus = Focus::NoFocus;
-                            } else {
-                                app.state.focus = available_focus_targets[0];
-                            }
-                        }
-                    }
+            Action::ExportBoardAndCopyPath => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_export_board_and_copy_path(app);
+                AppReturn::Continue
+            }
+            Action::GrowPanel | Action::ShrinkPanel => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                let grow = *action == Action::GrowPanel;
+                handle_resize_panel(app, grow);
+                AppReturn::Continue
+            }
+            Action::ToggleFocusTimer => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
                 }
-                if app.state.popup_mode.is_some() {
-                    app.state.popup_mode = None;
+                handle_toggle_focus_timer(app);
+                AppReturn::Continue
+            }
+            Action::ClearBoard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
                 }
+                handle_clear_board(app);
                 AppReturn::Continue
             }
-            Action::Up => {
-                reset_mouse(app);
-                if app.state.popup_mode.is_some() {
-                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
-                    match popup_mode {
-                        PopupMode::ChangeUIMode => app.select_default_view_prv(),
-                        PopupMode::CardStatusSelector => app.select_card_status_prv(),
-                        PopupMode::SelectDefaultView => app.select_default_view_prv(),
-                        PopupMode::ChangeTheme => app.select_change_theme_prv(),
-                        PopupMode::EditThemeStyle => {
-                            if app.state.focus == Focus::StyleEditorFG {
-                                app.select_edit_style_fg_prv();
-                            } else if app.state.focus == Focus::StyleEditorBG {
-                                app.select_edit_style_bg_prv();
-                            } else if app.state.focus == Focus::StyleEditorModifier {
-                                app.select_edit_style_modifier_prv();
-                            }
-                        }
-                        PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
-                        PopupMode::FilterByTag => app.filter_by_tag_popup_prv(),
-                        _ => {}
-                    }
+            Action::CycleCardStatus => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                {
                     return AppReturn::Continue;
                 }
-                match app.state.ui_mode {
-                    UiMode::ConfigMenu => {
-                        if app.state.focus == Focus::ConfigTable {
-                            app.config_prv();
+                let status_cycle = if app.config.status_cycle.is_empty() {
+                    CardStatus::all()
+                } else {
+                    app.config.status_cycle.clone()
+                };
+                let workflow_stages = app.config.workflow_stages.clone();
+                if let (Some(current_board), Some(current_card)) =
+                    (app.state.current_board_id, app.state.current_card_id)
+                {
+                    let card = app
+                        .boards
+                        .iter_mut()
+                        .find(|board| board.id == current_board)
+                        .and_then(|board| {
+                            board.cards.iter_mut().find(|card| card.id == current_card)
+                        });
+                    if let Some(card) = card {
+                        let temp_old_card = card.clone();
+                        if workflow_stages.is_empty() {
+                            // statuses left out of the cycle move to the start of the cycle
+                            let next_status = match status_cycle
+                                .iter()
+                                .position(|status| *status == card.card_status)
+                            {
+                                Some(position) => {
+                                    status_cycle[(position + 1) % status_cycle.len()].clone()
+                                }
+                                None => status_cycle[0].clone(),
+                            };
+                            card.date_completed = if next_status == CardStatus::Complete {
+                                Utc::now().to_string()
+                            } else {
+                                "N/A".to_string()
+                            };
+                            card.card_status = next_status;
+                            card.date_modified = Utc::now().to_string();
                         } else {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the Config Menu with {} or {}, to select a config option using the arrow keys",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    UiMode::MainMenu => {
-                        if app.state.focus == Focus::MainMenu {
-                            app.main_menu_prv();
-                        } else if app.state.focus == Focus::MainMenuHelp {
-                            app.help_prv();
+                            // with workflow stages the stages are cycled in order instead
+                            let next_stage = match workflow_stages
+                                .iter()
+                                .position(|stage| stage.name == card.workflow_stage)
+                            {
+                                Some(position) => {
+                                    &workflow_stages[(position + 1) % workflow_stages.len()]
+                                }
+                                None => &workflow_stages[0],
+                            };
+                            card.set_workflow_stage(next_stage);
+                        }
+                        let new_card = card.clone();
+                        let msg = if new_card.workflow_stage.is_empty() {
+                            format!(
+                                "Changed status to {} for card {}",
+                                new_card.card_status, new_card.name
+                            )
                         } else {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the Main Menu with {} or {}, to navigate the menu using the arrow keys",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    UiMode::LoadSave => {
-                        app.load_save_prv();
-                        app.dispatch(IoEvent::LoadPreview).await;
-                    }
-                    UiMode::EditKeybindings => {
-                        app.edit_keybindings_prv();
-                    }
-                    UiMode::CreateTheme => {
-                        if app.state.focus == Focus::ThemeEditor {
-                            app.select_create_theme_prv();
-                        } else if app.state.focus == Focus::SubmitButton {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the theme editor with {} or {}, to select a style to edit",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    _ => {
-                        if app.state.focus == Focus::Body {
-                            go_up(app);
-                        } else if app.state.focus == Focus::Help {
-                            app.help_prv();
-                        }
+                            format!(
+                                "Moved card {} to {}",
+                                new_card.name, new_card.workflow_stage
+                            )
+                        };
+                        app.action_history_manager
+                            .new_action(ActionHistory::EditCard(
+                                temp_old_card,
+                                new_card,
+                                current_board,
+                            ));
+                        info!("{}", msg);
+                        app.send_info_toast(&msg, None);
                     }
                 }
                 AppReturn::Continue
             }
-            Action::Down => {
-                reset_mouse(app);
-                if app.state.popup_mode.is_some() {
-                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
-                    match popup_mode {
-                        PopupMode::ChangeUIMode => app.select_default_view_next(),
-                        PopupMode::CardStatusSelector => app.select_card_status_next(),
-                        PopupMode::SelectDefaultView => app.select_default_view_next(),
-                        PopupMode::ChangeTheme => app.select_change_theme_next(),
-                        PopupMode::EditThemeStyle => {
-                            if app.state.focus == Focus::StyleEditorFG {
-                                app.select_edit_style_fg_next();
-                            } else if app.state.focus == Focus::StyleEditorBG {
-                                app.select_edit_style_bg_next();
-                            } else if app.state.focus == Focus::StyleEditorModifier {
-                                app.select_edit_style_modifier_next();
-                            }
-                        }
-                        PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_next(),
-                        PopupMode::FilterByTag => app.filter_by_tag_popup_next(),
-                        _ => {}
-                    }
+            Action::ChangeCardStatusToCompleted => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
-                match app.state.ui_mode {
-                    UiMode::ConfigMenu => {
-                        if app.state.focus == Focus::ConfigTable {
-                            app.config_next();
-                        } else {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the Config Menu with {} or {}, to select a config option using the arrow keys",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    UiMode::MainMenu => {
-                        if app.state.focus == Focus::MainMenu {
-                            app.main_menu_next();
-                        } else if app.state.focus == Focus::MainMenuHelp {
-                            app.help_next();
-                        } else {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the Main Menu with {} or {}, to navigate the menu using the arrow keys",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    UiMode::LoadSave => {
-                        app.load_save_next();
-                        app.dispatch(IoEvent::LoadPreview).await;
-                    }
-                    UiMode::EditKeybindings => {
-                        app.edit_keybindings_next();
-                    }
-                    UiMode::CreateTheme => {
-                        if app.state.focus == Focus::ThemeEditor {
-                            app.select_create_theme_next();
-                        } else if app.state.focus == Focus::SubmitButton {
-                            let next_focus_key = app
-                                .config
-                                .keybindings
-                                .next_focus
-                                .get(0)
-                                .unwrap_or(&Key::Tab);
-                            let prev_focus_key = app
-                                .config
-                                .keybindings
-                                .prev_focus
-                                .get(0)
-                                .unwrap_or(&Key::BackTab);
-                            app.send_warning_toast(&format!(
-                                "Move Focus to the theme editor with {} or {}, to select a style to edit",
-                                next_focus_key, prev_focus_key), None);
-                        }
-                    }
-                    _ => {
-                        if app.state.focus == Focus::Body {
-                            go_down(app);
-                        } else if app.state.focus == Focus::Help {
-                            app.help_next();
+                // check if focus is on body
+                if app.state.focus != Focus::Body {
+                    return AppReturn::Continue;
+                }
+                // get the current card and change its status to complete
+                if let Some(current_board) = app.state.current_board_id {
+                    // find index of current board id in app.boards
+                    let index = app
+                        .boards
+                        .iter()
+                        .position(|board| board.id == current_board);
+                    if let Some(current_card) = app.state.current_card_id {
+                        let card_index = app.boards[index.unwrap()]
+                            .cards
+                            .iter()
+                            .position(|card| card.id == current_card);
+                        if let Some(card_index) = card_index {
+                            let temp_old_card =
+                                app.boards[index.unwrap()].cards[card_index].clone();
+                            app.boards[index.unwrap()].cards[card_index].card_status =
+                                CardStatus::Complete;
+                            app.boards[index.unwrap()].cards[card_index].date_completed =
+                                Utc::now().to_string();
+                            app.boards[index.unwrap()].cards[card_index].date_modified =
+                                Utc::now().to_string();
+                            let workflow_stages = app.config.workflow_stages.clone();
+                            app.boards[index.unwrap()].cards[card_index]
+                                .sync_workflow_stage(&workflow_stages);
+                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
+                            app.action_history_manager
+                                .new_action(ActionHistory::EditCard(
+                                    temp_old_card,
+                                    new_card,
+                                    current_board,
+                                ));
+                            info!(
+                                "Changed status to Completed for card {}",
+                                app.boards[index.unwrap()].cards[card_index].name
+                            );
+                            app.send_info_toast(
+                                &format!(
+                                    "Changed status to Completed for card {}",
+                                    app.boards[index.unwrap()].cards[card_index].name
+                                ),
+                                None,
+                            );
                         }
                     }
                 }
                 AppReturn::Continue
             }
-            Action::Right => {
-                reset_mouse(app);
-                if app.state.focus == Focus::Body
-                    && UiMode::view_modes().contains(&app.state.ui_mode)
-                    && app.state.popup_mode.is_none()
-                {
-                    go_right(app);
+            Action::ChangeCardStatusToActive => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
                 }
-                AppReturn::Continue
-            }
-            Action::Left => {
-                reset_mouse(app);
-                if app.state.focus == Focus::Body
-                    && UiMode::view_modes().contains(&app.state.ui_mode)
-                    && app.state.popup_mode.is_none()
-                {
-                    go_left(app);
+                // check if focus is on body
+                if app.state.focus != Focus::Body {
+                    return AppReturn::Continue;
+                }
+                // get the current card and change its status to active
+                if let Some(current_board) = app.state.current_board_id {
+                    // find index of current board id in app.boards
+                    let index = app
+                        .boards
+                        .iter()
+                        .position(|board| board.id == current_board);
+                    if let Some(current_card) = app.state.current_card_id {
+                        let card_index = app.boards[index.unwrap()]
+                            .cards
+                            .iter()
+                            .position(|card| card.id == current_card);
+                        if let Some(card_index) = card_index {
+                            let temp_old_card =
+                                app.boards[index.unwrap()].cards[card_index].clone();
+                            app.boards[index.unwrap()].cards[card_index].card_status =
+                                CardStatus::Active;
+                            app.boards[index.unwrap()].cards[card_index].date_completed =
+                                "N/A".to_string();
+                            app.boards[index.unwrap()].cards[card_index].date_modified =
+                                Utc::now().to_string();
+                            let workflow_stages = app.config.workflow_stages.clone();
+                            app.boards[index.unwrap()].cards[card_index]
+                                .sync_workflow_stage(&workflow_stages);
+                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
+                            app.action_history_manager
+                                .new_action(ActionHistory::EditCard(
+                                    temp_old_card,
+                                    new_card,
+                                    current_board,
+                                ));
+                            info!(
+                                "Changed status to Active for card {}",
+                                app.boards[index.unwrap()].cards[card_index].name
+                            );
+                            app.send_info_toast(
+                                &format!(
+                                    "Changed status to Active for card {}",
+                                    app.boards[index.unwrap()].cards[card_index].name
+                                ),
+                                None,
+                            );
+                        }
+                    }
                 }
                 AppReturn::Continue
             }
-            Action::TakeUserInput => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard | UiMode::NewCard => {
-                        app.state.app_status = AppStatus::UserInput;
-                        info!("Taking user input");
-                    }
-                    _ => {
-                        if app.state.popup_mode.is_some() {
-                            match app.state.popup_mode.unwrap() {
-                                PopupMode::EditGeneralConfig
-                                | PopupMode::CustomRGBPromptFG
-                                | PopupMode::CustomRGBPromptBG => {
-                                    app.state.app_status = AppStatus::UserInput;
-                                    info!("Taking user input");
-                                }
-                                PopupMode::EditSpecificKeyBinding => {
-                                    app.state.app_status = AppStatus::KeyBindMode;
-                                    info!("Taking user keybind input");
-                                }
-                                PopupMode::ViewCard => {
-                                    if app.state.current_board_id.is_some()
-                                        && app.state.current_card_id.is_some()
-                                    {
-                                        let board = app.boards.iter().find(|board| {
-                                            board.id == app.state.current_board_id.unwrap()
-                                        });
-                                        if board.is_some() {
-                                            let card = board.unwrap().cards.iter().find(|card| {
-                                                card.id == app.state.current_card_id.unwrap()
-                                            });
-                                            if card.is_some() {
-                                                app.card_being_edited = Some((
-                                                    app.state.current_board_id.unwrap(),
-                                                    card.unwrap().clone(),
-                                                ));
-                                            }
-                                        }
-                                        app.state.app_status = AppStatus::UserInput;
-                                    } else {
-                                        debug!("No current board or card id to edit card");
-                                    }
-                                }
-                                _ => {}
-                            }
+            Action::ChangeCardStatusToStale => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
+                }
+                // check if focus is on body
+                if app.state.focus != Focus::Body {
+                    return AppReturn::Continue;
+                }
+                // get the current card and change its status to stale
+                if let Some(current_board) = app.state.current_board_id {
+                    // find index of current board id in app.boards
+                    let index = app
+                        .boards
+                        .iter()
+                        .position(|board| board.id == current_board);
+                    if let Some(current_card) = app.state.current_card_id {
+                        let card_index = app.boards[index.unwrap()]
+                            .cards
+                            .iter()
+                            .position(|card| card.id == current_card);
+                        if let Some(card_index) = card_index {
+                            let temp_old_card =
+                                app.boards[index.unwrap()].cards[card_index].clone();
+                            app.boards[index.unwrap()].cards[card_index].card_status =
+                                CardStatus::Stale;
+                            app.boards[index.unwrap()].cards[card_index].date_completed =
+                                "N/A".to_string();
+                            app.boards[index.unwrap()].cards[card_index].date_modified =
+                                Utc::now().to_string();
+                            let workflow_stages = app.config.workflow_stages.clone();
+                            app.boards[index.unwrap()].cards[card_index]
+                                .sync_workflow_stage(&workflow_stages);
+                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
+                            app.action_history_manager
+                                .new_action(ActionHistory::EditCard(
+                                    temp_old_card,
+                                    new_card,
+                                    current_board,
+                                ));
+                            info!(
+                                "Changed status to Stale for card {}",
+                                app.boards[index.unwrap()].cards[card_index].name
+                            );
+                            app.send_info_toast(
+                                &format!(
+                                    "Changed status to Stale for card {}",
+                                    app.boards[index.unwrap()].cards[card_index].name
+                                ),
+                                None,
+                            );
                         }
                     }
                 }
                 AppReturn::Continue
             }
-            Action::StopUserInput => {
-                if app.state.app_status == AppStatus::UserInput {
-                    app.state.app_status = AppStatus::Initialized;
-                    info!("Exiting user input mode");
+            Action::GoToMainMenu => {
+                app.state.current_board_id = None;
+                app.state.current_card_id = None;
+                app.state.focus = Focus::MainMenu;
+                app.state.ui_mode = UiMode::MainMenu;
+                if app.state.main_menu_state.selected().is_none() {
+                    app.state.main_menu_state.select(Some(0));
                 }
                 AppReturn::Continue
             }
-            Action::GoToPreviousUIMode => handle_go_to_previous_ui_mode(app).await,
-            Action::Enter => {
-                if app.state.popup_mode.is_some() {
-                    let popup_mode = app.state.popup_mode.as_ref().unwrap();
-                    match popup_mode {
-                        PopupMode::ChangeUIMode => handle_change_ui_mode(app),
-                        PopupMode::CardStatusSelector => {
-                            return handle_change_card_status(app);
-                        }
-                        PopupMode::EditGeneralConfig => {
-                            if app.state.ui_mode == UiMode::CreateTheme {
-                                handle_create_theme_action(app);
-                            } else {
-                                handle_edit_general_config(app);
-                            }
-                        }
-                        PopupMode::EditSpecificKeyBinding => handle_edit_specific_keybinding(app),
-                        PopupMode::SelectDefaultView => handle_default_view_selection(app),
-                        PopupMode::ChangeDateFormatPopup => handle_change_date_format(app),
-                        PopupMode::ChangeTheme => {
-                            return handle_change_theme(app, app.state.default_theme_mode)
-                        }
-                        PopupMode::EditThemeStyle => return handle_create_theme_action(app),
-                        PopupMode::SaveThemePrompt => handle_save_theme_prompt(app),
-                        PopupMode::CustomRGBPromptFG => return handle_custom_rgb_prompt(app, true),
-                        PopupMode::CustomRGBPromptBG => {
-                            return handle_custom_rgb_prompt(app, false)
-                        }
-                        PopupMode::ViewCard => match app.state.focus {
-                            Focus::CardPriority => {
-                                if app.card_being_edited.is_none() {
-                                    handle_edit_new_card(app);
-                                }
-                                app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
-                                return AppReturn::Continue;
-                            }
-                            Focus::CardStatus => {
-                                if app.card_being_edited.is_none() {
-                                    handle_edit_new_card(app);
-                                }
-                                app.state.popup_mode = Some(PopupMode::CardStatusSelector);
-                                return AppReturn::Continue;
-                            }
-                            Focus::CardName
-                            | Focus::CardDescription
-                            | Focus::CardDueDate
-                            | Focus::CardTags
-                            | Focus::CardComments => return handle_edit_new_card(app),
-                            Focus::SubmitButton => {
-                                return handle_edit_card_submit(app);
-                            }
-                            _ => {}
-                        },
-                        PopupMode::CommandPalette => {
-                            // not required to handle here as the command palette is handled in the user input mode
-                        }
-                        PopupMode::ConfirmDiscardCardChanges => match app.state.focus {
-                            Focus::SubmitButton => {
-                                handle_edit_card_submit(app);
-                                app.state.popup_mode = None;
-                            }
-                            Focus::ExtraFocus => {
-                                if app.card_being_edited.is_some() {
-                                    warn!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    );
-                                    app.send_warning_toast(
-                                        &format!(
-                                            "Discarding changes to card '{}'",
-                                            app.card_being_edited.as_ref().unwrap().1.name
-                                        ),
-                                        None,
-                                    );
-                                }
-                                app.state.popup_mode = None;
-                                app.card_being_edited = None;
-                            }
-                            _ => {}
-                        },
-                        PopupMode::CardPrioritySelector => {
-                            return handle_change_card_priority(app);
+            Action::MoveCardUp => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
+                }
+                if app.state.status_column_view {
+                    app.send_warning_toast(
+                        "Cards cannot be reordered in the status column view",
+                        None,
+                    );
+                    return AppReturn::Continue;
+                }
+                if app.state.focus == Focus::Body {
+                    if app.state.current_card_id.is_none() {
+                        return AppReturn::Continue;
+                    } else {
+                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+                            app.boards.as_mut()
+                        } else {
+                            app.filtered_boards.as_mut()
+                        };
+                        if app.state.current_board_id.is_none() {
+                            debug!("Cannot move card up without a current board id");
+                            return AppReturn::Continue;
                         }
-                        PopupMode::FilterByTag => {
-                            handle_filter_by_tag(app);
+                        if app.state.current_card_id.is_none() {
+                            debug!("Cannot move card up without a current card id");
                             return AppReturn::Continue;
                         }
-                    }
-                    app.state.popup_mode = None;
-                    return AppReturn::Continue;
-                }
-                match app.state.ui_mode {
-                    UiMode::ConfigMenu => handle_config_menu_action(app),
-                    UiMode::MainMenu => match app.state.focus {
-                        Focus::MainMenu => handle_main_menu_action(app).await,
-                        Focus::MainMenuHelp => {
-                            app.state.ui_mode = UiMode::HelpMenu;
-                            AppReturn::Continue
+                        let current_board_id = app.state.current_board_id.unwrap();
+                        let current_card_id = app.state.current_card_id.unwrap();
+                        let current_board_index_in_all_boards =
+                            boards.iter().position(|board| board.id == current_board_id);
+                        if current_board_index_in_all_boards.is_none() {
+                            debug!("Cannot move card up without a current board index");
+                            return AppReturn::Continue;
                         }
-                        Focus::Log => {
-                            app.state.ui_mode = UiMode::LogsOnly;
-                            AppReturn::Continue
+                        let current_card_index_in_all = boards
+                            [current_board_index_in_all_boards.unwrap()]
+                        .cards
+                        .iter()
+                        .position(|card| card.id == current_card_id);
+                        if current_card_index_in_all.is_none() {
+                            debug!("Cannot move card up without a current card index");
+                            return AppReturn::Continue;
                         }
-                        _ => AppReturn::Continue,
-                    },
-                    UiMode::NewBoard => {
-                        handle_new_board_action(app);
-                        AppReturn::Continue
-                    }
-                    UiMode::NewCard => handle_new_card_action(app),
-                    UiMode::LoadSave => {
-                        app.dispatch(IoEvent::LoadSave).await;
-                        AppReturn::Continue
-                    }
-                    UiMode::EditKeybindings => {
-                        handle_edit_keybindings_action(app);
-                        AppReturn::Continue
-                    }
-                    UiMode::CreateTheme => {
-                        handle_create_theme_action(app);
-                        AppReturn::Continue
-                    }
-                    _ => {
-                        match app.state.focus {
-                            Focus::Help => {
-                                app.state.prev_ui_mode = Some(app.state.ui_mode);
-                                app.state.ui_mode = UiMode::HelpMenu;
-                            }
-                            Focus::Log => {
-                                app.state.prev_ui_mode = Some(app.state.ui_mode);
-                                app.state.ui_mode = UiMode::LogsOnly;
-                            }
-                            _ => {}
+                        let current_card_index_in_all = current_card_index_in_all.unwrap();
+                        if current_card_index_in_all == 0 {
+                            app.send_error_toast(
+                                "Cannot move card up, it is already at the top of the board",
+                                None,
+                            );
+                            error!("Cannot move card up, it is already at the top of the board");
+                            return AppReturn::Continue;
                         }
-                        if UiMode::view_modes().contains(&app.state.ui_mode)
-                            && app.state.focus == Focus::Body
-                        {
-                            // check if there is a current card
-                            if let Some(current_card_id) = app.state.current_card_id {
-                                if let Some(current_board_id) = app.state.current_board_id {
-                                    // check if the current card is in the current board
-                                    let current_board = app
-                                        .boards
-                                        .iter()
-                                        .find(|board| board.id == current_board_id);
-                                    if let Some(current_board) = current_board {
-                                        let current_card = current_board
-                                            .cards
-                                            .iter()
-                                            .find(|card| card.id == current_card_id);
-                                        if current_card.is_some() {
-                                            app.state.popup_mode = Some(PopupMode::ViewCard);
-                                        } else {
-                                            // if the current card is not in the current board then set the current card to None
-                                            app.state.current_card_id = None;
-                                        }
-                                    } else {
-                                        // if the current board is not found then set the current card to None
-                                        app.state.current_card_id = None;
-                                    }
-                                } else {
-                                    // if the current board is not found then set the current card to None
-                                    app.state.current_card_id = None;
+                        // update visible boards and cards
+                        // check if both the cards that are being swapped are in the visible cards
+                        let current_card_index_in_visible = app.visible_boards_and_cards
+                            [&current_board_id]
+                            .iter()
+                            .position(|card_id| *card_id == current_card_id);
+                        if current_card_index_in_visible.is_none() {
+                            debug!(
+                                "Cannot move card up without a current card index in visible cards"
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let current_card_index_in_visible = current_card_index_in_visible.unwrap();
+                        if current_card_index_in_visible == 0 {
+                            let card_above_id = boards[current_board_index_in_all_boards.unwrap()]
+                                .cards[current_card_index_in_all - 1]
+                                .id;
+                            let mut visible_cards: Vec<u128> = vec![];
+                            visible_cards.push(current_card_id);
+                            visible_cards.push(card_above_id);
+
+                            for card in app.visible_boards_and_cards[&current_board_id].iter() {
+                                if *card != current_card_id
+                                    && visible_cards.len() < app.config.no_of_cards_to_show as usize
+                                {
+                                    visible_cards.push(*card);
                                 }
                             }
+                            app.visible_boards_and_cards
+                                .entry(current_board_id)
+                                .and_modify(|cards| *cards = visible_cards);
+                        } else {
+                            app.visible_boards_and_cards
+                                .get_mut(&current_board_id)
+                                .unwrap()
+                                .swap(
+                                    current_card_index_in_visible,
+                                    current_card_index_in_visible - 1,
+                                );
                         }
-                        AppReturn::Continue
+                        boards[current_board_index_in_all_boards.unwrap()]
+                            .cards
+                            .swap(current_card_index_in_all, current_card_index_in_all - 1);
+                        app.action_history_manager
+                            .new_action(ActionHistory::MoveCardWithinBoard(
+                                current_board_id,
+                                current_card_index_in_all,
+                                current_card_index_in_all - 1,
+                            ));
                     }
                 }
+                AppReturn::Continue
             }
-            Action::HideUiElement => {
-                let current_focus =
-                    if let Ok(current_focus) = Focus::from_str(app.state.focus.to_str()) {
-                        current_focus
-                    } else {
-                        Focus::NoFocus
-                    };
-                let current_ui_mode = app.state.ui_mode;
-                // hide the current focus by switching to a view where it is not available
-                // for example if current uimode is Title and focus is on Title then switch to Zen
-                if current_ui_mode == UiMode::Zen {
-                    app.state.ui_mode = UiMode::MainMenu;
-                    if app.state.main_menu_state.selected().is_none() {
-                        app.main_menu_next();
-                    }
-                } else if current_ui_mode == UiMode::TitleBody {
-                    if current_focus == Focus::Title {
-                        app.state.ui_mode = UiMode::Zen;
-                        app.state.focus = Focus::Body;
+            Action::MoveCardDown => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
+                }
+                if app.state.status_column_view {
+                    app.send_warning_toast(
+                        "Cards cannot be reordered in the status column view",
+                        None,
+                    );
+                    return AppReturn::Continue;
+                }
+                if app.state.focus == Focus::Body {
+                    if app.state.current_card_id.is_none() {
+                        return AppReturn::Continue;
                     } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+                            app.boards.as_mut()
+                        } else {
+                            app.filtered_boards.as_mut()
+                        };
+                        if app.state.current_board_id.is_none() {
+                            debug!("Cannot move card down without a current board id");
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::BodyHelp {
-                    if current_focus == Focus::Help {
-                        app.state.ui_mode = UiMode::Zen;
-                        app.state.focus = Focus::Body;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        if app.state.current_card_id.is_none() {
+                            debug!("Cannot move card down without a current card id");
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::BodyLog {
-                    if current_focus == Focus::Log {
-                        app.state.ui_mode = UiMode::Zen;
-                        app.state.focus = Focus::Body;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        let current_board_id = app.state.current_board_id.unwrap();
+                        let current_card_id = app.state.current_card_id.unwrap();
+                        let current_board_index_in_all_boards =
+                            boards.iter().position(|board| board.id == current_board_id);
+                        if current_board_index_in_all_boards.is_none() {
+                            debug!("Cannot move card down without a current board index");
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::TitleBodyHelp {
-                    if current_focus == Focus::Title {
-                        app.state.ui_mode = UiMode::BodyHelp;
-                        app.state.focus = Focus::Body;
-                    } else if current_focus == Focus::Help {
-                        app.state.ui_mode = UiMode::TitleBody;
-                        app.state.focus = Focus::Title;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        let current_card_index_in_all = boards
+                            [current_board_index_in_all_boards.unwrap()]
+                        .cards
+                        .iter()
+                        .position(|card| card.id == current_card_id);
+                        if current_card_index_in_all.is_none() {
+                            debug!("Cannot move card down without a current card index");
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::TitleBodyLog {
-                    if current_focus == Focus::Title {
-                        app.state.ui_mode = UiMode::BodyLog;
-                        app.state.focus = Focus::Body;
-                    } else if current_focus == Focus::Log {
-                        app.state.ui_mode = UiMode::TitleBody;
-                        app.state.focus = Focus::Title;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        let current_card_index_in_all = current_card_index_in_all.unwrap();
+                        if current_card_index_in_all
+                            == boards[current_board_index_in_all_boards.unwrap()]
+                                .cards
+                                .len()
+                                - 1
+                        {
+                            app.send_error_toast(
+                                "Cannot move card down, it is already at the bottom of the board",
+                                None,
+                            );
+                            error!(
+                                "Cannot move card down, it is already at the bottom of the board"
+                            );
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::TitleBodyHelpLog {
-                    if current_focus == Focus::Title {
-                        app.state.ui_mode = UiMode::BodyHelpLog;
-                        app.state.focus = Focus::Body;
-                    } else if current_focus == Focus::Help {
-                        app.state.ui_mode = UiMode::TitleBodyLog;
-                        app.state.focus = Focus::Title;
-                    } else if current_focus == Focus::Log {
-                        app.state.ui_mode = UiMode::TitleBodyHelp;
-                        app.state.focus = Focus::Title;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        // update visible boards and cards
+                        // check if both the cards that are being swapped are in the visible cards
+                        let current_card_index_in_visible = app.visible_boards_and_cards
+                            [&current_board_id]
+                            .iter()
+                            .position(|card_id| *card_id == current_card_id);
+                        if current_card_index_in_visible.is_none() {
+                            debug!("Cannot move card down without a current card index in visible cards");
+                            return AppReturn::Continue;
                         }
-                    }
-                } else if current_ui_mode == UiMode::BodyHelpLog {
-                    if current_focus == Focus::Help {
-                        app.state.ui_mode = UiMode::BodyLog;
-                        app.state.focus = Focus::Body;
-                    } else if current_focus == Focus::Log {
-                        app.state.ui_mode = UiMode::BodyHelp;
-                        app.state.focus = Focus::Body;
-                    } else {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next();
+                        let current_card_index_in_visible = current_card_index_in_visible.unwrap();
+                        if current_card_index_in_visible
+                            == app.visible_boards_and_cards[&current_board_id].len() - 1
+                        {
+                            let card_below_id = boards[current_board_index_in_all_boards.unwrap()]
+                                .cards[current_card_index_in_all + 1]
+                                .id;
+                            let mut visible_cards: Vec<u128> = vec![];
+                            visible_cards.push(card_below_id);
+                            visible_cards.push(current_card_id);
+                            // insert in reverse order till we reach the no of cards to show
+                            for card in app.visible_boards_and_cards[&current_board_id].iter().rev()
+                            {
+                                if *card != current_card_id
+                                    && visible_cards.len() < app.config.no_of_cards_to_show as usize
+                                {
+                                    visible_cards.insert(0, *card);
+                                }
+                            }
+
+                            app.visible_boards_and_cards
+                                .entry(current_board_id)
+                                .and_modify(|cards| *cards = visible_cards);
+                        } else {
+                            app.visible_boards_and_cards
+                                .get_mut(&current_board_id)
+                                .unwrap()
+                                .swap(
+                                    current_card_index_in_visible,
+                                    current_card_index_in_visible + 1,
+                                );
                         }
+                        boards[current_board_index_in_all_boards.unwrap()]
+                            .cards
+                            .swap(current_card_index_in_all, current_card_index_in_all + 1);
+                        app.action_history_manager
+                            .new_action(ActionHistory::MoveCardWithinBoard(
+                                current_board_id,
+                                current_card_index_in_all,
+                                current_card_index_in_all + 1,
+                            ));
                     }
                 }
                 AppReturn::Continue
             }
-            Action::SaveState => {
-                app.dispatch(IoEvent::SaveLocalData).await;
-                AppReturn::Continue
-            }
-            Action::NewBoard => {
-                // check if current ui_mode is in UiMode::view_modes()
-                if UiMode::view_modes().contains(&app.state.ui_mode) {
-                    app.state.new_board_form = NEW_BOARD_FORM_DEFAULT_STATE
-                        .iter()
-                        .map(|s| s.to_string())
-                        .collect();
-                    app.set_ui_mode(UiMode::NewBoard);
-                    app.state.previous_focus = Some(app.state.focus);
+            Action::MoveCardRight => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                    return AppReturn::Continue;
                 }
-                AppReturn::Continue
-            }
-            Action::NewCard => {
-                if UiMode::view_modes().contains(&app.state.ui_mode) {
-                    // check if current board is not empty
-                    if app.state.current_board_id.is_none() {
-                        warn!("No board available to add card to");
-                        app.send_warning_toast("No board available to add card to", None);
-                        return AppReturn::Continue;
+                if app.state.status_column_view {
+                    if app.state.focus == Focus::Body {
+                        move_card_to_status_column(app, true);
                     }
-                    app.state.new_card_form = NEW_CARD_FORM_DEFAULT_STATE
-                        .iter()
-                        .map(|s| s.to_string())
-                        .collect();
-                    app.set_ui_mode(UiMode::NewCard);
-                    app.state.previous_focus = Some(app.state.focus);
+                    return AppReturn::Continue;
                 }
-                AppReturn::Continue
-            }
-            Action::DeleteCard => {
-                match app.state.ui_mode {
-                    UiMode::LoadSave => {
-                        // run delete task in background
-                        app.dispatch(IoEvent::DeleteSave).await;
-                        tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
-                        app.dispatch(IoEvent::LoadPreview).await;
-                        AppReturn::Continue
-                    }
-                    _ => {
-                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                if app.state.focus == Focus::Body {
+                    if app.state.current_card_id.is_none() {
+                        return AppReturn::Continue;
+                    } else if let Some(current_board) = app.state.current_board_id {
+                        let mut filter_mode = false;
+                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+                            app.boards.as_mut()
+                        } else {
+                            filter_mode = true;
+                            app.filtered_boards.as_mut()
+                        };
+                        let moved_from_board_index =
+                            boards.iter().position(|board| board.id == current_board);
+                        if moved_from_board_index.is_none() {
+                            app.send_error_toast(
+                                "Something went wrong, could not find the board",
+                                None,
+                            );
+                            debug!("Moved from board index is none");
                             return AppReturn::Continue;
                         }
-                        match app.state.focus {
-                            Focus::Body => {
-                                // delete the current card
-                                if let Some(current_board) = app.state.current_board_id {
-                                    // find index of current board id in app.boards
-                                    let index = app
-                                        .boards
-                                        .iter()
-                                        .position(|board| board.id == current_board);
-                                    if let Some(current_card) = app.state.current_card_id {
-                                        let card_index = app.boards[index.unwrap()]
-                                            .cards
-                                            .iter()
-                                            .position(|card| card.id == current_card);
-                                        if let Some(card_index) = card_index {
-                                            let card = app.boards[index.unwrap()].cards[card_index]
-                                                .clone();
-                                            let card_name = card.name.clone();
-                                            app.boards[index.unwrap()].cards.remove(card_index);
-                                            // if index is > 0, set current card to previous card, else set to next card, else set to None
-                                            if card_index > 0 {
-                                                app.state.current_card_id = Some(
-                                                    app.boards[index.unwrap()].cards
-                                                        [card_index - 1]
-                                                        .id,
-                                                );
-                                            } else if !app.boards[index.unwrap()].cards.is_empty() {
-                                                app.state.current_card_id =
-                                                    Some(app.boards[index.unwrap()].cards[0].id);
-                                            } else {
-                                                app.state.current_card_id = None;
-                                            }
-                                            warn!("Deleted card {}", card_name);
-                                            app.action_history_manager.new_action(
-                                                ActionHistory::DeleteCard(card, current_board),
-                                            );
-                                            app.send_warning_toast(
-                                                &format!("Deleted card {}", card_name),
-                                                None,
-                                            );
-                                            // remove card_id from app.visible_boards_and_cards if it is there, where visible_boards_and_cards is a LinkedHashMap of board_id to a vector of card_ids
-                                            if let Some(visible_cards) =
-                                                app.visible_boards_and_cards.get_mut(&current_board)
-                                            {
-                                                if let Some(card_index) = visible_cards
-                                                    .iter()
-                                                    .position(|card_id| *card_id == current_card)
-                                                {
-                                                    visible_cards.remove(card_index);
-                                                }
-                                            }
-                                            refresh_visible_boards_and_cards(app);
+                        let moved_from_board_index = moved_from_board_index.unwrap();
+                        // check if board is the last board
+                        if moved_from_board_index < boards.len() - 1 {
+                            let moved_to_board_index = moved_from_board_index + 1;
+                            if let Some(current_card) = app.state.current_card_id {
+                                let card_index = boards[moved_from_board_index]
+                                    .cards
+                                    .iter()
+                                    .position(|card| card.id == current_card);
+                                if let Some(card_index) = card_index {
+                                    let moved_to_board_id = boards[moved_to_board_index].id;
+                                    let moved_from_board_id = boards[moved_from_board_index].id;
+                                    let card =
+                                        boards[moved_from_board_index].cards.remove(card_index);
+                                    let card_id = card.id;
+                                    let card_name = card.name.clone();
+                                    boards[moved_to_board_index].cards.push(card.clone());
+                                    // if the next board has cards less than the app.config.no_of_cards_to_show, then add the card to the visible cards
+                                    if boards[moved_to_board_index].cards.len()
+                                        <= app.config.no_of_cards_to_show as usize
+                                    {
+                                        app.visible_boards_and_cards
+                                            .entry(moved_to_board_id)
+                                            .and_modify(|cards| cards.push(card_id));
+                                    }
+                                    // remove the moved card from visible cards for the current board
+                                    app.visible_boards_and_cards
+                                        .entry(moved_from_board_id)
+                                        .and_modify(|cards| {
+                                            cards.retain(|card_id| *card_id != current_card)
+                                        });
+                                    // set the visible cards to the last no_of_cards_to_show cards
+                                    let mut moved_to_board_visible_cards: Vec<u128> = vec![];
+                                    let mut moved_from_board_visible_cards: Vec<u128> = vec![];
+                                    for card in boards[moved_to_board_index].cards.iter().rev() {
+                                        if moved_to_board_visible_cards.len()
+                                            < app.config.no_of_cards_to_show as usize
+                                        {
+                                            moved_to_board_visible_cards.insert(0, card.id);
                                         }
-                                    } else if let Some(current_board) = app.state.current_board_id {
-                                        // find index of current board id in app.boards
-                                        let index = app
+                                    }
+                                    for card in boards[moved_from_board_index].cards.iter().rev() {
+                                        if moved_from_board_visible_cards.len()
+                                            < app.config.no_of_cards_to_show as usize
+                                            && !moved_to_board_visible_cards.contains(&card.id)
+                                        {
+                                            moved_from_board_visible_cards.insert(0, card.id);
+                                        }
+                                    }
+                                    app.visible_boards_and_cards
+                                        .entry(moved_to_board_id)
+                                        .and_modify(|cards| *cards = moved_to_board_visible_cards);
+                                    app.visible_boards_and_cards
+                                        .entry(moved_from_board_id)
+                                        .and_modify(|cards| {
+                                            *cards = moved_from_board_visible_cards
+                                        });
+                                    app.state.current_board_id = Some(moved_to_board_id);
+
+                                    let info_msg = &format!(
+                                        "Moved card {} to board \"{}\"",
+                                        card_name, boards[moved_to_board_index].name
+                                    );
+                                    app.action_history_manager.new_action(
+                                        ActionHistory::MoveCardBetweenBoards(
+                                            card.clone(),
+                                            moved_from_board_id,
+                                            moved_to_board_id,
+                                        ),
+                                    );
+
+                                    // handling for filtered boards
+                                    if filter_mode {
+                                        let moved_from_board_index_in_all_boards = app
                                             .boards
                                             .iter()
-                                            .position(|board| board.id == current_board);
-                                        if let Some(index) = index {
-                                            let board = app.boards[index].clone();
-                                            let board_name = board.name.clone();
-                                            app.boards.remove(index);
-                                            // if index is > 0, set current board to previous board, else set to next board, else set to None
-                                            if index > 0 && !app.boards.is_empty() {
-                                                app.state.current_board_id =
-                                                    Some(app.boards[index - 1].id);
-                                            } else {
-                                                app.state.current_board_id = None;
-                                            }
-                                            warn!("Deleted board {}", board_name);
-                                            app.action_history_manager
-                                                .new_action(ActionHistory::DeleteBoard(board));
-                                            app.send_warning_toast(
-                                                &format!("Deleted board {}", board_name),
-                                                None,
-                                            );
-                                            // remove board_id from app.visible_boards_and_cards if it is there
-                                            app.visible_boards_and_cards.remove(&current_board);
-                                            refresh_visible_boards_and_cards(app);
-                                        }
+                                            .position(|board| board.id == moved_from_board_id)
+                                            .unwrap();
+                                        let moved_to_board_index_in_all_boards = app
+                                            .boards
+                                            .iter()
+                                            .position(|board| board.id == moved_to_board_id)
+                                            .unwrap();
+                                        let card_index_in_all = app.boards
+                                            [moved_from_board_index_in_all_boards]
+                                            .cards
+                                            .iter()
+                                            .position(|card| card.id == card_id)
+                                            .unwrap();
+                                        app.boards[moved_from_board_index_in_all_boards]
+                                            .cards
+                                            .remove(card_index_in_all);
+                                        app.boards[moved_to_board_index_in_all_boards]
+                                            .cards
+                                            .push(card);
                                     }
+
+                                    info!("{}", info_msg);
+                                    app.send_info_toast(info_msg, None);
                                 }
-                                AppReturn::Continue
                             }
-                            _ => AppReturn::Continue,
+                        } else {
+                            error!("Cannot move card right as it is the last board");
+                            app.send_error_toast(
+                                "Cannot move card right as it is the last board",
+                                None,
+                            );
                         }
                     }
                 }
+                AppReturn::Continue
             }
-            Action::DeleteBoard => {
+            Action::MoveCardLeft => {
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
-                match app.state.focus {
-                    Focus::Body => {
-                        // delete the current board from app.boards
-                        if let Some(current_board) = app.state.current_board_id {
-                            // find index of current board id in app.boards
-                            let index = app
-                                .boards
-                                .iter()
-                                .position(|board| board.id == current_board);
-                            if let Some(index) = index {
-                                let board = app.boards[index].clone();
-                                let board_name = board.name.clone();
-                                app.boards.remove(index);
-                                // if index is > 0, set current board to previous board, else set to next board, else set to None
-                                if index > 0 {
-                                    app.state.current_board_id = Some(app.boards[index - 1].id);
-                                } else if index < app.boards.len() {
-                                    app.state.current_board_id = Some(app.boards[index].id);
-                                } else {
-                                    app.state.current_board_id = None;
-                                }
-                                app.visible_boards_and_cards.remove(&current_board);
-                                warn!("Deleted board: {}", board_name);
-                                app.action_history_manager
-                                    .new_action(ActionHistory::DeleteBoard(board));
-                                app.send_warning_toast(
-                                    &format!("Deleted board: {}", board_name),
-                                    None,
-                                );
-                            }
-                        }
-                        AppReturn::Continue
+                if app.state.status_column_view {
+                    if app.state.focus == Focus::Body {
+                        move_card_to_status_column(app, false);
                     }
-                    _ => AppReturn::Continue,
-                }
-            }
-            Action::ChangeCardStatusToCompleted => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                    return AppReturn::Continue;
-                }
-                // check if focus is on body
-                if app.state.focus != Focus::Body {
                     return AppReturn::Continue;
                 }
-                // get the current card and change its status to complete
-                if let Some(current_board) = app.state.current_board_id {
-                    // find index of current board id in app.boards
-                    let index = app
-                        .boards
-                        .iter()
-                        .position(|board| board.id == current_board);
-                    if let Some(current_card) = app.state.current_card_id {
-                        let card_index = app.boards[index.unwrap()]
-                            .cards
-                            .iter()
-                            .position(|card| card.id == current_card);
-                        if let Some(card_index) = card_index {
-                            let temp_old_card =
-                                app.boards[index.unwrap()].cards[card_index].clone();
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Complete;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                Utc::now().to_string();
-                            app.boards[index.unwrap()].cards[card_index].date_modified =
-                                Utc::now().to_string();
-                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
-                            app.action_history_manager
-                                .new_action(ActionHistory::EditCard(
-                                    temp_old_card,
-                                    new_card,
-                                    current_board,
-                                ));
-                            info!(
-                                "Changed status to Completed for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
+                if app.state.focus == Focus::Body {
+                    if app.state.current_card_id.is_none() {
+                        return AppReturn::Continue;
+                    } else if let Some(current_board) = app.state.current_board_id {
+                        let mut filter_mode = false;
+                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+                            app.boards.as_mut()
+                        } else {
+                            filter_mode = true;
+                            app.filtered_boards.as_mut()
+                        };
+                        let moved_from_board_index =
+                            boards.iter().position(|board| board.id == current_board);
+                        if moved_from_board_index.is_none() {
+                            app.send_error_toast(
+                                "Something went wrong, could not find the board",
+                                None,
                             );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Completed for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
+                            debug!("Moved from board index is none");
+                            return AppReturn::Continue;
+                        }
+                        let moved_from_board_index = moved_from_board_index.unwrap();
+                        let moved_to_board_index = moved_from_board_index - 1;
+                        // check if board is the first board
+                        if moved_from_board_index > 0 {
+                            if let Some(current_card) = app.state.current_card_id {
+                                let card_index = boards[moved_from_board_index]
+                                    .cards
+                                    .iter()
+                                    .position(|card| card.id == current_card);
+                                if let Some(card_index) = card_index {
+                                    let moved_to_board_id = boards[moved_to_board_index].id;
+                                    let moved_from_board_id = boards[moved_from_board_index].id;
+                                    let card =
+                                        boards[moved_from_board_index].cards.remove(card_index);
+                                    let card_id = card.id;
+                                    let card_name = card.name.clone();
+                                    boards[moved_to_board_index].cards.push(card.clone());
+                                    // if the next board has cards less than the app.config.no_of_cards_to_show, then add the card to the visible cards
+                                    if boards[moved_to_board_index].cards.len()
+                                        <= app.config.no_of_cards_to_show as usize
+                                    {
+                                        app.visible_boards_and_cards
+                                            .entry(moved_to_board_id)
+                                            .and_modify(|cards| cards.push(card_id));
+                                    }
+                                    // remove the moved card from visible cards for the current board
+                                    app.visible_boards_and_cards
+                                        .entry(moved_from_board_id)
+                                        .and_modify(|cards| {
+                                            cards.retain(|card_id| *card_id != current_card)
+                                        });
+                                    // set the visible cards to the last no_of_cards_to_show cards
+                                    let mut moved_to_board_visible_cards: Vec<u128> = vec![];
+                                    let mut moved_from_board_visible_cards: Vec<u128> = vec![];
+                                    for card in boards[moved_to_board_index].cards.iter().rev() {
+                                        if moved_to_board_visible_cards.len()
+                                            < app.config.no_of_cards_to_show as usize
+                                        {
+                                            moved_to_board_visible_cards.insert(0, card.id);
+                                        }
+                                    }
+                                    for card in boards[moved_from_board_index].cards.iter().rev() {
+                                        if moved_from_board_visible_cards.len()
+                                            < app.config.no_of_cards_to_show as usize
+                                            && !moved_to_board_visible_cards.contains(&card.id)
+                                        {
+                                            moved_from_board_visible_cards.insert(0, card.id);
+                                        }
+                                    }
+                                    app.visible_boards_and_cards
+                                        .entry(moved_to_board_id)
+                                        .and_modify(|cards| *cards = moved_to_board_visible_cards);
+                                    app.visible_boards_and_cards
+                                        .entry(moved_from_board_id)
+                                        .and_modify(|cards| {
+                                            *cards = moved_from_board_visible_cards
+                                        });
+                                    app.state.current_board_id = Some(moved_to_board_id);
+
+                                    let info_msg = &format!(
+                                        "Moved card {} to board \"{}\"",
+                                        card_name, boards[moved_to_board_index].name
+                                    );
+                                    app.action_history_manager.new_action(
+                                        ActionHistory::MoveCardBetweenBoards(
+                                            card.clone(),
+                                            moved_from_board_id,
+                                            moved_to_board_id,
+                                        ),
+                                    );
+
+                                    // handling for filtered boards
+                                    if filter_mode {
+                                        let moved_from_board_index_in_all_boards = app
+                                            .boards
+                                            .iter()
+                                            .position(|board| board.id == moved_from_board_id)
+                                            .unwrap();
+                                        let moved_to_board_index_in_all_boards = app
+                                            .boards
+                                            .iter()
+                                            .position(|board| board.id == moved_to_board_id)
+                                            .unwrap();
+                                        let card_index_in_all = app.boards
+                                            [moved_from_board_index_in_all_boards]
+                                            .cards
+                                            .iter()
+                                            .position(|card| card.id == card_id)
+                                            .unwrap();
+                                        app.boards[moved_from_board_index_in_all_boards]
+                                            .cards
+                                            .remove(card_index_in_all);
+                                        app.boards[moved_to_board_index_in_all_boards]
+                                            .cards
+                                            .push(card);
+                                    }
+
+                                    info!("{}", info_msg);
+                                    app.send_info_toast(info_msg, None);
+                                }
+                            }
+                        } else {
+                            error!("Cannot move card left as it is the first board");
+                            app.send_error_toast(
+                                "Cannot move card left as it is the first board",
                                 None,
                             );
                         }
@@ -2929,1441 +5674,1787 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
-            Action::ChangeCardStatusToActive => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                    return AppReturn::Continue;
-                }
-                // check if focus is on body
-                if app.state.focus != Focus::Body {
-                    return AppReturn::Continue;
-                }
-                // get the current card and change its status to active
-                if let Some(current_board) = app.state.current_board_id {
-                    // find index of current board id in app.boards
-                    let index = app
-                        .boards
-                        .iter()
-                        .position(|board| board.id == current_board);
-                    if let Some(current_card) = app.state.current_card_id {
-                        let card_index = app.boards[index.unwrap()]
-                            .cards
-                            .iter()
-                            .position(|card| card.id == current_card);
-                        if let Some(card_index) = card_index {
-                            let temp_old_card =
-                                app.boards[index.unwrap()].cards[card_index].clone();
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Active;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                "N/A".to_string();
-                            app.boards[index.unwrap()].cards[card_index].date_modified =
-                                Utc::now().to_string();
-                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
-                            app.action_history_manager
-                                .new_action(ActionHistory::EditCard(
-                                    temp_old_card,
-                                    new_card,
-                                    current_board,
-                                ));
-                            info!(
-                                "Changed status to Active for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
-                            );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Active for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
-                                None,
-                            );
+            Action::ToggleCommandPalette => {
+                if app.state.popup_mode.is_none() {
+                    open_command_palette(app);
+                } else {
+                    match app.state.popup_mode.unwrap() {
+                        PopupMode::CommandPalette => {
+                            app.state.popup_mode = None;
+                            app.state.current_user_input = String::new();
+                            app.state.current_cursor_position = None;
+                            app.state.app_status = AppStatus::Initialized;
+                        }
+                        PopupMode::ViewCard => {
+                            if app.card_being_edited.is_some() {
+                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                                app.state.app_status = AppStatus::Initialized;
+                            } else {
+                                open_command_palette(app);
+                            }
+                        }
+                        PopupMode::ConfirmDiscardCardChanges => {
+                            if app.card_being_edited.is_some() {
+                                warn!(
+                                    "Discarding changes to card '{}'",
+                                    app.card_being_edited.as_ref().unwrap().1.name
+                                );
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    ),
+                                    None,
+                                );
+                            }
+                            app.state.popup_mode = None;
+                            app.card_being_edited = None;
+                            open_command_palette(app);
+                        }
+                        _ => {
+                            open_command_palette(app);
                         }
                     }
                 }
                 AppReturn::Continue
             }
-            Action::ChangeCardStatusToStale => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                    return AppReturn::Continue;
-                }
-                // check if focus is on body
-                if app.state.focus != Focus::Body {
+            Action::Undo => {
+                app.undo();
+                AppReturn::Continue
+            }
+            Action::Redo => {
+                app.redo();
+                AppReturn::Continue
+            }
+            Action::ClearAllToasts => {
+                app.state.toasts.clear();
+                info!("Cleared toast messages");
+                AppReturn::Continue
+            }
+            Action::ToggleBoardFocusMode => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
                     return AppReturn::Continue;
                 }
-                // get the current card and change its status to stale
-                if let Some(current_board) = app.state.current_board_id {
-                    // find index of current board id in app.boards
-                    let index = app
-                        .boards
-                        .iter()
-                        .position(|board| board.id == current_board);
-                    if let Some(current_card) = app.state.current_card_id {
-                        let card_index = app.boards[index.unwrap()]
-                            .cards
-                            .iter()
-                            .position(|card| card.id == current_card);
-                        if let Some(card_index) = card_index {
-                            let temp_old_card =
-                                app.boards[index.unwrap()].cards[card_index].clone();
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Stale;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                "N/A".to_string();
-                            app.boards[index.unwrap()].cards[card_index].date_modified =
-                                Utc::now().to_string();
-                            let new_card = app.boards[index.unwrap()].cards[card_index].clone();
-                            app.action_history_manager
-                                .new_action(ActionHistory::EditCard(
-                                    temp_old_card,
-                                    new_card,
-                                    current_board,
-                                ));
-                            info!(
-                                "Changed status to Stale for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
-                            );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Stale for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
-                                None,
-                            );
-                        }
+                app.state.board_focus_mode = !app.state.board_focus_mode;
+                if app.state.board_focus_mode {
+                    if app.state.current_board_id.is_none() {
+                        app.state.current_board_id =
+                            app.visible_boards_and_cards.keys().next().copied();
                     }
+                    app.state.focus = Focus::Body;
+                    info!("Entered focus mode");
+                } else {
+                    info!("Exited focus mode");
                 }
                 AppReturn::Continue
             }
-            Action::GoToMainMenu => {
-                app.state.current_board_id = None;
-                app.state.current_card_id = None;
-                app.state.focus = Focus::MainMenu;
-                app.state.ui_mode = UiMode::MainMenu;
-                if app.state.main_menu_state.selected().is_none() {
-                    app.state.main_menu_state.select(Some(0));
+            Action::ToggleSplitView => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
                 }
+                toggle_split_view(app);
                 AppReturn::Continue
             }
-            Action::MoveCardUp => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+            Action::SwitchSplitViewPane => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
                     return AppReturn::Continue;
-                }
-                if app.state.focus == Focus::Body {
-                    if app.state.current_card_id.is_none() {
-                        return AppReturn::Continue;
-                    } else {
-                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-                            app.boards.as_mut()
-                        } else {
-                            app.filtered_boards.as_mut()
-                        };
-                        if app.state.current_board_id.is_none() {
-                            debug!("Cannot move card up without a current board id");
-                            return AppReturn::Continue;
-                        }
-                        if app.state.current_card_id.is_none() {
-                            debug!("Cannot move card up without a current card id");
-                            return AppReturn::Continue;
-                        }
-                        let current_board_id = app.state.current_board_id.unwrap();
-                        let current_card_id = app.state.current_card_id.unwrap();
-                        let current_board_index_in_all_boards =
-                            boards.iter().position(|board| board.id == current_board_id);
-                        if current_board_index_in_all_boards.is_none() {
-                            debug!("Cannot move card up without a current board index");
-                            return AppReturn::Continue;
-                        }
-                        let current_card_index_in_all = boards
-                            [current_board_index_in_all_boards.unwrap()]
-                        .cards
-                        .iter()
-                        .position(|card| card.id == current_card_id);
-                        if current_card_index_in_all.is_none() {
-                            debug!("Cannot move card up without a current card index");
-                            return AppReturn::Continue;
-                        }
-                        let current_card_index_in_all = current_card_index_in_all.unwrap();
-                        if current_card_index_in_all == 0 {
-                            app.send_error_toast(
-                                "Cannot move card up, it is already at the top of the board",
-                                None,
-                            );
-                            error!("Cannot move card up, it is already at the top of the board");
-                            return AppReturn::Continue;
-                        }
-                        // update visible boards and cards
-                        // check if both the cards that are being swapped are in the visible cards
-                        let current_card_index_in_visible = app.visible_boards_and_cards
-                            [&current_board_id]
-                            .iter()
-                            .position(|card_id| *card_id == current_card_id);
-                        if current_card_index_in_visible.is_none() {
-                            debug!(
-                                "Cannot move card up without a current card index in visible cards"
-                            );
-                            return AppReturn::Continue;
-                        }
-                        let current_card_index_in_visible = current_card_index_in_visible.unwrap();
-                        if current_card_index_in_visible == 0 {
-                            let card_above_id = boards[current_board_index_in_all_boards.unwrap()]
-                                .cards[current_card_index_in_all - 1]
-                                .id;
-                            let mut visible_cards: Vec<u128> = vec![];
-                            visible_cards.push(current_card_id);
-                            visible_cards.push(card_above_id);
-
-                            for card in app.visible_boards_and_cards[&current_board_id].iter() {
-                                if *card != current_card_id
-                                    && visible_cards.len() < app.config.no_of_cards_to_show as usize
-                                {
-                                    visible_cards.push(*card);
-                                }
-                            }
-                            app.visible_boards_and_cards
-                                .entry(current_board_id)
-                                .and_modify(|cards| *cards = visible_cards);
-                        } else {
-                            app.visible_boards_and_cards
-                                .get_mut(&current_board_id)
-                                .unwrap()
-                                .swap(
-                                    current_card_index_in_visible,
-                                    current_card_index_in_visible - 1,
-                                );
-                        }
-                        boards[current_board_index_in_all_boards.unwrap()]
-                            .cards
-                            .swap(current_card_index_in_all, current_card_index_in_all - 1);
-                        app.action_history_manager
-                            .new_action(ActionHistory::MoveCardWithinBoard(
-                                current_board_id,
-                                current_card_index_in_all,
-                                current_card_index_in_all - 1,
-                            ));
+                }
+                switch_split_view_pane(app);
+                AppReturn::Continue
+            }
+            Action::ToggleCardSelection => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                }
+                let card_id = match app.state.current_card_id {
+                    Some(card_id) => card_id,
+                    None => {
+                        app.send_warning_toast("No card selected", None);
+                        return AppReturn::Continue;
                     }
+                };
+                if app.state.selected_card_ids.contains(&card_id) {
+                    app.state.selected_card_ids.retain(|id| *id != card_id);
+                } else {
+                    app.state.selected_card_ids.push(card_id);
                 }
+                app.send_info_toast(
+                    &format!("{} cards selected", app.state.selected_card_ids.len()),
+                    None,
+                );
                 AppReturn::Continue
             }
-            Action::MoveCardDown => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+            Action::MoveCardToTop => move_current_card_to_board_edge(app, true),
+            Action::MoveCardToBottom => move_current_card_to_board_edge(app, false),
+            Action::ShowQuickHelp => {
+                if app.state.popup_mode.is_none() {
+                    app.state.popup_mode = Some(PopupMode::QuickHelp);
+                }
+                AppReturn::Continue
+            }
+            Action::ResetBoardView => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
                     return AppReturn::Continue;
                 }
-                if app.state.focus == Focus::Body {
-                    if app.state.current_card_id.is_none() {
+                let current_board_id = match app.state.current_board_id {
+                    Some(board_id) => board_id,
+                    None => {
+                        app.send_warning_toast("No board selected to reset", None);
                         return AppReturn::Continue;
+                    }
+                };
+                let boards = if app.filtered_boards.is_empty() {
+                    &app.boards
+                } else {
+                    &app.filtered_boards
+                };
+                let board = boards.iter().find(|board| board.id == current_board_id);
+                if board.is_none() {
+                    debug!("Could not find board {} to reset", current_board_id);
+                    return AppReturn::Continue;
+                }
+                let board = board.unwrap();
+                let board_name = board.name.clone();
+                // only the card window of this board goes back to the top, other boards keep their scroll
+                let visible_cards = board
+                    .cards
+                    .iter()
+                    .take(app.config.no_of_cards_to_show.into())
+                    .map(|card| card.id)
+                    .collect::<Vec<u128>>();
+                if let Some(card_ids) = app.visible_boards_and_cards.get_mut(&current_board_id) {
+                    *card_ids = visible_cards.clone();
+                }
+                app.state.current_card_id = visible_cards.first().copied();
+                app.send_info_toast(&format!("Reset view of board {}", board_name), None);
+                AppReturn::Continue
+            }
+            Action::DismissTopToast => {
+                // start fading out the most recent toast that is not already fading out
+                let fade_out_time = Duration::from_millis(TOAST_FADE_OUT_TIME);
+                if let Some(toast) = app
+                    .state
+                    .toasts
+                    .iter_mut()
+                    .filter(|toast| toast.start_time.elapsed() + fade_out_time < toast.duration)
+                    .max_by_key(|toast| toast.start_time)
+                {
+                    toast.duration = toast.start_time.elapsed() + fade_out_time;
+                }
+                AppReturn::Continue
+            }
+            Action::JumpToCard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                // label the cards in the order they are drawn, left to right and top to bottom
+                let no_of_cards_to_show = app.config.no_of_cards_to_show as usize;
+                let visible_cards = app
+                    .visible_boards_and_cards
+                    .iter()
+                    .take(app.config.no_of_boards_to_show as usize)
+                    .flat_map(|(board_id, card_ids)| {
+                        card_ids
+                            .iter()
+                            .take(no_of_cards_to_show)
+                            .map(move |card_id| (*board_id, *card_id))
+                    })
+                    .collect::<Vec<(u128, u128)>>();
+                if visible_cards.is_empty() {
+                    warn!("No cards available to jump to");
+                    app.send_warning_toast("No cards available to jump to", None);
+                    return AppReturn::Continue;
+                }
+                let labels = generate_card_jump_labels(visible_cards.len());
+                app.state.card_jump_labels = labels
+                    .into_iter()
+                    .zip(visible_cards)
+                    .map(|(label, (board_id, card_id))| (label, board_id, card_id))
+                    .collect();
+                app.state.card_jump_input = String::new();
+                app.state.app_status = AppStatus::CardJumpMode;
+                info!("Entering card jump mode");
+                AppReturn::Continue
+            }
+        }
+    } else {
+        warn!("No action accociated to {}", key);
+        app.send_warning_toast(&format!("No action accociated to {}", key), None);
+        AppReturn::Continue
+    }
+}
+
+/// Makes the card under the mouse the current one and opens it, clicking a board outside of its
+/// cards only makes the board current
+fn open_hovered_card(app: &mut App) {
+    let (board_id, card_id) = if let Some(hovered_board_card) = app.state.hovered_board_card {
+        hovered_board_card
+    } else {
+        return;
+    };
+    app.state.focus = Focus::Body;
+    app.state.current_board_id = Some(board_id);
+    if let Some(card_id) = card_id {
+        app.state.current_card_id = Some(card_id);
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+    }
+}
+
+pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppReturn {
+    let mut left_button_pressed = false;
+    let mut right_button_pressed = false;
+    let mut middle_button_pressed = false;
+    let mut mouse_scroll_up = false;
+    let mut mouse_scroll_down = false;
+    let mut mouse_scroll_left = false;
+    let mut mouse_scroll_right = false;
+    match mouse_action {
+        Mouse::Move(x, y) => {
+            app.state.current_mouse_coordinates = (x, y);
+            app.state.keyboard_has_focus = false;
+        }
+        Mouse::Drag(x, y) => {
+            // TODO: handle mouse drag features
+            debug!("Mouse drag at {}, {}", x, y);
+        }
+        Mouse::LeftPress => left_button_pressed = true,
+        Mouse::RightPress => right_button_pressed = true,
+        Mouse::MiddlePress => middle_button_pressed = true,
+        Mouse::ScrollUp => mouse_scroll_up = true,
+        Mouse::ScrollDown => mouse_scroll_down = true,
+        Mouse::ScrollLeft => mouse_scroll_left = true,
+        Mouse::ScrollRight => mouse_scroll_right = true,
+        Mouse::Unknown => {}
+    }
+    if let Mouse::Move(x, y) = mouse_action {
+        app.state.current_mouse_coordinates = (x, y);
+    }
+    if right_button_pressed {
+        return handle_go_to_previous_ui_mode(app).await;
+    }
+
+    if middle_button_pressed {
+        if app.state.popup_mode.is_none() {
+            open_command_palette(app);
+        } else {
+            match app.state.popup_mode.unwrap() {
+                PopupMode::CommandPalette => {
+                    app.state.popup_mode = None;
+                    app.state.current_user_input = String::new();
+                    app.state.current_cursor_position = None;
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                PopupMode::ViewCard => {
+                    if app.card_being_edited.is_some() {
+                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                        app.state.app_status = AppStatus::Initialized;
                     } else {
-                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-                            app.boards.as_mut()
-                        } else {
-                            app.filtered_boards.as_mut()
-                        };
-                        if app.state.current_board_id.is_none() {
-                            debug!("Cannot move card down without a current board id");
-                            return AppReturn::Continue;
-                        }
-                        if app.state.current_card_id.is_none() {
-                            debug!("Cannot move card down without a current card id");
-                            return AppReturn::Continue;
+                        open_command_palette(app);
+                    }
+                }
+                PopupMode::ConfirmDiscardCardChanges => {
+                    if app.card_being_edited.is_some() {
+                        warn!(
+                            "Discarding changes to card '{}'",
+                            app.card_being_edited.as_ref().unwrap().1.name
+                        );
+                        app.send_warning_toast(
+                            &format!(
+                                "Discarding changes to card '{}'",
+                                app.card_being_edited.as_ref().unwrap().1.name
+                            ),
+                            None,
+                        );
+                    }
+                    app.state.popup_mode = None;
+                    app.card_being_edited = None;
+                    open_command_palette(app);
+                }
+                _ => {
+                    open_command_palette(app);
+                }
+            }
+        }
+        return AppReturn::Continue;
+    }
+
+    if app.state.popup_mode.is_some() {
+        let popup_mode = app.state.popup_mode.unwrap();
+        match popup_mode {
+            PopupMode::CommandPalette => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CommandPaletteCommand => {
+                            return CommandPaletteWidget::handle_command(app).await;
                         }
-                        let current_board_id = app.state.current_board_id.unwrap();
-                        let current_card_id = app.state.current_card_id.unwrap();
-                        let current_board_index_in_all_boards =
-                            boards.iter().position(|board| board.id == current_board_id);
-                        if current_board_index_in_all_boards.is_none() {
-                            debug!("Cannot move card down without a current board index");
-                            return AppReturn::Continue;
+                        Focus::CommandPaletteCard => {
+                            handle_command_palette_card_selection(app);
+                            app.state.popup_mode = None;
+                            app.state.current_user_input = String::new();
+                            app.state.current_cursor_position = None;
+                            app.state.app_status = AppStatus::Initialized;
                         }
-                        let current_card_index_in_all = boards
-                            [current_board_index_in_all_boards.unwrap()]
-                        .cards
-                        .iter()
-                        .position(|card| card.id == current_card_id);
-                        if current_card_index_in_all.is_none() {
-                            debug!("Cannot move card down without a current card index");
-                            return AppReturn::Continue;
+                        Focus::CommandPaletteBoard => {
+                            handle_command_palette_board_selection(app);
+                            app.state.popup_mode = None;
+                            app.state.current_user_input = String::new();
+                            app.state.current_cursor_position = None;
+                            app.state.app_status = AppStatus::Initialized;
                         }
-                        let current_card_index_in_all = current_card_index_in_all.unwrap();
-                        if current_card_index_in_all
-                            == boards[current_board_index_in_all_boards.unwrap()]
-                                .cards
-                                .len()
-                                - 1
-                        {
-                            app.send_error_toast(
-                                "Cannot move card down, it is already at the bottom of the board",
-                                None,
-                            );
-                            error!(
-                                "Cannot move card down, it is already at the bottom of the board"
-                            );
-                            return AppReturn::Continue;
+                        Focus::CloseButton => {
+                            app.state.popup_mode = None;
+                            app.state.current_user_input = String::new();
+                            app.state.current_cursor_position = None;
+                            app.state.app_status = AppStatus::Initialized;
                         }
-                        // update visible boards and cards
-                        // check if both the cards that are being swapped are in the visible cards
-                        let current_card_index_in_visible = app.visible_boards_and_cards
-                            [&current_board_id]
-                            .iter()
-                            .position(|card_id| *card_id == current_card_id);
-                        if current_card_index_in_visible.is_none() {
-                            debug!("Cannot move card down without a current card index in visible cards");
-                            return AppReturn::Continue;
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CommandPaletteCommand => {
+                            app.command_palette_command_search_prv();
                         }
-                        let current_card_index_in_visible = current_card_index_in_visible.unwrap();
-                        if current_card_index_in_visible
-                            == app.visible_boards_and_cards[&current_board_id].len() - 1
-                        {
-                            let card_below_id = boards[current_board_index_in_all_boards.unwrap()]
-                                .cards[current_card_index_in_all + 1]
-                                .id;
-                            let mut visible_cards: Vec<u128> = vec![];
-                            visible_cards.push(card_below_id);
-                            visible_cards.push(current_card_id);
-                            // insert in reverse order till we reach the no of cards to show
-                            for card in app.visible_boards_and_cards[&current_board_id].iter().rev()
-                            {
-                                if *card != current_card_id
-                                    && visible_cards.len() < app.config.no_of_cards_to_show as usize
-                                {
-                                    visible_cards.insert(0, *card);
-                                }
-                            }
-
-                            app.visible_boards_and_cards
-                                .entry(current_board_id)
-                                .and_modify(|cards| *cards = visible_cards);
-                        } else {
-                            app.visible_boards_and_cards
-                                .get_mut(&current_board_id)
-                                .unwrap()
-                                .swap(
-                                    current_card_index_in_visible,
-                                    current_card_index_in_visible + 1,
-                                );
+                        Focus::CommandPaletteCard => app.command_palette_card_search_prv(),
+                        Focus::CommandPaletteBoard => app.command_palette_board_search_prv(),
+                        _ => {}
+                    }
+                } else if mouse_scroll_down && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CommandPaletteCommand => {
+                            app.command_palette_command_search_next();
                         }
-                        boards[current_board_index_in_all_boards.unwrap()]
-                            .cards
-                            .swap(current_card_index_in_all, current_card_index_in_all + 1);
-                        app.action_history_manager
-                            .new_action(ActionHistory::MoveCardWithinBoard(
-                                current_board_id,
-                                current_card_index_in_all,
-                                current_card_index_in_all + 1,
-                            ));
+                        Focus::CommandPaletteCard => {
+                            app.command_palette_card_search_next();
+                        }
+                        Focus::CommandPaletteBoard => {
+                            app.command_palette_board_search_next();
+                        }
+                        _ => {}
                     }
                 }
-                AppReturn::Continue
             }
-            Action::MoveCardRight => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                    return AppReturn::Continue;
+            PopupMode::SelectDefaultView => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SelectDefaultView) {
+                        handle_default_view_selection(app);
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
                 }
-                if app.state.focus == Focus::Body {
-                    if app.state.current_card_id.is_none() {
-                        return AppReturn::Continue;
-                    } else if let Some(current_board) = app.state.current_board_id {
-                        let mut filter_mode = false;
-                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-                            app.boards.as_mut()
-                        } else {
-                            filter_mode = true;
-                            app.filtered_boards.as_mut()
-                        };
-                        let moved_from_board_index =
-                            boards.iter().position(|board| board.id == current_board);
-                        if moved_from_board_index.is_none() {
-                            app.send_error_toast(
-                                "Something went wrong, could not find the board",
-                                None,
-                            );
-                            debug!("Moved from board index is none");
-                            return AppReturn::Continue;
-                        }
-                        let moved_from_board_index = moved_from_board_index.unwrap();
-                        // check if board is the last board
-                        if moved_from_board_index < boards.len() - 1 {
-                            let moved_to_board_index = moved_from_board_index + 1;
-                            if let Some(current_card) = app.state.current_card_id {
-                                let card_index = boards[moved_from_board_index]
-                                    .cards
-                                    .iter()
-                                    .position(|card| card.id == current_card);
-                                if let Some(card_index) = card_index {
-                                    let moved_to_board_id = boards[moved_to_board_index].id;
-                                    let moved_from_board_id = boards[moved_from_board_index].id;
-                                    let card =
-                                        boards[moved_from_board_index].cards.remove(card_index);
-                                    let card_id = card.id;
-                                    let card_name = card.name.clone();
-                                    boards[moved_to_board_index].cards.push(card.clone());
-                                    // if the next board has cards less than the app.config.no_of_cards_to_show, then add the card to the visible cards
-                                    if boards[moved_to_board_index].cards.len()
-                                        <= app.config.no_of_cards_to_show as usize
-                                    {
-                                        app.visible_boards_and_cards
-                                            .entry(moved_to_board_id)
-                                            .and_modify(|cards| cards.push(card_id));
-                                    }
-                                    // remove the moved card from visible cards for the current board
-                                    app.visible_boards_and_cards
-                                        .entry(moved_from_board_id)
-                                        .and_modify(|cards| {
-                                            cards.retain(|card_id| *card_id != current_card)
-                                        });
-                                    // set the visible cards to the last no_of_cards_to_show cards
-                                    let mut moved_to_board_visible_cards: Vec<u128> = vec![];
-                                    let mut moved_from_board_visible_cards: Vec<u128> = vec![];
-                                    for card in boards[moved_to_board_index].cards.iter().rev() {
-                                        if moved_to_board_visible_cards.len()
-                                            < app.config.no_of_cards_to_show as usize
-                                        {
-                                            moved_to_board_visible_cards.insert(0, card.id);
-                                        }
-                                    }
-                                    for card in boards[moved_from_board_index].cards.iter().rev() {
-                                        if moved_from_board_visible_cards.len()
-                                            < app.config.no_of_cards_to_show as usize
-                                            && !moved_to_board_visible_cards.contains(&card.id)
-                                        {
-                                            moved_from_board_visible_cards.insert(0, card.id);
-                                        }
-                                    }
-                                    app.visible_boards_and_cards
-                                        .entry(moved_to_board_id)
-                                        .and_modify(|cards| *cards = moved_to_board_visible_cards);
-                                    app.visible_boards_and_cards
-                                        .entry(moved_from_board_id)
-                                        .and_modify(|cards| {
-                                            *cards = moved_from_board_visible_cards
-                                        });
-                                    app.state.current_board_id = Some(moved_to_board_id);
-
-                                    let info_msg = &format!(
-                                        "Moved card {} to board \"{}\"",
-                                        card_name, boards[moved_to_board_index].name
-                                    );
-                                    app.action_history_manager.new_action(
-                                        ActionHistory::MoveCardBetweenBoards(
-                                            card.clone(),
-                                            moved_from_board_id,
-                                            moved_to_board_id,
-                                        ),
-                                    );
-
-                                    // handling for filtered boards
-                                    if filter_mode {
-                                        let moved_from_board_index_in_all_boards = app
-                                            .boards
-                                            .iter()
-                                            .position(|board| board.id == moved_from_board_id)
-                                            .unwrap();
-                                        let moved_to_board_index_in_all_boards = app
-                                            .boards
-                                            .iter()
-                                            .position(|board| board.id == moved_to_board_id)
-                                            .unwrap();
-                                        let card_index_in_all = app.boards
-                                            [moved_from_board_index_in_all_boards]
-                                            .cards
-                                            .iter()
-                                            .position(|card| card.id == card_id)
-                                            .unwrap();
-                                        app.boards[moved_from_board_index_in_all_boards]
-                                            .cards
-                                            .remove(card_index_in_all);
-                                        app.boards[moved_to_board_index_in_all_boards]
-                                            .cards
-                                            .push(card);
-                                    }
-
-                                    info!("{}", info_msg);
-                                    app.send_info_toast(info_msg, None);
-                                }
-                            }
+            }
+            PopupMode::ChangeUIMode => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ChangeUiModePopup) {
+                        handle_change_ui_mode(app);
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::CardStatusSelector => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ChangeCardStatusPopup) {
+                        return handle_change_card_status(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::BoardStatusSelector => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ChangeCardStatusPopup) {
+                        return handle_change_board_cards_status(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::EditGeneralConfig => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        if app.state.ui_mode == UiMode::CreateTheme {
+                            handle_create_theme_action(app);
                         } else {
-                            error!("Cannot move card right as it is the last board");
-                            app.send_error_toast(
-                                "Cannot move card right as it is the last board",
-                                None,
-                            );
+                            handle_edit_general_config(app);
                         }
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::QuickHelp | PopupMode::ExpandedCard => {
+                if left_button_pressed {
+                    app.state.popup_mode = None;
+                }
+            }
+            PopupMode::EditBoardDescription => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_edit_board_description(app);
+                    }
+                }
+            }
+            PopupMode::SaveNote => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_save_note(app).await;
+                    }
+                }
+            }
+            PopupMode::ImportMarkdown => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_import_markdown(app);
+                    }
+                }
+            }
+            PopupMode::ImportJson => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_import_json(app).await;
                     }
                 }
-                AppReturn::Continue
             }
-            Action::MoveCardLeft => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                    return AppReturn::Continue;
+            PopupMode::ImportTrello => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_import_trello(app).await;
+                    }
                 }
-                if app.state.focus == Focus::Body {
-                    if app.state.current_card_id.is_none() {
-                        return AppReturn::Continue;
-                    } else if let Some(current_board) = app.state.current_board_id {
-                        let mut filter_mode = false;
-                        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-                            app.boards.as_mut()
-                        } else {
-                            filter_mode = true;
-                            app.filtered_boards.as_mut()
-                        };
-                        let moved_from_board_index =
-                            boards.iter().position(|board| board.id == current_board);
-                        if moved_from_board_index.is_none() {
-                            app.send_error_toast(
-                                "Something went wrong, could not find the board",
-                                None,
-                            );
-                            debug!("Moved from board index is none");
-                            return AppReturn::Continue;
-                        }
-                        let moved_from_board_index = moved_from_board_index.unwrap();
-                        let moved_to_board_index = moved_from_board_index - 1;
-                        // check if board is the first board
-                        if moved_from_board_index > 0 {
-                            if let Some(current_card) = app.state.current_card_id {
-                                let card_index = boards[moved_from_board_index]
-                                    .cards
-                                    .iter()
-                                    .position(|card| card.id == current_card);
-                                if let Some(card_index) = card_index {
-                                    let moved_to_board_id = boards[moved_to_board_index].id;
-                                    let moved_from_board_id = boards[moved_from_board_index].id;
-                                    let card =
-                                        boards[moved_from_board_index].cards.remove(card_index);
-                                    let card_id = card.id;
-                                    let card_name = card.name.clone();
-                                    boards[moved_to_board_index].cards.push(card.clone());
-                                    // if the next board has cards less than the app.config.no_of_cards_to_show, then add the card to the visible cards
-                                    if boards[moved_to_board_index].cards.len()
-                                        <= app.config.no_of_cards_to_show as usize
-                                    {
-                                        app.visible_boards_and_cards
-                                            .entry(moved_to_board_id)
-                                            .and_modify(|cards| cards.push(card_id));
-                                    }
-                                    // remove the moved card from visible cards for the current board
-                                    app.visible_boards_and_cards
-                                        .entry(moved_from_board_id)
-                                        .and_modify(|cards| {
-                                            cards.retain(|card_id| *card_id != current_card)
-                                        });
-                                    // set the visible cards to the last no_of_cards_to_show cards
-                                    let mut moved_to_board_visible_cards: Vec<u128> = vec![];
-                                    let mut moved_from_board_visible_cards: Vec<u128> = vec![];
-                                    for card in boards[moved_to_board_index].cards.iter().rev() {
-                                        if moved_to_board_visible_cards.len()
-                                            < app.config.no_of_cards_to_show as usize
-                                        {
-                                            moved_to_board_visible_cards.insert(0, card.id);
-                                        }
-                                    }
-                                    for card in boards[moved_from_board_index].cards.iter().rev() {
-                                        if moved_from_board_visible_cards.len()
-                                            < app.config.no_of_cards_to_show as usize
-                                            && !moved_to_board_visible_cards.contains(&card.id)
-                                        {
-                                            moved_from_board_visible_cards.insert(0, card.id);
-                                        }
-                                    }
-                                    app.visible_boards_and_cards
-                                        .entry(moved_to_board_id)
-                                        .and_modify(|cards| *cards = moved_to_board_visible_cards);
-                                    app.visible_boards_and_cards
-                                        .entry(moved_from_board_id)
-                                        .and_modify(|cards| {
-                                            *cards = moved_from_board_visible_cards
-                                        });
-                                    app.state.current_board_id = Some(moved_to_board_id);
-
-                                    let info_msg = &format!(
-                                        "Moved card {} to board \"{}\"",
-                                        card_name, boards[moved_to_board_index].name
-                                    );
-                                    app.action_history_manager.new_action(
-                                        ActionHistory::MoveCardBetweenBoards(
-                                            card.clone(),
-                                            moved_from_board_id,
-                                            moved_to_board_id,
-                                        ),
-                                    );
-
-                                    // handling for filtered boards
-                                    if filter_mode {
-                                        let moved_from_board_index_in_all_boards = app
-                                            .boards
-                                            .iter()
-                                            .position(|board| board.id == moved_from_board_id)
-                                            .unwrap();
-                                        let moved_to_board_index_in_all_boards = app
-                                            .boards
-                                            .iter()
-                                            .position(|board| board.id == moved_to_board_id)
-                                            .unwrap();
-                                        let card_index_in_all = app.boards
-                                            [moved_from_board_index_in_all_boards]
-                                            .cards
-                                            .iter()
-                                            .position(|card| card.id == card_id)
-                                            .unwrap();
-                                        app.boards[moved_from_board_index_in_all_boards]
-                                            .cards
-                                            .remove(card_index_in_all);
-                                        app.boards[moved_to_board_index_in_all_boards]
-                                            .cards
-                                            .push(card);
-                                    }
-
-                                    info!("{}", info_msg);
-                                    app.send_info_toast(info_msg, None);
-                                }
-                            }
-                        } else {
-                            error!("Cannot move card left as it is the first board");
-                            app.send_error_toast(
-                                "Cannot move card left as it is the first board",
-                                None,
-                            );
-                        }
+            }
+            PopupMode::EditBoardCardLimit => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_edit_board_card_limit(app);
+                    }
+                }
+            }
+            PopupMode::EditBoardDueDate => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_edit_board_due_date(app);
+                    }
+                }
+            }
+            PopupMode::EditCardProgress => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_edit_card_progress(app);
+                    }
+                }
+            }
+            PopupMode::EditBoardAccent => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_edit_board_accent(app);
+                    }
+                }
+            }
+            PopupMode::NewCardOnAllBoards => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        handle_new_card_on_all_boards(app);
+                    }
+                }
+            }
+            PopupMode::EditSpecificKeyBinding => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::EditSpecificKeyBindingPopup) {
+                        app.state.app_status = AppStatus::KeyBindMode;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                        handle_edit_specific_keybinding(app);
+                    }
+                }
+            }
+            PopupMode::ChangeDateFormatPopup => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ChangeDateFormatPopup) {
+                        handle_change_date_format(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::FilterPresetSelector => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::FilterPresetPopup) {
+                        handle_filter_preset_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::CardRecurrenceSelector => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardRecurrencePopup) {
+                        handle_card_recurrence_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::CardLinkSelector => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardLinkPopup) {
+                        handle_card_link_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::RecentCards => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::RecentCardsPopup) {
+                        handle_recent_card_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::ActivityLog => {
+                if left_button_pressed && app.state.mouse_focus == Some(Focus::CloseButton) {
+                    app.state.popup_mode = None;
+                } else if mouse_scroll_up {
+                    app.activity_log_popup_prv();
+                } else if mouse_scroll_down {
+                    app.activity_log_popup_next();
+                }
+            }
+            PopupMode::ChangeTheme => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ThemeSelector) {
+                        handle_change_theme(app, app.state.default_theme_mode);
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
                     }
                 }
-                AppReturn::Continue
             }
-            Action::ToggleCommandPalette => {
-                if app.state.popup_mode.is_none() {
-                    open_command_palette(app);
-                } else {
-                    match app.state.popup_mode.unwrap() {
-                        PopupMode::CommandPalette => {
+            PopupMode::EditThemeStyle => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton)
+                        || app.state.mouse_focus == Some(Focus::StyleEditorFG)
+                        || app.state.mouse_focus == Some(Focus::StyleEditorBG)
+                        || app.state.mouse_focus == Some(Focus::StyleEditorModifier)
+                        || app.state.mouse_focus == Some(Focus::ExtraFocus)
+                    {
+                        handle_create_theme_action(app);
+                    }
+                } else if mouse_scroll_up {
+                    handle_theme_maker_scroll_up(app);
+                } else if mouse_scroll_down {
+                    handle_theme_maker_scroll_down(app);
+                }
+            }
+            PopupMode::SaveThemePrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton)
+                        || app.state.mouse_focus == Some(Focus::ExtraFocus)
+                    {
+                        handle_save_theme_prompt(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::CustomRGBPromptFG => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_custom_rgb_prompt(app, true);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
+            PopupMode::CustomRGBPromptBG => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_custom_rgb_prompt(app, false);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                        app.state.current_user_input = String::new();
+                        app.state.current_cursor_position = None;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::ViewCard => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CloseButton => {
                             app.state.popup_mode = None;
-                            app.state.current_user_input = String::new();
-                            app.state.current_cursor_position = None;
                             app.state.app_status = AppStatus::Initialized;
-                        }
-                        PopupMode::ViewCard => {
                             if app.card_being_edited.is_some() {
                                 app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
-                                app.state.app_status = AppStatus::Initialized;
-                            } else {
-                                open_command_palette(app);
                             }
                         }
-                        PopupMode::ConfirmDiscardCardChanges => {
-                            if app.card_being_edited.is_some() {
-                                warn!(
-                                    "Discarding changes to card '{}'",
-                                    app.card_being_edited.as_ref().unwrap().1.name
-                                );
-                                app.send_warning_toast(
-                                    &format!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    ),
-                                    None,
-                                );
+                        Focus::CardName
+                        | Focus::CardDescription
+                        | Focus::CardTags
+                        | Focus::CardComments
+                        | Focus::CardDueDate => return handle_edit_new_card(app),
+                        Focus::CardPriority => {
+                            if app.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
                             }
-                            app.state.popup_mode = None;
-                            app.card_being_edited = None;
-                            open_command_palette(app);
+                            app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
+                            return AppReturn::Continue;
                         }
-                        _ => {
-                            open_command_palette(app);
+                        Focus::CardStatus => {
+                            if app.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            app.state.popup_mode = Some(PopupMode::CardStatusSelector);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardPoints => {
+                            if app.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            app.state.popup_mode = Some(PopupMode::CardPointsSelector);
+                            return AppReturn::Continue;
                         }
+                        Focus::SubmitButton => return handle_edit_card_submit(app),
+                        _ => {}
                     }
                 }
-                AppReturn::Continue
-            }
-            Action::Undo => {
-                app.undo();
-                AppReturn::Continue
-            }
-            Action::Redo => {
-                app.redo();
-                AppReturn::Continue
-            }
-            Action::ClearAllToasts => {
-                app.state.toasts.clear();
-                info!("Cleared toast messages");
-                AppReturn::Continue
             }
-        }
-    } else {
-        warn!("No action accociated to {}", key);
-        app.send_warning_toast(&format!("No action accociated to {}", key), None);
-        AppReturn::Continue
-    }
-}
-
-pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppReturn {
-    let mut left_button_pressed = false;
-    let mut right_button_pressed = false;
-    let mut middle_button_pressed = false;
-    let mut mouse_scroll_up = false;
-    let mut mouse_scroll_down = false;
-    let mut mouse_scroll_left = false;
-    let mut mouse_scroll_right = false;
-    match mouse_action {
-        Mouse::Move(x, y) => {
-            app.state.current_mouse_coordinates = (x, y);
-        }
-        Mouse::Drag(x, y) => {
-            // TODO: handle mouse drag features
-            debug!("Mouse drag at {}, {}", x, y);
-        }
-        Mouse::LeftPress => left_button_pressed = true,
-        Mouse::RightPress => right_button_pressed = true,
-        Mouse::MiddlePress => middle_button_pressed = true,
-        Mouse::ScrollUp => mouse_scroll_up = true,
-        Mouse::ScrollDown => mouse_scroll_down = true,
-        Mouse::ScrollLeft => mouse_scroll_left = true,
-        Mouse::ScrollRight => mouse_scroll_right = true,
-        Mouse::Unknown => {}
-    }
-    if let Mouse::Move(x, y) = mouse_action {
-        app.state.current_mouse_coordinates = (x, y);
-    }
-    if right_button_pressed {
-        return handle_go_to_previous_ui_mode(app).await;
-    }
-
-    if middle_button_pressed {
-        if app.state.popup_mode.is_none() {
-            open_command_palette(app);
-        } else {
-            match app.state.popup_mode.unwrap() {
-                PopupMode::CommandPalette => {
-                    app.state.popup_mode = None;
-                    app.state.current_user_input = String::new();
-                    app.state.current_cursor_position = None;
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                PopupMode::ViewCard => {
-                    if app.card_being_edited.is_some() {
-                        app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
-                        app.state.app_status = AppStatus::Initialized;
-                    } else {
-                        open_command_palette(app);
-                    }
-                }
-                PopupMode::ConfirmDiscardCardChanges => {
-                    if app.card_being_edited.is_some() {
-                        warn!(
-                            "Discarding changes to card '{}'",
-                            app.card_being_edited.as_ref().unwrap().1.name
-                        );
-                        app.send_warning_toast(
-                            &format!(
-                                "Discarding changes to card '{}'",
-                                app.card_being_edited.as_ref().unwrap().1.name
-                            ),
-                            None,
-                        );
+            PopupMode::CardPrioritySelector => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            if app.card_being_edited.is_some() {
+                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                            }
+                        }
+                        Focus::ChangeCardPriorityPopup => return handle_change_card_priority(app),
+                        _ => {}
                     }
-                    app.state.popup_mode = None;
-                    app.card_being_edited = None;
-                    open_command_palette(app);
-                }
-                _ => {
-                    open_command_palette(app);
                 }
             }
-        }
-        return AppReturn::Continue;
-    }
-
-    if app.state.popup_mode.is_some() {
-        let popup_mode = app.state.popup_mode.unwrap();
-        match popup_mode {
-            PopupMode::CommandPalette => {
+            PopupMode::CardPointsSelector => {
                 if left_button_pressed && app.state.mouse_focus.is_some() {
                     match app.state.mouse_focus.unwrap() {
-                        Focus::CommandPaletteCommand => {
-                            return CommandPaletteWidget::handle_command(app).await;
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            if app.card_being_edited.is_some() {
+                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                            }
                         }
-                        Focus::CommandPaletteCard => {
-                            handle_command_palette_card_selection(app);
+                        Focus::ChangeCardPointsPopup => return handle_change_card_points(app),
+                        _ => {}
+                    }
+                }
+            }
+            PopupMode::ConfirmDiscardCardChanges => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.focus {
+                        Focus::CloseButton => {
                             app.state.popup_mode = None;
-                            app.state.current_user_input = String::new();
-                            app.state.current_cursor_position = None;
                             app.state.app_status = AppStatus::Initialized;
+                            if app.card_being_edited.is_some() {
+                                if app.card_being_edited.is_some() {
+                                    warn!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    );
+                                    app.send_warning_toast(
+                                        &format!(
+                                            "Discarding changes to card '{}'",
+                                            app.card_being_edited.as_ref().unwrap().1.name
+                                        ),
+                                        None,
+                                    );
+                                }
+                                app.state.popup_mode = None;
+                                app.card_being_edited = None;
+                            }
                         }
-                        Focus::CommandPaletteBoard => {
-                            handle_command_palette_board_selection(app);
-                            app.state.popup_mode = None;
-                            app.state.current_user_input = String::new();
-                            app.state.current_cursor_position = None;
+                        Focus::SubmitButton => {
                             app.state.app_status = AppStatus::Initialized;
-                        }
-                        Focus::CloseButton => {
                             app.state.popup_mode = None;
-                            app.state.current_user_input = String::new();
-                            app.state.current_cursor_position = None;
+                            return handle_edit_card_submit(app);
+                        }
+                        Focus::ExtraFocus => {
                             app.state.app_status = AppStatus::Initialized;
+                            if app.card_being_edited.is_some() {
+                                warn!(
+                                    "Discarding changes to card '{}'",
+                                    app.card_being_edited.as_ref().unwrap().1.name
+                                );
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Discarding changes to card '{}'",
+                                        app.card_being_edited.as_ref().unwrap().1.name
+                                    ),
+                                    None,
+                                );
+                            }
+                            app.state.popup_mode = None;
+                            app.card_being_edited = None;
                         }
                         _ => {}
                     }
-                } else if mouse_scroll_up && app.state.mouse_focus.is_some() {
-                    match app.state.mouse_focus.unwrap() {
-                        Focus::CommandPaletteCommand => {
-                            app.command_palette_command_search_prv();
-                        }
-                        Focus::CommandPaletteCard => app.command_palette_card_search_prv(),
-                        Focus::CommandPaletteBoard => app.command_palette_board_search_prv(),
+                }
+            }
+            PopupMode::FilterByTag => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::FilterByTagPopup) {
+                        handle_filter_by_tag(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.filter_tags = None;
+                        app.state.all_available_tags = None;
+                        app.state.filter_by_tag_list_state.select(None);
+                        app.state.popup_mode = None;
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_filter_by_tag(app);
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
+                {
+                    app.filter_by_tag_popup_prv()
+                } else if mouse_scroll_down
+                    && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
+                {
+                    app.filter_by_tag_popup_next()
+                }
+            }
+            PopupMode::ConfirmLoadSave => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.focus {
+                        Focus::SubmitButton => confirm_load_save(app).await,
+                        Focus::CloseButton | Focus::ExtraFocus => cancel_load_save(app),
                         _ => {}
                     }
-                } else if mouse_scroll_down && app.state.mouse_focus.is_some() {
-                    match app.state.mouse_focus.unwrap() {
-                        Focus::CommandPaletteCommand => {
-                            app.command_palette_command_search_next();
-                        }
-                        Focus::CommandPaletteCard => {
-                            app.command_palette_card_search_next();
-                        }
-                        Focus::CommandPaletteBoard => {
-                            app.command_palette_board_search_next();
-                        }
+                }
+            }
+            PopupMode::ConfirmPruneArchivedCards => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.focus {
+                        Focus::SubmitButton => confirm_prune_archived_cards(app),
+                        Focus::CloseButton | Focus::ExtraFocus => cancel_prune_archived_cards(app),
                         _ => {}
                     }
                 }
             }
-            PopupMode::SelectDefaultView => {
+            PopupMode::ConfirmDeleteBoard => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.focus {
+                        Focus::SubmitButton => confirm_delete_board(app),
+                        Focus::CloseButton | Focus::ExtraFocus => cancel_delete_board(app),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    } else {
+        match app.state.ui_mode {
+            UiMode::Zen => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::SelectDefaultView) {
-                        handle_default_view_selection(app);
-                        app.state.popup_mode = None;
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_up(app);
+                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_down(app);
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::ChangeUIMode => {
+            UiMode::TitleBody => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::ChangeUiModePopup) {
-                        handle_change_ui_mode(app);
-                        app.state.popup_mode = None;
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::TitleBody);
+                    } else if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_up(app);
+                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_down(app);
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::CardStatusSelector => {
+            UiMode::BodyHelp => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::ChangeCardStatusPopup) {
-                        return handle_change_card_status(app);
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.state.ui_mode = UiMode::HelpMenu;
+                        app.state.prev_ui_mode = Some(UiMode::BodyHelp);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
+                    }
+                } else if mouse_scroll_up {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_up(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_prv();
+                    }
+                } else if mouse_scroll_down {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_down(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_next();
                     }
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::EditGeneralConfig => {
+            UiMode::BodyLog => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::EditGeneralConfigPopup) {
-                        app.state.app_status = AppStatus::UserInput;
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::BodyLog);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.app_status = AppStatus::Initialized;
-                        app.state.popup_mode = None;
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        if app.state.ui_mode == UiMode::CreateTheme {
-                            handle_create_theme_action(app);
-                        } else {
-                            handle_edit_general_config(app);
-                        }
-                        app.state.app_status = AppStatus::Initialized;
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_up(app);
+                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_down(app);
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::EditSpecificKeyBinding => {
+            UiMode::TitleBodyHelp => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::EditSpecificKeyBindingPopup) {
-                        app.state.app_status = AppStatus::KeyBindMode;
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelp);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.state.ui_mode = UiMode::HelpMenu;
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelp);
+                    } else if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.app_status = AppStatus::Initialized;
-                        app.state.popup_mode = None;
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        app.state.app_status = AppStatus::Initialized;
-                        app.state.popup_mode = None;
-                        handle_edit_specific_keybinding(app);
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
+                    }
+                } else if mouse_scroll_up {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_up(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_prv();
+                    }
+                } else if mouse_scroll_down {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_down(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_next();
                     }
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::ChangeDateFormatPopup => {
+            UiMode::TitleBodyLog => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::ChangeDateFormatPopup) {
-                        handle_change_date_format(app);
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyLog);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyLog);
+                    } else if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_up(app);
+                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_down(app);
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::ChangeTheme => {
+            UiMode::TitleBodyHelpLog => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::ThemeSelector) {
-                        handle_change_theme(app, app.state.default_theme_mode);
-                        app.state.popup_mode = None;
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.state.ui_mode = UiMode::HelpMenu;
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
+                    } else if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
+                    }
+                } else if mouse_scroll_up {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_up(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_prv();
+                    }
+                } else if mouse_scroll_down {
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_down(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_next();
                     }
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::EditThemeStyle => {
+            UiMode::BodyHelpLog => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.app_status = AppStatus::Initialized;
-                        app.state.popup_mode = None;
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton)
-                        || app.state.mouse_focus == Some(Focus::StyleEditorFG)
-                        || app.state.mouse_focus == Some(Focus::StyleEditorBG)
-                        || app.state.mouse_focus == Some(Focus::StyleEditorModifier)
-                        || app.state.mouse_focus == Some(Focus::ExtraFocus)
-                    {
-                        handle_create_theme_action(app);
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        open_hovered_card(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.state.ui_mode = UiMode::HelpMenu;
+                        app.state.prev_ui_mode = Some(UiMode::BodyHelpLog);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::BodyHelpLog);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
                 } else if mouse_scroll_up {
-                    handle_theme_maker_scroll_up(app);
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_up(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_prv();
+                    }
                 } else if mouse_scroll_down {
-                    handle_theme_maker_scroll_down(app);
+                    if app.state.mouse_focus == Some(Focus::Body) {
+                        scroll_down(app);
+                    } else if app.state.mouse_focus == Some(Focus::Help) {
+                        app.help_next();
+                    }
+                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_right(app);
+                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+                    scroll_left(app);
                 }
             }
-            PopupMode::SaveThemePrompt => {
+            UiMode::ConfigMenu => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::SubmitButton)
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
+                    } else if app.state.mouse_focus == Some(Focus::ConfigTable)
+                        || app.state.mouse_focus == Some(Focus::SubmitButton)
                         || app.state.mouse_focus == Some(Focus::ExtraFocus)
                     {
-                        handle_save_theme_prompt(app);
+                        return handle_config_menu_action(app);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        handle_go_to_prv_ui_mode(app);
                     }
+                } else if mouse_scroll_down {
+                    app.config_next();
+                } else if mouse_scroll_up {
+                    app.config_prv();
                 }
             }
-            PopupMode::CustomRGBPromptFG => {
+            UiMode::EditKeybindings => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_custom_rgb_prompt(app, true);
-                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
-                        app.state.app_status = AppStatus::UserInput;
-                        app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                    if app.state.mouse_focus == Some(Focus::Title) {
+                        app.state.ui_mode = UiMode::MainMenu;
+                        if app.state.main_menu_state.selected().is_none() {
+                            app.main_menu_next()
+                        }
+                        app.state.prev_ui_mode = Some(UiMode::EditKeybindings);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
+                        app.state.ui_mode = UiMode::ConfigMenu;
+                    } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
+                        handle_edit_keybindings_action(app);
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        app.state.focus = Focus::SubmitButton;
+                        handle_edit_keybindings_action(app);
                     }
+                } else if mouse_scroll_down
+                    && app.state.mouse_focus == Some(Focus::EditKeybindingsTable)
+                {
+                    app.edit_keybindings_next();
+                } else if mouse_scroll_up
+                    && app.state.mouse_focus == Some(Focus::EditKeybindingsTable)
+                {
+                    app.edit_keybindings_prv();
                 }
             }
-            PopupMode::CustomRGBPromptBG => {
+            UiMode::MainMenu => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_custom_rgb_prompt(app, false);
-                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
-                        app.state.app_status = AppStatus::UserInput;
-                        app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                    if app.state.mouse_focus == Some(Focus::Help) {
+                        app.state.ui_mode = UiMode::HelpMenu;
+                        app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                    } else if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                    } else if app.state.mouse_focus == Some(Focus::MainMenu) {
+                        return handle_main_menu_action(app).await;
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.popup_mode = None;
-                        app.state.app_status = AppStatus::Initialized;
+                        handle_exit(app).await;
+                        return AppReturn::Exit;
                     }
                 }
             }
-            PopupMode::ViewCard => {
-                if left_button_pressed && app.state.mouse_focus.is_some() {
-                    match app.state.mouse_focus.unwrap() {
-                        Focus::CloseButton => {
-                            app.state.popup_mode = None;
-                            app.state.app_status = AppStatus::Initialized;
-                            if app.card_being_edited.is_some() {
-                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
-                            }
-                        }
-                        Focus::CardName
-                        | Focus::CardDescription
-                        | Focus::CardTags
-                        | Focus::CardComments
-                        | Focus::CardDueDate => return handle_edit_new_card(app),
-                        Focus::CardPriority => {
-                            if app.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
-                            return AppReturn::Continue;
-                        }
-                        Focus::CardStatus => {
-                            if app.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            app.state.popup_mode = Some(PopupMode::CardStatusSelector);
-                            return AppReturn::Continue;
-                        }
-                        Focus::SubmitButton => return handle_edit_card_submit(app),
-                        _ => {}
+            UiMode::HelpMenu => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::Log) {
+                        app.state.ui_mode = UiMode::LogsOnly;
+                        app.state.prev_ui_mode = Some(UiMode::HelpMenu);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_prv_ui_mode(app);
                     }
                 }
             }
-            PopupMode::CardPrioritySelector => {
-                if left_button_pressed && app.state.mouse_focus.is_some() {
-                    match app.state.mouse_focus.unwrap() {
-                        Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
-                            if app.card_being_edited.is_some() {
-                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
-                            }
-                        }
-                        Focus::ChangeCardPriorityPopup => return handle_change_card_priority(app),
-                        _ => {}
-                    }
+            UiMode::LogsOnly => {
+                if left_button_pressed && app.state.mouse_focus == Some(Focus::CloseButton) {
+                    handle_go_to_prv_ui_mode(app);
                 }
-            }
-            PopupMode::ConfirmDiscardCardChanges => {
-                if left_button_pressed && app.state.mouse_focus.is_some() {
-                    match app.state.focus {
-                        Focus::CloseButton => {
-                            app.state.popup_mode = None;
-                            app.state.app_status = AppStatus::Initialized;
-                            if app.card_being_edited.is_some() {
-                                if app.card_being_edited.is_some() {
-                                    warn!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    );
-                                    app.send_warning_toast(
-                                        &format!(
-                                            "Discarding changes to card '{}'",
-                                            app.card_being_edited.as_ref().unwrap().1.name
-                                        ),
-                                        None,
-                                    );
-                                }
-                                app.state.popup_mode = None;
-                                app.card_being_edited = None;
-                            }
-                        }
-                        Focus::SubmitButton => {
-                            app.state.app_status = AppStatus::Initialized;
-                            app.state.popup_mode = None;
-                            return handle_edit_card_submit(app);
-                        }
-                        Focus::ExtraFocus => {
-                            app.state.app_status = AppStatus::Initialized;
-                            if app.card_being_edited.is_some() {
-                                warn!(
-                                    "Discarding changes to card '{}'",
-                                    app.card_being_edited.as_ref().unwrap().1.name
-                                );
-                                app.send_warning_toast(
-                                    &format!(
-                                        "Discarding changes to card '{}'",
-                                        app.card_being_edited.as_ref().unwrap().1.name
-                                    ),
-                                    None,
-                                );
-                            }
-                            app.state.popup_mode = None;
-                            app.card_being_edited = None;
-                        }
-                        _ => {}
+            }
+            UiMode::NewBoard => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_prv_ui_mode(app);
+                        app.state.new_board_form = NEW_BOARD_FORM_DEFAULT_STATE
+                            .iter()
+                            .map(|s| s.to_string())
+                            .collect();
+                    } else if app.state.mouse_focus == Some(Focus::NewBoardName)
+                        || app.state.mouse_focus == Some(Focus::NewBoardDescription)
+                    {
+                        app.state.app_status = AppStatus::UserInput
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_new_board_action(app);
+                        app.state.app_status = AppStatus::Initialized;
                     }
                 }
             }
-            PopupMode::FilterByTag => {
+            UiMode::NewCard => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::FilterByTagPopup) {
-                        handle_filter_by_tag(app);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.filter_tags = None;
-                        app.state.all_available_tags = None;
-                        app.state.filter_by_tag_list_state.select(None);
-                        app.state.popup_mode = None;
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_prv_ui_mode(app);
+                        app.state.new_board_form = NEW_CARD_FORM_DEFAULT_STATE
+                            .iter()
+                            .map(|s| s.to_string())
+                            .collect();
+                    } else if app.state.mouse_focus == Some(Focus::CardName)
+                        || app.state.mouse_focus == Some(Focus::CardDescription)
+                        || app.state.mouse_focus == Some(Focus::CardDueDate)
+                    {
+                        app.state.app_status = AppStatus::UserInput
                     } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_filter_by_tag(app);
-                        app.state.popup_mode = None;
+                        handle_new_card_action(app);
+                        app.state.app_status = AppStatus::Initialized;
                     }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
-                {
-                    app.filter_by_tag_popup_prv()
-                } else if mouse_scroll_down
-                    && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
-                {
-                    app.filter_by_tag_popup_next()
                 }
             }
-        }
-    } else {
-        match app.state.ui_mode {
-            UiMode::Zen => {
+            UiMode::LoadSave => {
                 if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_prv_ui_mode(app);
+                    } else if app.state.mouse_focus == Some(Focus::LoadSave)
+                        && app.state.load_save_state.selected().is_some()
+                    {
+                        app.dispatch(IoEvent::LoadPreview).await;
+                    }
+                }
+            }
+            UiMode::CreateTheme => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.theme_being_edited = Theme::default();
+                        handle_go_to_prv_ui_mode(app);
+                    } else if app.state.mouse_focus.is_some() {
+                        match app.state.mouse_focus.unwrap() {
+                            Focus::ThemeEditor | Focus::SubmitButton | Focus::ExtraFocus => {
+                                return handle_create_theme_action(app);
+                            }
+                            _ => {}
+                        }
                     }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_up(app);
-                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_down(app);
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
                 }
             }
-            UiMode::TitleBody => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::TitleBody);
-                    } else if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_up(app);
-                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_down(app);
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        }
+    }
+    AppReturn::Continue
+}
+
+fn handle_config_menu_action(app: &mut App) -> AppReturn {
+    if app.state.focus == Focus::SubmitButton {
+        app.config = AppConfig::default();
+        app.state.focus = Focus::ConfigTable;
+        app.state.config_state.select(Some(0));
+        let write_config_status = write_config(&app.config);
+        if write_config_status.is_err() {
+            error!(
+                "Error writing config file: {}",
+                write_config_status.clone().unwrap_err()
+            );
+            app.send_error_toast(
+                &format!(
+                    "Error writing config file: {}",
+                    write_config_status.unwrap_err()
+                ),
+                None,
+            );
+        } else {
+            warn!("Reset Config and Keybinds to default");
+            app.send_warning_toast("Reset Config and Keybinds to default", None);
+        }
+        app.keybind_list_maker();
+        return AppReturn::Continue;
+    } else if app.state.focus == Focus::ExtraFocus {
+        // make a copy of the keybinds and reset only config and then write the config
+        let keybinds = app.config.keybindings.clone();
+        app.config = AppConfig::default();
+        app.config.keybindings = keybinds;
+        app.state.focus = Focus::ConfigTable;
+        app.state.config_state.select(Some(0));
+        let write_config_status = write_config(&app.config);
+        if write_config_status.is_err() {
+            error!(
+                "Error writing config file: {}",
+                write_config_status.clone().unwrap_err()
+            );
+            app.send_error_toast(
+                &format!(
+                    "Error writing config file: {}",
+                    write_config_status.unwrap_err()
+                ),
+                None,
+            );
+        } else {
+            warn!("Reset Config to default");
+            app.send_warning_toast("Reset Config to default", None);
+        }
+        return AppReturn::Continue;
+    }
+    app.config_item_being_edited = Some(app.state.config_state.selected().unwrap_or(0));
+    // check if the config_item_being_edited index is in the AppConfig list and the value in the list is Edit Keybindings
+    let app_config_list = &app.config.to_list();
+    if app.config_item_being_edited.unwrap_or(0) < app_config_list.len() {
+        let default_config_item = String::from("");
+        let config_item = &app_config_list[app.config_item_being_edited.unwrap_or(0)]
+            .first()
+            .unwrap_or(&default_config_item);
+        if *config_item == "Edit Keybindings" {
+            app.state.ui_mode = UiMode::EditKeybindings;
+            if app.state.edit_keybindings_state.selected().is_none() {
+                app.edit_keybindings_next();
+            }
+        } else if *config_item == "Select Default View" {
+            if app.state.default_view_state.selected().is_none() {
+                app.select_default_view_next();
+            }
+            app.state.popup_mode = Some(PopupMode::SelectDefaultView);
+        } else if *config_item == "Auto Save on Exit" {
+            let save_on_exit = app.config.save_on_exit;
+            app.config.save_on_exit = !save_on_exit;
+            let config_string = format!("{}: {}", "Auto Save on Exit", app.config.save_on_exit);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
+        } else if *config_item == "Auto Load Last Save" {
+            let always_load_last_save = app.config.always_load_last_save;
+            app.config.always_load_last_save = !always_load_last_save;
+            let config_string = format!(
+                "{}: {}",
+                "Auto Load Last Save", app.config.always_load_last_save
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
+        } else if *config_item == "Disable Scrollbars" {
+            let disable_scrollbars = app.config.disable_scrollbars;
+            app.config.disable_scrollbars = !disable_scrollbars;
+            let config_string = format!(
+                "{}: {}",
+                "Disable Scrollbars", app.config.disable_scrollbars
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
+        } else if *config_item == "Enable Mouse Support" {
+            let enable_mouse_support = app.config.enable_mouse_support;
+            app.config.enable_mouse_support = !enable_mouse_support;
+            let config_string = format!(
+                "{}: {}",
+                "Enable Mouse Support", app.config.enable_mouse_support
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+                app.send_warning_toast("Please restart the app to apply the changes", None);
+            }
+        } else if *config_item == "Use Alternate Screen" {
+            let use_alternate_screen = app.config.use_alternate_screen;
+            app.config.use_alternate_screen = !use_alternate_screen;
+            let config_string = format!(
+                "{}: {}",
+                "Use Alternate Screen", app.config.use_alternate_screen
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+                app.send_warning_toast("Please restart the app to apply the changes", None);
             }
-            UiMode::BodyHelp => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.state.ui_mode = UiMode::HelpMenu;
-                        app.state.prev_ui_mode = Some(UiMode::BodyHelp);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_up(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_prv();
-                    }
-                } else if mouse_scroll_down {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_down(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_next();
-                    }
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "ASCII Only Rendering" {
+            let ascii_only = app.config.ascii_only;
+            app.config.ascii_only = !ascii_only;
+            let config_string = format!("{}: {}", "ASCII Only Rendering", app.config.ascii_only);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::BodyLog => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::BodyLog);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_up(app);
-                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_down(app);
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "Wrap Navigation" {
+            let wrap_navigation = app.config.wrap_navigation;
+            app.config.wrap_navigation = !wrap_navigation;
+            let config_string = format!("{}: {}", "Wrap Navigation", app.config.wrap_navigation);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::TitleBodyHelp => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelp);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.state.ui_mode = UiMode::HelpMenu;
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelp);
-                    } else if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_up(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_prv();
-                    }
-                } else if mouse_scroll_down {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_down(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_next();
-                    }
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "Wrap Focus" {
+            let wrap_focus = app.config.wrap_focus;
+            app.config.wrap_focus = !wrap_focus;
+            let config_string = format!("{}: {}", "Wrap Focus", app.config.wrap_focus);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::TitleBodyLog => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyLog);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyLog);
-                    } else if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_up(app);
-                } else if mouse_scroll_down && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_down(app);
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "Focus Follows Mouse" {
+            let focus_follows_mouse = app.config.focus_follows_mouse;
+            app.config.focus_follows_mouse = !focus_follows_mouse;
+            let config_string = format!(
+                "{}: {}",
+                "Focus Follows Mouse", app.config.focus_follows_mouse
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::TitleBodyHelpLog => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.state.ui_mode = UiMode::HelpMenu;
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::TitleBodyHelpLog);
-                    } else if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_up(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_prv();
-                    }
-                } else if mouse_scroll_down {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_down(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_next();
-                    }
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "Relative Due Dates" {
+            let relative_due_dates = app.config.relative_due_dates;
+            app.config.relative_due_dates = !relative_due_dates;
+            app.state.relative_due_dates_override = None;
+            let config_string = format!(
+                "{}: {}",
+                "Relative Due Dates", app.config.relative_due_dates
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::BodyHelpLog => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        app.state.popup_mode = Some(PopupMode::ViewCard);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.state.ui_mode = UiMode::HelpMenu;
-                        app.state.prev_ui_mode = Some(UiMode::BodyHelpLog);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::BodyHelpLog);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                } else if mouse_scroll_up {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_up(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_prv();
-                    }
-                } else if mouse_scroll_down {
-                    if app.state.mouse_focus == Some(Focus::Body) {
-                        scroll_down(app);
-                    } else if app.state.mouse_focus == Some(Focus::Help) {
-                        app.help_next();
-                    }
-                } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_right(app);
-                } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-                    scroll_left(app);
-                }
+        } else if *config_item == "Confirm UI Reset" {
+            let confirm_reset_ui = app.config.confirm_reset_ui;
+            app.config.confirm_reset_ui = !confirm_reset_ui;
+            let config_string = format!("{}: {}", "Confirm UI Reset", app.config.confirm_reset_ui);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::ConfigMenu => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
-                    } else if app.state.mouse_focus == Some(Focus::ConfigTable)
-                        || app.state.mouse_focus == Some(Focus::SubmitButton)
-                        || app.state.mouse_focus == Some(Focus::ExtraFocus)
-                    {
-                        return handle_config_menu_action(app);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_prv_ui_mode(app);
-                    }
-                } else if mouse_scroll_down {
-                    app.config_next();
-                } else if mouse_scroll_up {
-                    app.config_prv();
-                }
+        } else if *config_item == "Remember Last Filter" {
+            let remember_last_filter = app.config.remember_last_filter;
+            app.config.remember_last_filter = !remember_last_filter;
+            let config_string = format!(
+                "{}: {}",
+                "Remember Last Filter", app.config.remember_last_filter
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::EditKeybindings => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Title) {
-                        app.state.ui_mode = UiMode::MainMenu;
-                        if app.state.main_menu_state.selected().is_none() {
-                            app.main_menu_next()
-                        }
-                        app.state.prev_ui_mode = Some(UiMode::EditKeybindings);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.ui_mode = UiMode::ConfigMenu;
-                    } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
-                        handle_edit_keybindings_action(app);
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        app.state.focus = Focus::SubmitButton;
-                        handle_edit_keybindings_action(app);
-                    }
-                } else if mouse_scroll_down
-                    && app.state.mouse_focus == Some(Focus::EditKeybindingsTable)
-                {
-                    app.edit_keybindings_next();
-                } else if mouse_scroll_up
-                    && app.state.mouse_focus == Some(Focus::EditKeybindingsTable)
-                {
-                    app.edit_keybindings_prv();
-                }
+        } else if *config_item == "Remember Boards Hiding Completed Cards" {
+            let remember_boards_hiding_completed_cards =
+                app.config.remember_boards_hiding_completed_cards;
+            app.config.remember_boards_hiding_completed_cards =
+                !remember_boards_hiding_completed_cards;
+            let config_string = format!(
+                "{}: {}",
+                "Remember Boards Hiding Completed Cards",
+                app.config.remember_boards_hiding_completed_cards
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::MainMenu => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Help) {
-                        app.state.ui_mode = UiMode::HelpMenu;
-                        app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                    } else if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                    } else if app.state.mouse_focus == Some(Focus::MainMenu) {
-                        return handle_main_menu_action(app).await;
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_exit(app).await;
-                        return AppReturn::Exit;
-                    }
-                }
+        } else if *config_item == "Show Card IDs" {
+            let show_card_ids = app.config.show_card_ids;
+            app.config.show_card_ids = !show_card_ids;
+            let config_string = format!("{}: {}", "Show Card IDs", app.config.show_card_ids);
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::HelpMenu => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Log) {
-                        app.state.ui_mode = UiMode::LogsOnly;
-                        app.state.prev_ui_mode = Some(UiMode::HelpMenu);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_prv_ui_mode(app);
-                    }
-                }
+        } else if *config_item == "Strikethrough Completed Cards" {
+            let strikethrough_completed_cards = app.config.strikethrough_completed_cards;
+            app.config.strikethrough_completed_cards = !strikethrough_completed_cards;
+            let config_string = format!(
+                "{}: {}",
+                "Strikethrough Completed Cards", app.config.strikethrough_completed_cards
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
             }
-            UiMode::LogsOnly => {
-                if left_button_pressed && app.state.mouse_focus == Some(Focus::CloseButton) {
-                    handle_go_to_prv_ui_mode(app);
-                }
+        } else if *config_item == "Prompt for Save Note" {
+            let prompt_for_save_note = app.config.prompt_for_save_note;
+            app.config.prompt_for_save_note = !prompt_for_save_note;
+            let config_string = format!(
+                "{}: {}",
+                "Prompt for Save Note", app.config.prompt_for_save_note
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
+        } else if *config_item == "Default Theme" {
+            app.state.default_theme_mode = true;
+            app.state.popup_mode = Some(PopupMode::ChangeTheme);
+        } else if *config_item == "Default Date Format" {
+            app.state.popup_mode = Some(PopupMode::ChangeDateFormatPopup);
+        } else {
+            app.state.popup_mode = Some(PopupMode::EditGeneralConfig);
+        }
+    } else {
+        debug!(
+            "Config item being edited {} is not in the AppConfig list",
+            app.config_item_being_edited.unwrap_or(0)
+        );
+    }
+    AppReturn::Continue
+}
+
+async fn handle_main_menu_action(app: &mut App) -> AppReturn {
+    if app.state.main_menu_state.selected().is_some() {
+        let selected_index = app.state.main_menu_state.selected().unwrap();
+        let selected_item = MainMenu::from_index(selected_index);
+        match selected_item {
+            MainMenuItem::Quit => {
+                handle_exit(app).await;
+                return AppReturn::Exit;
             }
-            UiMode::NewBoard => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_prv_ui_mode(app);
-                        app.state.new_board_form = NEW_BOARD_FORM_DEFAULT_STATE
-                            .iter()
-                            .map(|s| s.to_string())
-                            .collect();
-                    } else if app.state.mouse_focus == Some(Focus::NewBoardName)
-                        || app.state.mouse_focus == Some(Focus::NewBoardDescription)
-                    {
-                        app.state.app_status = AppStatus::UserInput
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_new_board_action(app);
-                        app.state.app_status = AppStatus::Initialized;
-                    }
+            MainMenuItem::Config => {
+                app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                app.state.ui_mode = UiMode::ConfigMenu;
+                if app.state.config_state.selected().is_none() {
+                    app.config_next();
                 }
             }
-            UiMode::NewCard => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_prv_ui_mode(app);
-                        app.state.new_board_form = NEW_CARD_FORM_DEFAULT_STATE
-                            .iter()
-                            .map(|s| s.to_string())
-                            .collect();
-                    } else if app.state.mouse_focus == Some(Focus::CardName)
-                        || app.state.mouse_focus == Some(Focus::CardDescription)
-                        || app.state.mouse_focus == Some(Focus::CardDueDate)
-                    {
-                        app.state.app_status = AppStatus::UserInput
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_new_card_action(app);
-                        app.state.app_status = AppStatus::Initialized;
-                    }
-                }
+            MainMenuItem::View => {
+                app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                app.state.ui_mode = app.config.default_view;
             }
-            UiMode::LoadSave => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_prv_ui_mode(app);
-                    } else if app.state.mouse_focus == Some(Focus::LoadSave)
-                        && app.state.load_save_state.selected().is_some()
-                    {
-                        app.dispatch(IoEvent::LoadPreview).await;
-                    }
-                }
+            MainMenuItem::Help => {
+                app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                app.state.ui_mode = UiMode::HelpMenu;
             }
-            UiMode::CreateTheme => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.theme_being_edited = Theme::default();
-                        handle_go_to_prv_ui_mode(app);
-                    } else if app.state.mouse_focus.is_some() {
-                        match app.state.mouse_focus.unwrap() {
-                            Focus::ThemeEditor | Focus::SubmitButton | Focus::ExtraFocus => {
-                                return handle_create_theme_action(app);
-                            }
-                            _ => {}
-                        }
-                    }
-                }
+            MainMenuItem::LoadSave => {
+                app.state.prev_ui_mode = Some(UiMode::MainMenu);
+                app.state.ui_mode = UiMode::LoadSave;
             }
         }
     }
     AppReturn::Continue
 }
 
-fn handle_config_menu_action(app: &mut App) -> AppReturn {
-    if app.state.focus == Focus::SubmitButton {
-        app.config = AppConfig::default();
-        app.state.focus = Focus::ConfigTable;
-        app.state.config_state.select(Some(0));
-        let write_config_status = write_config(&app.config);
+fn handle_default_view_selection(app: &mut App) {
+    let all_ui_modes = UiMode::all();
+    let current_selected_mode = app.state.default_view_state.selected().unwrap_or(0);
+    if current_selected_mode < all_ui_modes.len() {
+        let selected_mode = &all_ui_modes[current_selected_mode];
+        app.config.default_view = UiMode::from_string(selected_mode).unwrap_or(UiMode::MainMenu);
+        app.state.prev_ui_mode = Some(app.config.default_view);
+        let config_string = format!("{}: {}", "Select Default View", selected_mode);
+        let app_config = AppConfig::edit_with_string(&config_string, app);
+        app.config = app_config.clone();
+        let write_config_status = write_config(&app_config);
         if write_config_status.is_err() {
             error!(
                 "Error writing config file: {}",
@@ -4377,211 +7468,559 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
                 None,
             );
         } else {
-            warn!("Reset Config and Keybinds to default");
-            app.send_warning_toast("Reset Config and Keybinds to default", None);
+            app.send_info_toast("Config updated Successfully", None);
+        }
+
+        // reset everything
+        app.state.default_view_state.select(Some(0));
+        if app.state.config_state.selected().is_none() {
+            app.config_next();
+        }
+        if app.state.popup_mode.is_some() {
+            app.state.popup_mode = None;
+        }
+    } else {
+        debug!(
+            "Selected mode {} is not in the list of all UI modes",
+            current_selected_mode
+        );
+    }
+}
+
+fn handle_change_date_format(app: &mut App) {
+    let all_date_formats = DateFormat::get_all_date_formats();
+    let current_selected_format = app.state.date_format_selector_state.selected().unwrap_or(0);
+    if current_selected_format < all_date_formats.len() {
+        let selected_format = &all_date_formats[current_selected_format];
+        app.config.date_format = *selected_format;
+        let config_string = format!(
+            "{}: {}",
+            "Default Date Format",
+            selected_format.to_human_readable_string()
+        );
+        let app_config = AppConfig::edit_with_string(&config_string, app);
+        app.config = app_config.clone();
+        let write_config_status = write_config(&app_config);
+        if write_config_status.is_err() {
+            error!(
+                "Error writing config file: {}",
+                write_config_status.clone().unwrap_err()
+            );
+            app.send_error_toast(
+                &format!(
+                    "Error writing config file: {}",
+                    write_config_status.unwrap_err()
+                ),
+                None,
+            );
+        } else {
+            app.send_info_toast("Config updated Successfully", None);
+        }
+
+        // reset everything
+        app.state.date_format_selector_state.select(Some(0));
+        if app.state.config_state.selected().is_none() {
+            app.config_next();
+        }
+        if app.state.popup_mode.is_some() {
+            app.state.popup_mode = None;
+        }
+    } else {
+        debug!(
+            "Selected format {} is not in the list of all date formats",
+            current_selected_format
+        );
+    }
+}
+
+fn handle_change_ui_mode(app: &mut App) {
+    let current_index = app.state.default_view_state.selected().unwrap_or(0);
+    // UiMode::all() has strings map all of them to UiMode using UiMode::from_string which returns an option<UiMode>
+    let all_ui_modes = UiMode::all()
+        .iter()
+        .filter_map(|s| UiMode::from_string(s))
+        .collect::<Vec<UiMode>>();
+
+    // make sure index is in bounds
+    let current_index = if current_index >= all_ui_modes.len() {
+        all_ui_modes.len() - 1
+    } else {
+        current_index
+    };
+    let selected_ui_mode = all_ui_modes[current_index];
+    app.state.ui_mode = selected_ui_mode;
+}
+
+fn handle_edit_keybindings_action(app: &mut App) {
+    if app.state.edit_keybindings_state.selected().is_some()
+        && app.state.focus != Focus::SubmitButton
+    {
+        app.state.popup_mode = Some(PopupMode::EditSpecificKeyBinding);
+    } else if app.state.focus == Focus::SubmitButton {
+        app.config.keybindings = KeyBindings::default();
+        warn!("Reset keybindings to default");
+        app.send_warning_toast("Reset keybindings to default", None);
+        app.state.focus = Focus::NoFocus;
+        app.state.edit_keybindings_state.select(None);
+        let write_config_status = write_config(&app.config);
+        if let Err(error_message) = write_config_status {
+            error!("Error writing config: {}", error_message);
+            app.send_error_toast(&format!("Error writing config: {}", error_message), None);
+        }
+        app.keybind_list_maker();
+    }
+}
+
+async fn handle_go_to_previous_ui_mode(app: &mut App) -> AppReturn {
+    if app.state.popup_mode.is_some() {
+        match app.state.popup_mode.unwrap() {
+            PopupMode::EditGeneralConfig => {
+                if app.state.ui_mode == UiMode::CreateTheme {
+                    app.state.popup_mode = None;
+                } else {
+                    app.state.ui_mode = UiMode::ConfigMenu;
+                    if app.state.config_state.selected().is_none() {
+                        app.config_next()
+                    }
+                }
+                app.state.current_user_input = String::new();
+                app.state.current_cursor_position = None;
+            }
+            PopupMode::EditBoardDescription
+            | PopupMode::SaveNote
+            | PopupMode::ImportMarkdown
+            | PopupMode::ImportJson
+            | PopupMode::ImportTrello
+            | PopupMode::EditBoardCardLimit
+            | PopupMode::EditBoardDueDate
+            | PopupMode::EditCardProgress
+            | PopupMode::EditBoardAccent
+            | PopupMode::NewCardOnAllBoards => {
+                app.state.current_user_input = String::new();
+                app.state.current_cursor_position = None;
+            }
+            PopupMode::EditSpecificKeyBinding => {
+                app.state.ui_mode = UiMode::EditKeybindings;
+                if app.state.edit_keybindings_state.selected().is_none() {
+                    app.edit_keybindings_next();
+                }
+            }
+            PopupMode::ViewCard => {
+                if app.card_being_edited.is_some() {
+                    warn!(
+                        "Discarding changes to card '{}'",
+                        app.card_being_edited.as_ref().unwrap().1.name
+                    );
+                    app.send_warning_toast(
+                        &format!(
+                            "Discarding changes to card '{}'",
+                            app.card_being_edited.as_ref().unwrap().1.name
+                        ),
+                        None,
+                    );
+                }
+                app.state.popup_mode = None;
+                app.card_being_edited = None;
+            }
+            PopupMode::ConfirmDiscardCardChanges => {
+                if app.card_being_edited.is_some() {
+                    warn!(
+                        "Discarding changes to card '{}'",
+                        app.card_being_edited.as_ref().unwrap().1.name
+                    );
+                    app.send_warning_toast(
+                        &format!(
+                            "Discarding changes to card '{}'",
+                            app.card_being_edited.as_ref().unwrap().1.name
+                        ),
+                        None,
+                    );
+                }
+                app.state.popup_mode = None;
+                app.card_being_edited = None;
+            }
+            PopupMode::FilterByTag => {
+                app.state.filter_tags = None;
+                app.state.all_available_tags = None;
+                app.state.filter_by_tag_list_state.select(None);
+            }
+            PopupMode::ConfirmLoadSave => cancel_load_save(app),
+            PopupMode::ConfirmPruneArchivedCards => cancel_prune_archived_cards(app),
+            PopupMode::ConfirmDeleteBoard => cancel_delete_board(app),
+            _ => {}
         }
-        app.keybind_list_maker();
-        return AppReturn::Continue;
-    } else if app.state.focus == Focus::ExtraFocus {
-        // make a copy of the keybinds and reset only config and then write the config
-        let keybinds = app.config.keybindings.clone();
-        app.config = AppConfig::default();
-        app.config.keybindings = keybinds;
-        app.state.focus = Focus::ConfigTable;
-        app.state.config_state.select(Some(0));
-        let write_config_status = write_config(&app.config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
-            );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
-                None,
-            );
-        } else {
-            warn!("Reset Config to default");
-            app.send_warning_toast("Reset Config to default", None);
+        app.state.popup_mode = None;
+        if app.state.app_status == AppStatus::UserInput {
+            app.state.app_status = AppStatus::Initialized;
         }
         return AppReturn::Continue;
     }
-    app.config_item_being_edited = Some(app.state.config_state.selected().unwrap_or(0));
-    // check if the config_item_being_edited index is in the AppConfig list and the value in the list is Edit Keybindings
-    let app_config_list = &app.config.to_list();
-    if app.config_item_being_edited.unwrap_or(0) < app_config_list.len() {
-        let default_config_item = String::from("");
-        let config_item = &app_config_list[app.config_item_being_edited.unwrap_or(0)]
-            .first()
-            .unwrap_or(&default_config_item);
-        if *config_item == "Edit Keybindings" {
-            app.state.ui_mode = UiMode::EditKeybindings;
-            if app.state.edit_keybindings_state.selected().is_none() {
-                app.edit_keybindings_next();
-            }
-        } else if *config_item == "Select Default View" {
-            if app.state.default_view_state.selected().is_none() {
-                app.select_default_view_next();
-            }
-            app.state.popup_mode = Some(PopupMode::SelectDefaultView);
-        } else if *config_item == "Auto Save on Exit" {
-            let save_on_exit = app.config.save_on_exit;
-            app.config.save_on_exit = !save_on_exit;
-            let config_string = format!("{}: {}", "Auto Save on Exit", app.config.save_on_exit);
-            let app_config = AppConfig::edit_with_string(&config_string, app);
-            app.config = app_config.clone();
-            let write_config_status = write_config(&app_config);
-            if write_config_status.is_err() {
-                error!(
-                    "Error writing config file: {}",
-                    write_config_status.clone().unwrap_err()
-                );
-                app.send_error_toast(
-                    &format!(
-                        "Error writing config file: {}",
-                        write_config_status.unwrap_err()
-                    ),
-                    None,
-                );
+    match app.state.ui_mode {
+        UiMode::ConfigMenu => {
+            if app.state.prev_ui_mode == Some(UiMode::ConfigMenu) {
+                app.state.prev_ui_mode = None;
+                app.state.ui_mode = app.config.default_view;
             } else {
-                app.send_info_toast("Config updated Successfully", None);
+                app.state.ui_mode = *app
+                    .state
+                    .prev_ui_mode
+                    .as_ref()
+                    .unwrap_or(&app.config.default_view);
+                app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
             }
-        } else if *config_item == "Auto Load Last Save" {
-            let always_load_last_save = app.config.always_load_last_save;
-            app.config.always_load_last_save = !always_load_last_save;
-            let config_string = format!(
-                "{}: {}",
-                "Auto Load Last Save", app.config.always_load_last_save
-            );
-            let app_config = AppConfig::edit_with_string(&config_string, app);
-            app.config = app_config.clone();
-            let write_config_status = write_config(&app_config);
-            if write_config_status.is_err() {
-                error!(
-                    "Error writing config file: {}",
-                    write_config_status.clone().unwrap_err()
-                );
-                app.send_error_toast(
-                    &format!(
-                        "Error writing config file: {}",
-                        write_config_status.unwrap_err()
-                    ),
-                    None,
-                );
-            } else {
-                app.send_info_toast("Config updated Successfully", None);
+            AppReturn::Continue
+        }
+        UiMode::MainMenu => {
+            handle_exit(app).await;
+            AppReturn::Exit
+        }
+        UiMode::EditKeybindings => {
+            app.state.ui_mode = UiMode::ConfigMenu;
+            if app.state.config_state.selected().is_none() {
+                app.config_next()
             }
-        } else if *config_item == "Disable Scrollbars" {
-            let disable_scrollbars = app.config.disable_scrollbars;
-            app.config.disable_scrollbars = !disable_scrollbars;
-            let config_string = format!(
-                "{}: {}",
-                "Disable Scrollbars", app.config.disable_scrollbars
-            );
-            let app_config = AppConfig::edit_with_string(&config_string, app);
-            app.config = app_config.clone();
-            let write_config_status = write_config(&app_config);
-            if write_config_status.is_err() {
-                error!(
-                    "Error writing config file: {}",
-                    write_config_status.clone().unwrap_err()
-                );
-                app.send_error_toast(
-                    &format!(
-                        "Error writing config file: {}",
-                        write_config_status.unwrap_err()
-                    ),
-                    None,
-                );
-            } else {
-                app.send_info_toast("Config updated Successfully", None);
+            AppReturn::Continue
+        }
+        _ => {
+            if app.state.ui_mode == UiMode::LoadSave {
+                app.state.load_save_state = ListState::default();
             }
-        } else if *config_item == "Enable Mouse Support" {
-            let enable_mouse_support = app.config.enable_mouse_support;
-            app.config.enable_mouse_support = !enable_mouse_support;
-            let config_string = format!(
-                "{}: {}",
-                "Enable Mouse Support", app.config.enable_mouse_support
-            );
-            let app_config = AppConfig::edit_with_string(&config_string, app);
-            app.config = app_config.clone();
-            let write_config_status = write_config(&app_config);
-            if write_config_status.is_err() {
-                error!(
-                    "Error writing config file: {}",
-                    write_config_status.clone().unwrap_err()
-                );
-                app.send_error_toast(
-                    &format!(
-                        "Error writing config file: {}",
-                        write_config_status.unwrap_err()
-                    ),
-                    None,
-                );
+            // check if previous ui mode is the same as the current ui mode
+            if app.state.prev_ui_mode == Some(app.state.ui_mode) {
+                app.state.ui_mode = UiMode::MainMenu;
+                if app.state.main_menu_state.selected().is_none() {
+                    app.main_menu_next();
+                }
             } else {
-                app.send_info_toast("Config updated Successfully", None);
-                app.send_warning_toast("Please restart the app to apply the changes", None);
+                app.state.ui_mode = *app.state.prev_ui_mode.as_ref().unwrap_or(&UiMode::MainMenu);
+                if app.state.main_menu_state.selected().is_none() {
+                    app.main_menu_next();
+                }
             }
-        } else if *config_item == "Default Theme" {
-            app.state.default_theme_mode = true;
-            app.state.popup_mode = Some(PopupMode::ChangeTheme);
-        } else if *config_item == "Default Date Format" {
-            app.state.popup_mode = Some(PopupMode::ChangeDateFormatPopup);
+            AppReturn::Continue
+        }
+    }
+}
+
+fn handle_change_card_status(app: &mut App) -> AppReturn {
+    let current_index = app.state.card_status_selector_state.selected().unwrap_or(0);
+    let all_statuses = CardStatus::all();
+
+    let current_index = if current_index >= all_statuses.len() {
+        all_statuses.len() - 1
+    } else {
+        current_index
+    };
+    let selected_status = all_statuses[current_index].clone();
+
+    if app.card_being_edited.is_some() {
+        let workflow_stages = app.config.workflow_stages.clone();
+        let card_being_edited = &mut app.card_being_edited.as_mut().unwrap().1;
+        card_being_edited.card_status = selected_status;
+        card_being_edited.sync_workflow_stage(&workflow_stages);
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        return AppReturn::Continue;
+    } else if let Some(current_board_id) = app.state.current_board_id {
+        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+            app.boards.as_mut()
         } else {
-            app.state.popup_mode = Some(PopupMode::EditGeneralConfig);
+            app.filtered_boards.as_mut()
+        };
+        if let Some(current_board) = boards.iter_mut().find(|b| b.id == current_board_id) {
+            if let Some(current_card_id) = app.state.current_card_id {
+                if let Some(current_card) = current_board
+                    .cards
+                    .iter_mut()
+                    .find(|c| c.id == current_card_id)
+                {
+                    let temp_old_card = current_card.clone();
+                    current_card.card_status = selected_status;
+                    current_card.sync_workflow_stage(&app.config.workflow_stages);
+                    app.action_history_manager
+                        .new_action(ActionHistory::EditCard(
+                            temp_old_card,
+                            current_card.clone(),
+                            current_board_id,
+                        ));
+                    app.state.popup_mode = None;
+                    return AppReturn::Continue;
+                }
+            }
+        }
+    }
+    app.send_error_toast("Error Could not find current card", None);
+    AppReturn::Continue
+}
+
+/// Replaces the current config with the latest config export from the save directory,
+/// values that are out of range or not available on this machine are adjusted and reported
+pub fn handle_import_config(app: &mut App) {
+    let (mut imported_config, file_path) = match import_config(&app.config.get_save_directory()) {
+        Ok(import) => import,
+        Err(e) => {
+            error!("Failed to import config: {}", e);
+            app.send_error_toast(&format!("Failed to import config: {}", e), None);
+            return;
         }
+    };
+    let mut adjustments = vec![];
+    if !imported_config.get_save_directory().exists() {
+        adjustments.push(format!(
+            "Save directory {} does not exist, kept {}",
+            imported_config.save_directory.to_string_lossy(),
+            app.config.save_directory.to_string_lossy()
+        ));
+        imported_config.save_directory = app.config.save_directory.clone();
+    }
+    let no_of_cards_to_show = imported_config
+        .no_of_cards_to_show
+        .clamp(MIN_NO_CARDS_PER_BOARD, MAX_NO_CARDS_PER_BOARD);
+    if no_of_cards_to_show != imported_config.no_of_cards_to_show {
+        adjustments.push(format!(
+            "Number of cards to show clamped from {} to {}",
+            imported_config.no_of_cards_to_show, no_of_cards_to_show
+        ));
+        imported_config.no_of_cards_to_show = no_of_cards_to_show;
+    }
+    let no_of_boards_to_show = imported_config
+        .no_of_boards_to_show
+        .clamp(MIN_NO_BOARDS_PER_PAGE, MAX_NO_BOARDS_PER_PAGE);
+    if no_of_boards_to_show != imported_config.no_of_boards_to_show {
+        adjustments.push(format!(
+            "Number of boards to show clamped from {} to {}",
+            imported_config.no_of_boards_to_show, no_of_boards_to_show
+        ));
+        imported_config.no_of_boards_to_show = no_of_boards_to_show;
+    }
+    let tickrate = imported_config.tickrate.clamp(10, 1000);
+    if tickrate != imported_config.tickrate {
+        adjustments.push(format!(
+            "Tickrate clamped from {}ms to {}ms",
+            imported_config.tickrate, tickrate
+        ));
+        imported_config.tickrate = tickrate;
+    }
+    if let Some(theme) = app
+        .all_themes
+        .iter()
+        .find(|theme| theme.name == imported_config.default_theme)
+    {
+        app.theme = theme.clone();
+    } else {
+        adjustments.push(format!(
+            "Theme '{}' is not available, kept '{}'",
+            imported_config.default_theme, app.config.default_theme
+        ));
+        imported_config.default_theme = app.config.default_theme.clone();
+    }
+
+    app.config = imported_config;
+    if let Err(e) = write_config(&app.config) {
+        error!("Error writing config file: {}", e);
+        app.send_error_toast(&format!("Error writing config file: {}", e), None);
+    }
+    app.keybind_list_maker();
+    refresh_visible_boards_and_cards(app);
+    for adjustment in adjustments.iter() {
+        warn!("{}", adjustment);
+        app.send_warning_toast(adjustment, None);
+    }
+    info!("Imported config from {}", file_path);
+    app.send_info_toast(&format!("Imported config from {}", file_path), None);
+}
+
+/// Sets the status of every card on the current board to the selected status as a single
+/// undoable action
+fn handle_change_board_cards_status(app: &mut App) -> AppReturn {
+    let current_index = app.state.card_status_selector_state.selected().unwrap_or(0);
+    let all_statuses = CardStatus::all();
+    let current_index = if current_index >= all_statuses.len() {
+        all_statuses.len() - 1
     } else {
-        debug!(
-            "Config item being edited {} is not in the AppConfig list",
-            app.config_item_being_edited.unwrap_or(0)
+        current_index
+    };
+    let selected_status = all_statuses[current_index].clone();
+    app.state.popup_mode = None;
+
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected", None);
+        return AppReturn::Continue;
+    };
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return AppReturn::Continue;
+        };
+    let board_name = current_board.name.clone();
+    let mut old_cards = Vec::new();
+    let mut new_cards = Vec::new();
+    for card in current_board
+        .cards
+        .iter_mut()
+        .filter(|card| card.card_status != selected_status)
+    {
+        old_cards.push(card.clone());
+        card.card_status = selected_status.clone();
+        card.date_completed = if selected_status == CardStatus::Complete {
+            Utc::now().to_string()
+        } else {
+            "N/A".to_string()
+        };
+        card.date_modified = Utc::now().to_string();
+        card.sync_workflow_stage(&app.config.workflow_stages);
+        new_cards.push(card.clone());
+    }
+
+    if new_cards.is_empty() {
+        app.send_info_toast(
+            &format!(
+                "All cards on board '{}' are already {}",
+                board_name, selected_status
+            ),
+            None,
         );
+        return AppReturn::Continue;
+    }
+    let changed_count = new_cards.len();
+    app.action_history_manager
+        .new_action(ActionHistory::EditBoardCards(
+            old_cards,
+            new_cards,
+            current_board_id,
+        ));
+    if !app.filtered_boards.is_empty() {
+        // keep the filtered view in sync with the changed cards
+        if let Some(filtered_board) = app
+            .filtered_boards
+            .iter_mut()
+            .find(|b| b.id == current_board_id)
+        {
+            for card in filtered_board.cards.iter_mut() {
+                if let Some(updated_card) = app
+                    .boards
+                    .iter()
+                    .find(|b| b.id == current_board_id)
+                    .and_then(|b| b.get_card(card.id))
+                {
+                    *card = updated_card.clone();
+                }
+            }
+        }
     }
+    info!(
+        "Changed status to {} for {} cards on board {}",
+        selected_status, changed_count, board_name
+    );
+    app.send_info_toast(
+        &format!(
+            "Changed status to {} for {} cards on board '{}'",
+            selected_status, changed_count, board_name
+        ),
+        None,
+    );
     AppReturn::Continue
 }
 
-async fn handle_main_menu_action(app: &mut App) -> AppReturn {
-    if app.state.main_menu_state.selected().is_some() {
-        let selected_index = app.state.main_menu_state.selected().unwrap();
-        let selected_item = MainMenu::from_index(selected_index);
-        match selected_item {
-            MainMenuItem::Quit => {
-                handle_exit(app).await;
-                return AppReturn::Exit;
-            }
-            MainMenuItem::Config => {
-                app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                app.state.ui_mode = UiMode::ConfigMenu;
-                if app.state.config_state.selected().is_none() {
-                    app.config_next();
+fn handle_change_card_priority(app: &mut App) -> AppReturn {
+    let current_index = app
+        .state
+        .card_priority_selector_state
+        .selected()
+        .unwrap_or(0);
+    let all_priorities = CardPriority::all();
+
+    let current_index = if current_index >= all_priorities.len() {
+        all_priorities.len() - 1
+    } else {
+        current_index
+    };
+    let selected_priority = all_priorities[current_index].clone();
+
+    if app.card_being_edited.is_some() {
+        app.card_being_edited.as_mut().unwrap().1.priority = selected_priority;
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        return AppReturn::Continue;
+    } else if let Some(current_board_id) = app.state.current_board_id {
+        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+            app.boards.as_mut()
+        } else {
+            app.filtered_boards.as_mut()
+        };
+        if let Some(current_board) = boards.iter_mut().find(|b| b.id == current_board_id) {
+            if let Some(current_card_id) = app.state.current_card_id {
+                if let Some(current_card) = current_board
+                    .cards
+                    .iter_mut()
+                    .find(|c| c.id == current_card_id)
+                {
+                    current_card.priority = selected_priority;
+                    app.state.popup_mode = None;
+                    return AppReturn::Continue;
                 }
             }
-            MainMenuItem::View => {
-                app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                app.state.ui_mode = app.config.default_view;
-            }
-            MainMenuItem::Help => {
-                app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                app.state.ui_mode = UiMode::HelpMenu;
-            }
-            MainMenuItem::LoadSave => {
-                app.state.prev_ui_mode = Some(UiMode::MainMenu);
-                app.state.ui_mode = UiMode::LoadSave;
+        }
+    }
+    app.send_error_toast("Error Could not find current card", None);
+    AppReturn::Continue
+}
+
+fn handle_change_card_points(app: &mut App) -> AppReturn {
+    // index 0 is "Not Set", the rest map onto CARD_POINTS_OPTIONS
+    let current_index = app
+        .state
+        .card_points_selector_state
+        .selected()
+        .unwrap_or(0)
+        .min(CARD_POINTS_OPTIONS.len());
+    let selected_points = if current_index == 0 {
+        None
+    } else {
+        Some(CARD_POINTS_OPTIONS[current_index - 1])
+    };
+
+    if app.card_being_edited.is_some() {
+        app.card_being_edited.as_mut().unwrap().1.points = selected_points;
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        return AppReturn::Continue;
+    } else if let Some(current_board_id) = app.state.current_board_id {
+        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
+            app.boards.as_mut()
+        } else {
+            app.filtered_boards.as_mut()
+        };
+        if let Some(current_board) = boards.iter_mut().find(|b| b.id == current_board_id) {
+            if let Some(current_card_id) = app.state.current_card_id {
+                if let Some(current_card) = current_board
+                    .cards
+                    .iter_mut()
+                    .find(|c| c.id == current_card_id)
+                {
+                    current_card.points = selected_points;
+                    app.state.popup_mode = None;
+                    return AppReturn::Continue;
+                }
             }
         }
     }
+    app.send_error_toast("Error Could not find current card", None);
     AppReturn::Continue
 }
 
-fn handle_default_view_selection(app: &mut App) {
-    let all_ui_modes = UiMode::all();
-    let current_selected_mode = app.state.default_view_state.selected().unwrap_or(0);
-    if current_selected_mode < all_ui_modes.len() {
-        let selected_mode = &all_ui_modes[current_selected_mode];
-        app.config.default_view = UiMode::from_string(selected_mode).unwrap_or(UiMode::MainMenu);
-        app.state.prev_ui_mode = Some(app.config.default_view);
-        let config_string = format!("{}: {}", "Select Default View", selected_mode);
+fn handle_edit_general_config(app: &mut App) {
+    let config_item_index = app.state.config_state.selected().unwrap_or(0);
+    let config_item_list = AppConfig::to_list(&app.config);
+    let config_item = config_item_list[config_item_index].clone();
+    // key is the second item in the list
+    let default_key = String::from("");
+    let config_item_key = config_item.get(0).unwrap_or(&default_key);
+    let new_value = app.state.current_user_input.clone();
+    // if new value is not empty update the config
+    if !new_value.is_empty() {
+        let config_string = format!("{}: {}", config_item_key, new_value);
         let app_config = AppConfig::edit_with_string(&config_string, app);
         app.config = app_config.clone();
         let write_config_status = write_config(&app_config);
@@ -4602,351 +8041,877 @@ fn handle_default_view_selection(app: &mut App) {
         }
 
         // reset everything
-        app.state.default_view_state.select(Some(0));
+        app.state.config_state.select(Some(0));
+        app.config_item_being_edited = None;
+        app.state.current_user_input = String::new();
+        app.state.current_cursor_position = None;
+        app.state.ui_mode = UiMode::ConfigMenu;
         if app.state.config_state.selected().is_none() {
             app.config_next();
         }
-        if app.state.popup_mode.is_some() {
-            app.state.popup_mode = None;
-        }
-    } else {
-        debug!(
-            "Selected mode {} is not in the list of all UI modes",
-            current_selected_mode
-        );
+        refresh_visible_boards_and_cards(app);
     }
+    app.state.config_state.select(Some(0));
 }
 
-fn handle_change_date_format(app: &mut App) {
-    let all_date_formats = DateFormat::get_all_date_formats();
-    let current_selected_format = app.state.date_format_selector_state.selected().unwrap_or(0);
-    if current_selected_format < all_date_formats.len() {
-        let selected_format = &all_date_formats[current_selected_format];
-        app.config.date_format = *selected_format;
-        let config_string = format!(
-            "{}: {}",
-            "Default Date Format",
-            selected_format.to_human_readable_string()
-        );
-        let app_config = AppConfig::edit_with_string(&config_string, app);
-        app.config = app_config.clone();
-        let write_config_status = write_config(&app_config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
-            );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
-                None,
-            );
-        } else {
-            app.send_info_toast("Config updated Successfully", None);
+/// Opens the prompt for an optional note describing the save that is about to be made
+pub fn open_save_note_prompt(app: &mut App) {
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.pending_save_note = None;
+    app.state.popup_mode = Some(PopupMode::SaveNote);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+async fn handle_save_note(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let note = app
+        .state
+        .current_user_input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.pending_save_note = if note.is_empty() { None } else { Some(note) };
+    app.dispatch(IoEvent::SaveLocalData).await;
+}
+
+/// Opens the prompt for the path of a Markdown checklist to import
+pub fn open_import_markdown_prompt(app: &mut App) {
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.popup_mode = Some(PopupMode::ImportMarkdown);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Adds the boards and cards of the Markdown checklist at the path in the user input, each
+/// board is added as its own undoable action
+fn handle_import_markdown(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let raw_path = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    if raw_path.is_empty() {
+        app.send_warning_toast("No file given to import", None);
+        return;
+    }
+    let path = expand_path(&raw_path);
+    let markdown = match std::fs::read_to_string(&path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            error!("Cannot read {:?}: {}", path, e);
+            app.send_error_toast(&format!("Cannot read {}: {}", raw_path, e), None);
+            return;
         }
+    };
+    let default_board_name = path
+        .file_stem()
+        .map(|file_stem| file_stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| String::from("Imported"));
+    let mut import = parse_markdown_checklist(&markdown, &default_board_name);
+    if import.boards.is_empty() {
+        warn!("No checklist items found in {:?}", path);
+        app.send_warning_toast(&format!("No checklist items found in {}", raw_path), None);
+        return;
+    }
+    migrate_cards_to_workflow_stages(&mut import.boards, &app.config.workflow_stages);
+    let card_count = import.card_count();
+    let board_count = import.boards.len();
+    for mut board in import.boards {
+        // board names are kept unique like boards created from the new board form
+        let base_name = board.name.clone();
+        let mut suffix = 1;
+        while app.boards.iter().any(|b| b.name == board.name) {
+            suffix += 1;
+            board.name = format!("{} ({})", base_name, suffix);
+        }
+        app.boards.push(board.clone());
+        app.action_history_manager
+            .new_action(ActionHistory::CreateBoard(board));
+    }
+    refresh_visible_boards_and_cards(app);
+    let summary = format!(
+        "Imported {} boards with {} cards from {}, skipped {} other lines",
+        board_count, card_count, raw_path, import.skipped_lines
+    );
+    info!("{}", summary);
+    app.send_info_toast(&summary, None);
+}
+
+/// Opens the prompt for the path of a JSON export to import
+pub fn open_import_json_prompt(app: &mut App) {
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.popup_mode = Some(PopupMode::ImportJson);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Reads the JSON export at the path in the user input in the IO thread
+async fn handle_import_json(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let raw_path = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    if raw_path.is_empty() {
+        app.send_warning_toast("No file given to import", None);
+        return;
+    }
+    app.dispatch(IoEvent::ImportJson(expand_path(&raw_path)))
+        .await;
+}
+
+/// Opens the prompt for the path of a Trello board export to import
+pub fn open_import_trello_prompt(app: &mut App) {
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.popup_mode = Some(PopupMode::ImportTrello);
+    app.state.app_status = AppStatus::UserInput;
+}
 
-        // reset everything
-        app.state.date_format_selector_state.select(Some(0));
-        if app.state.config_state.selected().is_none() {
-            app.config_next();
+/// Reads the Trello board export at the path in the user input in the IO thread
+async fn handle_import_trello(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let raw_path = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    if raw_path.is_empty() {
+        app.send_warning_toast("No file given to import", None);
+        return;
+    }
+    app.dispatch(IoEvent::ImportTrello(expand_path(&raw_path)))
+        .await;
+}
+
+/// Adds imported boards next to the current ones as a single undoable action, board names are
+/// kept unique, ids that are already used get new ones and cards without a sequential id get one
+/// when sequential ids are on
+pub fn add_imported_boards(app: &mut App, mut boards: Vec<Board>, source: &str) {
+    if boards.is_empty() {
+        warn!("No boards found in {}", source);
+        app.send_warning_toast(&format!("No boards found in {}", source), None);
+        return;
+    }
+    migrate_cards_to_workflow_stages(&mut boards, &app.config.workflow_stages);
+    assign_unused_ids(&mut boards, &app.boards);
+    let sequential_ids = app.config.card_id_scheme == CardIdScheme::Sequential;
+    let mut card_count = 0;
+    let mut created_boards = Vec::new();
+    for mut board in boards {
+        let base_name = board.name.clone();
+        let mut suffix = 1;
+        while app.boards.iter().any(|b| b.name == board.name) {
+            suffix += 1;
+            board.name = format!("{} ({})", base_name, suffix);
         }
-        if app.state.popup_mode.is_some() {
-            app.state.popup_mode = None;
+        if sequential_ids {
+            let mut cards = std::mem::take(&mut board.cards);
+            let mut archived_cards = std::mem::take(&mut board.archived_cards);
+            let unnumbered_cards = cards
+                .iter_mut()
+                .chain(archived_cards.iter_mut().map(|archived| &mut archived.card))
+                .filter(|card| card.display_id.is_empty());
+            for card in unnumbered_cards {
+                card.display_id = board.next_card_display_id();
+            }
+            board.cards = cards;
+            board.archived_cards = archived_cards;
         }
-    } else {
-        debug!(
-            "Selected format {} is not in the list of all date formats",
-            current_selected_format
-        );
+        card_count += board.cards.len();
+        app.boards.push(board.clone());
+        created_boards.push(ActionHistory::CreateBoard(board));
     }
+    let board_count = created_boards.len();
+    app.action_history_manager
+        .new_action(ActionHistory::CompoundOperation(
+            format!("Imported {} boards from {}", board_count, source),
+            created_boards,
+        ));
+    refresh_visible_boards_and_cards(app);
+    let summary = format!(
+        "Imported {} boards with {} cards from {}",
+        board_count, card_count, source
+    );
+    info!("{}", summary);
+    app.send_info_toast(&summary, None);
 }
 
-fn handle_change_ui_mode(app: &mut App) {
-    let current_index = app.state.default_view_state.selected().unwrap_or(0);
-    // UiMode::all() has strings map all of them to UiMode using UiMode::from_string which returns an option<UiMode>
-    let all_ui_modes = UiMode::all()
-        .iter()
-        .filter_map(|s| UiMode::from_string(s))
-        .collect::<Vec<UiMode>>();
+/// Replaces the description of the current board with the user input, an empty input clears
+/// the description
+fn handle_edit_board_description(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let new_description = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
 
-    // make sure index is in bounds
-    let current_index = if current_index >= all_ui_modes.len() {
-        all_ui_modes.len() - 1
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
     } else {
-        current_index
+        app.send_error_toast("No board selected", None);
+        return;
     };
-    let selected_ui_mode = all_ui_modes[current_index];
-    app.state.ui_mode = selected_ui_mode;
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return;
+        };
+    current_board.description = new_description.clone();
+    let board_name = current_board.name.clone();
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        filtered_board.description = new_description.clone();
+    }
+    info!("Updated description of board '{}'", board_name);
+    if new_description.is_empty() {
+        app.send_info_toast(
+            &format!("Cleared description of board '{}'", board_name),
+            None,
+        );
+    } else {
+        app.send_info_toast(
+            &format!("Updated description of board '{}'", board_name),
+            None,
+        );
+    }
 }
 
-fn handle_edit_keybindings_action(app: &mut App) {
-    if app.state.edit_keybindings_state.selected().is_some()
-        && app.state.focus != Focus::SubmitButton
+/// Opens the prompt for the card limit of the current board, filled in with its own limit if it
+/// has one
+pub fn open_edit_board_card_limit_prompt(app: &mut App) {
+    let card_limit = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .and_then(|board| board.card_limit)
+        .map(|card_limit| card_limit.to_string())
+        .unwrap_or_default();
+    app.state.current_cursor_position = Some(card_limit.len());
+    app.state.current_user_input = card_limit;
+    app.state.popup_mode = Some(PopupMode::EditBoardCardLimit);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Sets the card limit of the current board, an empty input goes back to the limit from the
+/// config
+fn handle_edit_board_card_limit(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let user_input = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+
+    let new_card_limit = if user_input.is_empty() {
+        None
+    } else if let Ok(card_limit) = user_input.parse::<u16>() {
+        Some(card_limit)
+    } else {
+        error!("Invalid board card limit: {}", user_input);
+        app.send_error_toast(
+            &format!(
+                "Board card limit must be a number, 0 turns it off, got: {}",
+                user_input
+            ),
+            None,
+        );
+        return;
+    };
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return;
+        };
+    current_board.card_limit = new_card_limit;
+    let board_name = current_board.name.clone();
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
     {
-        app.state.popup_mode = Some(PopupMode::EditSpecificKeyBinding);
-    } else if app.state.focus == Focus::SubmitButton {
-        app.config.keybindings = KeyBindings::default();
-        warn!("Reset keybindings to default");
-        app.send_warning_toast("Reset keybindings to default", None);
-        app.state.focus = Focus::NoFocus;
-        app.state.edit_keybindings_state.select(None);
-        let write_config_status = write_config(&app.config);
-        if let Err(error_message) = write_config_status {
-            error!("Error writing config: {}", error_message);
-            app.send_error_toast(&format!("Error writing config: {}", error_message), None);
-        }
-        app.keybind_list_maker();
+        filtered_board.card_limit = new_card_limit;
+    }
+    info!(
+        "Updated card limit of board '{}' to {:?}",
+        board_name, new_card_limit
+    );
+    match new_card_limit {
+        None => app.send_info_toast(
+            &format!(
+                "Board '{}' now uses the card limit from the config",
+                board_name
+            ),
+            None,
+        ),
+        Some(0) => app.send_info_toast(
+            &format!("Turned off the card limit of board '{}'", board_name),
+            None,
+        ),
+        Some(card_limit) => app.send_info_toast(
+            &format!(
+                "Set the card limit of board '{}' to {}",
+                board_name, card_limit
+            ),
+            None,
+        ),
     }
 }
 
-async fn handle_go_to_previous_ui_mode(app: &mut App) -> AppReturn {
-    if app.state.popup_mode.is_some() {
-        match app.state.popup_mode.unwrap() {
-            PopupMode::EditGeneralConfig => {
-                if app.state.ui_mode == UiMode::CreateTheme {
-                    app.state.popup_mode = None;
-                } else {
-                    app.state.ui_mode = UiMode::ConfigMenu;
-                    if app.state.config_state.selected().is_none() {
-                        app.config_next()
-                    }
-                }
-                app.state.current_user_input = String::new();
-                app.state.current_cursor_position = None;
-            }
-            PopupMode::EditSpecificKeyBinding => {
-                app.state.ui_mode = UiMode::EditKeybindings;
-                if app.state.edit_keybindings_state.selected().is_none() {
-                    app.edit_keybindings_next();
-                }
-            }
-            PopupMode::ViewCard => {
-                if app.card_being_edited.is_some() {
-                    warn!(
-                        "Discarding changes to card '{}'",
-                        app.card_being_edited.as_ref().unwrap().1.name
-                    );
-                    app.send_warning_toast(
-                        &format!(
-                            "Discarding changes to card '{}'",
-                            app.card_being_edited.as_ref().unwrap().1.name
-                        ),
-                        None,
-                    );
-                }
-                app.state.popup_mode = None;
-                app.card_being_edited = None;
-            }
-            PopupMode::ConfirmDiscardCardChanges => {
-                if app.card_being_edited.is_some() {
-                    warn!(
-                        "Discarding changes to card '{}'",
-                        app.card_being_edited.as_ref().unwrap().1.name
-                    );
-                    app.send_warning_toast(
-                        &format!(
-                            "Discarding changes to card '{}'",
-                            app.card_being_edited.as_ref().unwrap().1.name
-                        ),
-                        None,
-                    );
-                }
-                app.state.popup_mode = None;
-                app.card_being_edited = None;
-            }
-            PopupMode::FilterByTag => {
-                app.state.filter_tags = None;
-                app.state.all_available_tags = None;
-                app.state.filter_by_tag_list_state.select(None);
-            }
-            _ => {}
-        }
-        app.state.popup_mode = None;
-        if app.state.app_status == AppStatus::UserInput {
-            app.state.app_status = AppStatus::Initialized;
-        }
-        return AppReturn::Continue;
-    }
-    match app.state.ui_mode {
-        UiMode::ConfigMenu => {
-            if app.state.prev_ui_mode == Some(UiMode::ConfigMenu) {
-                app.state.prev_ui_mode = None;
-                app.state.ui_mode = app.config.default_view;
-            } else {
-                app.state.ui_mode = *app
-                    .state
-                    .prev_ui_mode
-                    .as_ref()
-                    .unwrap_or(&app.config.default_view);
-                app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
-            }
-            AppReturn::Continue
-        }
-        UiMode::MainMenu => {
-            handle_exit(app).await;
-            AppReturn::Exit
-        }
-        UiMode::EditKeybindings => {
-            app.state.ui_mode = UiMode::ConfigMenu;
-            if app.state.config_state.selected().is_none() {
-                app.config_next()
+/// Opens the prompt for the due date of the current board, filled in with its due date if it
+/// has one
+pub fn open_edit_board_due_date_prompt(app: &mut App) {
+    let due_date = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .and_then(|board| board.due_date.clone())
+        .unwrap_or_default();
+    app.state.current_cursor_position = Some(due_date.len());
+    app.state.current_user_input = due_date;
+    app.state.popup_mode = Some(PopupMode::EditBoardDueDate);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Opens the prompt for the progress of the current card, filled in with its progress if it has
+/// one
+pub fn open_edit_card_progress_prompt(app: &mut App) {
+    let progress = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .and_then(|board| {
+            board
+                .cards
+                .iter()
+                .find(|card| Some(card.id) == app.state.current_card_id)
+        })
+        .and_then(|card| card.progress)
+        .map(|progress| progress.to_string())
+        .unwrap_or_default();
+    app.state.current_cursor_position = Some(progress.len());
+    app.state.current_user_input = progress;
+    app.state.popup_mode = Some(PopupMode::EditCardProgress);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Sets the progress of the current card as a percentage, an empty input stops tracking it
+fn handle_edit_card_progress(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let user_input = app
+        .state
+        .current_user_input
+        .trim()
+        .trim_end_matches('%')
+        .to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+
+    let new_progress = if user_input.is_empty() {
+        None
+    } else {
+        match user_input.parse::<u8>() {
+            Ok(progress) if progress <= 100 => Some(progress),
+            _ => {
+                error!("Invalid card progress: {}", user_input);
+                app.send_error_toast(
+                    &format!(
+                        "Card progress must be a number from 0 to 100, got: {}",
+                        user_input
+                    ),
+                    None,
+                );
+                return;
             }
-            AppReturn::Continue
         }
-        _ => {
-            if app.state.ui_mode == UiMode::LoadSave {
-                app.state.load_save_state = ListState::default();
-            }
-            // check if previous ui mode is the same as the current ui mode
-            if app.state.prev_ui_mode == Some(app.state.ui_mode) {
-                app.state.ui_mode = UiMode::MainMenu;
-                if app.state.main_menu_state.selected().is_none() {
-                    app.main_menu_next();
-                }
-            } else {
-                app.state.ui_mode = *app.state.prev_ui_mode.as_ref().unwrap_or(&UiMode::MainMenu);
-                if app.state.main_menu_state.selected().is_none() {
-                    app.main_menu_next();
-                }
+    };
+    let current_card_id = app.state.current_card_id.unwrap_or(0);
+    let board = app
+        .boards
+        .iter_mut()
+        .find(|board| Some(board.id) == app.state.current_board_id);
+    let (board_id, card) = if let Some(board) = board {
+        let board_id = board.id;
+        match board
+            .cards
+            .iter_mut()
+            .find(|card| card.id == current_card_id)
+        {
+            Some(card) => (board_id, card),
+            None => {
+                app.send_error_toast("Error Could not find current card", None);
+                return;
             }
-            AppReturn::Continue
         }
+    } else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    let old_card = card.clone();
+    card.progress = new_progress;
+    card.date_modified = Utc::now().to_string();
+    let new_card = card.clone();
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .iter_mut()
+        .flat_map(|board| board.cards.iter_mut())
+        .find(|card| card.id == current_card_id)
+    {
+        *filtered_card = new_card.clone();
     }
+    let message = match new_progress {
+        Some(progress) => format!("'{}' is {}% done", new_card.name, progress),
+        None => format!("'{}' no longer tracks progress", new_card.name),
+    };
+    info!("{}", message);
+    app.send_info_toast(&message, None);
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(old_card, new_card, board_id));
 }
 
-fn handle_change_card_status(app: &mut App) -> AppReturn {
-    let current_index = app.state.card_status_selector_state.selected().unwrap_or(0);
-    let all_statuses = CardStatus::all();
+/// Sets the due date of the current board, the date is read like a card due date and an empty
+/// input removes it
+fn handle_edit_board_due_date(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let user_input = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
 
-    let current_index = if current_index >= all_statuses.len() {
-        all_statuses.len() - 1
+    let new_due_date = if user_input.is_empty() {
+        None
     } else {
-        current_index
-    };
-    let selected_status = all_statuses[current_index].clone();
-
-    if app.card_being_edited.is_some() {
-        app.card_being_edited.as_mut().unwrap().1.card_status = selected_status;
-        app.state.popup_mode = Some(PopupMode::ViewCard);
-        return AppReturn::Continue;
-    } else if let Some(current_board_id) = app.state.current_board_id {
-        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-            app.boards.as_mut()
-        } else {
-            app.filtered_boards.as_mut()
-        };
-        if let Some(current_board) = boards.iter_mut().find(|b| b.id == current_board_id) {
-            if let Some(current_card_id) = app.state.current_card_id {
-                if let Some(current_card) = current_board
-                    .cards
-                    .iter_mut()
-                    .find(|c| c.id == current_card_id)
-                {
-                    let temp_old_card = current_card.clone();
-                    current_card.card_status = selected_status;
-                    app.action_history_manager
-                        .new_action(ActionHistory::EditCard(
-                            temp_old_card,
-                            current_card.clone(),
-                            current_board_id,
-                        ));
-                    app.state.popup_mode = None;
-                    return AppReturn::Continue;
+        let parsed_due_date =
+            match parse_localized_date(&user_input, app.config.date_locale, app.config.date_format)
+            {
+                Ok(Some(localized_date)) => Ok(localized_date),
+                Ok(None) => date_format_converter(&user_input, app.config.date_format),
+                Err(clarification) => {
+                    warn!("{}", clarification);
+                    app.send_warning_toast(&clarification, Some(Duration::from_secs(10)));
+                    return;
                 }
+            };
+        match parsed_due_date {
+            Ok(parsed_due_date) => Some(parsed_due_date),
+            Err(_) => {
+                let all_date_formats = DateFormat::get_all_date_formats()
+                    .iter()
+                    .map(|x| x.to_human_readable_string())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                warn!("Invalid board due date '{}'", user_input);
+                app.send_warning_toast(
+                    &format!(
+                        "Invalid date format '{}'. Please use any of the following {}",
+                        user_input, all_date_formats
+                    ),
+                    Some(Duration::from_secs(10)),
+                );
+                return;
             }
         }
+    };
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
+        } else {
+            app.send_error_toast("Error Could not find current board", None);
+            return;
+        };
+    current_board.due_date = new_due_date.clone();
+    let board_name = current_board.name.clone();
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        filtered_board.due_date = new_due_date.clone();
+    }
+    info!(
+        "Updated due date of board '{}' to {:?}",
+        board_name, new_due_date
+    );
+    match new_due_date {
+        Some(due_date) => app.send_info_toast(
+            &format!("Board '{}' is now due on {}", board_name, due_date),
+            None,
+        ),
+        None => app.send_info_toast(
+            &format!("Removed the due date of board '{}'", board_name),
+            None,
+        ),
     }
-    app.send_error_toast("Error Could not find current card", None);
-    AppReturn::Continue
 }
 
-fn handle_change_card_priority(app: &mut App) -> AppReturn {
-    let current_index = app
+/// Opens the prompt for the accent color of the current board, filled in with its accent if it
+/// has one
+pub fn open_edit_board_accent_prompt(app: &mut App) {
+    let accent = app
         .state
-        .card_priority_selector_state
-        .selected()
-        .unwrap_or(0);
-    let all_priorities = CardPriority::all();
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .and_then(|board| board.accent.clone())
+        .unwrap_or_default();
+    app.state.current_cursor_position = Some(accent.len());
+    app.state.current_user_input = accent;
+    app.state.popup_mode = Some(PopupMode::EditBoardAccent);
+    app.state.app_status = AppStatus::UserInput;
+}
 
-    let current_index = if current_index >= all_priorities.len() {
-        all_priorities.len() - 1
+/// Sets the accent color of the current board, an empty input goes back to the theme
+fn handle_edit_board_accent(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let user_input = app.state.current_user_input.trim().to_string();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+
+    let new_accent = if user_input.is_empty() {
+        None
     } else {
-        current_index
+        match parse_accent_color(&user_input) {
+            Ok((_, accent)) => Some(accent),
+            Err(e) => {
+                warn!("Invalid board accent '{}': {}", user_input, e);
+                app.send_warning_toast(&e, None);
+                return;
+            }
+        }
     };
-    let selected_priority = all_priorities[current_index].clone();
-
-    if app.card_being_edited.is_some() {
-        app.card_being_edited.as_mut().unwrap().1.priority = selected_priority;
-        app.state.popup_mode = Some(PopupMode::ViewCard);
-        return AppReturn::Continue;
-    } else if let Some(current_board_id) = app.state.current_board_id {
-        let boards: &mut Vec<Board> = if app.filtered_boards.is_empty() {
-            app.boards.as_mut()
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let current_board =
+        if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
+            board
         } else {
-            app.filtered_boards.as_mut()
+            app.send_error_toast("Error Could not find current board", None);
+            return;
         };
-        if let Some(current_board) = boards.iter_mut().find(|b| b.id == current_board_id) {
-            if let Some(current_card_id) = app.state.current_card_id {
-                if let Some(current_card) = current_board
-                    .cards
-                    .iter_mut()
-                    .find(|c| c.id == current_card_id)
-                {
-                    current_card.priority = selected_priority;
-                    app.state.popup_mode = None;
-                    return AppReturn::Continue;
-                }
-            }
+    current_board.accent = new_accent.clone();
+    let board_name = current_board.name.clone();
+    if let Some(filtered_board) = app
+        .filtered_boards
+        .iter_mut()
+        .find(|b| b.id == current_board_id)
+    {
+        filtered_board.accent = new_accent.clone();
+    }
+    info!(
+        "Updated accent of board '{}' to {:?}",
+        board_name, new_accent
+    );
+    match new_accent {
+        Some(accent) => app.send_info_toast(
+            &format!("Board '{}' now uses the accent {}", board_name, accent),
+            None,
+        ),
+        None => app.send_info_toast(
+            &format!("Removed the accent of board '{}'", board_name),
+            None,
+        ),
+    }
+}
+
+/// Opens the prompt for a card that is added to every board
+pub fn open_new_card_on_all_boards_prompt(app: &mut App) {
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+    app.state.popup_mode = Some(PopupMode::NewCardOnAllBoards);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// Adds the card from the user input to every board as one undoable action, words starting with
+/// '#' are taken as tags. Boards that already have a card with the name or are at their card limit
+/// are skipped
+fn handle_new_card_on_all_boards(app: &mut App) {
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    let user_input = app.state.current_user_input.clone();
+    app.state.current_user_input = String::new();
+    app.state.current_cursor_position = None;
+
+    let (tags, name_words): (Vec<&str>, Vec<&str>) = user_input
+        .split_whitespace()
+        .partition(|word| word.starts_with('#') && word.len() > 1);
+    let card_name = name_words.join(" ");
+    if card_name.is_empty() {
+        warn!("New card name is empty");
+        app.send_warning_toast("New card name is empty", None);
+        return;
+    }
+    let tags = tags
+        .iter()
+        .map(|tag| tag.trim_start_matches('#').to_string())
+        .collect::<Vec<String>>();
+
+    let mut created_cards = vec![];
+    let mut boards_with_card = 0;
+    let mut boards_at_limit = 0;
+    for board in app.boards.iter_mut() {
+        if board.cards.iter().any(|card| card.name == card_name) {
+            boards_with_card += 1;
+            continue;
+        }
+        if board
+            .effective_card_limit(app.config.board_card_limit)
+            .map_or(false, |card_limit| board.cards.len() >= card_limit)
+        {
+            boards_at_limit += 1;
+            continue;
+        }
+        let mut new_card = Card::new(
+            card_name.clone(),
+            String::new(),
+            FIELD_NOT_SET.to_string(),
+            CardPriority::Low,
+            tags.clone(),
+            vec![],
+        );
+        new_card.sync_workflow_stage(&app.config.workflow_stages);
+        if app.config.card_id_scheme == CardIdScheme::Sequential {
+            new_card.display_id = board.next_card_display_id();
         }
+        board.cards.push(new_card.clone());
+        created_cards.push((new_card, board.id));
     }
-    app.send_error_toast("Error Could not find current card", None);
-    AppReturn::Continue
+
+    let mut skipped_notes = vec![];
+    if boards_with_card > 0 {
+        skipped_notes.push(format!("{} already had it", boards_with_card));
+    }
+    if boards_at_limit > 0 {
+        skipped_notes.push(format!("{} at their card limit", boards_at_limit));
+    }
+    let skipped_note = if skipped_notes.is_empty() {
+        String::new()
+    } else {
+        format!(", skipped {}", skipped_notes.join(" and "))
+    };
+    if created_cards.is_empty() {
+        warn!("Card '{}' was not added to any board", card_name);
+        app.send_warning_toast(
+            &format!(
+                "Card '{}' was not added to any board{}",
+                card_name, skipped_note
+            ),
+            None,
+        );
+        return;
+    }
+    let created_count = created_cards.len();
+    app.action_history_manager
+        .new_action(ActionHistory::CompoundOperation(
+            format!("Created card '{}' on {} boards", card_name, created_count),
+            created_cards
+                .into_iter()
+                .map(|(card, board_id)| ActionHistory::CreateCard(card, board_id))
+                .collect(),
+        ));
+    refresh_visible_boards_and_cards(app);
+    info!("Added card '{}' to {} boards", card_name, created_count);
+    app.send_info_toast(
+        &format!(
+            "Added card '{}' to {} boards{}",
+            card_name, created_count, skipped_note
+        ),
+        None,
+    );
 }
 
-fn handle_edit_general_config(app: &mut App) {
-    let config_item_index = app.state.config_state.selected().unwrap_or(0);
-    let config_item_list = AppConfig::to_list(&app.config);
-    let config_item = config_item_list[config_item_index].clone();
-    // key is the second item in the list
-    let default_key = String::from("");
-    let config_item_key = config_item.get(0).unwrap_or(&default_key);
-    let new_value = app.state.current_user_input.clone();
-    // if new value is not empty update the config
-    if !new_value.is_empty() {
-        let config_string = format!("{}: {}", config_item_key, new_value);
-        let app_config = AppConfig::edit_with_string(&config_string, app);
-        app.config = app_config.clone();
-        let write_config_status = write_config(&app_config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
+/// Keys in the expanded card overlay jump to editing a field of the card in the card view, the
+/// toggle key, Esc and every other key collapse the overlay
+fn handle_expanded_card_key(app: &mut App, key: Key) {
+    app.state.popup_mode = None;
+    let focus = match key {
+        Key::Char('n') => Focus::CardName,
+        Key::Char('d') => Focus::CardDescription,
+        Key::Char('u') => Focus::CardDueDate,
+        Key::Char('t') => Focus::CardTags,
+        Key::Char('c') => Focus::CardComments,
+        Key::Char('p') => Focus::CardPriority,
+        Key::Char('s') => Focus::CardStatus,
+        _ => return,
+    };
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.focus = focus;
+    handle_edit_new_card(app);
+    match focus {
+        Focus::CardPriority => app.state.popup_mode = Some(PopupMode::CardPrioritySelector),
+        Focus::CardStatus => app.state.popup_mode = Some(PopupMode::CardStatusSelector),
+        _ => {}
+    }
+}
+
+/// Exports the current board to JSON and copies the path of the export to the clipboard, the
+/// path is shown in a toast either way
+pub fn handle_export_board_and_copy_path(app: &mut App) {
+    let board = if let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+    {
+        board.clone()
+    } else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let file_path = match export_kanban_to_json(&[board.clone()]) {
+        Ok(file_path) => file_path,
+        Err(e) => {
+            let msg = format!("Failed to export board '{}': {}", board.name, e);
+            error!("{}", msg);
+            app.send_error_toast(&msg, None);
+            return;
+        }
+    };
+    match copy_to_clipboard(&file_path) {
+        Ok(_) => {
+            let msg = format!(
+                "Exported board '{}' to {}, the path was copied to the clipboard",
+                board.name, file_path
             );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
-                None,
+            info!("{}", msg);
+            app.send_info_toast(&msg, None);
+        }
+        Err(e) => {
+            warn!("Could not copy the export path to the clipboard: {}", e);
+            let msg = format!(
+                "Exported board '{}' to {}, the clipboard is not available",
+                board.name, file_path
             );
-        } else {
-            app.send_info_toast("Config updated Successfully", None);
+            info!("{}", msg);
+            app.send_info_toast(&msg, Some(Duration::from_secs(10)));
         }
+    }
+}
 
-        // reset everything
-        app.state.config_state.select(Some(0));
-        app.config_item_being_edited = None;
-        app.state.current_user_input = String::new();
-        app.state.current_cursor_position = None;
-        app.state.ui_mode = UiMode::ConfigMenu;
-        if app.state.config_state.selected().is_none() {
-            app.config_next();
-        }
-        refresh_visible_boards_and_cards(app);
+/// Lists the boards whose due date has passed in a warning toast, shown once the save is loaded
+pub fn send_overdue_boards_reminder(app: &mut App) {
+    let today = Local::now().date_naive();
+    let overdue_boards = app
+        .boards
+        .iter()
+        .filter(|board| board_days_until_due(board, today).map_or(false, |days| days < 0))
+        .map(|board| format!("'{}'", board.name))
+        .collect::<Vec<String>>();
+    if overdue_boards.is_empty() {
+        return;
     }
-    app.state.config_state.select(Some(0));
+    let reminder = if overdue_boards.len() == 1 {
+        format!("Board {} is past its due date", overdue_boards[0])
+    } else {
+        format!(
+            "{} boards are past their due date: {}",
+            overdue_boards.len(),
+            overdue_boards.join(", ")
+        )
+    };
+    warn!("{}", reminder);
+    app.send_warning_toast(&reminder, Some(Duration::from_secs(10)));
+}
+
+/// Puts the selected keybinding back to its default keys, the others are left as they are.
+/// The default keys are checked against the other keybindings like an edit
+fn handle_reset_selected_keybinding(app: &mut App) {
+    let selected = if let Some(selected) = app.state.edit_keybindings_state.selected() {
+        selected
+    } else {
+        return;
+    };
+    let single_key_count = app.config.keybindings.iter().count();
+    if selected >= single_key_count {
+        handle_reset_selected_key_chord(app, selected - single_key_count);
+        return;
+    }
+    let action = if let Some((action, _)) = app.config.keybindings.iter().nth(selected) {
+        action.to_string()
+    } else {
+        error!("Selected keybind with id {} not found", selected);
+        app.send_error_toast("Selected keybind not found", None);
+        return;
+    };
+    let default_keys = KeyBindings::default_for(&action);
+    if let Err(e) = app.config.edit_keybinding(selected, default_keys.clone()) {
+        app.send_error_toast(&format!("Error resetting keybind: {}", e), None);
+        return;
+    }
+    let write_config_status = write_config(&app.config);
+    if let Err(error_message) = write_config_status {
+        error!("Error writing config: {}", error_message);
+        app.send_error_toast(&format!("Error writing config: {}", error_message), None);
+    }
+    let default_keys = default_keys
+        .iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<String>>()
+        .join(" ");
+    info!("Reset keybind for {} to {}", action, default_keys);
+    app.send_info_toast(
+        &format!("Keybind for {} reset to {}", action, default_keys),
+        None,
+    );
+    app.keybind_list_maker();
+}
+
+fn handle_reset_selected_key_chord(app: &mut App, chord_index: usize) {
+    let action = if let Some(chord) = app.config.keybindings.chords.get(chord_index) {
+        chord.action.clone()
+    } else {
+        error!("Selected chord with id {} not found", chord_index);
+        app.send_error_toast("Selected keybind not found", None);
+        return;
+    };
+    let default_keys = if let Some(default_keys) = KeyBindings::default_chord_for(&action) {
+        default_keys
+    } else {
+        app.send_warning_toast(&format!("There is no default chord for {}", action), None);
+        return;
+    };
+    if let Err(e) = app.config.edit_key_chord(chord_index, default_keys.clone()) {
+        app.send_error_toast(&format!("Error resetting keybind: {}", e), None);
+        return;
+    }
+    let write_config_status = write_config(&app.config);
+    if let Err(error_message) = write_config_status {
+        error!("Error writing config: {}", error_message);
+        app.send_error_toast(&format!("Error writing config: {}", error_message), None);
+    }
+    let default_keys = KeyChord::keys_to_string(&default_keys);
+    info!("Reset chord for {} to {}", action, default_keys);
+    app.send_info_toast(
+        &format!("Chord for {} reset to {}", action, default_keys),
+        None,
+    );
+    app.keybind_list_maker();
 }
 
 fn handle_edit_specific_keybinding(app: &mut App) {
@@ -4977,6 +8942,24 @@ fn handle_edit_specific_keybinding(app: &mut App) {
                     None,
                 );
             }
+        } else if selected - app.config.keybindings.iter().count()
+            < app.config.keybindings.chords.len()
+        {
+            let chord_index = selected - app.config.keybindings.iter().count();
+            let value = app.state.edited_keybinding.clone().unwrap_or(vec![]);
+            if let Err(e) = app.config.edit_key_chord(chord_index, value.clone()) {
+                app.send_error_toast(&format!("Error editing keybind: {}", e), None);
+            } else {
+                let action = app.config.keybindings.chords[chord_index].action.clone();
+                app.send_info_toast(
+                    &format!(
+                        "Chord for {} updated to {}",
+                        action,
+                        KeyChord::keys_to_string(&value)
+                    ),
+                    None,
+                );
+            }
         } else {
             error!("Selected keybind with id {} not found", selected);
             app.send_error_toast("Selected keybind not found", None);
@@ -5047,6 +9030,7 @@ fn handle_new_board_action(app: &mut App) {
     }
     if !app.filtered_boards.is_empty() {
         app.state.filter_tags = None;
+        persist_active_filter(app, None);
         app.send_warning_toast("Filter Reset", None);
     }
 }
@@ -5077,8 +9061,20 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
                 .unwrap_or(&app.config.default_view);
             return AppReturn::Continue;
         }
-        let parsed_due_date =
-            date_format_converter(new_card_due_date.trim(), app.config.date_format);
+        let parsed_due_date = match parse_localized_date(
+            new_card_due_date.trim(),
+            app.config.date_locale,
+            app.config.date_format,
+        ) {
+            Ok(Some(localized_date)) => Ok(localized_date),
+            Ok(None) => date_format_converter(new_card_due_date.trim(), app.config.date_format),
+            Err(clarification) => {
+                // ask the user to clarify instead of guessing, the form is left as is
+                warn!("{}", clarification);
+                app.send_warning_toast(&clarification, Some(Duration::from_secs(10)));
+                return AppReturn::Continue;
+            }
+        };
         if parsed_due_date.is_err() {
             let all_date_formats = DateFormat::get_all_date_formats()
                 .iter()
@@ -5109,15 +9105,34 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
                 vec![],
                 vec![],
             );
+            let mut new_card = new_card;
+            new_card.sync_workflow_stage(&app.config.workflow_stages);
             let current_board = app
                 .boards
                 .iter_mut()
                 .find(|board| board.id == current_board_id);
             if let Some(current_board) = current_board {
+                if app.config.card_id_scheme == CardIdScheme::Sequential {
+                    new_card.display_id = current_board.next_card_display_id();
+                }
                 current_board.cards.push(new_card.clone());
                 app.state.current_card_id = Some(new_card.id);
+                // the limit only warns once, when the new card takes the board over it
+                let limit_warning = current_board
+                    .effective_card_limit(app.config.board_card_limit)
+                    .filter(|card_limit| current_board.cards.len() == card_limit + 1)
+                    .map(|card_limit| {
+                        format!(
+                            "Board '{}' now has more than {} cards, consider splitting it",
+                            current_board.name, card_limit
+                        )
+                    });
                 app.action_history_manager
                     .new_action(ActionHistory::CreateCard(new_card, current_board.id));
+                if let Some(limit_warning) = limit_warning {
+                    warn!("{}", limit_warning);
+                    app.send_warning_toast(&limit_warning, None);
+                }
             } else {
                 debug!("Current board not found");
                 app.send_error_toast("Something went wrong", None);
@@ -5153,6 +9168,7 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
         app.state.filter_tags = None;
         app.state.all_available_tags = None;
         app.state.filter_by_tag_list_state.select(None);
+        persist_active_filter(app, None);
         app.send_warning_toast("Filter Reset", None);
     }
     AppReturn::Continue
@@ -5403,6 +9419,31 @@ fn reset_mouse(app: &mut App) {
     app.state.mouse_focus = None;
 }
 
+/// Applies the next or previous of the default and saved themes for this session only, the
+/// default theme in the config is left unchanged
+fn handle_cycle_theme(app: &mut App, forward: bool) {
+    if app.all_themes.is_empty() {
+        app.send_warning_toast("No themes found", None);
+        return;
+    }
+    let theme_count = app.all_themes.len();
+    let next_index = match app
+        .all_themes
+        .iter()
+        .position(|theme| theme.name == app.theme.name)
+    {
+        Some(index) if forward => (index + 1) % theme_count,
+        Some(index) => (index + theme_count - 1) % theme_count,
+        None => 0,
+    };
+    app.theme = app.all_themes[next_index].clone();
+    debug!("Switched to theme {}", app.theme.name);
+    app.send_info_toast(
+        &format!("Theme: {}", app.theme.name),
+        Some(Duration::from_secs(2)),
+    );
+}
+
 fn handle_change_theme(app: &mut App, default_theme_mode: bool) -> AppReturn {
     if default_theme_mode {
         app.state.default_theme_mode = false;
@@ -5725,7 +9766,10 @@ fn handle_next_focus(app: &mut App) {
         }
         return;
     }
-    let next_focus = app.state.focus.next(&available_targets);
+    let next_focus = app
+        .state
+        .focus
+        .next(&available_targets, app.config.wrap_focus);
     if next_focus != app.state.focus && next_focus != Focus::NoFocus {
         app.state.focus = next_focus;
     }
@@ -5759,7 +9803,10 @@ fn handle_prv_focus(app: &mut App) {
         }
         return;
     }
-    let prv_focus = app.state.focus.prev(&available_targets);
+    let prv_focus = app
+        .state
+        .focus
+        .prev(&available_targets, app.config.wrap_focus);
     if prv_focus != app.state.focus && prv_focus != Focus::NoFocus {
         app.state.focus = prv_focus;
     }
@@ -5781,8 +9828,9 @@ fn handle_save_theme_prompt(app: &mut App) {
         let theme_name = app.state.theme_being_edited.name.clone();
         let save_theme_status = save_theme(app.state.theme_being_edited.clone());
         if save_theme_status.is_err() {
-            debug!("Failed to save theme: {}", save_theme_status.unwrap_err());
-            app.send_error_toast("Failed to save theme", None);
+            let save_theme_error = save_theme_status.unwrap_err();
+            debug!("Failed to save theme: {}", save_theme_error);
+            app.send_error_toast(&format!("Failed to save theme: {}", save_theme_error), None);
             return;
         } else {
             app.send_info_toast(&format!("Saved theme {}", theme_name), None);
@@ -5800,27 +9848,14 @@ fn handle_custom_rgb_prompt(app: &mut App, fg: bool) -> AppReturn {
         app.state.current_cursor_position = None;
         app.state.app_status = AppStatus::UserInput;
     } else if app.state.focus == Focus::SubmitButton {
-        // check if the current_user_input is in the format x,y,z where x,y,z are three digit numbers from 0 to 255. use trim to remove whitespace if any
-        let rgb_values: Vec<&str> = app
-            .state
-            .current_user_input
-            .trim()
-            .split(',')
-            .collect::<Vec<&str>>();
-        let rgb_values = rgb_values.iter().map(|x| x.trim()).collect::<Vec<&str>>();
-        if rgb_values.len() != 3 {
-            app.send_error_toast("Invalid RGB format. Please enter the RGB values in the format x,y,z where x,y,z are three digit numbers from 0 to 255", None);
-            return AppReturn::Continue;
-        }
-        let rgb_values = rgb_values
-            .iter()
-            .map(|x| x.parse::<u8>())
-            .collect::<Result<Vec<u8>, _>>();
-        if rgb_values.is_err() {
-            app.send_error_toast("Invalid RGB format. Please enter the RGB values in the format x,y,z where x,y,z are three digit numbers from 0 to 255", None);
-            return AppReturn::Continue;
-        }
-        let rgb_values = rgb_values.unwrap();
+        // the color can be written as #RRGGBB or as r,g,b
+        let custom_color = match parse_custom_color(&app.state.current_user_input) {
+            Ok(custom_color) => custom_color,
+            Err(e) => {
+                app.send_error_toast(&e, None);
+                return AppReturn::Continue;
+            }
+        };
         let all_color_options = TextColorOptions::to_iter().collect::<Vec<TextColorOptions>>();
         let selected_index = app.state.edit_specific_style_state.0.selected();
         if selected_index.is_none() {
@@ -5847,7 +9882,7 @@ fn handle_custom_rgb_prompt(app: &mut App, fg: bool) -> AppReturn {
         if fg {
             app.state.theme_being_edited = app.state.theme_being_edited.edit_style(
                 theme_style_being_edited,
-                Some(Color::Rgb(rgb_values[0], rgb_values[1], rgb_values[2])),
+                Some(custom_color),
                 None,
                 None,
             );
@@ -5855,7 +9890,7 @@ fn handle_custom_rgb_prompt(app: &mut App, fg: bool) -> AppReturn {
             app.state.theme_being_edited = app.state.theme_being_edited.edit_style(
                 theme_style_being_edited,
                 None,
-                Some(Color::Rgb(rgb_values[0], rgb_values[1], rgb_values[2])),
+                Some(custom_color),
                 None,
             );
         }
@@ -6109,26 +10144,22 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     if app.state.current_card_id.is_none() {
         return AppReturn::Continue;
     }
-    let board = app
-        .boards
-        .iter_mut()
-        .find(|board| board.id == app.state.current_board_id.unwrap());
-    if board.is_none() {
-        return AppReturn::Continue;
-    }
-    let board = board.unwrap();
-    // replace the card with the edited card
-    let card = board
-        .cards
-        .iter_mut()
-        .find(|card| card.id == app.state.current_card_id.unwrap());
-    if card.is_none() {
-        return AppReturn::Continue;
-    }
-    let card = card.unwrap();
     let mut edited_card = app.card_being_edited.as_ref().unwrap().1.clone();
     let card_due_date = edited_card.date_due.clone();
-    let parsed_due_date = date_format_converter(card_due_date.trim(), app.config.date_format);
+    let parsed_due_date = match parse_localized_date(
+        card_due_date.trim(),
+        app.config.date_locale,
+        app.config.date_format,
+    ) {
+        Ok(Some(localized_date)) => Ok(localized_date),
+        Ok(None) => date_format_converter(card_due_date.trim(), app.config.date_format),
+        Err(clarification) => {
+            // ask the user to clarify instead of guessing, the changes are kept
+            warn!("{}", clarification);
+            app.send_warning_toast(&clarification, Some(Duration::from_secs(10)));
+            return AppReturn::Continue;
+        }
+    };
     let parsed_date = match parsed_due_date {
         Ok(date) => {
             if date.is_empty() {
@@ -6145,6 +10176,23 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     };
     edited_card.date_due = parsed_date;
     edited_card.date_modified = Utc::now().to_string();
+    let board = app
+        .boards
+        .iter_mut()
+        .find(|board| board.id == app.state.current_board_id.unwrap());
+    if board.is_none() {
+        return AppReturn::Continue;
+    }
+    let board = board.unwrap();
+    // replace the card with the edited card
+    let card = board
+        .cards
+        .iter_mut()
+        .find(|card| card.id == app.state.current_card_id.unwrap());
+    if card.is_none() {
+        return AppReturn::Continue;
+    }
+    let card = card.unwrap();
     app.action_history_manager
         .new_action(ActionHistory::EditCard(
             card.clone(),
@@ -6263,11 +10311,25 @@ fn filter_boards(app: &mut App) {
                 name: board.name,
                 description: board.description,
                 cards: filtered_cards,
+                archived_cards: board.archived_cards,
+                last_card_number: board.last_card_number,
+                card_limit: board.card_limit,
+                due_date: board.due_date,
+                accent: board.accent,
             });
         }
     }
-    app.filtered_boards = filtered_boards;
+    app.filtered_boards = without_hidden_completed_cards(app, filtered_boards);
     refresh_visible_boards_and_cards(app);
+    persist_active_filter(
+        app,
+        Some(FilterPreset {
+            name: TAG_FILTER_NAME.to_string(),
+            tags: filter_tags,
+            priority: None,
+            overdue: false,
+        }),
+    );
     app.send_info_toast(
         &format!(
             "Filtered by {} tags",
@@ -6279,6 +10341,229 @@ fn filter_boards(app: &mut App) {
     app.state.filter_by_tag_list_state.select(None);
 }
 
+fn handle_filter_preset_selection(app: &mut App) {
+    let selected_index = app.state.filter_preset_list_state.selected();
+    if selected_index.is_none() {
+        return;
+    }
+    let selected_index = selected_index.unwrap();
+    if selected_index >= app.config.filter_presets.len() {
+        debug!("Selected index is out of bounds");
+        return;
+    }
+    let preset = app.config.filter_presets[selected_index].clone();
+    apply_filter_preset(app, &preset);
+}
+
+pub fn apply_filter_preset(app: &mut App, preset: &FilterPreset) {
+    let all_boards = app.boards.clone();
+    app.state.current_board_id = None;
+    app.state.current_card_id = None;
+    let mut filtered_boards = Vec::new();
+    for board in all_boards {
+        let filtered_cards = board
+            .cards
+            .into_iter()
+            .filter(|card| preset.matches(card))
+            .collect::<Vec<Card>>();
+        if !filtered_cards.is_empty() {
+            filtered_boards.push(Board {
+                id: board.id,
+                name: board.name,
+                description: board.description,
+                cards: filtered_cards,
+                archived_cards: board.archived_cards,
+                last_card_number: board.last_card_number,
+                card_limit: board.card_limit,
+                due_date: board.due_date,
+                accent: board.accent,
+            });
+        }
+    }
+    if filtered_boards.is_empty() {
+        app.send_warning_toast(
+            &format!("No cards match filter preset '{}'", preset.name),
+            None,
+        );
+    } else {
+        app.send_info_toast(&format!("Applied filter preset '{}'", preset.name), None);
+    }
+    app.state.filter_tags = if preset.tags.is_empty() {
+        None
+    } else {
+        Some(preset.tags.clone())
+    };
+    app.filtered_boards = without_hidden_completed_cards(app, filtered_boards);
+    refresh_visible_boards_and_cards(app);
+    persist_active_filter(app, Some(preset.clone()));
+    app.state.popup_mode = None;
+    app.state.filter_preset_list_state.select(None);
+}
+
+/// Applies the quick filter, or clears it when it is already the active filter
+pub fn handle_toggle_quick_filter(app: &mut App, quick_filter: QuickFilter) {
+    let preset = quick_filter.preset();
+    if app.state.active_filter.as_ref() == Some(&preset) {
+        clear_filters(app);
+        info!("Cleared quick filter '{}'", quick_filter);
+        app.send_info_toast(&format!("Cleared quick filter '{}'", quick_filter), None);
+    } else {
+        info!("Applying quick filter '{}'", quick_filter);
+        apply_filter_preset(app, &preset);
+    }
+}
+
+/// Drops the active filter and shows every board and card again, completed cards stay hidden if
+/// they were
+pub fn clear_filters(app: &mut App) {
+    app.state.filter_tags = None;
+    app.state.all_available_tags = None;
+    app.state.filter_by_tag_list_state.select(None);
+    app.state.popup_mode = None;
+    app.filtered_boards = vec![];
+    refresh_visible_boards_and_cards(app);
+    persist_active_filter(app, None);
+    if hides_completed_cards(app) {
+        refresh_hidden_completed_cards(app);
+    }
+}
+
+/// True when completed cards are hidden on every board or on some of them
+pub fn hides_completed_cards(app: &App) -> bool {
+    app.state.hide_completed_cards || !app.state.boards_hiding_completed_cards.is_empty()
+}
+
+/// Leaves out the completed cards when they are hidden, the boards are kept so cards can still be
+/// added to them
+fn without_hidden_completed_cards(app: &App, boards: Vec<Board>) -> Vec<Board> {
+    if !hides_completed_cards(app) {
+        return boards;
+    }
+    boards
+        .into_iter()
+        .map(|mut board| {
+            if app.state.hide_completed_cards
+                || app.state.boards_hiding_completed_cards.contains(&board.id)
+            {
+                board
+                    .cards
+                    .retain(|card| card.card_status != CardStatus::Complete);
+            }
+            board
+        })
+        .collect()
+}
+
+/// Rebuilds the shown boards from the active filter, without the completed cards when they are
+/// hidden
+pub fn refresh_hidden_completed_cards(app: &mut App) {
+    let boards = match &app.state.active_filter {
+        Some(filter) => app
+            .boards
+            .iter()
+            .cloned()
+            .map(|mut board| {
+                board.cards.retain(|card| filter.matches(card));
+                board
+            })
+            .filter(|board| !board.cards.is_empty())
+            .collect::<Vec<Board>>(),
+        None if hides_completed_cards(app) => app.boards.clone(),
+        None => vec![],
+    };
+    app.filtered_boards = without_hidden_completed_cards(app, boards);
+    refresh_visible_boards_and_cards(app);
+}
+
+pub fn handle_toggle_hide_completed_cards(app: &mut App) {
+    app.state.hide_completed_cards = !app.state.hide_completed_cards;
+    refresh_hidden_completed_cards(app);
+    if app.state.hide_completed_cards {
+        info!("Hiding completed cards");
+        app.send_info_toast("Hiding completed cards", None);
+    } else {
+        info!("Showing completed cards");
+        app.send_info_toast("Showing completed cards", None);
+    }
+}
+
+/// Hides or shows the completed cards of the current board only, the other boards are left as
+/// they are
+pub fn handle_toggle_board_hide_completed_cards(app: &mut App) {
+    let current_board = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id));
+    let (board_id, board_name) = match current_board {
+        Some(board) => (board.id, board.name.clone()),
+        None => {
+            app.send_error_toast("No board Selected / Available", None);
+            return;
+        }
+    };
+    let hidden_boards = &mut app.state.boards_hiding_completed_cards;
+    let now_hidden = if let Some(index) = hidden_boards.iter().position(|id| *id == board_id) {
+        hidden_boards.remove(index);
+        false
+    } else {
+        hidden_boards.push(board_id);
+        true
+    };
+    let current_card_id = app.state.current_card_id;
+    refresh_hidden_completed_cards(app);
+    // refreshing moves to the first board, stay on the board that was toggled
+    if let Some(card_ids) = app.visible_boards_and_cards.get(&board_id) {
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = current_card_id
+            .filter(|card_id| card_ids.contains(card_id))
+            .or_else(|| card_ids.first().copied());
+    } else {
+        let shown_boards = if app.filtered_boards.is_empty() {
+            &app.boards
+        } else {
+            &app.filtered_boards
+        };
+        let number_of_times_to_go_right = shown_boards
+            .iter()
+            .position(|board| board.id == board_id)
+            .unwrap_or(0);
+        for _ in 0..number_of_times_to_go_right {
+            go_right(app);
+        }
+    }
+    let msg = if now_hidden {
+        format!("Hiding completed cards on board '{}'", board_name)
+    } else {
+        format!("Showing completed cards on board '{}'", board_name)
+    };
+    info!("{}", msg);
+    app.send_info_toast(&msg, None);
+    if app.config.remember_boards_hiding_completed_cards {
+        app.config.boards_hiding_completed_cards = app.state.boards_hiding_completed_cards.clone();
+        if let Err(e) = write_config(&app.config) {
+            error!("Error saving the boards hiding completed cards: {}", e);
+            app.send_error_toast(
+                &format!("Error saving the boards hiding completed cards: {}", e),
+                None,
+            );
+        }
+    }
+}
+
+/// Saves the active filter in the config so it is reapplied on the next launch, `None` clears it
+pub fn persist_active_filter(app: &mut App, filter: Option<FilterPreset>) {
+    app.state.active_filter = filter.clone();
+    if !app.config.remember_last_filter || app.config.last_filter == filter {
+        return;
+    }
+    app.config.last_filter = filter;
+    let write_config_status = write_config(&app.config);
+    if let Err(e) = write_config_status {
+        error!("Error saving the active filter: {}", e);
+        app.send_error_toast(&format!("Error saving the active filter: {}", e), None);
+    }
+}
+
 fn handle_command_palette_card_selection(app: &mut App) {
     reset_mouse(app);
     refresh_visible_boards_and_cards(app);
@@ -6298,6 +10583,63 @@ fn handle_command_palette_card_selection(app: &mut App) {
         return;
     }
     let card_id = all_card_details[card_details_index].1;
+    go_to_card(app, card_id);
+}
+
+/// Copies of the boards that contain selected cards with only the selected cards in them,
+/// so exports keep the board each card belongs to
+pub fn get_boards_with_selected_cards(app: &App) -> Vec<Board> {
+    app.boards
+        .iter()
+        .filter_map(|board| {
+            let cards = board
+                .cards
+                .iter()
+                .filter(|card| app.state.selected_card_ids.contains(&card.id))
+                .cloned()
+                .collect::<Vec<Card>>();
+            if cards.is_empty() {
+                None
+            } else {
+                Some(Board {
+                    id: board.id,
+                    name: board.name.clone(),
+                    description: board.description.clone(),
+                    cards,
+                    archived_cards: Vec::new(),
+                    last_card_number: board.last_card_number,
+                    card_limit: board.card_limit,
+                    due_date: board.due_date.clone(),
+                    accent: board.accent.clone(),
+                })
+            }
+        })
+        .collect()
+}
+
+/// How many cards a single up or down press moves, when the same key is pressed rapidly or held
+/// the step grows every few presses up to the configured navigation acceleration
+fn get_navigation_steps(app: &mut App, action: Action) -> usize {
+    let now = Instant::now();
+    let repeat_window = Duration::from_millis(NAVIGATION_REPEAT_WINDOW);
+    let is_repeat = match app.state.last_navigation {
+        Some((last_action, last_time)) => {
+            last_action == action && now.duration_since(last_time) < repeat_window
+        }
+        None => false,
+    };
+    app.state.navigation_repeat_count = if is_repeat {
+        app.state.navigation_repeat_count + 1
+    } else {
+        0
+    };
+    app.state.last_navigation = Some((action, now));
+    let max_steps = app.config.navigation_acceleration.max(1) as usize;
+    (1 + app.state.navigation_repeat_count / NAVIGATION_PRESSES_PER_STEP).min(max_steps)
+}
+
+/// Moves the selection to the card with the given id by scrolling through the boards
+fn go_to_card(app: &mut App, card_id: u128) {
     let mut number_of_times_to_go_right = 0;
     let mut number_of_times_to_go_down = 0;
     // find the number of times to go right and down to get to the card in app.boards
@@ -6319,6 +10661,106 @@ fn handle_command_palette_card_selection(app: &mut App) {
     app.state.focus = Focus::Body;
 }
 
+/// Follows the card links in the description of the current card, when the links point to
+/// more than one card a chooser is shown instead
+pub fn handle_follow_card_link(app: &mut App) {
+    let current_card = app
+        .boards
+        .iter()
+        .find(|board| Some(board.id) == app.state.current_board_id)
+        .and_then(|board| board.get_card(app.state.current_card_id.unwrap_or(0)))
+        .cloned();
+    let current_card = match current_card {
+        Some(card) => card,
+        None => {
+            app.send_warning_toast("Select a card to follow its links", None);
+            return;
+        }
+    };
+    let links = CardLink::parse(&current_card.description)
+        .into_iter()
+        .filter_map(|(_, link)| link)
+        .collect::<Vec<CardLink>>();
+    if links.is_empty() {
+        app.send_warning_toast(
+            &format!("Card '{}' has no card links", current_card.name),
+            None,
+        );
+        return;
+    }
+    let mut candidates: Vec<(u128, u128)> = Vec::new();
+    for link in links.iter() {
+        for candidate in link.resolve(&app.boards) {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+    match candidates.len() {
+        0 => {
+            app.send_error_toast("None of the card links point to an existing card", None);
+        }
+        1 => {
+            app.state.popup_mode = None;
+            app.filtered_boards = vec![];
+            reset_mouse(app);
+            refresh_visible_boards_and_cards(app);
+            go_to_card(app, candidates[0].1);
+        }
+        _ => {
+            app.state.card_link_candidates = candidates;
+            app.state.card_link_list_state.select(Some(0));
+            app.state.popup_mode = Some(PopupMode::CardLinkSelector);
+        }
+    }
+}
+
+fn handle_card_link_selection(app: &mut App) {
+    let selected_index = app.state.card_link_list_state.selected().unwrap_or(0);
+    if selected_index >= app.state.card_link_candidates.len() {
+        debug!("Selected index is out of bounds");
+        return;
+    }
+    let card_id = app.state.card_link_candidates[selected_index].1;
+    app.state.card_link_candidates = vec![];
+    app.state.card_link_list_state.select(None);
+    app.state.popup_mode = None;
+    app.filtered_boards = vec![];
+    reset_mouse(app);
+    refresh_visible_boards_and_cards(app);
+    go_to_card(app, card_id);
+}
+
+/// Lists the most recently modified cards across all boards, the number is set by "Recent Cards
+/// Count" in the config
+pub fn open_recent_cards(app: &mut App) {
+    let recent_cards = recently_modified_cards(&app.boards, app.config.recent_cards_count as usize);
+    if recent_cards.is_empty() {
+        app.state.popup_mode = None;
+        app.send_warning_toast("There are no cards yet", None);
+        return;
+    }
+    app.state.recent_cards = recent_cards;
+    app.state.recent_cards_list_state.select(Some(0));
+    app.state.popup_mode = Some(PopupMode::RecentCards);
+}
+
+fn handle_recent_card_selection(app: &mut App) {
+    let selected_index = app.state.recent_cards_list_state.selected().unwrap_or(0);
+    if selected_index >= app.state.recent_cards.len() {
+        debug!("Selected index is out of bounds");
+        return;
+    }
+    let card_id = app.state.recent_cards[selected_index].1;
+    app.state.recent_cards = vec![];
+    app.state.recent_cards_list_state.select(None);
+    app.state.popup_mode = None;
+    app.filtered_boards = vec![];
+    reset_mouse(app);
+    refresh_visible_boards_and_cards(app);
+    go_to_card(app, card_id);
+}
+
 fn handle_command_palette_board_selection(app: &mut App) {
     reset_mouse(app);
     refresh_visible_boards_and_cards(app);
@@ -6351,3 +10793,97 @@ fn handle_command_palette_board_selection(app: &mut App) {
     }
     app.state.focus = Focus::Body;
 }
+
+/// Keeps the position before an action in the navigation history when the action jumped away
+/// from it, entries for boards or cards that no longer exist are dropped
+pub fn record_navigation(app: &mut App, previous_position: Option<(u128, Option<u128>)>) {
+    let boards = &app.boards;
+    let position_exists = |(board_id, card_id): &(u128, Option<u128>)| {
+        boards.iter().any(|board| {
+            board.id == *board_id
+                && card_id.map_or(true, |card_id| board.get_card(card_id).is_some())
+        })
+    };
+    app.state.navigation_back.retain(position_exists);
+    app.state.navigation_forward.retain(position_exists);
+    if std::mem::take(&mut app.state.navigated_through_history) {
+        return;
+    }
+    let (previous_position, current_position) = match (previous_position, app.navigation_position())
+    {
+        (Some(previous_position), Some(current_position)) => (previous_position, current_position),
+        _ => return,
+    };
+    if !position_exists(&previous_position)
+        || !is_navigation_jump(&app.boards, previous_position, current_position)
+    {
+        return;
+    }
+    if app.state.navigation_back.last() != Some(&previous_position) {
+        app.state.navigation_back.push(previous_position);
+    }
+    if app.state.navigation_back.len() > MAX_NAVIGATION_HISTORY_ENTRIES {
+        app.state.navigation_back.remove(0);
+    }
+    app.state.navigation_forward.clear();
+}
+
+/// Moves the focus to the previous or next position in the navigation history, the data is not
+/// changed
+fn handle_navigate_history(app: &mut App, back: bool) {
+    let current_position = match app.navigation_position() {
+        Some(current_position) => current_position,
+        None => return,
+    };
+    let history = if back {
+        &mut app.state.navigation_back
+    } else {
+        &mut app.state.navigation_forward
+    };
+    let mut target_position = history.pop();
+    while target_position == Some(current_position) {
+        target_position = history.pop();
+    }
+    let (board_id, card_id) = match target_position {
+        Some(target_position) => target_position,
+        None => {
+            let direction = if back { "back to" } else { "forward to" };
+            app.send_info_toast(&format!("Nothing to go {}", direction), None);
+            return;
+        }
+    };
+    if back {
+        app.state.navigation_forward.push(current_position);
+    } else {
+        app.state.navigation_back.push(current_position);
+    }
+    app.state.navigated_through_history = true;
+    reset_mouse(app);
+    let is_visible = app
+        .visible_boards_and_cards
+        .get(&board_id)
+        .map_or(false, |card_ids| {
+            card_id.map_or(true, |card_id| card_ids.contains(&card_id))
+        });
+    if is_visible {
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = card_id;
+        app.state.focus = Focus::Body;
+        return;
+    }
+    app.filtered_boards = vec![];
+    refresh_visible_boards_and_cards(app);
+    if let Some(card_id) = card_id {
+        go_to_card(app, card_id);
+        return;
+    }
+    let number_of_times_to_go_right = app
+        .boards
+        .iter()
+        .position(|board| board.id == board_id)
+        .unwrap_or(0);
+    for _ in 0..number_of_times_to_go_right {
+        go_right(app);
+    }
+    app.state.focus = Focus::Body;
+}
@@ -1,11 +1,11 @@
 use std::fmt;
 
-use chrono::Utc;
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc};
 use savefile_derive::Savefile;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::constants::FIELD_NOT_SET;
+use crate::constants::{ARCHIVE_DATE_FORMAT, FIELD_NOT_SET};
 
 #[derive(Serialize, Deserialize, Debug, Savefile, Clone, Eq, PartialEq)]
 pub struct Board {
@@ -13,21 +13,202 @@ pub struct Board {
     pub name: String,
     pub description: String,
     pub cards: Vec<Card>,
+    /// Cards archived from this board, save files written before schema version 1001 do not
+    /// have this field and load it as empty
+    #[savefile_versions = "1001.."]
+    #[serde(default)]
+    pub archived_cards: Vec<ArchivedCard>,
+    /// Number of the last card given a sequential id on this board, numbers are never reused
+    #[savefile_versions = "1004.."]
+    #[serde(default)]
+    pub last_card_number: u32,
+    /// Soft cap on the number of cards on this board, overrides the "Board Card Limit" config
+    /// when set and 0 turns the warning off for this board
+    #[savefile_versions = "1006.."]
+    #[serde(default)]
+    pub card_limit: Option<u16>,
+    /// Milestone for the whole board, written in the same date format as card due dates
+    #[savefile_versions = "1007.."]
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Color of the band drawn on the board header, a color name or `#RRGGBB`, the theme is
+    /// used as is when not set
+    #[savefile_versions = "1009.."]
+    #[serde(default)]
+    pub accent: Option<String>,
 }
 
 impl Board {
+    /// Average progress of the cards that track progress, None when no card does
+    pub fn average_progress(&self) -> Option<u8> {
+        let progress = self
+            .cards
+            .iter()
+            .filter_map(|card| card.progress)
+            .map(u32::from)
+            .collect::<Vec<u32>>();
+        if progress.is_empty() {
+            return None;
+        }
+        Some((progress.iter().sum::<u32>() / progress.len() as u32) as u8)
+    }
+
     pub fn new(name: String, description: String) -> Self {
         Self {
             id: get_id(),
             name,
             description,
             cards: Vec::new(),
+            archived_cards: Vec::new(),
+            last_card_number: 0,
+            card_limit: None,
+            due_date: None,
+            accent: None,
         }
     }
 
+    /// Moves the card out of the board into its archive, returns the card if it was found
+    pub fn archive_card(&mut self, card_id: u128, date_archived: NaiveDate) -> Option<Card> {
+        let card_index = self.cards.iter().position(|c| c.id == card_id)?;
+        let card = self.cards.remove(card_index);
+        self.archived_cards.push(ArchivedCard {
+            card: card.clone(),
+            date_archived: date_archived.format(ARCHIVE_DATE_FORMAT).to_string(),
+        });
+        Some(card)
+    }
+
+    /// Up to four letters or digits of the board name in uppercase, used in front of sequential
+    /// card ids
+    pub fn card_id_prefix(&self) -> String {
+        let prefix = self
+            .name
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .take(4)
+            .collect::<String>()
+            .to_uppercase();
+        if prefix.is_empty() {
+            String::from("CARD")
+        } else {
+            prefix
+        }
+    }
+
+    /// Takes the next sequential id on the board like `PROJ-12`
+    pub fn next_card_display_id(&mut self) -> String {
+        self.last_card_number += 1;
+        format!("{}-{}", self.card_id_prefix(), self.last_card_number)
+    }
+
+    /// The card limit that applies to this board, `None` when there is no limit
+    pub fn effective_card_limit(&self, default_limit: u16) -> Option<usize> {
+        match self.card_limit.unwrap_or(default_limit) {
+            0 => None,
+            limit => Some(limit as usize),
+        }
+    }
+
+    /// True when the board has more cards than its limit, the limit only warns and never stops
+    /// cards from being added
+    pub fn is_over_card_limit(&self, default_limit: u16) -> bool {
+        self.effective_card_limit(default_limit)
+            .map_or(false, |limit| self.cards.len() > limit)
+    }
+
     pub fn get_card(&self, id: u128) -> Option<&Card> {
         self.cards.iter().find(|c| c.id == id)
     }
+
+    pub fn total_points(&self) -> u32 {
+        self.cards.iter().filter_map(|c| c.points).sum()
+    }
+
+    pub fn completed_points(&self) -> u32 {
+        self.cards
+            .iter()
+            .filter(|c| c.card_status == CardStatus::Complete)
+            .filter_map(|c| c.points)
+            .sum()
+    }
+
+    /// Share of the cards that are complete, an empty board counts as complete
+    pub fn done_ratio(&self) -> f64 {
+        if self.cards.is_empty() {
+            return 1.0;
+        }
+        let completed_cards = self
+            .cards
+            .iter()
+            .filter(|c| c.card_status == CardStatus::Complete)
+            .count();
+        completed_cards as f64 / self.cards.len() as f64
+    }
+}
+
+/// Card counts and points of a board for the statistics export, the totals of all boards use the
+/// same fields
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BoardStatistics {
+    pub name: String,
+    pub total_cards: usize,
+    pub active_cards: usize,
+    pub completed_cards: usize,
+    pub stale_cards: usize,
+    /// Cards that are not complete and whose due date has passed
+    pub overdue_cards: usize,
+    pub total_points: u32,
+    pub completed_points: u32,
+    /// Share of the cards that are complete, no cards count as complete
+    pub completion_ratio: f64,
+}
+
+impl BoardStatistics {
+    pub fn new(board: &Board, is_overdue: impl Fn(&Card) -> bool) -> Self {
+        let count_status = |status: CardStatus| {
+            board
+                .cards
+                .iter()
+                .filter(|card| card.card_status == status)
+                .count()
+        };
+        Self {
+            name: board.name.clone(),
+            total_cards: board.cards.len(),
+            active_cards: count_status(CardStatus::Active),
+            completed_cards: count_status(CardStatus::Complete),
+            stale_cards: count_status(CardStatus::Stale),
+            overdue_cards: board
+                .cards
+                .iter()
+                .filter(|card| card.card_status != CardStatus::Complete && is_overdue(card))
+                .count(),
+            total_points: board.total_points(),
+            completed_points: board.completed_points(),
+            completion_ratio: board.done_ratio(),
+        }
+    }
+
+    /// Adds up the statistics of every board, the ratio is taken over all their cards
+    pub fn total(name: &str, statistics: &[BoardStatistics]) -> Self {
+        let total_cards = statistics.iter().map(|s| s.total_cards).sum::<usize>();
+        let completed_cards = statistics.iter().map(|s| s.completed_cards).sum::<usize>();
+        Self {
+            name: name.to_string(),
+            total_cards,
+            active_cards: statistics.iter().map(|s| s.active_cards).sum(),
+            completed_cards,
+            stale_cards: statistics.iter().map(|s| s.stale_cards).sum(),
+            overdue_cards: statistics.iter().map(|s| s.overdue_cards).sum(),
+            total_points: statistics.iter().map(|s| s.total_points).sum(),
+            completed_points: statistics.iter().map(|s| s.completed_points).sum(),
+            completion_ratio: if total_cards == 0 {
+                1.0
+            } else {
+                completed_cards as f64 / total_cards as f64
+            },
+        }
+    }
 }
 
 impl Default for Board {
@@ -37,8 +218,230 @@ impl Default for Board {
             name: String::from("Default Board"),
             description: String::from("Default Board Description"),
             cards: vec![Card::default()],
+            archived_cards: Vec::new(),
+            last_card_number: 0,
+            card_limit: None,
+            due_date: None,
+            accent: None,
+        }
+    }
+}
+
+/// A card that was archived, it is kept out of the board cards so it is not shown or counted
+#[derive(Serialize, Deserialize, Debug, Savefile, Clone, PartialEq, Eq)]
+pub struct ArchivedCard {
+    pub card: Card,
+    /// Day the card was archived in the ARCHIVE_DATE_FORMAT format
+    pub date_archived: String,
+}
+
+/// Sorts the boards so the least complete ones come first, boards with the same ratio keep their order
+pub fn sort_boards_by_done_ratio(boards: &mut [Board]) {
+    boards.sort_by(|a, b| a.done_ratio().total_cmp(&b.done_ratio()));
+}
+
+/// Removes the cards that were archived more than `older_than_days` days before `today` and
+/// returns them as (board name, card name), with `dry_run` set nothing is removed so the cards
+/// can be listed before they are deleted. Cards with an unreadable archive date are kept
+pub fn prune_archived_cards(
+    boards: &mut [Board],
+    older_than_days: u16,
+    today: NaiveDate,
+    dry_run: bool,
+) -> Vec<(String, String)> {
+    let is_expired = |archived_card: &ArchivedCard| {
+        NaiveDate::parse_from_str(&archived_card.date_archived, ARCHIVE_DATE_FORMAT)
+            .map(|date_archived| (today - date_archived).num_days() > older_than_days as i64)
+            .unwrap_or(false)
+    };
+    let mut pruned = Vec::new();
+    for board in boards.iter_mut() {
+        for archived_card in board.archived_cards.iter().filter(|c| is_expired(*c)) {
+            pruned.push((board.name.clone(), archived_card.card.name.clone()));
+        }
+        if !dry_run {
+            board.archived_cards.retain(|c| !is_expired(c));
+        }
+    }
+    pruned
+}
+
+/// A stage of a custom workflow that replaces the three statuses, cards in a stage use the status
+/// of the stage for styling and completion
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowStage {
+    pub name: String,
+    pub glyph: String,
+    pub status: CardStatus,
+}
+
+impl WorkflowStage {
+    /// Parses stages written in order as "Backlog = ○ @ Stale; In Progress @ Active; Done = ✓ @ Complete",
+    /// the glyph defaults to the glyph of the status and the status defaults to Active
+    pub fn list_from_string(s: &str) -> Result<Vec<WorkflowStage>, String> {
+        let mut stages: Vec<WorkflowStage> = Vec::new();
+        for stage_str in s.split(';').filter(|s| !s.trim().is_empty()) {
+            let (name_and_glyph, status) = match stage_str.split_once('@') {
+                Some((name_and_glyph, status_str)) => match CardStatus::from_string(status_str) {
+                    Some(status) => (name_and_glyph, status),
+                    None => return Err(format!("Invalid status: {}", status_str.trim())),
+                },
+                None => (stage_str, CardStatus::Active),
+            };
+            let (name, glyph) = match name_and_glyph.split_once('=') {
+                Some((name, glyph)) => (name.trim(), glyph.trim()),
+                None => (name_and_glyph.trim(), ""),
+            };
+            if name.is_empty() {
+                return Err(format!("Missing name in stage: {}", stage_str.trim()));
+            }
+            if stages.iter().any(|stage| stage.name == name) {
+                return Err(format!("Duplicate stage name: {}", name));
+            }
+            let glyph = if glyph.is_empty() {
+                status.glyph().to_string()
+            } else {
+                glyph.to_string()
+            };
+            stages.push(WorkflowStage {
+                name: name.to_string(),
+                glyph,
+                status,
+            });
+        }
+        Ok(stages)
+    }
+}
+
+impl fmt::Display for WorkflowStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {} @ {}", self.name, self.glyph, self.status)
+    }
+}
+
+/// The first stage with the status, if there is none completed cards go to the last stage and
+/// all others to the first one
+pub fn nearest_workflow_stage<'a>(
+    stages: &'a [WorkflowStage],
+    status: &CardStatus,
+) -> Option<&'a WorkflowStage> {
+    stages
+        .iter()
+        .find(|stage| stage.status == *status)
+        .or_else(|| match status {
+            CardStatus::Complete => stages.last(),
+            _ => stages.first(),
+        })
+}
+
+/// Puts every card that is not in one of the stages into the stage closest to its status,
+/// returns the number of cards that were moved
+pub fn migrate_cards_to_workflow_stages(boards: &mut [Board], stages: &[WorkflowStage]) -> usize {
+    let mut migrated_cards = 0;
+    for card in boards.iter_mut().flat_map(|board| board.cards.iter_mut()) {
+        if card.sync_workflow_stage(stages) {
+            migrated_cards += 1;
         }
     }
+    migrated_cards
+}
+
+/// Boards read from a Markdown checklist with `parse_markdown_checklist`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownImport {
+    pub boards: Vec<Board>,
+    /// Lines that were neither `##` headings nor checklist items and were left out
+    pub skipped_lines: usize,
+}
+
+impl MarkdownImport {
+    pub fn card_count(&self) -> usize {
+        self.boards.iter().map(|board| board.cards.len()).sum()
+    }
+}
+
+/// Turns `##` headings into boards and `- [ ]` / `- [x]` items into active and completed cards.
+/// Cards have no checklists so indented items are added to the description of the item above
+/// them, items before the first heading go into a board named `default_board_name` and headings
+/// without any items are left out
+pub fn parse_markdown_checklist(markdown: &str, default_board_name: &str) -> MarkdownImport {
+    let mut import = MarkdownImport::default();
+    let mut current_board: Option<Board> = None;
+    for line in markdown.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(heading) = line.strip_prefix("## ") {
+            if let Some(board) = current_board.take() {
+                if !board.cards.is_empty() {
+                    import.boards.push(board);
+                }
+            }
+            current_board = Some(Board::new(heading.trim().to_string(), String::new()));
+            continue;
+        }
+        let (done, text) = if let Some(item) = parse_checklist_item(line.trim_start()) {
+            item
+        } else {
+            import.skipped_lines += 1;
+            continue;
+        };
+        let board = current_board
+            .get_or_insert_with(|| Board::new(default_board_name.to_string(), String::new()));
+        let is_nested = line.starts_with(' ') || line.starts_with('\t');
+        if let (true, Some(parent_card)) = (is_nested, board.cards.last_mut()) {
+            let sub_item = format!("- [{}] {}", if done { "x" } else { " " }, text);
+            if parent_card.description == FIELD_NOT_SET {
+                parent_card.description = sub_item;
+            } else {
+                parent_card.description.push('\n');
+                parent_card.description.push_str(&sub_item);
+            }
+            continue;
+        }
+        let mut card = Card::new(
+            text.to_string(),
+            String::new(),
+            String::new(),
+            CardPriority::Low,
+            vec![],
+            vec![],
+        );
+        if done {
+            card.card_status = CardStatus::Complete;
+            card.date_completed = Utc::now().to_string();
+        }
+        board.cards.push(card);
+    }
+    if let Some(board) = current_board {
+        if !board.cards.is_empty() {
+            import.boards.push(board);
+        }
+    }
+    import
+}
+
+/// Reads `- [ ] text` or `* [x] text` as (done, text)
+fn parse_checklist_item(line: &str) -> Option<(bool, &str)> {
+    let item = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))?;
+    let (done, text) = if let Some(text) = item.strip_prefix("[ ] ") {
+        (false, text)
+    } else if let Some(text) = item
+        .strip_prefix("[x] ")
+        .or_else(|| item.strip_prefix("[X] "))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some((done, text))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
@@ -62,6 +465,30 @@ impl CardStatus {
     pub fn all() -> Vec<CardStatus> {
         vec![CardStatus::Active, CardStatus::Complete, CardStatus::Stale]
     }
+
+    pub fn from_string(s: &str) -> Option<CardStatus> {
+        CardStatus::all()
+            .into_iter()
+            .find(|status| status.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+
+    /// Symbol shown next to the status so it can be told apart without relying on color
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            CardStatus::Active => "●",
+            CardStatus::Complete => "✓",
+            CardStatus::Stale => "○",
+        }
+    }
+
+    /// Plain ASCII version of the glyph for terminals that cannot show the unicode symbols
+    pub fn ascii_glyph(&self) -> &'static str {
+        match self {
+            CardStatus::Active => "o",
+            CardStatus::Complete => "x",
+            CardStatus::Stale => "zz",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
@@ -81,10 +508,100 @@ impl fmt::Display for CardPriority {
     }
 }
 
+/// How often the due date of a card repeats, the due date moves forward on its own once it has
+/// passed whatever the status of the card is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+pub enum CardRecurrence {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl fmt::Display for CardRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardRecurrence::Daily => write!(f, "Daily"),
+            CardRecurrence::Weekly => write!(f, "Weekly"),
+            CardRecurrence::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
+
+impl CardRecurrence {
+    pub fn all() -> Vec<CardRecurrence> {
+        vec![
+            CardRecurrence::Daily,
+            CardRecurrence::Weekly,
+            CardRecurrence::Monthly,
+        ]
+    }
+
+    /// The date one step after `date`, monthly steps land on `anchor_day` or on the last day of
+    /// months that are shorter, so a card due on the 31st stays at the end of the month
+    pub fn next_date(&self, date: NaiveDateTime, anchor_day: u32) -> NaiveDateTime {
+        match self {
+            CardRecurrence::Daily => date + Duration::days(1),
+            CardRecurrence::Weekly => date + Duration::weeks(1),
+            CardRecurrence::Monthly => date
+                .date()
+                .with_day(1)
+                .and_then(|first_day| first_day.checked_add_months(Months::new(1)))
+                .and_then(|first_day| {
+                    first_day.with_day(anchor_day.clamp(1, days_in_month(first_day)))
+                })
+                .map(|next_date| next_date.and_time(date.time()))
+                .unwrap_or(date + Duration::days(30)),
+        }
+    }
+
+    /// Moves a due date that is before `now` forward until it is not, returns None when it has
+    /// not passed yet. The result only depends on the dates and the anchor day, so checking
+    /// again after a restart never moves a due date twice
+    pub fn advance_past(
+        &self,
+        due_date: NaiveDateTime,
+        now: NaiveDateTime,
+        anchor_day: u32,
+    ) -> Option<NaiveDateTime> {
+        if due_date >= now {
+            return None;
+        }
+        let mut next_due_date = due_date;
+        while next_due_date < now {
+            next_due_date = self.next_date(next_due_date, anchor_day);
+        }
+        Some(next_due_date)
+    }
+}
+
 impl CardPriority {
     pub fn all() -> Vec<CardPriority> {
         vec![CardPriority::Low, CardPriority::Medium, CardPriority::High]
     }
+
+    pub fn from_string(s: &str) -> Option<CardPriority> {
+        CardPriority::all()
+            .into_iter()
+            .find(|priority| priority.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+
+    /// Symbol shown next to the priority so it can be told apart without relying on color
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            CardPriority::Low => "▼",
+            CardPriority::Medium => "◆",
+            CardPriority::High => "▲",
+        }
+    }
+
+    /// Plain ASCII version of the glyph for terminals that cannot show the unicode symbols
+    pub fn ascii_glyph(&self) -> &'static str {
+        match self {
+            CardPriority::Low => "v",
+            CardPriority::Medium => "=",
+            CardPriority::High => "^",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Savefile, Clone, PartialEq, Eq)]
@@ -100,6 +617,35 @@ pub struct Card {
     pub card_status: CardStatus,
     pub tags: Vec<String>,
     pub comments: Vec<String>,
+    /// Story points used for estimation, save files written before the schema
+    /// version was introduced do not have this field and load it as None
+    #[savefile_versions = "1000.."]
+    #[serde(default)]
+    pub points: Option<u32>,
+    /// Name of the workflow stage the card is in, empty when no workflow stages are configured
+    #[savefile_versions = "1002.."]
+    #[serde(default)]
+    pub workflow_stage: String,
+    /// Minutes of work logged on the card with the focus timer
+    #[savefile_versions = "1003.."]
+    #[serde(default)]
+    pub minutes_spent: u32,
+    /// Human readable id given when the card was created, empty when cards use uuids
+    #[savefile_versions = "1004.."]
+    #[serde(default)]
+    pub display_id: String,
+    /// Repeats the due date on the calendar, None for cards that do not repeat
+    #[savefile_versions = "1005.."]
+    #[serde(default)]
+    pub recurrence: Option<CardRecurrence>,
+    /// How far along the card is from 0 to 100, None for cards that do not track progress
+    #[savefile_versions = "1008.."]
+    #[serde(default)]
+    pub progress: Option<u8>,
+    /// Day of the month a monthly recurrence returns to, 0 until the due date first repeats
+    #[savefile_versions = "1010.."]
+    #[serde(default)]
+    pub recurrence_day: u32,
 }
 
 impl Card {
@@ -150,6 +696,81 @@ impl Card {
             card_status: CardStatus::Active,
             tags,
             comments,
+            points: None,
+            workflow_stage: String::new(),
+            minutes_spent: 0,
+            display_id: String::new(),
+            recurrence: None,
+            progress: None,
+            recurrence_day: 0,
+        }
+    }
+
+    /// Day of the month the due date repeats on, the stored day is kept while the due date is on
+    /// it or on the last day of a shorter month, otherwise the due date was changed and its day
+    /// is used
+    pub fn recurrence_anchor_day(&self, due_date: NaiveDate) -> u32 {
+        if self.recurrence_day > 0
+            && due_date.day() == self.recurrence_day.min(days_in_month(due_date))
+        {
+            self.recurrence_day
+        } else {
+            due_date.day()
+        }
+    }
+
+    /// The id shown for the card, the sequential id if it has one or else the start of its uuid
+    pub fn id_label(&self) -> String {
+        if self.display_id.is_empty() {
+            format!("{:032x}", self.id)[..8].to_string()
+        } else {
+            self.display_id.clone()
+        }
+    }
+
+    /// Copy of the card with a new id so it can be added to another board or save without
+    /// colliding with the original, the sequential id is cleared as it belongs to the old board
+    pub fn duplicate(&self) -> Card {
+        Card {
+            id: get_id(),
+            display_id: String::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Moves the card to the workflow stage and takes over the status of the stage
+    pub fn set_workflow_stage(&mut self, stage: &WorkflowStage) {
+        self.workflow_stage = stage.name.clone();
+        self.card_status = stage.status.clone();
+        self.date_completed = if stage.status == CardStatus::Complete {
+            Utc::now().to_string()
+        } else {
+            "N/A".to_string()
+        };
+        self.date_modified = Utc::now().to_string();
+    }
+
+    /// Puts the card in the stage closest to its status when its stage does not exist or no longer
+    /// matches the status, returns true if the card was changed
+    pub fn sync_workflow_stage(&mut self, stages: &[WorkflowStage]) -> bool {
+        if stages
+            .iter()
+            .any(|stage| stage.name == self.workflow_stage && stage.status == self.card_status)
+        {
+            return false;
+        }
+        match nearest_workflow_stage(stages, &self.card_status) {
+            Some(stage) => {
+                self.workflow_stage = stage.name.clone();
+                if stage.status != self.card_status {
+                    if self.card_status == CardStatus::Complete {
+                        self.date_completed = "N/A".to_string();
+                    }
+                    self.card_status = stage.status.clone();
+                }
+                true
+            }
+            None => false,
         }
     }
 }
@@ -168,6 +789,13 @@ impl Default for Card {
             card_status: CardStatus::Active,
             tags: Vec::new(),
             comments: Vec::new(),
+            points: None,
+            workflow_stage: String::new(),
+            minutes_spent: 0,
+            display_id: String::new(),
+            recurrence: None,
+            progress: None,
+            recurrence_day: 0,
         }
     }
 }
@@ -175,3 +803,546 @@ impl Default for Card {
 fn get_id() -> u128 {
     Uuid::new_v4().as_u128()
 }
+
+/// Number of days in the month of the date
+fn days_in_month(date: NaiveDate) -> u32 {
+    date.with_day(1)
+        .and_then(|first_day| first_day.checked_add_months(Months::new(1)))
+        .and_then(|next_month| next_month.pred_opt())
+        .map_or(31, |last_day| last_day.day())
+}
+
+/// Gives boards and cards new ids where their id is already used by the existing boards or by an
+/// earlier board or card in the list, so imported boards can be added next to the existing ones
+pub fn assign_unused_ids(boards: &mut [Board], existing_boards: &[Board]) {
+    let mut used_board_ids = existing_boards.iter().map(|b| b.id).collect::<Vec<u128>>();
+    let mut used_card_ids = existing_boards
+        .iter()
+        .flat_map(|b| {
+            b.cards
+                .iter()
+                .chain(b.archived_cards.iter().map(|archived| &archived.card))
+        })
+        .map(|c| c.id)
+        .collect::<Vec<u128>>();
+    for board in boards.iter_mut() {
+        if used_board_ids.contains(&board.id) {
+            board.id = get_id();
+        }
+        used_board_ids.push(board.id);
+        for card in board.cards.iter_mut().chain(
+            board
+                .archived_cards
+                .iter_mut()
+                .map(|archived| &mut archived.card),
+        ) {
+            if used_card_ids.contains(&card.id) {
+                card.id = get_id();
+            }
+            used_card_ids.push(card.id);
+        }
+    }
+}
+
+/// A reference to another card written in a description as `@<card id>` or `[[Card Name]]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardLink {
+    Id(u128),
+    Name(String),
+}
+
+impl CardLink {
+    /// Splits text into plain and link segments in the order they appear, every segment keeps
+    /// its original text so the segments can be joined back into the input
+    pub fn parse(text: &str) -> Vec<(String, Option<CardLink>)> {
+        let mut segments: Vec<(String, Option<CardLink>)> = Vec::new();
+        let mut plain = String::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let mut found_link = None;
+            if let Some(after_open) = rest.strip_prefix("[[") {
+                if let Some(end) = after_open.find("]]") {
+                    let name = after_open[..end].trim();
+                    if !name.is_empty() {
+                        found_link = Some((end + 4, CardLink::Name(name.to_string())));
+                    }
+                }
+            } else if let Some(after_at) = rest.strip_prefix('@') {
+                // only treat @ as a link at the start of a word so emails are left alone
+                let at_word_start = plain.is_empty() || plain.ends_with(char::is_whitespace);
+                let digits = after_at
+                    .chars()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect::<String>();
+                if at_word_start && !digits.is_empty() {
+                    if let Ok(id) = digits.parse::<u128>() {
+                        found_link = Some((digits.len() + 1, CardLink::Id(id)));
+                    }
+                }
+            }
+            match found_link {
+                Some((length, link)) => {
+                    if !plain.is_empty() {
+                        segments.push((std::mem::take(&mut plain), None));
+                    }
+                    segments.push((rest[..length].to_string(), Some(link)));
+                    rest = &rest[length..];
+                }
+                None => {
+                    let next_char = rest.chars().next().unwrap();
+                    plain.push(next_char);
+                    rest = &rest[next_char.len_utf8()..];
+                }
+            }
+        }
+        if !plain.is_empty() {
+            segments.push((plain, None));
+        }
+        segments
+    }
+
+    /// Finds every card the link could refer to as (board id, card id), names are matched
+    /// without case so more than one card can match
+    pub fn resolve(&self, boards: &[Board]) -> Vec<(u128, u128)> {
+        boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .iter()
+                    .filter(|card| match self {
+                        CardLink::Id(id) => card.id == *id,
+                        CardLink::Name(name) => card.name.to_lowercase() == name.to_lowercase(),
+                    })
+                    .map(move |card| (board.id, card.id))
+            })
+            .collect()
+    }
+}
+
+/// Summary of the differences between two sets of boards, used to warn the user
+/// before the current boards are replaced by a save file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardDiff {
+    pub boards_added: Vec<String>,
+    pub boards_removed: Vec<String>,
+    pub boards_changed: Vec<String>,
+    pub cards_added: Vec<String>,
+    pub cards_removed: Vec<String>,
+    pub cards_changed: Vec<String>,
+}
+
+impl BoardDiff {
+    pub fn is_empty(&self) -> bool {
+        self.boards_added.is_empty()
+            && self.boards_removed.is_empty()
+            && self.boards_changed.is_empty()
+            && self.cards_added.is_empty()
+            && self.cards_removed.is_empty()
+            && self.cards_changed.is_empty()
+    }
+}
+
+/// Compares the boards in `a` (current) with the boards in `b` (incoming) by id.
+/// A board is changed if its name or description differ, a card is changed if any
+/// of its fields differ or it has moved to a different board
+pub fn diff_boards(a: &[Board], b: &[Board]) -> BoardDiff {
+    let mut diff = BoardDiff::default();
+    for old_board in a {
+        match b.iter().find(|board| board.id == old_board.id) {
+            Some(new_board) => {
+                if new_board.name != old_board.name
+                    || new_board.description != old_board.description
+                {
+                    diff.boards_changed.push(new_board.name.clone());
+                }
+            }
+            None => diff.boards_removed.push(old_board.name.clone()),
+        }
+    }
+    for new_board in b {
+        if !a.iter().any(|board| board.id == new_board.id) {
+            diff.boards_added.push(new_board.name.clone());
+        }
+    }
+
+    let old_cards: Vec<(u128, &Card)> = a
+        .iter()
+        .flat_map(|board| board.cards.iter().map(move |card| (board.id, card)))
+        .collect();
+    let new_cards: Vec<(u128, &Card)> = b
+        .iter()
+        .flat_map(|board| board.cards.iter().map(move |card| (board.id, card)))
+        .collect();
+    for (old_board_id, old_card) in &old_cards {
+        match new_cards.iter().find(|(_, card)| card.id == old_card.id) {
+            Some((new_board_id, new_card)) => {
+                if new_board_id != old_board_id || new_card != old_card {
+                    diff.cards_changed.push(new_card.name.clone());
+                }
+            }
+            None => diff.cards_removed.push(old_card.name.clone()),
+        }
+    }
+    for (_, new_card) in &new_cards {
+        if !old_cards.iter().any(|(_, card)| card.id == new_card.id) {
+            diff.cards_added.push(new_card.name.clone());
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_with_cards(name: &str, cards: Vec<Card>) -> Board {
+        let mut board = Board::new(name.to_string(), String::new());
+        board.cards = cards;
+        board
+    }
+
+    fn card(name: &str) -> Card {
+        Card {
+            name: name.to_string(),
+            ..Card::default()
+        }
+    }
+
+    #[test]
+    fn should_report_no_changes_for_identical_boards() {
+        let boards = vec![board_with_cards("Board", vec![card("Card")])];
+        let diff = diff_boards(&boards, &boards.clone());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn should_report_added_and_removed_boards() {
+        let current = vec![board_with_cards("Old", vec![card("Old Card")])];
+        let incoming = vec![board_with_cards("New", vec![card("New Card")])];
+        let diff = diff_boards(&current, &incoming);
+        assert_eq!(diff.boards_added, vec!["New".to_string()]);
+        assert_eq!(diff.boards_removed, vec!["Old".to_string()]);
+        assert_eq!(diff.cards_added, vec!["New Card".to_string()]);
+        assert_eq!(diff.cards_removed, vec!["Old Card".to_string()]);
+        assert!(diff.boards_changed.is_empty());
+        assert!(diff.cards_changed.is_empty());
+    }
+
+    #[test]
+    fn should_report_changed_boards_and_cards() {
+        let current = vec![board_with_cards("Board", vec![card("Card")])];
+        let mut incoming = current.clone();
+        incoming[0].name = "Renamed Board".to_string();
+        incoming[0].cards[0].card_status = CardStatus::Complete;
+        let diff = diff_boards(&current, &incoming);
+        assert_eq!(diff.boards_changed, vec!["Renamed Board".to_string()]);
+        assert_eq!(diff.cards_changed, vec!["Card".to_string()]);
+        assert!(diff.boards_added.is_empty());
+        assert!(diff.cards_removed.is_empty());
+    }
+
+    #[test]
+    fn should_report_card_moved_between_boards_as_changed() {
+        let moved_card = card("Card");
+        let current = vec![
+            board_with_cards("First", vec![moved_card.clone()]),
+            board_with_cards("Second", Vec::new()),
+        ];
+        let mut incoming = current.clone();
+        incoming[0].cards.clear();
+        incoming[1].cards.push(moved_card);
+        let diff = diff_boards(&current, &incoming);
+        assert_eq!(diff.cards_changed, vec!["Card".to_string()]);
+        assert!(diff.cards_added.is_empty());
+        assert!(diff.cards_removed.is_empty());
+    }
+
+    #[test]
+    fn should_parse_card_links_in_text() {
+        let segments = CardLink::parse("see [[Fix Login]] and @42, mail me@example.com");
+        let links = segments
+            .iter()
+            .filter_map(|(_, link)| link.clone())
+            .collect::<Vec<CardLink>>();
+        assert_eq!(
+            links,
+            vec![CardLink::Name("Fix Login".to_string()), CardLink::Id(42)]
+        );
+        let joined = segments
+            .iter()
+            .map(|(text, _)| text.as_str())
+            .collect::<String>();
+        assert_eq!(joined, "see [[Fix Login]] and @42, mail me@example.com");
+    }
+
+    #[test]
+    fn should_resolve_card_links_by_name_and_id() {
+        let first = card("Duplicate");
+        let second = card("Duplicate");
+        let unique = card("Unique");
+        let boards = vec![
+            board_with_cards("First", vec![first.clone(), unique.clone()]),
+            board_with_cards("Second", vec![second.clone()]),
+        ];
+        assert_eq!(
+            CardLink::Name("duplicate".to_string())
+                .resolve(&boards)
+                .len(),
+            2
+        );
+        assert_eq!(
+            CardLink::Id(unique.id).resolve(&boards),
+            vec![(boards[0].id, unique.id)]
+        );
+        assert!(CardLink::Name("Missing".to_string())
+            .resolve(&boards)
+            .is_empty());
+    }
+
+    #[test]
+    fn should_prune_only_cards_archived_before_the_retention_period() {
+        let today = NaiveDate::from_ymd_opt(2023, 6, 30).unwrap();
+        let mut board = board_with_cards("Board", vec![card("Old"), card("Recent")]);
+        let old_id = board.cards[0].id;
+        let recent_id = board.cards[1].id;
+        board.archive_card(old_id, NaiveDate::from_ymd_opt(2023, 5, 1).unwrap());
+        board.archive_card(recent_id, NaiveDate::from_ymd_opt(2023, 6, 25).unwrap());
+        let mut boards = vec![board];
+
+        let expected = vec![("Board".to_string(), "Old".to_string())];
+        assert_eq!(prune_archived_cards(&mut boards, 30, today, true), expected);
+        assert_eq!(boards[0].archived_cards.len(), 2);
+        assert_eq!(
+            prune_archived_cards(&mut boards, 30, today, false),
+            expected
+        );
+        assert_eq!(boards[0].archived_cards.len(), 1);
+        assert_eq!(boards[0].archived_cards[0].card.id, recent_id);
+    }
+
+    #[test]
+    fn should_sort_least_complete_boards_first_and_empty_boards_last() {
+        let completed_card = Card {
+            card_status: CardStatus::Complete,
+            ..card("Done")
+        };
+        let mut boards = vec![
+            board_with_cards("Empty", vec![]),
+            board_with_cards("Half", vec![completed_card.clone(), card("Todo")]),
+            board_with_cards("Done", vec![completed_card]),
+            board_with_cards("Behind", vec![card("Todo")]),
+        ];
+        sort_boards_by_done_ratio(&mut boards);
+        let names = boards
+            .iter()
+            .map(|board| board.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Behind", "Half", "Empty", "Done"]);
+    }
+
+    #[test]
+    fn should_migrate_cards_to_the_nearest_workflow_stage() {
+        let stages = WorkflowStage::list_from_string(
+            "Backlog @ Active; In Progress = > @ Active; Done = ✓ @ Complete",
+        )
+        .unwrap();
+        let completed_card = Card {
+            card_status: CardStatus::Complete,
+            ..card("Done")
+        };
+        let stale_card = Card {
+            card_status: CardStatus::Stale,
+            ..card("Stale")
+        };
+        let staged_card = Card {
+            workflow_stage: "In Progress".to_string(),
+            ..card("Staged")
+        };
+        let mut boards = vec![board_with_cards(
+            "Board",
+            vec![completed_card, stale_card, staged_card],
+        )];
+        assert_eq!(migrate_cards_to_workflow_stages(&mut boards, &stages), 2);
+        let cards = &boards[0].cards;
+        assert_eq!(cards[0].workflow_stage, "Done");
+        assert_eq!(cards[1].workflow_stage, "Backlog");
+        assert_eq!(cards[1].card_status, CardStatus::Active);
+        assert_eq!(cards[2].workflow_stage, "In Progress");
+    }
+
+    #[test]
+    fn should_number_card_ids_per_board() {
+        let mut board = Board::new("Project Alpha".to_string(), String::new());
+        assert_eq!(board.next_card_display_id(), "PROJ-1");
+        assert_eq!(board.next_card_display_id(), "PROJ-2");
+        let mut unnamed = Board::new("--".to_string(), String::new());
+        assert_eq!(unnamed.next_card_display_id(), "CARD-1");
+        let card = Card {
+            display_id: "PROJ-2".to_string(),
+            ..card("Card")
+        };
+        assert_eq!(card.id_label(), "PROJ-2");
+        assert_eq!(Card::default().id_label().len(), 8);
+    }
+
+    #[test]
+    fn should_advance_recurring_due_date_only_once_it_has_passed() {
+        let date = |day: u32, hour: u32| {
+            NaiveDate::from_ymd_opt(2023, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let recurrence = CardRecurrence::Daily;
+        assert_eq!(recurrence.advance_past(date(10, 9), date(10, 8), 10), None);
+        assert_eq!(
+            recurrence.advance_past(date(10, 9), date(13, 12), 10),
+            Some(date(14, 9))
+        );
+        // a second check with the advanced date does nothing
+        assert_eq!(recurrence.advance_past(date(14, 9), date(13, 12), 14), None);
+        assert_eq!(
+            CardRecurrence::Weekly.advance_past(date(1, 0), date(9, 0), 1),
+            Some(date(15, 0))
+        );
+    }
+
+    #[test]
+    fn should_keep_monthly_due_dates_at_the_end_of_the_month() {
+        let date = |month: u32, day: u32| {
+            NaiveDate::from_ymd_opt(2023, month, day)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+        };
+        let monthly = CardRecurrence::Monthly;
+        assert_eq!(
+            monthly.advance_past(date(1, 31), date(2, 1), 31),
+            Some(date(2, 28))
+        );
+        assert_eq!(
+            monthly.advance_past(date(2, 28), date(3, 1), 31),
+            Some(date(3, 31))
+        );
+        assert_eq!(
+            monthly.advance_past(date(1, 31), date(5, 1), 31),
+            Some(date(5, 31))
+        );
+        assert_eq!(
+            monthly.advance_past(date(3, 31), date(4, 1), 31),
+            Some(date(4, 30))
+        );
+
+        let rent = Card {
+            recurrence_day: 31,
+            ..card("Rent")
+        };
+        assert_eq!(rent.recurrence_anchor_day(date(2, 28).date()), 31);
+        assert_eq!(rent.recurrence_anchor_day(date(4, 30).date()), 31);
+        // a due date moved by hand takes its own day
+        assert_eq!(rent.recurrence_anchor_day(date(3, 15).date()), 15);
+        assert_eq!(card("New").recurrence_anchor_day(date(3, 15).date()), 15);
+    }
+
+    #[test]
+    fn should_import_markdown_checklist_as_boards_and_cards() {
+        let markdown = "# Todo\n\
+            - [ ] Loose item\n\
+            ## Work\n\
+            Some notes\n\
+            - [ ] Write report\n  - [x] Outline\n\
+            * [X] Send invoice\n\
+            ## Empty\n\
+            ## Home\n\
+            - [ ] Groceries\n";
+        let import = parse_markdown_checklist(markdown, "todo");
+        let board_names = import
+            .boards
+            .iter()
+            .map(|board| board.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(board_names, vec!["todo", "Work", "Home"]);
+        assert_eq!(import.card_count(), 4);
+        assert_eq!(import.skipped_lines, 2);
+        let work = &import.boards[1];
+        assert_eq!(work.cards[0].name, "Write report");
+        assert_eq!(work.cards[0].description, "- [x] Outline");
+        assert_eq!(work.cards[1].card_status, CardStatus::Complete);
+    }
+
+    #[test]
+    fn should_prefer_board_card_limit_over_config() {
+        let mut board = board_with_cards("Work", vec![card("a"), card("b"), card("c")]);
+        assert!(!board.is_over_card_limit(0));
+        assert!(board.is_over_card_limit(2));
+        board.card_limit = Some(5);
+        assert!(!board.is_over_card_limit(2));
+        board.card_limit = Some(0);
+        assert_eq!(board.effective_card_limit(2), None);
+    }
+
+    #[test]
+    fn should_total_board_statistics_across_boards() {
+        let mut done = card("done");
+        done.card_status = CardStatus::Complete;
+        done.points = Some(3);
+        let mut late = card("late");
+        late.points = Some(2);
+        let work = board_with_cards("Work", vec![done, late.clone()]);
+        let home = board_with_cards("Home", vec![late]);
+        let statistics = vec![
+            BoardStatistics::new(&work, |card| card.name == "late"),
+            BoardStatistics::new(&home, |_| false),
+        ];
+        assert_eq!(statistics[0].completed_cards, 1);
+        assert_eq!(statistics[0].overdue_cards, 1);
+        assert_eq!(statistics[0].completion_ratio, 0.5);
+        let total = BoardStatistics::total("All Boards", &statistics);
+        assert_eq!(total.total_cards, 3);
+        assert_eq!(total.overdue_cards, 1);
+        assert_eq!(total.total_points, 7);
+        assert_eq!(total.completed_points, 3);
+        assert!((total.completion_ratio - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn should_average_progress_of_cards_that_track_it() {
+        let mut board = Board::new(String::from("Board"), String::new());
+        assert_eq!(board.average_progress(), None);
+        for progress in [Some(20), None, Some(70)] {
+            board.cards.push(Card {
+                progress,
+                ..Card::default()
+            });
+        }
+        assert_eq!(board.average_progress(), Some(45));
+    }
+
+    #[test]
+    fn should_give_new_ids_only_to_imported_boards_and_cards_that_collide() {
+        let existing_card = card("existing");
+        let existing = board_with_cards("Existing", vec![existing_card.clone()]);
+        let untouched_card = card("untouched");
+        let mut clashing = board_with_cards("Clashing", vec![existing_card.clone()]);
+        clashing.id = existing.id;
+        clashing.archived_cards.push(ArchivedCard {
+            card: untouched_card.clone(),
+            date_archived: String::from("01/01/2023"),
+        });
+        let mut duplicate = board_with_cards("Duplicate", vec![untouched_card.clone()]);
+        duplicate.id = clashing.id;
+        let fresh = board_with_cards("Fresh", vec![card("fresh")]);
+        let fresh_id = fresh.id;
+        let mut boards = vec![clashing, duplicate, fresh];
+        assign_unused_ids(&mut boards, &[existing.clone()]);
+
+        assert_ne!(boards[0].id, existing.id);
+        assert_ne!(boards[1].id, existing.id);
+        assert_ne!(boards[1].id, boards[0].id);
+        assert_eq!(boards[2].id, fresh_id);
+        assert_ne!(boards[0].cards[0].id, existing_card.id);
+        assert_eq!(boards[0].archived_cards[0].card.id, untouched_card.id);
+        assert_ne!(boards[1].cards[0].id, untouched_card.id);
+    }
+}
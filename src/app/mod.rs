@@ -1,9 +1,14 @@
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info};
-use ratatui::widgets::{ListState, TableState};
+use ratatui::{
+    style::Color,
+    widgets::{ListState, TableState},
+};
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
+    collections::HashMap,
     fmt::{self, Display, Formatter},
     path::PathBuf,
     time::{Duration, Instant},
@@ -13,30 +18,42 @@ use std::{
 use self::{
     actions::Actions,
     app_helper::{
-        handle_general_actions, handle_keybind_mode, handle_mouse_action, handle_user_input_mode,
-        prepare_config_for_new_app,
+        advance_recurring_due_dates, handle_card_jump_mode, handle_general_actions,
+        handle_keybind_mode, handle_mouse_action, handle_user_input_mode, mark_stale_cards,
+        prepare_config_for_new_app, record_navigation, take_periodic_save_due, update_focus_timer,
     },
-    kanban::{Board, Card, CardPriority},
-    state::{AppStatus, Focus, KeyBindings, UiMode},
+    kanban::{Board, BoardDiff, Card, CardPriority, CardRecurrence},
+    state::{AppStatus, Focus, KeyBindings, KeyChord, UiMode},
 };
 use crate::{
-    app::{actions::Action, kanban::CardStatus},
+    app::{
+        actions::Action,
+        kanban::{migrate_cards_to_workflow_stages, CardStatus, WorkflowStage},
+    },
     constants::{
-        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION,
-        FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD,
-        MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MOUSE_OUT_OF_BOUNDS_COORDINATES,
-        NO_OF_BOARDS_PER_PAGE, NO_OF_CARDS_PER_BOARD,
+        AUTO_SAVE_DEBOUNCE_TIME, BLOCKED_TAG, CARD_POINTS_OPTIONS, CONFIG_FILE_VERSION,
+        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_FOCUS_TIMER_BREAK_MINUTES,
+        DEFAULT_FOCUS_TIMER_WORK_MINUTES, DEFAULT_HELP_PANEL_HEIGHT, DEFAULT_LOG_PANEL_HEIGHT,
+        DEFAULT_RECENT_CARDS_COUNT, DEFAULT_SPLIT_VIEW_RATIO, DEFAULT_TAG_DISPLAY_LENGTH,
+        DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME,
+        MAX_ACTIVITY_LOG_ENTRIES, MAX_HELP_PANEL_HEIGHT, MAX_LOG_PANEL_HEIGHT,
+        MAX_NAVIGATION_ACCELERATION, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD,
+        MAX_SPLIT_VIEW_RATIO, MIN_COMPACT_TERM_WIDTH, MIN_HELP_PANEL_HEIGHT, MIN_LOG_PANEL_HEIGHT,
+        MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_SPLIT_VIEW_RATIO,
+        MIN_TAG_DISPLAY_LENGTH, MIN_TERM_WIDTH, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        NO_OF_BOARDS_PER_PAGE, NO_OF_CARDS_PER_BOARD, WATCHED_TAG,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
         data_handler::{
-            get_available_local_savefiles, get_config, get_default_save_directory,
-            get_default_ui_mode,
+            expand_path, get_available_local_savefiles, get_config, get_default_save_directory,
+            get_default_ui_mode, validate_save_directory, SaveDirectoryStatus,
         },
         handler::refresh_visible_boards_and_cards,
         IoEvent,
     },
     ui::{
+        parse_custom_color,
         widgets::{CommandPaletteWidget, ToastType, ToastWidget},
         TextColorOptions, TextModifierOptions, Theme,
     },
@@ -62,12 +79,412 @@ pub enum ActionHistory {
     MoveCardWithinBoard(u128, usize, usize),
     CreateBoard(Board),
     EditCard(Card, Card, u128),
+    EditBoardCards(Vec<Card>, Vec<Card>, u128),
+    ClearBoard(Vec<Card>, u128),
+    /// Several actions made by one batch operation, kept as a single history entry so one undo
+    /// or redo covers the whole batch. The description is shown in the activity log
+    CompoundOperation(String, Vec<ActionHistory>),
+}
+
+impl ActionHistory {
+    /// Short human readable summary used in the activity log
+    pub fn describe(&self) -> String {
+        match self {
+            ActionHistory::DeleteCard(card, _) => format!("Deleted card '{}'", card.name),
+            ActionHistory::CreateCard(card, _) => format!("Created card '{}'", card.name),
+            ActionHistory::DeleteBoard(board) => format!("Deleted board '{}'", board.name),
+            ActionHistory::MoveCardBetweenBoards(card, _, _) => {
+                format!("Moved card '{}' to another board", card.name)
+            }
+            ActionHistory::MoveCardWithinBoard(_, from, to) => format!(
+                "Moved a card from position {} to {} within its board",
+                from + 1,
+                to + 1
+            ),
+            ActionHistory::CreateBoard(board) => format!("Created board '{}'", board.name),
+            ActionHistory::EditCard(old_card, new_card, _) => {
+                if old_card.card_status != new_card.card_status {
+                    format!(
+                        "Changed status of card '{}' from {} to {}",
+                        new_card.name, old_card.card_status, new_card.card_status
+                    )
+                } else {
+                    format!("Edited card '{}'", new_card.name)
+                }
+            }
+            ActionHistory::EditBoardCards(_, new_cards, _) => {
+                format!("Edited {} cards on a board", new_cards.len())
+            }
+            ActionHistory::ClearBoard(cards, _) => {
+                format!("Removed all {} cards from a board", cards.len())
+            }
+            ActionHistory::CompoundOperation(description, _) => description.clone(),
+        }
+    }
+
+    /// The kind of change this action makes, used to decide if it triggers an auto save
+    pub fn auto_save_trigger(&self) -> AutoSaveTrigger {
+        match self {
+            ActionHistory::CreateCard(_, _) | ActionHistory::CreateBoard(_) => {
+                AutoSaveTrigger::Create
+            }
+            ActionHistory::DeleteCard(_, _)
+            | ActionHistory::DeleteBoard(_)
+            | ActionHistory::ClearBoard(_, _) => AutoSaveTrigger::Delete,
+            ActionHistory::MoveCardBetweenBoards(_, _, _)
+            | ActionHistory::MoveCardWithinBoard(_, _, _) => AutoSaveTrigger::Move,
+            ActionHistory::EditCard(_, _, _) | ActionHistory::EditBoardCards(_, _, _) => {
+                AutoSaveTrigger::Edit
+            }
+            // a batch is made of one kind of action, so the first one stands for all of them
+            ActionHistory::CompoundOperation(_, actions) => actions
+                .first()
+                .map_or(AutoSaveTrigger::Edit, |action| action.auto_save_trigger()),
+        }
+    }
+
+    /// Reverses the action on the boards, returns the message to show or why it could not be
+    /// undone. A compound operation undoes its parts in reverse order and stops at the first
+    /// part that fails
+    pub fn undo(&self, boards: &mut Vec<Board>) -> Result<String, String> {
+        match self {
+            ActionHistory::DeleteCard(card, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    board.cards.push(card.clone());
+                    Ok(format!("Undo Delete Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo delete card '{}' as the board with id '{}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    board.cards.retain(|c| c.id != card.id);
+                    Ok(format!("Undo Create Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo create card '{}' as the board with id '{}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::MoveCardBetweenBoards(card, moved_from_board_id, moved_to_board_id) => {
+                // find the card in the moved_to_board_id and remove it
+                if let Some(moved_to_board) = boards.iter_mut().find(|b| b.id == *moved_to_board_id)
+                {
+                    moved_to_board.cards.retain(|c| c.id != card.id);
+                } else {
+                    return Err(format!(
+                        "Could not undo move card '{}' as the board with id '{}' was not found",
+                        card.name, moved_to_board_id
+                    ));
+                }
+                // find the card in the moved_from_board_id and add it
+                if let Some(moved_from_board) =
+                    boards.iter_mut().find(|b| b.id == *moved_from_board_id)
+                {
+                    moved_from_board.cards.push(card.clone());
+                    Ok(format!("Undo Move Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo move card '{}' as the board with id '{}' was not found",
+                        card.name, moved_from_board_id
+                    ))
+                }
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    // check if both the index's are valid if so swap the card
+                    if *moved_from_index >= board.cards.len()
+                        || *moved_to_index >= board.cards.len()
+                    {
+                        return Err(String::from(
+                            "Could not undo move card 'N/A' as the index's were invalid",
+                        ));
+                    }
+                    // moving the card back also covers moves of more than one position
+                    let card = board.cards.remove(*moved_to_index);
+                    let card_name = card.name.clone();
+                    board.cards.insert(*moved_from_index, card);
+                    Ok(format!("Undo Move Card '{}'", card_name))
+                } else {
+                    Err(format!(
+                        "Could not undo move card 'N/A' as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::DeleteBoard(board) => {
+                boards.push(board.clone());
+                Ok(format!("Undo Delete Board '{}'", board.name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                boards.retain(|b| b.id != board.id);
+                Ok(format!("Undo Create Board '{}'", board.name))
+            }
+            ActionHistory::EditBoardCards(old_cards, _, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    for old_card in old_cards.iter() {
+                        if let Some(card) = board.cards.iter_mut().find(|c| c.id == old_card.id) {
+                            *card = old_card.clone();
+                        }
+                    }
+                    Ok(format!(
+                        "Undo Edit {} Cards on Board '{}'",
+                        old_cards.len(),
+                        board.name
+                    ))
+                } else {
+                    Err(format!(
+                        "Could not undo edit cards as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::ClearBoard(cards, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    // cards added after the board was cleared stay below the restored ones
+                    let mut restored_cards = cards.clone();
+                    restored_cards.append(&mut board.cards);
+                    board.cards = restored_cards;
+                    Ok(format!(
+                        "Undo Remove {} Cards from Board '{}'",
+                        cards.len(),
+                        board.name
+                    ))
+                } else {
+                    Err(format!(
+                        "Could not undo clear board as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::EditCard(old_card, _, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    if let Some(card) = board.cards.iter_mut().find(|c| c.id == old_card.id) {
+                        *card = old_card.clone();
+                        Ok(format!("Undo Edit Card '{}'", card.name))
+                    } else {
+                        Err(format!(
+                            "Could not undo edit card '{}' as the card was not found",
+                            old_card.name
+                        ))
+                    }
+                } else {
+                    Err(format!(
+                        "Could not undo edit card '{}' as the board with id '{}' was not found",
+                        old_card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::CompoundOperation(description, actions) => {
+                for action in actions.iter().rev() {
+                    action.undo(boards)?;
+                }
+                Ok(format!("Undo '{}'", description))
+            }
+        }
+    }
+
+    /// Applies the action to the boards again after it was undone, returns the message to show
+    /// or why it could not be redone. A compound operation redoes its parts in their original
+    /// order
+    pub fn redo(&self, boards: &mut Vec<Board>) -> Result<String, String> {
+        match self {
+            ActionHistory::DeleteCard(card, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    board.cards.retain(|c| c.id != card.id);
+                    Ok(format!("Redo Delete Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo delete card '{}' as the board with id '{}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    board.cards.push(card.clone());
+                    Ok(format!("Redo Create Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo create card '{}' as the board with id '{}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::MoveCardBetweenBoards(card, moved_from_board_id, moved_to_board_id) => {
+                // find the card in the moved_to_board_id and add it
+                if let Some(moved_to_board) = boards.iter_mut().find(|b| b.id == *moved_to_board_id)
+                {
+                    moved_to_board.cards.push(card.clone());
+                } else {
+                    return Err(format!(
+                        "Could not redo move card '{}' as the board with id '{}' was not found",
+                        card.name, moved_to_board_id
+                    ));
+                }
+                // find the card in the moved_from_board_id and remove it
+                if let Some(moved_from_board) =
+                    boards.iter_mut().find(|b| b.id == *moved_from_board_id)
+                {
+                    moved_from_board.cards.retain(|c| c.id != card.id);
+                    Ok(format!("Redo Move Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo move card '{}' as the board with id '{}' was not found",
+                        card.name, moved_from_board_id
+                    ))
+                }
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    // check if both the index's are valid if so swap the card
+                    if *moved_from_index >= board.cards.len()
+                        || *moved_to_index >= board.cards.len()
+                    {
+                        return Err(String::from(
+                            "Could not redo move card 'N/A' as the index's were invalid",
+                        ));
+                    }
+                    let card = board.cards.remove(*moved_from_index);
+                    let card_name = card.name.clone();
+                    board.cards.insert(*moved_to_index, card);
+                    Ok(format!("Redo Move Card '{}'", card_name))
+                } else {
+                    Err(format!(
+                        "Could not redo move card 'N/A' as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::DeleteBoard(board) => {
+                boards.retain(|b| b.id != board.id);
+                Ok(format!("Redo Delete Board '{}'", board.name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                boards.push(board.clone());
+                Ok(format!("Redo Create Board '{}'", board.name))
+            }
+            ActionHistory::EditBoardCards(_, new_cards, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    for new_card in new_cards.iter() {
+                        if let Some(card) = board.cards.iter_mut().find(|c| c.id == new_card.id) {
+                            *card = new_card.clone();
+                        }
+                    }
+                    Ok(format!(
+                        "Redo Edit {} Cards on Board '{}'",
+                        new_cards.len(),
+                        board.name
+                    ))
+                } else {
+                    Err(format!(
+                        "Could not redo edit cards as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::ClearBoard(cards, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    board
+                        .cards
+                        .retain(|card| !cards.iter().any(|cleared| cleared.id == card.id));
+                    Ok(format!(
+                        "Redo Remove {} Cards from Board '{}'",
+                        cards.len(),
+                        board.name
+                    ))
+                } else {
+                    Err(format!(
+                        "Could not redo clear board as the board with id '{}' was not found",
+                        board_id
+                    ))
+                }
+            }
+            ActionHistory::EditCard(_, new_card, board_id) => {
+                if let Some(board) = boards.iter_mut().find(|b| b.id == *board_id) {
+                    if let Some(card) = board.cards.iter_mut().find(|c| c.id == new_card.id) {
+                        *card = new_card.clone();
+                        Ok(format!("Redo Edit Card '{}'", card.name))
+                    } else {
+                        Err(format!(
+                            "Could not redo edit card '{}' as the card was not found",
+                            new_card.name
+                        ))
+                    }
+                } else {
+                    Err(format!(
+                        "Could not redo edit card '{}' as the board with id '{}' was not found",
+                        new_card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::CompoundOperation(description, actions) => {
+                for action in actions.iter() {
+                    action.redo(boards)?;
+                }
+                Ok(format!("Redo '{}'", description))
+            }
+        }
+    }
+}
+
+/// Kinds of changes that can be configured to save the kanban state shortly after they are made
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoSaveTrigger {
+    Create,
+    Delete,
+    Move,
+    Edit,
+}
+
+impl AutoSaveTrigger {
+    pub fn all() -> Vec<AutoSaveTrigger> {
+        vec![
+            AutoSaveTrigger::Create,
+            AutoSaveTrigger::Delete,
+            AutoSaveTrigger::Move,
+            AutoSaveTrigger::Edit,
+        ]
+    }
+    pub fn from_string(s: &str) -> Option<AutoSaveTrigger> {
+        AutoSaveTrigger::all()
+            .into_iter()
+            .find(|trigger| trigger.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+}
+
+impl Display for AutoSaveTrigger {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            AutoSaveTrigger::Create => write!(f, "Create"),
+            AutoSaveTrigger::Delete => write!(f, "Delete"),
+            AutoSaveTrigger::Move => write!(f, "Move"),
+            AutoSaveTrigger::Edit => write!(f, "Edit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityLogEntry {
+    pub time: DateTime<Local>,
+    pub description: String,
 }
 
 #[derive(Default)]
 pub struct ActionHistoryManager {
     pub history: Vec<ActionHistory>,
     pub history_index: usize,
+    /// Every change made this session, oldest first, kept even when the action is undone
+    pub activity_log: Vec<ActivityLogEntry>,
+    /// Kinds of changes made since the last auto save check and when the latest one was made
+    pub unsaved_changes: Vec<AutoSaveTrigger>,
+    pub last_change_time: Option<Instant>,
+    /// Changes, undos and redos made since the boards were last saved or loaded
+    pub changes_since_save: usize,
 }
 
 impl ActionHistoryManager {
@@ -75,9 +492,44 @@ impl ActionHistoryManager {
         if self.history_index != self.history.len() {
             self.history.truncate(self.history_index);
         }
+        self.activity_log.push(ActivityLogEntry {
+            time: Local::now(),
+            description: action.describe(),
+        });
+        if self.activity_log.len() > MAX_ACTIVITY_LOG_ENTRIES {
+            let overflow = self.activity_log.len() - MAX_ACTIVITY_LOG_ENTRIES;
+            self.activity_log.drain(..overflow);
+        }
+        let trigger = action.auto_save_trigger();
+        if !self.unsaved_changes.contains(&trigger) {
+            self.unsaved_changes.push(trigger);
+        }
+        self.last_change_time = Some(Instant::now());
+        self.changes_since_save += 1;
         self.history.push(action);
         self.history_index += 1;
     }
+
+    /// Returns true once no change has been made for the debounce time and at least one of
+    /// the changes since the last check is in the given triggers, the pending changes are
+    /// cleared either way so a burst of changes leads to a single save
+    pub fn take_auto_save_due(&mut self, triggers: &[AutoSaveTrigger]) -> bool {
+        let last_change_time = if let Some(last_change_time) = self.last_change_time {
+            last_change_time
+        } else {
+            return false;
+        };
+        if last_change_time.elapsed() < Duration::from_millis(AUTO_SAVE_DEBOUNCE_TIME) {
+            return false;
+        }
+        let save_due = self
+            .unsaved_changes
+            .iter()
+            .any(|change| triggers.contains(change));
+        self.unsaved_changes.clear();
+        self.last_change_time = None;
+        save_due
+    }
 }
 
 /// The main application, containing the state
@@ -101,9 +553,18 @@ pub struct App {
 
 impl App {
     pub fn new(io_tx: tokio::sync::mpsc::Sender<IoEvent>) -> Self {
+        Self::with_safe_mode(io_tx, false)
+    }
+
+    /// Safe mode ignores the config file and does not load the last save, it is offered after
+    /// a session that did not exit cleanly so a broken config or save cannot block the start
+    pub fn with_safe_mode(io_tx: tokio::sync::mpsc::Sender<IoEvent>, safe_mode: bool) -> Self {
         let actions = vec![Action::Quit].into();
         let is_loading = false;
-        let mut state = AppState::default();
+        let mut state = AppState {
+            safe_mode,
+            ..AppState::default()
+        };
         let boards = vec![];
         let filtered_boards = vec![];
         let all_themes = Theme::all_default_themes();
@@ -138,14 +599,32 @@ impl App {
 
     /// Handle a user action
     pub async fn do_action(&mut self, key: Key) -> AppReturn {
+        self.state.keyboard_has_focus = true;
+        let previous_position = self.navigation_position();
         // check if we are in a user input mode
-        if self.state.app_status == AppStatus::UserInput {
+        let app_return = if self.state.app_status == AppStatus::UserInput {
             handle_user_input_mode(self, key).await
         } else if self.state.app_status == AppStatus::KeyBindMode {
             handle_keybind_mode(self, key).await
+        } else if self.state.app_status == AppStatus::CardJumpMode {
+            handle_card_jump_mode(self, key)
         } else {
             handle_general_actions(self, key).await
+        };
+        record_navigation(self, previous_position);
+        app_return
+    }
+    /// Runs the keys queued by a custom command one after the other, stops early when one of
+    /// them exits the app
+    pub async fn run_queued_keys(&mut self) -> AppReturn {
+        while !self.state.queued_keys.is_empty() {
+            let key = self.state.queued_keys.remove(0);
+            if self.do_action(key).await == AppReturn::Exit {
+                self.state.queued_keys.clear();
+                return AppReturn::Exit;
+            }
         }
+        AppReturn::Continue
     }
     /// Send a network event to the IO thread
     pub async fn dispatch(&mut self, action: IoEvent) {
@@ -169,13 +648,62 @@ impl App {
         };
     }
 
+    /// Board and card the keyboard is on in a board view, used for the navigation history
+    pub fn navigation_position(&self) -> Option<(u128, Option<u128>)> {
+        if !UiMode::view_modes().contains(&self.state.ui_mode) {
+            return None;
+        }
+        self.state
+            .current_board_id
+            .map(|board_id| (board_id, self.state.current_card_id))
+    }
+
+    /// True when the board views only show the current board, either in focus mode or because
+    /// the terminal is too narrow for more
+    pub fn single_board_view(&self) -> bool {
+        self.state.board_focus_mode || self.state.compact_layout
+    }
+
+    /// If hovering the mouse over a board or card should make it the current one
+    pub fn mouse_sets_focus(&self) -> bool {
+        self.config.focus_follows_mouse && !self.state.keyboard_has_focus
+    }
     pub async fn handle_mouse(&mut self, mouse_action: Mouse) -> AppReturn {
         handle_mouse_action(self, mouse_action).await
     }
 
+    /// Runs the work that waits for the app to be idle, such as saving after a burst of changes
+    pub async fn handle_tick(&mut self) -> AppReturn {
+        if self
+            .action_history_manager
+            .take_auto_save_due(&self.config.auto_save_on)
+        {
+            info!("Auto saving after changes");
+            self.dispatch(IoEvent::AutoSave).await;
+        }
+        if take_periodic_save_due(self) {
+            info!("Auto saving on the interval");
+            self.dispatch(IoEvent::PeriodicSave).await;
+        }
+        update_focus_timer(self);
+        advance_recurring_due_dates(self);
+        mark_stale_cards(self);
+        AppReturn::Continue
+    }
+
     pub fn actions(&self) -> &Actions {
         &self.actions
     }
+    /// True when the frame is drawn with ASCII characters only and without colors
+    pub fn ascii_only(&self) -> bool {
+        self.config.ascii_only || self.state.ascii_only_forced
+    }
+    /// True when card due dates are shown relative to today on boards
+    pub fn relative_due_dates(&self) -> bool {
+        self.state
+            .relative_due_dates_override
+            .unwrap_or(self.config.relative_due_dates)
+    }
     pub fn status(&self) -> &AppStatus {
         &self.state.app_status
     }
@@ -319,10 +847,10 @@ impl App {
         }
     }
     pub fn edit_keybindings_next(&mut self) {
-        let keybind_iterator = self.config.keybindings.iter();
+        let editor_rows = self.config.keybindings.editor_rows();
         let i = match self.state.edit_keybindings_state.selected() {
             Some(i) => {
-                if i >= keybind_iterator.count() - 1 {
+                if i >= editor_rows - 1 {
                     0
                 } else {
                     i + 1
@@ -333,11 +861,11 @@ impl App {
         self.state.edit_keybindings_state.select(Some(i));
     }
     pub fn edit_keybindings_prv(&mut self) {
-        let keybind_iterator = self.config.keybindings.iter();
+        let editor_rows = self.config.keybindings.editor_rows();
         let i = match self.state.edit_keybindings_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    keybind_iterator.count() - 1
+                    editor_rows - 1
                 } else {
                     i - 1
                 }
@@ -611,8 +1139,55 @@ impl App {
                 keybind_action_list.push(keybind_action);
             }
         }
+
+        for chord in keybinds.chords.iter() {
+            let action_translated_string =
+                KeyBindings::str_to_action(keybinds.clone(), &chord.action)
+                    .unwrap_or(&Action::Quit)
+                    .to_string();
+            keybind_action_list.push(vec![
+                KeyChord::keys_to_string(&chord.keys),
+                action_translated_string,
+            ]);
+        }
         self.state.keybind_store = keybind_action_list;
     }
+    /// Opens a confirmation popup, with `TypeName` the text has to be typed before confirming
+    /// and typing starts right away
+    pub fn open_confirmation(
+        &mut self,
+        popup_mode: PopupMode,
+        style: ConfirmationStyle,
+        text: &str,
+    ) {
+        self.state.popup_mode = Some(popup_mode);
+        self.state.focus = Focus::SubmitButton;
+        self.state.current_user_input = String::new();
+        if style == ConfirmationStyle::TypeName {
+            self.state.confirmation_text = Some(text.to_string());
+            self.state.current_cursor_position = Some(0);
+            self.state.app_status = AppStatus::UserInput;
+        } else {
+            self.state.confirmation_text = None;
+        }
+    }
+    /// True when the open confirmation popup can be confirmed, the typed text has to match
+    /// exactly
+    pub fn confirmation_typed(&self) -> bool {
+        self.state
+            .confirmation_text
+            .as_ref()
+            .map_or(true, |text| self.state.current_user_input == *text)
+    }
+    pub fn close_confirmation(&mut self) {
+        self.state.popup_mode = None;
+        self.state.confirmation_text = None;
+        self.state.current_user_input = String::new();
+        self.state.current_cursor_position = None;
+        if self.state.app_status == AppStatus::UserInput {
+            self.state.app_status = AppStatus::Initialized;
+        }
+    }
     pub fn send_info_toast(&mut self, message: &str, duration: Option<Duration>) {
         if let Some(duration) = duration {
             self.state.toasts.push(ToastWidget::new(
@@ -874,6 +1449,33 @@ impl App {
         };
         self.state.card_priority_selector_state.select(Some(i));
     }
+    pub fn select_card_points_next(&mut self) {
+        // the first entry clears the points
+        let i = match self.state.card_points_selector_state.selected() {
+            Some(i) => {
+                if i >= CARD_POINTS_OPTIONS.len() {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.card_points_selector_state.select(Some(i));
+    }
+    pub fn select_card_points_prv(&mut self) {
+        let i = match self.state.card_points_selector_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    CARD_POINTS_OPTIONS.len()
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.card_points_selector_state.select(Some(i));
+    }
     pub fn filter_by_tag_popup_next(&mut self) {
         let all_tags_len = if self.state.all_available_tags.is_some() {
             self.state.all_available_tags.clone().unwrap().len()
@@ -914,10 +1516,44 @@ impl App {
             self.state.filter_by_tag_list_state.select(Some(i));
         }
     }
-    pub fn change_date_format_popup_next(&mut self) {
-        let i = match self.state.date_format_selector_state.selected() {
+    pub fn filter_preset_popup_next(&mut self) {
+        let presets_len = self.config.filter_presets.len();
+        if presets_len > 0 {
+            let i = match self.state.filter_preset_list_state.selected() {
+                Some(i) => {
+                    if i >= presets_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state.filter_preset_list_state.select(Some(i));
+        }
+    }
+    pub fn filter_preset_popup_prv(&mut self) {
+        let presets_len = self.config.filter_presets.len();
+        if presets_len > 0 {
+            let i = match self.state.filter_preset_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        presets_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.state.filter_preset_list_state.select(Some(i));
+        }
+    }
+    pub fn card_recurrence_popup_next(&mut self) {
+        // the first entry stops the card from repeating
+        let options_len = CardRecurrence::all().len() + 1;
+        let i = match self.state.card_recurrence_list_state.selected() {
             Some(i) => {
-                if i >= DateFormat::get_all_date_formats().len() - 1 {
+                if i >= options_len - 1 {
                     0
                 } else {
                     i + 1
@@ -925,244 +1561,198 @@ impl App {
             }
             None => 0,
         };
-        self.state.date_format_selector_state.select(Some(i));
+        self.state.card_recurrence_list_state.select(Some(i));
     }
-    pub fn change_date_format_popup_prv(&mut self) {
-        let i = match self.state.date_format_selector_state.selected() {
+    pub fn card_recurrence_popup_prv(&mut self) {
+        let options_len = CardRecurrence::all().len() + 1;
+        let i = match self.state.card_recurrence_list_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    DateFormat::get_all_date_formats().len() - 1
+                    options_len - 1
                 } else {
                     i - 1
                 }
             }
             None => 0,
         };
-        self.state.date_format_selector_state.select(Some(i));
+        self.state.card_recurrence_list_state.select(Some(i));
     }
-    pub fn undo(&mut self) {
-        if self.action_history_manager.history_index == 0 {
-            self.send_error_toast("No more actions to undo", None);
-        } else {
-            let history_index = self.action_history_manager.history_index - 1;
-            let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        board.cards.push(card.clone());
-                        self.action_history_manager.history_index -= 1;
-                        refresh_visible_boards_and_cards(self);
-                        self.send_info_toast(&format!("Undo Delete Card '{}'", card.name), None);
+    pub fn activity_log_popup_next(&mut self) {
+        let log_len = self.action_history_manager.activity_log.len();
+        if log_len > 0 {
+            let i = match self.state.activity_log_list_state.selected() {
+                Some(i) => {
+                    if i >= log_len - 1 {
+                        0
                     } else {
-                        self.send_error_toast(&format!("Could not undo delete card '{}' as the board with id '{}' was not found", card.name, board_id), None);
+                        i + 1
                     }
                 }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        board.cards.retain(|c| c.id != card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        self.send_info_toast(&format!("Undo Create Card '{}'", card.name), None);
+                None => 0,
+            };
+            self.state.activity_log_list_state.select(Some(i));
+        }
+    }
+    pub fn activity_log_popup_prv(&mut self) {
+        let log_len = self.action_history_manager.activity_log.len();
+        if log_len > 0 {
+            let i = match self.state.activity_log_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        log_len - 1
                     } else {
-                        self.send_error_toast(&format!("Could not undo create card '{}' as the board with id '{}' was not found", card.name, board_id), None);
+                        i - 1
                     }
                 }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                ) => {
-                    // find the card in the moved_to_board_id and remove it
-                    if let Some(moved_to_board) =
-                        self.boards.iter_mut().find(|b| b.id == moved_to_board_id)
-                    {
-                        moved_to_board.cards.retain(|c| c.id != card.id);
+                None => 0,
+            };
+            self.state.activity_log_list_state.select(Some(i));
+        }
+    }
+    pub fn card_link_popup_next(&mut self) {
+        let candidates_len = self.state.card_link_candidates.len();
+        if candidates_len > 0 {
+            let i = match self.state.card_link_list_state.selected() {
+                Some(i) => {
+                    if i >= candidates_len - 1 {
+                        0
                     } else {
-                        self.send_error_toast(&format!("Could not undo move card '{}' as the board with id '{}' was not found", card.name, moved_to_board_id), None);
-                        return;
+                        i + 1
                     }
-                    // find the card in the moved_from_board_id and add it
-                    if let Some(moved_from_board) =
-                        self.boards.iter_mut().find(|b| b.id == moved_from_board_id)
-                    {
-                        moved_from_board.cards.push(card.clone());
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        self.send_info_toast(&format!("Undo Move Card '{}'", card.name), None);
+                }
+                None => 0,
+            };
+            self.state.card_link_list_state.select(Some(i));
+        }
+    }
+    pub fn card_link_popup_prv(&mut self) {
+        let candidates_len = self.state.card_link_candidates.len();
+        if candidates_len > 0 {
+            let i = match self.state.card_link_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        candidates_len - 1
                     } else {
-                        self.send_error_toast(&format!("Could not undo move card '{}' as the board with id '{}' was not found", card.name, moved_from_board_id), None);
+                        i - 1
                     }
                 }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        // check if both the index's are valid if so swap the card
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            self.send_error_toast(
-                                "Could not undo move card 'N/A' as the index's were invalid",
-                                None,
-                            );
-                            return;
-                        }
-                        let card_name = board.cards[moved_to_index].name.clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        self.send_info_toast(&format!("Undo Move Card '{}'", card_name), None);
+                None => 0,
+            };
+            self.state.card_link_list_state.select(Some(i));
+        }
+    }
+    pub fn recent_cards_popup_next(&mut self) {
+        let recent_cards_len = self.state.recent_cards.len();
+        if recent_cards_len > 0 {
+            let i = match self.state.recent_cards_list_state.selected() {
+                Some(i) => {
+                    if i >= recent_cards_len - 1 {
+                        0
                     } else {
-                        self.send_error_toast(&format!("Could not undo move card 'N/A' as the board with id '{}' was not found", board_id), None);
+                        i + 1
                     }
                 }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.push(board.clone());
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    self.send_info_toast(&format!("Undo Delete Board '{}'", board.name), None);
-                }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.retain(|b| b.id != board.id);
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    self.send_info_toast(&format!("Undo Create Board '{}'", board.name), None);
-                }
-                ActionHistory::EditCard(old_card, _, board_id) => {
-                    let mut card_name = String::new();
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        if let Some(card) = board.cards.iter_mut().find(|c| c.id == old_card.id) {
-                            *card = old_card.clone();
-                            self.action_history_manager.history_index -= 1;
-                            card_name = card.name.clone();
-                        } else {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not undo edit card '{}' as the card was not found",
-                                    old_card.name
-                                ),
-                                None,
-                            );
-                        }
-                        if !card_name.is_empty() {
-                            self.send_info_toast(&format!("Undo Edit Card '{}'", card_name), None);
-                            refresh_visible_boards_and_cards(self);
-                        }
+                None => 0,
+            };
+            self.state.recent_cards_list_state.select(Some(i));
+        }
+    }
+    pub fn recent_cards_popup_prv(&mut self) {
+        let recent_cards_len = self.state.recent_cards.len();
+        if recent_cards_len > 0 {
+            let i = match self.state.recent_cards_list_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        recent_cards_len - 1
                     } else {
-                        self.send_error_toast(&format!("Could not undo edit card '{}' as the board with id '{}' was not found", old_card.name, board_id), None);
+                        i - 1
                     }
                 }
+                None => 0,
+            };
+            self.state.recent_cards_list_state.select(Some(i));
+        }
+    }
+    pub fn change_date_format_popup_next(&mut self) {
+        let i = match self.state.date_format_selector_state.selected() {
+            Some(i) => {
+                if i >= DateFormat::get_all_date_formats().len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.date_format_selector_state.select(Some(i));
+    }
+    pub fn change_date_format_popup_prv(&mut self) {
+        let i = match self.state.date_format_selector_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    DateFormat::get_all_date_formats().len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.date_format_selector_state.select(Some(i));
+    }
+    pub fn undo(&mut self) {
+        let history_index = self.action_history_manager.history_index;
+        self.undo_last_action();
+        if self.action_history_manager.history_index != history_index {
+            self.action_history_manager.changes_since_save += 1;
+        }
+    }
+
+    fn undo_last_action(&mut self) {
+        if self.action_history_manager.history_index == 0 {
+            self.send_error_toast("No more actions to undo", None);
+        } else {
+            let history_index = self.action_history_manager.history_index - 1;
+            let history = self.action_history_manager.history[history_index].clone();
+            // the action is undone on a copy so a failing part of it leaves the boards untouched
+            let mut boards = self.boards.clone();
+            match history.undo(&mut boards) {
+                Ok(message) => {
+                    self.boards = boards;
+                    self.action_history_manager.history_index -= 1;
+                    refresh_visible_boards_and_cards(self);
+                    self.send_info_toast(&message, None);
+                }
+                Err(message) => {
+                    self.send_error_toast(&message, None);
+                }
             }
         }
     }
 
     pub fn redo(&mut self) {
+        let history_index = self.action_history_manager.history_index;
+        self.redo_next_action();
+        if self.action_history_manager.history_index != history_index {
+            self.action_history_manager.changes_since_save += 1;
+        }
+    }
+
+    fn redo_next_action(&mut self) {
         if self.action_history_manager.history_index == self.action_history_manager.history.len() {
             self.send_error_toast("No more actions to redo", None);
         } else {
             let history_index = self.action_history_manager.history_index;
             let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        board.cards.retain(|c| c.id != card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Delete Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo delete card '{}' as the board with id '{}' was not found", card.name, board_id), None);
-                    }
-                }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        board.cards.push(card.clone());
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Create Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo create card '{}' as the board with id '{}' was not found", card.name, board_id), None);
-                    }
-                }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                ) => {
-                    // find the card in the moved_to_board_id and remove it
-                    if let Some(moved_to_board) =
-                        self.boards.iter_mut().find(|b| b.id == moved_to_board_id)
-                    {
-                        moved_to_board.cards.push(card.clone());
-                    } else {
-                        self.send_error_toast(&format!("Could not redo move card '{}' as the board with id '{}' was not found", card.name, moved_to_board_id), None);
-                        return;
-                    }
-                    // find the card in the moved_from_board_id and add it
-                    if let Some(moved_from_board) =
-                        self.boards.iter_mut().find(|b| b.id == moved_from_board_id)
-                    {
-                        moved_from_board.cards.retain(|c| c.id != card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Move Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo move card '{}' as the board with id '{}' was not found", card.name, moved_from_board_id), None);
-                    }
-                }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        // check if both the index's are valid if so swap the card
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            self.send_error_toast(
-                                "Could not redo move card 'N/A' as the index's were invalid",
-                                None,
-                            );
-                            return;
-                        }
-                        let card_name = board.cards[moved_to_index].name.clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Move Card '{}'", card_name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo move card 'N/A' as the board with id '{}' was not found", board_id), None);
-                    }
-                }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.retain(|b| b.id != board.id);
-                    refresh_visible_boards_and_cards(self);
+            let mut boards = self.boards.clone();
+            match history.redo(&mut boards) {
+                Ok(message) => {
+                    self.boards = boards;
                     self.action_history_manager.history_index += 1;
-                    self.send_info_toast(&format!("Redo Delete Board '{}'", board.name), None);
-                }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.push(board.clone());
                     refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    self.send_info_toast(&format!("Redo Create Board '{}'", board.name), None);
+                    self.send_info_toast(&message, None);
                 }
-                ActionHistory::EditCard(_, new_card, board_id) => {
-                    let mut card_name = String::new();
-                    if let Some(board) = self.boards.iter_mut().find(|b| b.id == board_id) {
-                        if let Some(card) = board.cards.iter_mut().find(|c| c.id == new_card.id) {
-                            *card = new_card.clone();
-                            self.action_history_manager.history_index += 1;
-                            card_name = card.name.clone();
-                        } else {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not redo edit card '{}' as the card was not found",
-                                    new_card.name
-                                ),
-                                None,
-                            );
-                        }
-                        if !card_name.is_empty() {
-                            self.send_info_toast(&format!("Redo Edit Card '{}'", card_name), None);
-                            refresh_visible_boards_and_cards(self);
-                        }
-                    } else {
-                        self.send_error_toast(&format!("Could not redo edit card '{}' as the board with id '{}' was not found", new_card.name, board_id), None);
-                    }
+                Err(message) => {
+                    self.send_error_toast(&message, None);
                 }
             }
         }
@@ -1234,7 +1824,29 @@ pub enum PopupMode {
     CustomRGBPromptBG,
     ConfirmDiscardCardChanges,
     CardPrioritySelector,
+    CardPointsSelector,
     FilterByTag,
+    ConfirmLoadSave,
+    BoardStatusSelector,
+    FilterPresetSelector,
+    ActivityLog,
+    CardLinkSelector,
+    RecentCards,
+    EditBoardDescription,
+    QuickHelp,
+    ConfirmPruneArchivedCards,
+    ConfirmDeleteBoard,
+    SaveNote,
+    CardRecurrenceSelector,
+    ImportMarkdown,
+    ImportJson,
+    ImportTrello,
+    EditBoardCardLimit,
+    EditBoardDueDate,
+    NewCardOnAllBoards,
+    ExpandedCard,
+    EditCardProgress,
+    EditBoardAccent,
 }
 
 impl Display for PopupMode {
@@ -1255,7 +1867,29 @@ impl Display for PopupMode {
             PopupMode::CustomRGBPromptBG => write!(f, "Custom RGB Prompt"),
             PopupMode::ConfirmDiscardCardChanges => write!(f, "Confirm Discard Card Changes"),
             PopupMode::CardPrioritySelector => write!(f, "Change Card Priority"),
+            PopupMode::CardPointsSelector => write!(f, "Change Card Points"),
             PopupMode::FilterByTag => write!(f, "Filter By Tag"),
+            PopupMode::ConfirmLoadSave => write!(f, "Confirm Load Save"),
+            PopupMode::BoardStatusSelector => write!(f, "Change Status of All Cards on Board"),
+            PopupMode::FilterPresetSelector => write!(f, "Apply Filter Preset"),
+            PopupMode::ActivityLog => write!(f, "Activity Log"),
+            PopupMode::CardLinkSelector => write!(f, "Select Linked Card"),
+            PopupMode::RecentCards => write!(f, "Recently Modified Cards"),
+            PopupMode::EditBoardDescription => write!(f, "Edit Board Description"),
+            PopupMode::QuickHelp => write!(f, "Quick Help"),
+            PopupMode::ConfirmPruneArchivedCards => write!(f, "Confirm Delete Archived Cards"),
+            PopupMode::ConfirmDeleteBoard => write!(f, "Confirm Delete Board"),
+            PopupMode::SaveNote => write!(f, "Save Note"),
+            PopupMode::CardRecurrenceSelector => write!(f, "Change Card Recurrence"),
+            PopupMode::ImportMarkdown => write!(f, "Import Markdown"),
+            PopupMode::ImportJson => write!(f, "Import JSON"),
+            PopupMode::ImportTrello => write!(f, "Import Trello"),
+            PopupMode::EditBoardCardLimit => write!(f, "Edit Board Card Limit"),
+            PopupMode::EditBoardDueDate => write!(f, "Edit Board Due Date"),
+            PopupMode::NewCardOnAllBoards => write!(f, "New Card on Every Board"),
+            PopupMode::ExpandedCard => write!(f, "Expanded Card"),
+            PopupMode::EditCardProgress => write!(f, "Edit Card Progress"),
+            PopupMode::EditBoardAccent => write!(f, "Edit Board Accent"),
         }
     }
 }
@@ -1269,6 +1903,7 @@ impl PopupMode {
                 Focus::CardDueDate,
                 Focus::CardPriority,
                 Focus::CardStatus,
+                Focus::CardPoints,
                 Focus::CardTags,
                 Focus::CardComments,
                 Focus::SubmitButton,
@@ -1296,11 +1931,90 @@ impl PopupMode {
             PopupMode::CustomRGBPromptBG => vec![Focus::TextInput, Focus::SubmitButton],
             PopupMode::ConfirmDiscardCardChanges => vec![Focus::SubmitButton, Focus::ExtraFocus],
             PopupMode::CardPrioritySelector => vec![],
+            PopupMode::CardPointsSelector => vec![],
             PopupMode::FilterByTag => vec![Focus::FilterByTagPopup, Focus::SubmitButton],
+            PopupMode::ConfirmLoadSave => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopupMode::BoardStatusSelector => vec![],
+            PopupMode::FilterPresetSelector => vec![],
+            PopupMode::ActivityLog => vec![],
+            PopupMode::CardLinkSelector => vec![],
+            PopupMode::RecentCards => vec![],
+            PopupMode::EditBoardDescription => vec![],
+            PopupMode::QuickHelp => vec![],
+            PopupMode::ConfirmPruneArchivedCards => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopupMode::ConfirmDeleteBoard => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopupMode::SaveNote => vec![],
+            PopupMode::CardRecurrenceSelector => vec![],
+            PopupMode::ImportMarkdown => vec![],
+            PopupMode::ImportJson => vec![],
+            PopupMode::ImportTrello => vec![],
+            PopupMode::EditBoardCardLimit => vec![],
+            PopupMode::EditBoardDueDate => vec![],
+            PopupMode::NewCardOnAllBoards => vec![],
+            PopupMode::ExpandedCard => vec![],
+            PopupMode::EditCardProgress => vec![],
+            PopupMode::EditBoardAccent => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusTimerPhase {
+    Work,
+    Break,
+}
+
+/// A pomodoro style timer for the card that was focused when it was started, the work time is
+/// logged on the card when the work phase ends
+#[derive(Debug, Clone)]
+pub struct FocusTimer {
+    pub card_id: u128,
+    pub card_name: String,
+    pub phase: FocusTimerPhase,
+    pub started_at: Instant,
+    pub duration: Duration,
+}
+
+impl FocusTimer {
+    pub fn new(card_id: u128, card_name: String, phase: FocusTimerPhase, minutes: u16) -> Self {
+        Self {
+            card_id,
+            card_name,
+            phase,
+            started_at: Instant::now(),
+            duration: Duration::from_secs(minutes as u64 * 60),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.started_at.elapsed() >= self.duration
+    }
+
+    /// Text for the status line, for example "Focus on 'Card' 12:34"
+    pub fn status_text(&self) -> String {
+        let remaining = self.duration.saturating_sub(self.started_at.elapsed());
+        let remaining = format!(
+            "{:02}:{:02}",
+            remaining.as_secs() / 60,
+            remaining.as_secs() % 60
+        );
+        match self.phase {
+            FocusTimerPhase::Work => format!("Focus on '{}' {}", self.card_name, remaining),
+            FocusTimerPhase::Break => format!("Break {}", remaining),
         }
     }
 }
 
+/// The pane of the split view that is not being navigated, the other pane always shows the
+/// current board
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitViewPane {
+    pub board_id: u128,
+    pub card_id: Option<u128>,
+    pub visible_card_ids: Vec<u128>,
+    pub is_left: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub app_status: AppStatus,
@@ -1323,6 +2037,36 @@ pub struct AppState {
     pub toasts: Vec<ToastWidget>,
     pub term_background_color: (u8, u8, u8),
     pub preview_boards_and_cards: Option<Vec<Board>>,
+    pub load_save_diff: Option<BoardDiff>,
+    /// Archived cards past the retention period as (board name, card name), waiting for the
+    /// user to confirm they can be deleted
+    pub archived_cards_to_prune: Vec<(String, String)>,
+    /// The board waiting for the user to confirm it can be deleted
+    pub board_to_delete: Option<u128>,
+    /// Text that has to be typed before the open confirmation popup can be confirmed, None when
+    /// confirming only takes the button
+    pub confirmation_text: Option<String>,
+    /// The boards were read from stdin and have not been saved yet, auto saves are skipped so
+    /// they are only written to a save file when saved explicitly
+    pub boards_from_stdin: bool,
+    /// Started with the default config and without loading the last save, auto saves are skipped
+    pub safe_mode: bool,
+    /// The alternate screen was turned off for this launch from the command line, the config is
+    /// left as is
+    pub alternate_screen_disabled: bool,
+    /// ASCII only rendering was turned on for this launch from the command line, the config is
+    /// left as is
+    pub ascii_only_forced: bool,
+    /// Due date display flipped with the toggle key for this session, None follows the config
+    pub relative_due_dates_override: Option<bool>,
+    /// Completed cards are left out of the shown boards on top of the active filter
+    pub hide_completed_cards: bool,
+    /// Boards that hide their completed cards, on top of hiding them on every board
+    pub boards_hiding_completed_cards: Vec<u128>,
+    /// Filter the shown boards were last built from, None when no filter is applied
+    pub active_filter: Option<FilterPreset>,
+    /// Note entered in the save note prompt, written next to the save file once it is saved
+    pub pending_save_note: Option<String>,
     pub preview_visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>>,
     pub preview_file_name: Option<String>,
     pub popup_mode: Option<PopupMode>,
@@ -1334,6 +2078,17 @@ pub struct AppState {
     pub card_status_selector_state: ListState,
     pub prev_ui_mode: Option<UiMode>,
     pub debug_menu_toggled: bool,
+    pub status_column_view: bool,
+    pub board_focus_mode: bool,
+    /// Set while drawing when the terminal is narrower than the compact layout width
+    pub compact_layout: bool,
+    /// The view and focus to go back to when the log panel that was opened with the toggle log
+    /// panel action is closed
+    pub log_panel_return: Option<(UiMode, Focus)>,
+    /// The running focus timer, kept across view changes until it ends or is stopped
+    pub focus_timer: Option<FocusTimer>,
+    /// The inactive pane when the body is split to show two boards side by side
+    pub split_view: Option<SplitViewPane>,
     pub ui_render_time: Option<u128>,
     pub current_mouse_coordinates: (u16, u16),
     pub mouse_focus: Option<Focus>,
@@ -1349,10 +2104,50 @@ pub struct AppState {
     pub card_view_tag_list_state: ListState,
     pub card_view_comment_list_state: ListState,
     pub card_priority_selector_state: ListState,
+    pub card_points_selector_state: ListState,
     pub all_available_tags: Option<Vec<(String, u32)>>,
     pub filter_tags: Option<Vec<String>>,
     pub filter_by_tag_list_state: ListState,
+    pub filter_preset_list_state: ListState,
+    pub card_recurrence_list_state: ListState,
+    /// When the due dates of repeating cards were last checked, None checks on the next tick
+    pub last_recurrence_check: Option<Instant>,
+    /// When active cards were last checked for going stale, None checks on the next tick
+    pub last_stale_check: Option<Instant>,
+    /// When the boards were last saved on the auto save interval, None starts the interval on
+    /// the next tick
+    pub last_periodic_save: Option<Instant>,
+    pub activity_log_list_state: ListState,
+    pub card_link_candidates: Vec<(u128, u128)>, // (board_id, card_id)
+    pub recent_cards: Vec<(u128, u128)>,         // (board_id, card_id)
+    pub recent_cards_list_state: ListState,
+    pub card_link_list_state: ListState,
+    /// Cards marked for bulk commands like exporting a selection
+    pub selected_card_ids: Vec<u128>,
     pub date_format_selector_state: ListState,
+    pub card_jump_labels: Vec<(String, u128, u128)>, // (label, board_id, card_id)
+    pub card_jump_input: String,
+    /// When the reset ui key was last pressed, used to ask for a second press when
+    /// confirm_reset_ui is enabled
+    pub reset_ui_requested_at: Option<Instant>,
+    /// Positions jumped away from, newest last, only focus is moved when going back to them
+    pub navigation_back: Vec<(u128, Option<u128>)>, // (board_id, card_id)
+    pub navigation_forward: Vec<(u128, Option<u128>)>, // (board_id, card_id)
+    /// Set by the back and forward actions so that their own move is not recorded
+    pub navigated_through_history: bool,
+    /// Keys of a custom command that still have to be run, oldest first
+    pub queued_keys: Vec<Key>,
+    pub clear_all_boards_requested_at: Option<Instant>,
+    /// Board and card under the mouse in the last frame, the card is `None` over the board itself
+    pub hovered_board_card: Option<(u128, Option<u128>)>,
+    pub keyboard_has_focus: bool,
+    /// Last path checked while editing the save directory, so it is not checked on every frame
+    pub save_directory_check: Option<(String, SaveDirectoryStatus)>,
+    pub pending_chord: Vec<Key>,
+    pub pending_chord_time: Option<Instant>,
+    /// The last up or down press in the body and how many rapid presses came before it
+    pub last_navigation: Option<(Action, Instant)>,
+    pub navigation_repeat_count: usize,
 }
 
 impl Default for AppState {
@@ -1378,6 +2173,19 @@ impl Default for AppState {
             toasts: Vec::new(),
             term_background_color: get_term_bg_color(),
             preview_boards_and_cards: None,
+            load_save_diff: None,
+            archived_cards_to_prune: Vec::new(),
+            board_to_delete: None,
+            confirmation_text: None,
+            boards_from_stdin: false,
+            safe_mode: false,
+            alternate_screen_disabled: false,
+            ascii_only_forced: false,
+            relative_due_dates_override: None,
+            hide_completed_cards: false,
+            boards_hiding_completed_cards: Vec::new(),
+            active_filter: None,
+            pending_save_note: None,
             preview_visible_boards_and_cards: LinkedHashMap::new(),
             preview_file_name: None,
             popup_mode: None,
@@ -1389,6 +2197,12 @@ impl Default for AppState {
             card_status_selector_state: ListState::default(),
             prev_ui_mode: None,
             debug_menu_toggled: false,
+            status_column_view: false,
+            board_focus_mode: false,
+            compact_layout: false,
+            log_panel_return: None,
+            focus_timer: None,
+            split_view: None,
             ui_render_time: None,
             current_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES, // make sure it's out of bounds when mouse mode is disabled
             mouse_focus: None,
@@ -1408,10 +2222,37 @@ impl Default for AppState {
             card_view_tag_list_state: ListState::default(),
             card_view_comment_list_state: ListState::default(),
             card_priority_selector_state: ListState::default(),
+            card_points_selector_state: ListState::default(),
             all_available_tags: None,
             filter_tags: None,
             filter_by_tag_list_state: ListState::default(),
+            filter_preset_list_state: ListState::default(),
+            card_recurrence_list_state: ListState::default(),
+            last_recurrence_check: None,
+            last_stale_check: None,
+            last_periodic_save: None,
+            activity_log_list_state: ListState::default(),
+            card_link_candidates: Vec::new(),
+            recent_cards: Vec::new(),
+            recent_cards_list_state: ListState::default(),
+            card_link_list_state: ListState::default(),
+            selected_card_ids: Vec::new(),
             date_format_selector_state: ListState::default(),
+            card_jump_labels: Vec::new(),
+            card_jump_input: String::new(),
+            reset_ui_requested_at: None,
+            navigation_back: Vec::new(),
+            navigation_forward: Vec::new(),
+            navigated_through_history: false,
+            queued_keys: Vec::new(),
+            clear_all_boards_requested_at: None,
+            hovered_board_card: None,
+            keyboard_has_focus: false,
+            save_directory_check: None,
+            pending_chord: Vec::new(),
+            pending_chord_time: None,
+            last_navigation: None,
+            navigation_repeat_count: 0,
         }
     }
 }
@@ -1474,146 +2315,1240 @@ impl DateFormat {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AppConfig {
-    pub save_directory: PathBuf,
-    pub default_view: UiMode,
-    pub always_load_last_save: bool,
-    pub save_on_exit: bool,
-    pub disable_scrollbars: bool,
-    pub warning_delta: u16,
-    pub keybindings: KeyBindings,
-    pub tickrate: u64,
-    pub no_of_cards_to_show: u16,
-    pub no_of_boards_to_show: u16,
-    pub enable_mouse_support: bool,
-    pub default_theme: String,
-    pub date_format: DateFormat,
-}
-
-impl Default for AppConfig {
-    fn default() -> Self {
-        let default_view = UiMode::TitleBodyHelpLog;
-        let default_theme = Theme::default();
-        Self {
-            save_directory: get_default_save_directory(),
-            default_view,
-            always_load_last_save: true,
-            save_on_exit: true,
-            disable_scrollbars: false,
-            warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
-            keybindings: KeyBindings::default(),
-            tickrate: DEFAULT_TICKRATE,
-            no_of_cards_to_show: NO_OF_CARDS_PER_BOARD,
-            no_of_boards_to_show: NO_OF_BOARDS_PER_PAGE,
-            enable_mouse_support: true,
-            default_theme: default_theme.name,
-            date_format: DateFormat::default(),
-        }
-    }
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum DateLocale {
+    #[default]
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
 }
 
-impl AppConfig {
-    pub fn to_list(&self) -> Vec<Vec<String>> {
+impl DateLocale {
+    pub fn all() -> Vec<DateLocale> {
         vec![
-            vec![
-                String::from("Save Directory"),
-                self.save_directory.to_str().unwrap().to_string(),
-            ],
-            vec![
-                String::from("Select Default View"),
-                self.default_view.to_string(),
-            ],
-            vec![
-                String::from("Auto Load Last Save"),
-                self.always_load_last_save.to_string(),
-            ],
-            vec![
-                String::from("Auto Save on Exit"),
-                self.save_on_exit.to_string(),
-            ],
-            vec![
-                String::from("Disable Scrollbars"),
-                self.disable_scrollbars.to_string(),
-            ],
-            vec![
-                String::from("Number of Days to Warn Before Due Date"),
-                self.warning_delta.to_string(),
+            DateLocale::English,
+            DateLocale::French,
+            DateLocale::German,
+            DateLocale::Spanish,
+            DateLocale::Italian,
+            DateLocale::Portuguese,
+        ]
+    }
+    pub fn from_string(s: &str) -> Option<DateLocale> {
+        DateLocale::all()
+            .into_iter()
+            .find(|locale| locale.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+    /// Month names and abbreviations accepted for each month, index 0 is January
+    fn month_names(&self) -> [&[&str]; 12] {
+        match self {
+            DateLocale::English => [
+                &["january", "jan"],
+                &["february", "feb"],
+                &["march", "mar"],
+                &["april", "apr"],
+                &["may"],
+                &["june", "jun"],
+                &["july", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["october", "oct"],
+                &["november", "nov"],
+                &["december", "dec"],
             ],
-            vec![String::from("Tickrate"), self.tickrate.to_string()],
-            vec![
-                String::from("Number of Cards to Show per board"),
-                self.no_of_cards_to_show.to_string(),
+            DateLocale::French => [
+                &["janvier", "janv"],
+                &["février", "fevrier", "févr", "fevr"],
+                &["mars"],
+                &["avril", "avr"],
+                &["mai"],
+                &["juin"],
+                &["juillet", "juil"],
+                &["août", "aout"],
+                &["septembre", "sept"],
+                &["octobre", "oct"],
+                &["novembre", "nov"],
+                &["décembre", "decembre", "déc", "dec"],
             ],
-            vec![
-                String::from("Number of Boards to Show"),
-                self.no_of_boards_to_show.to_string(),
+            DateLocale::German => [
+                &["januar", "jan"],
+                &["februar", "feb"],
+                &["märz", "maerz", "marz", "mär"],
+                &["april", "apr"],
+                &["mai"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["dezember", "dez"],
             ],
-            vec![
-                String::from("Enable Mouse Support"),
-                self.enable_mouse_support.to_string(),
+            DateLocale::Spanish => [
+                &["enero", "ene"],
+                &["febrero", "feb"],
+                &["marzo", "mar"],
+                &["abril", "abr"],
+                &["mayo", "may"],
+                &["junio", "jun"],
+                &["julio", "jul"],
+                &["agosto", "ago"],
+                &["septiembre", "setiembre", "sep", "sept"],
+                &["octubre", "oct"],
+                &["noviembre", "nov"],
+                &["diciembre", "dic"],
             ],
-            vec![
-                String::from("Default Theme"),
-                self.default_theme.to_string(),
+            DateLocale::Italian => [
+                &["gennaio", "gen"],
+                &["febbraio", "feb"],
+                &["marzo", "mar"],
+                &["aprile", "apr"],
+                &["maggio", "mag"],
+                &["giugno", "giu"],
+                &["luglio", "lug"],
+                &["agosto", "ago"],
+                &["settembre", "set"],
+                &["ottobre", "ott"],
+                &["novembre", "nov"],
+                &["dicembre", "dic"],
             ],
-            vec![
-                String::from("Default Date Format"),
-                self.date_format.to_human_readable_string().to_string(),
+            DateLocale::Portuguese => [
+                &["janeiro", "jan"],
+                &["fevereiro", "fev"],
+                &["março", "marco", "mar"],
+                &["abril", "abr"],
+                &["maio", "mai"],
+                &["junho", "jun"],
+                &["julho", "jul"],
+                &["agosto", "ago"],
+                &["setembro", "set"],
+                &["outubro", "out"],
+                &["novembro", "nov"],
+                &["dezembro", "dez"],
             ],
-            vec![String::from("Edit Keybindings")],
+        }
+    }
+    /// Words that can appear between the parts of a date and carry no meaning, e.g. "15 de marzo"
+    fn filler_words(&self) -> &[&str] {
+        match self {
+            DateLocale::English => &["of", "the"],
+            DateLocale::French => &["le"],
+            DateLocale::German => &["den"],
+            DateLocale::Spanish => &["de", "del"],
+            DateLocale::Italian => &["di", "del"],
+            DateLocale::Portuguese => &["de", "do"],
+        }
+    }
+    fn month_from_name(&self, name: &str) -> Option<u32> {
+        let name = name.to_lowercase();
+        self.month_names()
+            .iter()
+            .position(|names| names.contains(&name.as_str()))
+            .map(|index| index as u32 + 1)
+    }
+}
+
+impl Display for DateLocale {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            DateLocale::English => write!(f, "English"),
+            DateLocale::French => write!(f, "French"),
+            DateLocale::German => write!(f, "German"),
+            DateLocale::Spanish => write!(f, "Spanish"),
+            DateLocale::Italian => write!(f, "Italian"),
+            DateLocale::Portuguese => write!(f, "Portuguese"),
+        }
+    }
+}
+
+/// Card information that can be shown on a card in the board view
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum CardPreviewField {
+    DueDate,
+    Status,
+    Priority,
+    Tags,
+}
+
+impl CardPreviewField {
+    pub fn all() -> Vec<CardPreviewField> {
+        vec![
+            CardPreviewField::DueDate,
+            CardPreviewField::Status,
+            CardPreviewField::Priority,
+            CardPreviewField::Tags,
         ]
     }
+    pub fn from_string(s: &str) -> Option<CardPreviewField> {
+        CardPreviewField::all()
+            .into_iter()
+            .find(|field| field.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+    pub fn default_fields() -> Vec<CardPreviewField> {
+        vec![CardPreviewField::DueDate, CardPreviewField::Status]
+    }
+}
 
-    pub fn edit_with_string(change_str: &str, app: &mut App) -> Self {
-        let mut config = app.config.clone();
-        let lines = change_str.lines();
-        for line in lines {
-            let mut parts = line.split(':');
-            let key = parts.next().unwrap_or("").trim();
-            let value = parts.next().unwrap_or("").trim();
-            match key {
-                "Save Directory" => {
-                    let new_path = PathBuf::from(value);
-                    // check if the new path is valid
-                    if new_path.exists() {
-                        config.save_directory = new_path;
-                    } else {
-                        error!("Invalid path: {}", value);
-                        app.send_error_toast(&format!("Invalid path: {}", value), None);
-                        app.send_info_toast("Check if the path exists", None);
-                    }
-                }
-                "Select Default View" => {
-                    let new_ui_mode = UiMode::from_string(value);
-                    if let Some(new_ui_mode) = new_ui_mode {
-                        config.default_view = new_ui_mode;
-                    } else {
-                        error!("Invalid UiMode: {}", value);
-                        app.send_error_toast(&format!("Invalid UiMode: {}", value), None);
-                        info!("Valid UiModes are: {:?}", UiMode::all());
-                    }
-                }
-                "Auto Load Last Save" => {
-                    if value.to_lowercase() == "true" {
-                        config.always_load_last_save = true;
-                    } else if value.to_lowercase() == "false" {
-                        config.always_load_last_save = false;
-                    } else {
-                        error!("Invalid boolean: {}", value);
-                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
-                    }
-                }
-                "Auto Save on Exit" => {
-                    if value.to_lowercase() == "true" {
-                        config.save_on_exit = true;
-                    } else if value.to_lowercase() == "false" {
-                        config.save_on_exit = false;
-                    } else {
-                        error!("Invalid boolean: {}", value);
-                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
-                    }
-                }
+impl Display for CardPreviewField {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CardPreviewField::DueDate => write!(f, "Due Date"),
+            CardPreviewField::Status => write!(f, "Status"),
+            CardPreviewField::Priority => write!(f, "Priority"),
+            CardPreviewField::Tags => write!(f, "Tags"),
+        }
+    }
+}
+
+/// What pressing Enter on a focused card in the board view does
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum CardEnterAction {
+    #[default]
+    OpenDetail,
+    ChangeStatus,
+    EditCard,
+}
+
+impl CardEnterAction {
+    pub fn all() -> Vec<CardEnterAction> {
+        vec![
+            CardEnterAction::OpenDetail,
+            CardEnterAction::ChangeStatus,
+            CardEnterAction::EditCard,
+        ]
+    }
+    pub fn from_string(s: &str) -> Option<CardEnterAction> {
+        CardEnterAction::all()
+            .into_iter()
+            .find(|action| action.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+}
+
+impl Display for CardEnterAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CardEnterAction::OpenDetail => write!(f, "Open Detail"),
+            CardEnterAction::ChangeStatus => write!(f, "Change Status"),
+            CardEnterAction::EditCard => write!(f, "Edit Card"),
+        }
+    }
+}
+
+/// How new cards are identified, uuids are always used internally so switching the scheme never
+/// changes the id of an existing card
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum CardIdScheme {
+    #[default]
+    Uuid,
+    /// Numbered per board with a prefix taken from the board name like `PROJ-12`
+    Sequential,
+}
+
+impl CardIdScheme {
+    pub fn all() -> Vec<CardIdScheme> {
+        vec![CardIdScheme::Uuid, CardIdScheme::Sequential]
+    }
+    pub fn from_string(s: &str) -> Option<CardIdScheme> {
+        CardIdScheme::all()
+            .into_iter()
+            .find(|scheme| scheme.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+}
+
+impl Display for CardIdScheme {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CardIdScheme::Uuid => write!(f, "UUID"),
+            CardIdScheme::Sequential => write!(f, "Sequential"),
+        }
+    }
+}
+
+/// Order of the tags in the tag filter popup
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum TagSortOrder {
+    /// Most used tags first, tags used equally often are sorted alphabetically
+    #[default]
+    Frequency,
+    Alphabetical,
+}
+
+impl TagSortOrder {
+    pub fn all() -> Vec<TagSortOrder> {
+        vec![TagSortOrder::Frequency, TagSortOrder::Alphabetical]
+    }
+    pub fn from_string(s: &str) -> Option<TagSortOrder> {
+        TagSortOrder::all()
+            .into_iter()
+            .find(|order| order.to_string().to_lowercase() == s.trim().to_lowercase())
+    }
+    /// Sorts tags with their number of uses
+    pub fn sort(&self, tags: &mut [(String, u32)]) {
+        match self {
+            TagSortOrder::Frequency => {
+                tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)))
+            }
+            TagSortOrder::Alphabetical => tags.sort_by(|a, b| a.0.cmp(&b.0)),
+        }
+    }
+}
+
+impl Display for TagSortOrder {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            TagSortOrder::Frequency => write!(f, "Frequency"),
+            TagSortOrder::Alphabetical => write!(f, "Alphabetical"),
+        }
+    }
+}
+
+/// How cards that sort equally, for example cards due on the same day, are ordered among
+/// themselves
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum CardSortTieBreaker {
+    /// Keep the order the cards already have on the board
+    #[default]
+    Manual,
+    Name,
+    /// Oldest cards first, cards with a created date that cannot be read come last
+    DateCreated,
+}
+
+impl CardSortTieBreaker {
+    pub fn all() -> Vec<CardSortTieBreaker> {
+        vec![
+            CardSortTieBreaker::Manual,
+            CardSortTieBreaker::Name,
+            CardSortTieBreaker::DateCreated,
+        ]
+    }
+    pub fn from_string(s: &str) -> Option<CardSortTieBreaker> {
+        let s = s.trim().to_lowercase().replace(' ', "");
+        CardSortTieBreaker::all()
+            .into_iter()
+            .find(|tie_breaker| tie_breaker.to_string().to_lowercase().replace(' ', "") == s)
+    }
+    /// Compares two cards that the primary sort key considers equal
+    pub fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        match self {
+            CardSortTieBreaker::Manual => Ordering::Equal,
+            CardSortTieBreaker::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            CardSortTieBreaker::DateCreated => {
+                let (a, b) = (card_created_at(a), card_created_at(b));
+                (a.is_none(), a).cmp(&(b.is_none(), b))
+            }
+        }
+    }
+}
+
+impl Display for CardSortTieBreaker {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CardSortTieBreaker::Manual => write!(f, "Manual"),
+            CardSortTieBreaker::Name => write!(f, "Name"),
+            CardSortTieBreaker::DateCreated => write!(f, "Date Created"),
+        }
+    }
+}
+
+/// How a change is confirmed before it is made, `TypeName` only enables the confirm button once
+/// the name of what is changed is typed exactly
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConfirmationStyle {
+    None,
+    #[default]
+    Popup,
+    TypeName,
+}
+
+impl ConfirmationStyle {
+    pub fn all() -> Vec<ConfirmationStyle> {
+        vec![
+            ConfirmationStyle::None,
+            ConfirmationStyle::Popup,
+            ConfirmationStyle::TypeName,
+        ]
+    }
+    pub fn from_string(s: &str) -> Option<ConfirmationStyle> {
+        let s = s.trim().to_lowercase().replace(' ', "");
+        ConfirmationStyle::all()
+            .into_iter()
+            .find(|style| style.to_string().to_lowercase().replace(' ', "") == s)
+    }
+}
+
+impl Display for ConfirmationStyle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ConfirmationStyle::None => write!(f, "None"),
+            ConfirmationStyle::Popup => write!(f, "Popup"),
+            ConfirmationStyle::TypeName => write!(f, "Type Name"),
+        }
+    }
+}
+
+/// A named filter that is saved in the config and can be applied from the command palette,
+/// a card matches when it has any of the tags (if any are set), the priority (if set) and is
+/// overdue (if set)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FilterPreset {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub priority: Option<CardPriority>,
+    #[serde(default)]
+    pub overdue: bool,
+}
+
+impl FilterPreset {
+    pub fn matches(&self, card: &Card) -> bool {
+        let tags_match = self.tags.is_empty()
+            || card.tags.iter().any(|tag| {
+                self.tags
+                    .iter()
+                    .any(|preset_tag| preset_tag.to_lowercase() == tag.to_lowercase())
+            });
+        let priority_match = match &self.priority {
+            Some(priority) => card.priority == *priority,
+            None => true,
+        };
+        let overdue_match = !self.overdue
+            || (card.card_status != CardStatus::Complete
+                && card_due_date(card).map_or(false, |due| due < Local::now().date_naive()));
+        tags_match && priority_match && overdue_match
+    }
+
+    /// Parses a list of presets written as "Name = tag1, tag2 @ Priority; Other = tag3"
+    pub fn list_from_string(s: &str) -> Result<Vec<FilterPreset>, String> {
+        let mut presets: Vec<FilterPreset> = Vec::new();
+        for preset_str in s.split(';').filter(|p| !p.trim().is_empty()) {
+            let (name, filter) = match preset_str.split_once('=') {
+                Some((name, filter)) => (name.trim(), filter.trim()),
+                None => return Err(format!("Missing '=' in preset: {}", preset_str.trim())),
+            };
+            if name.is_empty() {
+                return Err(format!("Missing name in preset: {}", preset_str.trim()));
+            }
+            if presets.iter().any(|preset| preset.name == name) {
+                return Err(format!("Duplicate preset name: {}", name));
+            }
+            let (tags_str, priority) = match filter.split_once('@') {
+                Some((tags_str, priority_str)) => match CardPriority::from_string(priority_str) {
+                    Some(priority) => (tags_str, Some(priority)),
+                    None => return Err(format!("Invalid priority: {}", priority_str.trim())),
+                },
+                None => (filter, None),
+            };
+            let tags = tags_str
+                .split(',')
+                .map(|tag| tag.trim().to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect::<Vec<String>>();
+            if tags.is_empty() && priority.is_none() {
+                return Err(format!("Preset {} has no tags or priority", name));
+            }
+            presets.push(FilterPreset {
+                name: name.to_string(),
+                tags,
+                priority,
+                overdue: false,
+            });
+        }
+        Ok(presets)
+    }
+}
+
+impl Display for FilterPreset {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.tags.join(", "))?;
+        if let Some(priority) = &self.priority {
+            write!(f, " @ {}", priority)?;
+        }
+        Ok(())
+    }
+}
+
+/// Built in filters that are toggled with a single key, pressing the same key again clears it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickFilter {
+    Overdue,
+    Watched,
+    Blocked,
+    HighPriority,
+}
+
+impl QuickFilter {
+    pub fn all() -> Vec<QuickFilter> {
+        vec![
+            QuickFilter::Overdue,
+            QuickFilter::Watched,
+            QuickFilter::Blocked,
+            QuickFilter::HighPriority,
+        ]
+    }
+
+    pub fn preset(&self) -> FilterPreset {
+        let (tags, priority, overdue) = match self {
+            QuickFilter::Overdue => (vec![], None, true),
+            QuickFilter::Watched => (vec![WATCHED_TAG.to_string()], None, false),
+            QuickFilter::Blocked => (vec![BLOCKED_TAG.to_string()], None, false),
+            QuickFilter::HighPriority => (vec![], Some(CardPriority::High), false),
+        };
+        FilterPreset {
+            name: self.to_string(),
+            tags,
+            priority,
+            overdue,
+        }
+    }
+
+    /// The quick filter the preset was made from, `None` for any other preset
+    pub fn from_preset(preset: &FilterPreset) -> Option<QuickFilter> {
+        QuickFilter::all()
+            .into_iter()
+            .find(|quick_filter| quick_filter.preset() == *preset)
+    }
+}
+
+impl Display for QuickFilter {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            QuickFilter::Overdue => write!(f, "Overdue Cards"),
+            QuickFilter::Watched => write!(f, "Watched Cards"),
+            QuickFilter::Blocked => write!(f, "Blocked Cards"),
+            QuickFilter::HighPriority => write!(f, "High Priority Cards"),
+        }
+    }
+}
+
+/// Draws the border of cards matching a filter preset in a color, highlights earlier in the list
+/// take priority when a card matches more than one
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CardHighlight {
+    pub preset: String,
+    pub color: (u8, u8, u8),
+}
+
+impl CardHighlight {
+    /// Parses a list of highlights written as "Preset = #RRGGBB; Other Preset = r,g,b", every
+    /// preset has to be one of the given filter presets
+    pub fn list_from_string(
+        s: &str,
+        filter_presets: &[FilterPreset],
+    ) -> Result<Vec<CardHighlight>, String> {
+        let mut highlights: Vec<CardHighlight> = Vec::new();
+        if s.trim().eq_ignore_ascii_case("none") {
+            return Ok(highlights);
+        }
+        for highlight_str in s.split(';').filter(|h| !h.trim().is_empty()) {
+            let (preset, color) = match highlight_str.split_once('=') {
+                Some((preset, color)) => (preset.trim(), color.trim()),
+                None => {
+                    return Err(format!(
+                        "Missing '=' in highlight: {}",
+                        highlight_str.trim()
+                    ))
+                }
+            };
+            let preset = match filter_presets
+                .iter()
+                .find(|filter_preset| filter_preset.name.eq_ignore_ascii_case(preset))
+            {
+                Some(filter_preset) => filter_preset.name.clone(),
+                None => return Err(format!("No filter preset named: {}", preset)),
+            };
+            if highlights
+                .iter()
+                .any(|highlight| highlight.preset == preset)
+            {
+                return Err(format!("Duplicate highlight for preset: {}", preset));
+            }
+            let color = match parse_custom_color(color)? {
+                Color::Rgb(r, g, b) => (r, g, b),
+                _ => return Err(format!("Invalid color: {}", color)),
+            };
+            highlights.push(CardHighlight { preset, color });
+        }
+        Ok(highlights)
+    }
+}
+
+impl Display for CardHighlight {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (r, g, b) = self.color;
+        write!(f, "{} = #{:02x}{:02x}{:02x}", self.preset, r, g, b)
+    }
+}
+
+/// A command palette entry that runs actions in order as if their keys were pressed, the actions
+/// are named like in the keybindings of the config
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomCommand {
+    pub name: String,
+    pub actions: Vec<String>,
+}
+
+impl CustomCommand {
+    /// Parses a list of commands written as "Name = action_one, action_two; Other = action",
+    /// every action has to be one of the keybinding actions
+    pub fn list_from_string(
+        s: &str,
+        keybindings: &KeyBindings,
+    ) -> Result<Vec<CustomCommand>, String> {
+        let mut commands: Vec<CustomCommand> = Vec::new();
+        if s.trim().eq_ignore_ascii_case("none") {
+            return Ok(commands);
+        }
+        for command_str in s.split(';').filter(|c| !c.trim().is_empty()) {
+            let (name, actions) = match command_str.split_once('=') {
+                Some((name, actions)) => (name.trim(), actions.trim()),
+                None => return Err(format!("Missing '=' in command: {}", command_str.trim())),
+            };
+            if name.is_empty() {
+                return Err(format!("Missing name in command: {}", command_str.trim()));
+            }
+            if commands.iter().any(|command| command.name == name) {
+                return Err(format!("Duplicate command name: {}", name));
+            }
+            let command = CustomCommand {
+                name: name.to_string(),
+                actions: actions
+                    .split(',')
+                    .map(|action| action.trim().to_string())
+                    .filter(|action| !action.is_empty())
+                    .collect(),
+            };
+            if command.actions.is_empty() {
+                return Err(format!("Command {} has no actions", name));
+            }
+            if let Some(unknown_action) = command.unknown_actions(keybindings).first() {
+                return Err(format!("Unknown action: {}", unknown_action));
+            }
+            commands.push(command);
+        }
+        Ok(commands)
+    }
+
+    /// Actions of the command that are not keybinding actions
+    pub fn unknown_actions(&self, keybindings: &KeyBindings) -> Vec<String> {
+        self.actions
+            .iter()
+            .filter(|action| KeyBindings::str_to_action(keybindings.clone(), action).is_none())
+            .cloned()
+            .collect()
+    }
+
+    /// The keys that run the actions of the command, an action without a key of its own uses
+    /// its key chord
+    pub fn keys(&self, keybindings: &KeyBindings) -> Result<Vec<Key>, String> {
+        let mut keys = Vec::new();
+        for action in &self.actions {
+            let bound_key = keybindings
+                .iter()
+                .find(|(name, _)| *name == action.as_str())
+                .and_then(|(_, bound_keys)| bound_keys.first().copied());
+            if let Some(bound_key) = bound_key {
+                keys.push(bound_key);
+                continue;
+            }
+            match keybindings
+                .chords
+                .iter()
+                .find(|chord| chord.action == *action)
+            {
+                Some(chord) => keys.extend(chord.keys.iter().copied()),
+                None => return Err(format!("Action {} has no key to run it with", action)),
+            }
+        }
+        Ok(keys)
+    }
+}
+
+impl Display for CustomCommand {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.actions.join(", "))
+    }
+}
+
+/// Glyphs shown next to cards for each status so the status can be read without color
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CardStatusGlyphs {
+    pub active: String,
+    pub complete: String,
+    pub stale: String,
+}
+
+impl Default for CardStatusGlyphs {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+impl CardStatusGlyphs {
+    pub fn unicode() -> Self {
+        Self {
+            active: CardStatus::Active.glyph().to_string(),
+            complete: CardStatus::Complete.glyph().to_string(),
+            stale: CardStatus::Stale.glyph().to_string(),
+        }
+    }
+
+    pub fn ascii() -> Self {
+        Self {
+            active: CardStatus::Active.ascii_glyph().to_string(),
+            complete: CardStatus::Complete.ascii_glyph().to_string(),
+            stale: CardStatus::Stale.ascii_glyph().to_string(),
+        }
+    }
+
+    pub fn get(&self, status: &CardStatus) -> &str {
+        match status {
+            CardStatus::Active => &self.active,
+            CardStatus::Complete => &self.complete,
+            CardStatus::Stale => &self.stale,
+        }
+    }
+
+    /// Parses "Unicode", "ASCII" or the glyphs for active, complete and stale separated by commas
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "unicode" => return Some(Self::unicode()),
+            "ascii" => return Some(Self::ascii()),
+            _ => {}
+        }
+        let glyphs = s.split(',').map(|g| g.trim()).collect::<Vec<&str>>();
+        if glyphs.len() != 3 || glyphs.iter().any(|g| g.is_empty()) {
+            return None;
+        }
+        Some(Self {
+            active: glyphs[0].to_string(),
+            complete: glyphs[1].to_string(),
+            stale: glyphs[2].to_string(),
+        })
+    }
+}
+
+impl Display for CardStatusGlyphs {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.active, self.complete, self.stale)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Version of the config format, a config from a newer version is still loaded
+    pub config_version: u32,
+    pub save_directory: PathBuf,
+    pub default_view: UiMode,
+    /// Loads the newest save file on startup, when off the app starts with no boards
+    #[serde(alias = "auto_load_latest")]
+    pub always_load_last_save: bool,
+    pub save_on_exit: bool,
+    pub disable_scrollbars: bool,
+    pub warning_delta: u16,
+    /// Active cards that were not changed for this many days are marked as stale, 0 never marks
+    /// cards as stale
+    pub stale_after_days: u16,
+    pub keybindings: KeyBindings,
+    pub tickrate: u64,
+    pub no_of_cards_to_show: u16,
+    pub no_of_boards_to_show: u16,
+    /// Number of cards a board can hold before its header shows a warning, 0 turns the warning
+    /// off, boards can override it with their own limit
+    pub board_card_limit: u16,
+    /// Number of cards listed by the "Recently Modified Cards" command
+    pub recent_cards_count: u16,
+    /// Terminals narrower than this show only the current board with the rest of the layout
+    /// left out, 0 turns the compact layout off
+    pub compact_layout_width: u16,
+    pub enable_mouse_support: bool,
+    /// Draw on the alternate screen so the terminal contents come back on exit, when off the last
+    /// frame is left in the scrollback
+    pub use_alternate_screen: bool,
+    /// Draw with ASCII characters only and without colors, for serial consoles and terminals
+    /// that cannot show unicode or colors
+    pub ascii_only: bool,
+    pub wrap_navigation: bool,
+    /// Let Tab and BackTab cycle past the last and first focus target, when off focus stops at
+    /// the ends so forms stay on the submit button
+    pub wrap_focus: bool,
+    /// Hovering a board or card makes it the current one, when off the mouse only highlights
+    /// and a click is needed. A key press keeps the focus until the mouse moves again
+    pub focus_follows_mouse: bool,
+    /// Show card due dates as "in 3 days" or "2 days ago" on boards instead of the date
+    pub relative_due_dates: bool,
+    /// Shown in the due date slot of cards without a due date, empty leaves the slot blank
+    pub no_due_date_text: String,
+    pub confirm_reset_ui: bool,
+    /// How changes that can be undone, such as deleting a board, are confirmed
+    pub undoable_change_confirmation: ConfirmationStyle,
+    /// How changes that cannot be undone, such as loading a save over the current boards or
+    /// deleting old archived cards, are confirmed. These always ask
+    pub permanent_change_confirmation: ConfirmationStyle,
+    pub navigation_acceleration: u16,
+    pub card_preview_fields: Vec<CardPreviewField>,
+    pub card_enter_action: CardEnterAction,
+    pub card_id_scheme: CardIdScheme,
+    /// Show the card id in front of the card title on boards and in the card view
+    pub show_card_ids: bool,
+    /// Cross out the names of completed cards, with ASCII only rendering they get a "[done]"
+    /// prefix instead
+    pub strikethrough_completed_cards: bool,
+    pub status_cycle: Vec<CardStatus>,
+    pub card_status_glyphs: CardStatusGlyphs,
+    /// Tags longer than this are cut short with an ellipsis on boards and in lists, the card
+    /// detail view always shows the whole tag
+    pub tag_display_length: u16,
+    pub tag_sort_order: TagSortOrder,
+    pub card_sort_tie_breaker: CardSortTieBreaker,
+    /// Heights of the help and log panels in rows and the share of the width in percent given to
+    /// the left pane of the split view, changed with the grow and shrink panel keys
+    pub help_panel_height: u16,
+    pub log_panel_height: u16,
+    pub split_view_ratio: u16,
+    /// Ordered workflow stages that replace the three statuses, empty uses the statuses
+    pub workflow_stages: Vec<WorkflowStage>,
+    pub filter_presets: Vec<FilterPreset>,
+    /// Filter presets whose matching cards are always drawn in a color, in priority order
+    pub card_highlights: Vec<CardHighlight>,
+    /// Commands added to the command palette, each runs a list of actions
+    pub custom_commands: Vec<CustomCommand>,
+    pub remember_last_filter: bool,
+    /// The filter that was active when the app was last used, reapplied on startup
+    pub last_filter: Option<FilterPreset>,
+    pub remember_boards_hiding_completed_cards: bool,
+    /// Boards that hid their completed cards when the app was last used, only kept when
+    /// remembering them is turned on
+    pub boards_hiding_completed_cards: Vec<u128>,
+    /// Card copied with "Copy Card", kept in the config so it can be pasted after loading
+    /// another save or switching the save directory
+    pub card_clipboard: Option<Card>,
+    /// Ask for a short note describing the save when saving manually
+    pub prompt_for_save_note: bool,
+    pub auto_save_on: Vec<AutoSaveTrigger>,
+    /// Saves a new version of the boards this often while they have unsaved changes, None turns
+    /// it off
+    pub auto_save_interval_secs: Option<u64>,
+    /// Only the newest this many save files are kept, older ones are deleted after a save, None
+    /// keeps every save
+    pub max_saves_to_keep: Option<usize>,
+    /// Archived cards older than this many days are deleted at startup, 0 keeps them forever
+    pub archive_retention_days: u16,
+    pub focus_timer_work_minutes: u16,
+    pub focus_timer_break_minutes: u16,
+    pub default_theme: String,
+    pub date_format: DateFormat,
+    pub date_locale: DateLocale,
+    pub command_usage: HashMap<String, u32>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        let default_view = UiMode::TitleBodyHelpLog;
+        let default_theme = Theme::default();
+        Self {
+            config_version: CONFIG_FILE_VERSION,
+            save_directory: get_default_save_directory(),
+            default_view,
+            always_load_last_save: true,
+            save_on_exit: true,
+            disable_scrollbars: false,
+            warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
+            stale_after_days: 0,
+            keybindings: KeyBindings::default(),
+            tickrate: DEFAULT_TICKRATE,
+            no_of_cards_to_show: NO_OF_CARDS_PER_BOARD,
+            no_of_boards_to_show: NO_OF_BOARDS_PER_PAGE,
+            board_card_limit: 0,
+            recent_cards_count: DEFAULT_RECENT_CARDS_COUNT,
+            compact_layout_width: MIN_TERM_WIDTH,
+            enable_mouse_support: true,
+            use_alternate_screen: true,
+            ascii_only: false,
+            wrap_navigation: true,
+            wrap_focus: true,
+            focus_follows_mouse: true,
+            relative_due_dates: false,
+            no_due_date_text: FIELD_NOT_SET.to_string(),
+            confirm_reset_ui: true,
+            undoable_change_confirmation: ConfirmationStyle::None,
+            permanent_change_confirmation: ConfirmationStyle::Popup,
+            navigation_acceleration: 1,
+            card_preview_fields: CardPreviewField::default_fields(),
+            card_enter_action: CardEnterAction::default(),
+            card_id_scheme: CardIdScheme::default(),
+            show_card_ids: false,
+            strikethrough_completed_cards: false,
+            status_cycle: CardStatus::all(),
+            card_status_glyphs: CardStatusGlyphs::default(),
+            tag_display_length: DEFAULT_TAG_DISPLAY_LENGTH,
+            tag_sort_order: TagSortOrder::default(),
+            card_sort_tie_breaker: CardSortTieBreaker::default(),
+            help_panel_height: DEFAULT_HELP_PANEL_HEIGHT,
+            log_panel_height: DEFAULT_LOG_PANEL_HEIGHT,
+            split_view_ratio: DEFAULT_SPLIT_VIEW_RATIO,
+            workflow_stages: Vec::new(),
+            filter_presets: Vec::new(),
+            card_highlights: Vec::new(),
+            custom_commands: Vec::new(),
+            remember_last_filter: true,
+            last_filter: None,
+            remember_boards_hiding_completed_cards: false,
+            boards_hiding_completed_cards: Vec::new(),
+            card_clipboard: None,
+            prompt_for_save_note: true,
+            auto_save_on: Vec::new(),
+            auto_save_interval_secs: None,
+            max_saves_to_keep: None,
+            archive_retention_days: 0,
+            focus_timer_work_minutes: DEFAULT_FOCUS_TIMER_WORK_MINUTES,
+            focus_timer_break_minutes: DEFAULT_FOCUS_TIMER_BREAK_MINUTES,
+            default_theme: default_theme.name,
+            date_format: DateFormat::default(),
+            date_locale: DateLocale::default(),
+            command_usage: HashMap::new(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// The save directory with `~` and environment variables expanded
+    pub fn get_save_directory(&self) -> PathBuf {
+        expand_path(&self.save_directory.to_string_lossy())
+    }
+
+    pub fn to_list(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![
+                String::from("Save Directory"),
+                self.save_directory.to_str().unwrap().to_string(),
+            ],
+            vec![
+                String::from("Select Default View"),
+                self.default_view.to_string(),
+            ],
+            vec![
+                String::from("Auto Load Last Save"),
+                self.always_load_last_save.to_string(),
+            ],
+            vec![
+                String::from("Auto Save on Exit"),
+                self.save_on_exit.to_string(),
+            ],
+            vec![
+                String::from("Disable Scrollbars"),
+                self.disable_scrollbars.to_string(),
+            ],
+            vec![
+                String::from("Number of Days to Warn Before Due Date"),
+                self.warning_delta.to_string(),
+            ],
+            vec![
+                String::from("Mark Cards Stale After Days"),
+                self.stale_after_days.to_string(),
+            ],
+            vec![String::from("Tickrate"), self.tickrate.to_string()],
+            vec![
+                String::from("Number of Cards to Show per board"),
+                self.no_of_cards_to_show.to_string(),
+            ],
+            vec![
+                String::from("Number of Boards to Show"),
+                self.no_of_boards_to_show.to_string(),
+            ],
+            vec![
+                String::from("Board Card Limit"),
+                self.board_card_limit.to_string(),
+            ],
+            vec![
+                String::from("Recent Cards Count"),
+                self.recent_cards_count.to_string(),
+            ],
+            vec![
+                String::from("Compact Layout Width"),
+                self.compact_layout_width.to_string(),
+            ],
+            vec![
+                String::from("Enable Mouse Support"),
+                self.enable_mouse_support.to_string(),
+            ],
+            vec![
+                String::from("Use Alternate Screen"),
+                self.use_alternate_screen.to_string(),
+            ],
+            vec![
+                String::from("ASCII Only Rendering"),
+                self.ascii_only.to_string(),
+            ],
+            vec![
+                String::from("Wrap Navigation"),
+                self.wrap_navigation.to_string(),
+            ],
+            vec![String::from("Wrap Focus"), self.wrap_focus.to_string()],
+            vec![
+                String::from("Focus Follows Mouse"),
+                self.focus_follows_mouse.to_string(),
+            ],
+            vec![
+                String::from("Relative Due Dates"),
+                self.relative_due_dates.to_string(),
+            ],
+            vec![
+                String::from("No Due Date Text"),
+                self.no_due_date_text.clone(),
+            ],
+            vec![
+                String::from("Confirm UI Reset"),
+                self.confirm_reset_ui.to_string(),
+            ],
+            vec![
+                String::from("Confirm Undoable Changes"),
+                self.undoable_change_confirmation.to_string(),
+            ],
+            vec![
+                String::from("Confirm Permanent Changes"),
+                self.permanent_change_confirmation.to_string(),
+            ],
+            vec![
+                String::from("Remember Last Filter"),
+                self.remember_last_filter.to_string(),
+            ],
+            vec![
+                String::from("Remember Boards Hiding Completed Cards"),
+                self.remember_boards_hiding_completed_cards.to_string(),
+            ],
+            vec![
+                String::from("Prompt for Save Note"),
+                self.prompt_for_save_note.to_string(),
+            ],
+            vec![
+                String::from("Navigation Acceleration"),
+                self.navigation_acceleration.to_string(),
+            ],
+            vec![
+                String::from("Card Preview Fields"),
+                self.card_preview_fields
+                    .iter()
+                    .map(|field| field.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ],
+            vec![
+                String::from("Card Enter Action"),
+                self.card_enter_action.to_string(),
+            ],
+            vec![
+                String::from("Card ID Scheme"),
+                self.card_id_scheme.to_string(),
+            ],
+            vec![
+                String::from("Show Card IDs"),
+                self.show_card_ids.to_string(),
+            ],
+            vec![
+                String::from("Strikethrough Completed Cards"),
+                self.strikethrough_completed_cards.to_string(),
+            ],
+            vec![
+                String::from("Status Cycle"),
+                self.status_cycle
+                    .iter()
+                    .map(|status| status.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ],
+            vec![
+                String::from("Card Status Glyphs"),
+                self.card_status_glyphs.to_string(),
+            ],
+            vec![
+                String::from("Tag Display Length"),
+                self.tag_display_length.to_string(),
+            ],
+            vec![
+                String::from("Tag Sort Order"),
+                self.tag_sort_order.to_string(),
+            ],
+            vec![
+                String::from("Card Sort Tie Breaker"),
+                self.card_sort_tie_breaker.to_string(),
+            ],
+            vec![
+                String::from("Help Panel Height"),
+                self.help_panel_height.to_string(),
+            ],
+            vec![
+                String::from("Log Panel Height"),
+                self.log_panel_height.to_string(),
+            ],
+            vec![
+                String::from("Split View Ratio"),
+                self.split_view_ratio.to_string(),
+            ],
+            vec![
+                String::from("Workflow Stages"),
+                if self.workflow_stages.is_empty() {
+                    String::from("None")
+                } else {
+                    self.workflow_stages
+                        .iter()
+                        .map(|stage| stage.to_string())
+                        .collect::<Vec<String>>()
+                        .join("; ")
+                },
+            ],
+            vec![
+                String::from("Filter Presets"),
+                self.filter_presets
+                    .iter()
+                    .map(|preset| preset.to_string())
+                    .collect::<Vec<String>>()
+                    .join("; "),
+            ],
+            vec![
+                String::from("Card Highlights"),
+                if self.card_highlights.is_empty() {
+                    String::from("None")
+                } else {
+                    self.card_highlights
+                        .iter()
+                        .map(|highlight| highlight.to_string())
+                        .collect::<Vec<String>>()
+                        .join("; ")
+                },
+            ],
+            vec![
+                String::from("Custom Commands"),
+                if self.custom_commands.is_empty() {
+                    String::from("None")
+                } else {
+                    self.custom_commands
+                        .iter()
+                        .map(|command| command.to_string())
+                        .collect::<Vec<String>>()
+                        .join("; ")
+                },
+            ],
+            vec![
+                String::from("Auto Save On"),
+                if self.auto_save_on.is_empty() {
+                    String::from("None")
+                } else {
+                    self.auto_save_on
+                        .iter()
+                        .map(|trigger| trigger.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                },
+            ],
+            vec![
+                String::from("Auto Save Interval Seconds"),
+                match self.auto_save_interval_secs {
+                    Some(interval) => interval.to_string(),
+                    None => String::from("None"),
+                },
+            ],
+            vec![
+                String::from("Max Saves to Keep"),
+                match self.max_saves_to_keep {
+                    Some(max_saves) => max_saves.to_string(),
+                    None => String::from("None"),
+                },
+            ],
+            vec![
+                String::from("Archived Card Retention Days"),
+                self.archive_retention_days.to_string(),
+            ],
+            vec![
+                String::from("Focus Timer Work Minutes"),
+                self.focus_timer_work_minutes.to_string(),
+            ],
+            vec![
+                String::from("Focus Timer Break Minutes"),
+                self.focus_timer_break_minutes.to_string(),
+            ],
+            vec![
+                String::from("Default Theme"),
+                self.default_theme.to_string(),
+            ],
+            vec![
+                String::from("Default Date Format"),
+                self.date_format.to_human_readable_string().to_string(),
+            ],
+            vec![
+                String::from("Date Parsing Locale"),
+                self.date_locale.to_string(),
+            ],
+            vec![String::from("Edit Keybindings")],
+        ]
+    }
+
+    pub fn edit_with_string(change_str: &str, app: &mut App) -> Self {
+        let mut config = app.config.clone();
+        let lines = change_str.lines();
+        for line in lines {
+            let mut parts = line.split(':');
+            let key = parts.next().unwrap_or("").trim();
+            let value = parts.next().unwrap_or("").trim();
+            match key {
+                "Save Directory" => {
+                    let new_path = PathBuf::from(value);
+                    // the path is stored as written so environment variables are expanded again
+                    // on every start, a missing directory is created as the editor offers
+                    let expanded_path = expand_path(value);
+                    let existed = expanded_path.exists();
+                    match validate_save_directory(&expanded_path) {
+                        Ok(_) => {
+                            if !existed {
+                                app.send_info_toast(
+                                    &format!("Created save directory {}", value),
+                                    None,
+                                );
+                            }
+                            config.save_directory = new_path;
+                        }
+                        Err(e) => {
+                            error!("Invalid save directory {}: {}", value, e);
+                            app.send_error_toast(
+                                &format!("Invalid save directory {}: {}", value, e),
+                                None,
+                            );
+                        }
+                    }
+                }
+                "Select Default View" => {
+                    let new_ui_mode = UiMode::from_string(value);
+                    if let Some(new_ui_mode) = new_ui_mode {
+                        config.default_view = new_ui_mode;
+                    } else {
+                        error!("Invalid UiMode: {}", value);
+                        app.send_error_toast(&format!("Invalid UiMode: {}", value), None);
+                        info!("Valid UiModes are: {:?}", UiMode::all());
+                    }
+                }
+                "Auto Load Last Save" => {
+                    if value.to_lowercase() == "true" {
+                        config.always_load_last_save = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.always_load_last_save = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Auto Save on Exit" => {
+                    if value.to_lowercase() == "true" {
+                        config.save_on_exit = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.save_on_exit = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
                 "Disable Scrollbars" => {
                     if value.to_lowercase() == "true" {
                         config.disable_scrollbars = true;
@@ -1624,16 +3559,354 @@ impl AppConfig {
                         app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
                     }
                 }
-                "Enable Mouse Support" => {
-                    if value.to_lowercase() == "true" {
-                        config.enable_mouse_support = true;
-                    } else if value.to_lowercase() == "false" {
-                        config.enable_mouse_support = false;
+                "Use Alternate Screen" => {
+                    if value.to_lowercase() == "true" {
+                        config.use_alternate_screen = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.use_alternate_screen = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Enable Mouse Support" => {
+                    if value.to_lowercase() == "true" {
+                        config.enable_mouse_support = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.enable_mouse_support = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "ASCII Only Rendering" => {
+                    if value.to_lowercase() == "true" {
+                        config.ascii_only = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.ascii_only = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Wrap Navigation" => {
+                    if value.to_lowercase() == "true" {
+                        config.wrap_navigation = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.wrap_navigation = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Wrap Focus" => {
+                    if value.to_lowercase() == "true" {
+                        config.wrap_focus = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.wrap_focus = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Focus Follows Mouse" => {
+                    if value.to_lowercase() == "true" {
+                        config.focus_follows_mouse = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.focus_follows_mouse = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Relative Due Dates" => {
+                    if value.to_lowercase() == "true" {
+                        config.relative_due_dates = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.relative_due_dates = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Confirm UI Reset" => {
+                    if value.to_lowercase() == "true" {
+                        config.confirm_reset_ui = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.confirm_reset_ui = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Confirm Undoable Changes" | "Confirm Permanent Changes" => {
+                    let permanent = key == "Confirm Permanent Changes";
+                    match ConfirmationStyle::from_string(value) {
+                        Some(ConfirmationStyle::None) if permanent => {
+                            error!("Changes that cannot be undone always ask for confirmation");
+                            app.send_error_toast(
+                                "Changes that cannot be undone always ask for confirmation, use Popup or Type Name",
+                                None,
+                            );
+                        }
+                        Some(new_style) if permanent => {
+                            config.permanent_change_confirmation = new_style
+                        }
+                        Some(new_style) => config.undoable_change_confirmation = new_style,
+                        None => {
+                            let all_styles = ConfirmationStyle::all()
+                                .iter()
+                                .map(|style| style.to_string())
+                                .collect::<Vec<String>>()
+                                .join(", ");
+                            error!("Invalid confirmation style: {}", value);
+                            app.send_error_toast(
+                                &format!(
+                                    "Invalid confirmation style: {}, valid styles are: {}",
+                                    value, all_styles
+                                ),
+                                None,
+                            );
+                        }
+                    }
+                }
+                "Remember Last Filter" => {
+                    if value.to_lowercase() == "true" {
+                        config.remember_last_filter = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.remember_last_filter = false;
+                        config.last_filter = None;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Remember Boards Hiding Completed Cards" => {
+                    if value.to_lowercase() == "true" {
+                        config.remember_boards_hiding_completed_cards = true;
+                        config.boards_hiding_completed_cards =
+                            app.state.boards_hiding_completed_cards.clone();
+                    } else if value.to_lowercase() == "false" {
+                        config.remember_boards_hiding_completed_cards = false;
+                        config.boards_hiding_completed_cards = vec![];
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Show Card IDs" => {
+                    if value.to_lowercase() == "true" {
+                        config.show_card_ids = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.show_card_ids = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Strikethrough Completed Cards" => {
+                    if value.to_lowercase() == "true" {
+                        config.strikethrough_completed_cards = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.strikethrough_completed_cards = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Prompt for Save Note" => {
+                    if value.to_lowercase() == "true" {
+                        config.prompt_for_save_note = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.prompt_for_save_note = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Board Card Limit" => match value.parse::<u16>() {
+                    Ok(new_limit) => {
+                        config.board_card_limit = new_limit;
+                    }
+                    Err(_) => {
+                        error!("Invalid board card limit: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Board card limit must be a number, 0 turns it off, got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Recent Cards Count" => match value.parse::<u16>() {
+                    Ok(new_count) if new_count > 0 => {
+                        config.recent_cards_count = new_count;
+                    }
+                    _ => {
+                        error!("Invalid recent cards count: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Recent cards count must be a number above 0, got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Compact Layout Width" => match value.parse::<u16>() {
+                    Ok(new_width) if new_width == 0 || new_width >= MIN_COMPACT_TERM_WIDTH => {
+                        config.compact_layout_width = new_width;
+                    }
+                    _ => {
+                        error!("Invalid compact layout width: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Compact layout width must be 0 or a number from {}, got: {}",
+                                MIN_COMPACT_TERM_WIDTH, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Navigation Acceleration" => match value.parse::<u16>() {
+                    Ok(new_acceleration)
+                        if (1..=MAX_NAVIGATION_ACCELERATION).contains(&new_acceleration) =>
+                    {
+                        config.navigation_acceleration = new_acceleration;
+                    }
+                    _ => {
+                        error!("Invalid navigation acceleration: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Navigation acceleration must be a number from 1 to {}, got: {}",
+                                MAX_NAVIGATION_ACCELERATION, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "No Due Date Text" => {
+                    config.no_due_date_text = value.trim().to_string();
+                }
+                "Tag Display Length" => match value.parse::<u16>() {
+                    Ok(new_length) if new_length >= MIN_TAG_DISPLAY_LENGTH => {
+                        config.tag_display_length = new_length;
+                    }
+                    _ => {
+                        error!("Invalid tag display length: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Tag display length must be a number of at least {}, got: {}",
+                                MIN_TAG_DISPLAY_LENGTH, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Tag Sort Order" => {
+                    if let Some(new_order) = TagSortOrder::from_string(value) {
+                        config.tag_sort_order = new_order;
+                    } else {
+                        let all_orders = TagSortOrder::all()
+                            .iter()
+                            .map(|order| order.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid tag sort order: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid tag sort order: {}, valid orders are: {}",
+                                value, all_orders
+                            ),
+                            None,
+                        );
+                    }
+                }
+                "Card Sort Tie Breaker" => {
+                    if let Some(new_tie_breaker) = CardSortTieBreaker::from_string(value) {
+                        config.card_sort_tie_breaker = new_tie_breaker;
                     } else {
-                        error!("Invalid boolean: {}", value);
-                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                        let all_tie_breakers = CardSortTieBreaker::all()
+                            .iter()
+                            .map(|tie_breaker| tie_breaker.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid card sort tie breaker: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid card sort tie breaker: {}, valid tie breakers are: {}",
+                                value, all_tie_breakers
+                            ),
+                            None,
+                        );
                     }
                 }
+                "Help Panel Height" => match value.parse::<u16>() {
+                    Ok(new_height)
+                        if (MIN_HELP_PANEL_HEIGHT..=MAX_HELP_PANEL_HEIGHT)
+                            .contains(&new_height) =>
+                    {
+                        config.help_panel_height = new_height;
+                    }
+                    _ => {
+                        error!("Invalid help panel height: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Help panel height must be a number from {} to {}, got: {}",
+                                MIN_HELP_PANEL_HEIGHT, MAX_HELP_PANEL_HEIGHT, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Log Panel Height" => match value.parse::<u16>() {
+                    Ok(new_height)
+                        if (MIN_LOG_PANEL_HEIGHT..=MAX_LOG_PANEL_HEIGHT).contains(&new_height) =>
+                    {
+                        config.log_panel_height = new_height;
+                    }
+                    _ => {
+                        error!("Invalid log panel height: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Log panel height must be a number from {} to {}, got: {}",
+                                MIN_LOG_PANEL_HEIGHT, MAX_LOG_PANEL_HEIGHT, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Split View Ratio" => match value.parse::<u16>() {
+                    Ok(new_ratio)
+                        if (MIN_SPLIT_VIEW_RATIO..=MAX_SPLIT_VIEW_RATIO).contains(&new_ratio) =>
+                    {
+                        config.split_view_ratio = new_ratio;
+                    }
+                    _ => {
+                        error!("Invalid split view ratio: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Split view ratio must be a percentage from {} to {}, got: {}",
+                                MIN_SPLIT_VIEW_RATIO, MAX_SPLIT_VIEW_RATIO, value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Mark Cards Stale After Days" => match value.parse::<u16>() {
+                    Ok(new_stale_after_days) => {
+                        config.stale_after_days = new_stale_after_days;
+                        app.state.last_stale_check = None;
+                    }
+                    Err(_) => {
+                        error!("Invalid number: {}", value);
+                        app.send_error_toast(
+                            &format!("Expected number of days (integer), got: {}", value),
+                            None,
+                        );
+                    }
+                },
                 "Number of Days to Warn Before Due Date" => {
                     let new_delta = value.parse::<u16>();
                     if let Ok(new_delta) = new_delta {
@@ -1772,6 +4045,343 @@ impl AppConfig {
                 "Default Date Format" => {
                     // TODO
                 }
+                "Card Preview Fields" => {
+                    let mut new_fields = Vec::new();
+                    let mut invalid_fields = Vec::new();
+                    for field_name in value.split(',').filter(|s| !s.trim().is_empty()) {
+                        match CardPreviewField::from_string(field_name) {
+                            Some(field) => {
+                                if !new_fields.contains(&field) {
+                                    new_fields.push(field);
+                                }
+                            }
+                            None => invalid_fields.push(field_name.trim().to_string()),
+                        }
+                    }
+                    if invalid_fields.is_empty() {
+                        config.card_preview_fields = new_fields;
+                    } else {
+                        let all_fields = CardPreviewField::all()
+                            .iter()
+                            .map(|field| field.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid card preview fields: {}", invalid_fields.join(", "));
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid card preview fields: {}, valid fields are: {}",
+                                invalid_fields.join(", "),
+                                all_fields
+                            ),
+                            None,
+                        );
+                    }
+                }
+                "Status Cycle" => {
+                    let mut new_cycle = Vec::new();
+                    let mut invalid_statuses = Vec::new();
+                    for status_name in value.split(',').filter(|s| !s.trim().is_empty()) {
+                        match CardStatus::from_string(status_name) {
+                            Some(status) => {
+                                if !new_cycle.contains(&status) {
+                                    new_cycle.push(status);
+                                }
+                            }
+                            None => invalid_statuses.push(status_name.trim().to_string()),
+                        }
+                    }
+                    if !invalid_statuses.is_empty() {
+                        let all_statuses = CardStatus::all()
+                            .iter()
+                            .map(|status| status.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid card statuses: {}", invalid_statuses.join(", "));
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid card statuses: {}, valid statuses are: {}",
+                                invalid_statuses.join(", "),
+                                all_statuses
+                            ),
+                            None,
+                        );
+                    } else if new_cycle.is_empty() {
+                        error!("Status cycle must contain at least one status");
+                        app.send_error_toast("Status cycle must contain at least one status", None);
+                    } else {
+                        config.status_cycle = new_cycle;
+                    }
+                }
+                "Workflow Stages" => {
+                    let stages_str = if value.to_lowercase() == "none" {
+                        ""
+                    } else {
+                        value
+                    };
+                    match WorkflowStage::list_from_string(stages_str) {
+                        Ok(new_stages) => {
+                            let migrated_cards =
+                                migrate_cards_to_workflow_stages(&mut app.boards, &new_stages);
+                            if migrated_cards > 0 {
+                                info!("Moved {} cards to workflow stages", migrated_cards);
+                                app.send_info_toast(
+                                    &format!("Moved {} cards to workflow stages", migrated_cards),
+                                    None,
+                                );
+                            }
+                            config.workflow_stages = new_stages;
+                        }
+                        Err(e) => {
+                            error!("Invalid workflow stages: {}", e);
+                            app.send_error_toast(&format!("Invalid workflow stages: {}", e), None);
+                        }
+                    }
+                }
+                "Card Status Glyphs" => match CardStatusGlyphs::from_string(value) {
+                    Some(new_glyphs) => {
+                        config.card_status_glyphs = new_glyphs;
+                    }
+                    None => {
+                        error!("Invalid card status glyphs: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Expected Unicode, ASCII or glyphs for active, complete and stale separated by commas, got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Auto Save On" => {
+                    let mut new_triggers = Vec::new();
+                    let mut invalid_triggers = Vec::new();
+                    for trigger_name in value
+                        .split(',')
+                        .filter(|s| !s.trim().is_empty() && s.trim().to_lowercase() != "none")
+                    {
+                        match AutoSaveTrigger::from_string(trigger_name) {
+                            Some(trigger) => {
+                                if !new_triggers.contains(&trigger) {
+                                    new_triggers.push(trigger);
+                                }
+                            }
+                            None => invalid_triggers.push(trigger_name.trim().to_string()),
+                        }
+                    }
+                    if !invalid_triggers.is_empty() {
+                        let all_triggers = AutoSaveTrigger::all()
+                            .iter()
+                            .map(|trigger| trigger.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!(
+                            "Invalid auto save triggers: {}",
+                            invalid_triggers.join(", ")
+                        );
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid auto save triggers: {}, valid triggers are: {} or None",
+                                invalid_triggers.join(", "),
+                                all_triggers
+                            ),
+                            None,
+                        );
+                    } else {
+                        config.auto_save_on = new_triggers;
+                    }
+                }
+                "Auto Save Interval Seconds" => {
+                    if value.trim().is_empty() || value.trim().eq_ignore_ascii_case("none") {
+                        config.auto_save_interval_secs = None;
+                    } else {
+                        match value.trim().parse::<u64>() {
+                            Ok(new_interval) if new_interval > 0 => {
+                                config.auto_save_interval_secs = Some(new_interval);
+                                app.state.last_periodic_save = None;
+                            }
+                            _ => {
+                                error!("Invalid auto save interval: {}", value);
+                                app.send_error_toast(
+                                    &format!(
+                                        "Auto save interval must be a number of seconds above 0 or None, got: {}",
+                                        value
+                                    ),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                }
+                "Max Saves to Keep" => {
+                    if value.trim().is_empty() || value.trim().eq_ignore_ascii_case("none") {
+                        config.max_saves_to_keep = None;
+                    } else {
+                        match value.trim().parse::<usize>() {
+                            Ok(new_max_saves) if new_max_saves > 0 => {
+                                config.max_saves_to_keep = Some(new_max_saves);
+                            }
+                            _ => {
+                                error!("Invalid max saves to keep: {}", value);
+                                app.send_error_toast(
+                                    &format!(
+                                        "Max saves to keep must be a number above 0 or None to keep every save, got: {}",
+                                        value
+                                    ),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                }
+                "Archived Card Retention Days" => match value.parse::<u16>() {
+                    Ok(new_retention_days) => {
+                        config.archive_retention_days = new_retention_days;
+                    }
+                    Err(_) => {
+                        error!("Invalid archived card retention days: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Archived card retention must be a number of days (0 to keep archived cards forever), got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Focus Timer Work Minutes" => match value.parse::<u16>() {
+                    Ok(new_minutes) if new_minutes > 0 => {
+                        config.focus_timer_work_minutes = new_minutes;
+                    }
+                    _ => {
+                        error!("Invalid focus timer work minutes: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Focus timer work minutes must be a number above 0, got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Focus Timer Break Minutes" => match value.parse::<u16>() {
+                    Ok(new_minutes) => {
+                        config.focus_timer_break_minutes = new_minutes;
+                    }
+                    Err(_) => {
+                        error!("Invalid focus timer break minutes: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Focus timer break minutes must be a number (0 to skip the break), got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                },
+                "Filter Presets" => match FilterPreset::list_from_string(value) {
+                    Ok(new_presets) => {
+                        config.filter_presets = new_presets;
+                    }
+                    Err(e) => {
+                        error!("Invalid filter presets: {}", e);
+                        app.send_error_toast(&format!("Invalid filter presets: {}", e), None);
+                        app.send_info_toast(
+                            "Expected format is Name = tag1, tag2 @ Priority; Other = tag3",
+                            None,
+                        );
+                    }
+                },
+                "Card Highlights" => {
+                    match CardHighlight::list_from_string(value, &config.filter_presets) {
+                        Ok(new_highlights) => {
+                            config.card_highlights = new_highlights;
+                        }
+                        Err(e) => {
+                            error!("Invalid card highlights: {}", e);
+                            app.send_error_toast(&format!("Invalid card highlights: {}", e), None);
+                            app.send_info_toast(
+                                "Expected format is Preset = #RRGGBB; Other Preset = r,g,b",
+                                None,
+                            );
+                        }
+                    }
+                }
+                "Custom Commands" => {
+                    match CustomCommand::list_from_string(value, &config.keybindings) {
+                        Ok(new_commands) => {
+                            config.custom_commands = new_commands;
+                        }
+                        Err(e) => {
+                            error!("Invalid custom commands: {}", e);
+                            app.send_error_toast(&format!("Invalid custom commands: {}", e), None);
+                            app.send_info_toast(
+                                "Expected format is Name = action_one, action_two; Other = action",
+                                None,
+                            );
+                        }
+                    }
+                }
+                "Card Enter Action" => {
+                    if let Some(new_action) = CardEnterAction::from_string(value) {
+                        config.card_enter_action = new_action;
+                    } else {
+                        let all_actions = CardEnterAction::all()
+                            .iter()
+                            .map(|action| action.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid card enter action: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid card enter action: {}, valid actions are: {}",
+                                value, all_actions
+                            ),
+                            None,
+                        );
+                    }
+                }
+                "Card ID Scheme" => {
+                    if let Some(new_scheme) = CardIdScheme::from_string(value) {
+                        config.card_id_scheme = new_scheme;
+                    } else {
+                        let all_schemes = CardIdScheme::all()
+                            .iter()
+                            .map(|scheme| scheme.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid card id scheme: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid card id scheme: {}, valid schemes are: {}",
+                                value, all_schemes
+                            ),
+                            None,
+                        );
+                    }
+                }
+                "Date Parsing Locale" => {
+                    if let Some(new_locale) = DateLocale::from_string(value) {
+                        config.date_locale = new_locale;
+                        app.send_info_toast(
+                            &format!("Date parsing locale set to {}", new_locale),
+                            None,
+                        );
+                    } else {
+                        let all_locales = DateLocale::all()
+                            .iter()
+                            .map(|locale| locale.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        error!("Invalid date locale: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Invalid date locale: {}, valid locales are: {}",
+                                value, all_locales
+                            ),
+                            None,
+                        );
+                    }
+                }
                 _ => {
                     debug!("Invalid key: {}", key);
                     app.send_error_toast("Something went wrong 😢 ", None);
@@ -1822,6 +4432,17 @@ impl AppConfig {
                     return Err(format!("Value {} is already assigned to {}", new_value, k));
                 }
             }
+            if let Some(chord) = current_bindings
+                .chords
+                .iter()
+                .find(|chord| chord.keys.first() == Some(new_value))
+            {
+                error!("Value {} starts the chord for {}", new_value, chord.action);
+                return Err(format!(
+                    "Value {} starts the chord for {}",
+                    new_value, chord.action
+                ));
+            }
         }
 
         debug!("Editing keybinding: {} to {:?}", key, value);
@@ -1854,6 +4475,40 @@ impl AppConfig {
             "clear_all_toasts" => self.keybindings.clear_all_toasts = value,
             "undo" => self.keybindings.undo = value,
             "redo" => self.keybindings.redo = value,
+            "jump_to_card" => self.keybindings.jump_to_card = value,
+            "dismiss_top_toast" => self.keybindings.dismiss_top_toast = value,
+            "toggle_board_focus_mode" => self.keybindings.toggle_board_focus_mode = value,
+            "cycle_card_status" => self.keybindings.cycle_card_status = value,
+            "reset_board_view" => self.keybindings.reset_board_view = value,
+            "toggle_card_selection" => self.keybindings.toggle_card_selection = value,
+            "show_quick_help" => self.keybindings.show_quick_help = value,
+            "move_card_to_top" => self.keybindings.move_card_to_top = value,
+            "move_card_to_bottom" => self.keybindings.move_card_to_bottom = value,
+            "toggle_split_view" => self.keybindings.toggle_split_view = value,
+            "switch_split_view_pane" => self.keybindings.switch_split_view_pane = value,
+            "clear_board" => self.keybindings.clear_board = value,
+            "toggle_focus_timer" => self.keybindings.toggle_focus_timer = value,
+            "toggle_log_panel" => self.keybindings.toggle_log_panel = value,
+            "change_card_recurrence" => self.keybindings.change_card_recurrence = value,
+            "grow_panel" => self.keybindings.grow_panel = value,
+            "shrink_panel" => self.keybindings.shrink_panel = value,
+            "export_board_and_copy_path" => self.keybindings.export_board_and_copy_path = value,
+            "toggle_expanded_card" => self.keybindings.toggle_expanded_card = value,
+            "toggle_hide_completed_cards" => self.keybindings.toggle_hide_completed_cards = value,
+            "toggle_relative_due_dates" => self.keybindings.toggle_relative_due_dates = value,
+            "next_theme" => self.keybindings.next_theme = value,
+            "prv_theme" => self.keybindings.prv_theme = value,
+            "navigate_back" => self.keybindings.navigate_back = value,
+            "navigate_forward" => self.keybindings.navigate_forward = value,
+            "toggle_board_hide_completed_cards" => {
+                self.keybindings.toggle_board_hide_completed_cards = value
+            }
+            "toggle_overdue_quick_filter" => self.keybindings.toggle_overdue_quick_filter = value,
+            "toggle_watched_quick_filter" => self.keybindings.toggle_watched_quick_filter = value,
+            "toggle_blocked_quick_filter" => self.keybindings.toggle_blocked_quick_filter = value,
+            "toggle_high_priority_quick_filter" => {
+                self.keybindings.toggle_high_priority_quick_filter = value
+            }
             _ => {
                 debug!("Invalid key: {}", key);
                 error!("Unable to edit keybinding");
@@ -1862,6 +4517,24 @@ impl AppConfig {
         }
         Ok(())
     }
+
+    pub fn edit_key_chord(&mut self, chord_index: usize, value: Vec<Key>) -> Result<(), String> {
+        if chord_index >= self.keybindings.chords.len() {
+            debug!("Invalid chord index: {}", chord_index);
+            error!("Unable to edit keybinding");
+            return Err("Unable to edit keybinding 😢 ".to_string());
+        }
+        if let Some(conflict) = self.keybindings.chord_conflict(&value, Some(chord_index)) {
+            error!("{}", conflict);
+            return Err(conflict);
+        }
+        debug!(
+            "Editing chord: {} to {:?}",
+            self.keybindings.chords[chord_index].action, value
+        );
+        self.keybindings.chords[chord_index].keys = value;
+        Ok(())
+    }
 }
 
 pub fn get_term_bg_color() -> (u8, u8, u8) {
@@ -1891,6 +4564,132 @@ pub fn date_format_finder(date_string: &str) -> Result<DateFormat, String> {
     Err("Invalid date format".to_string())
 }
 
+/// Number of calendar days between `today` and a due date written in `date_format`, the time
+/// of day is ignored so cards do not switch between the warning and overdue styles depending on
+/// the current time
+pub fn days_until_due(
+    due_date: &str,
+    date_format: DateFormat,
+    today: NaiveDate,
+) -> Result<i64, String> {
+    let due_date = if DateFormat::all_formats_with_time().contains(&date_format) {
+        NaiveDateTime::parse_from_str(due_date, date_format.to_parser_string())
+            .map(|date_time| date_time.date())
+    } else {
+        NaiveDate::parse_from_str(due_date, date_format.to_parser_string())
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(due_date.signed_duration_since(today).num_days())
+}
+
+/// Describes how far away a due date is, like "in 3 days" or "yesterday"
+pub fn relative_due_date_text(days_until_due: i64) -> String {
+    match days_until_due {
+        0 => String::from("today"),
+        1 => String::from("tomorrow"),
+        -1 => String::from("yesterday"),
+        days if days > 1 => format!("in {} days", days),
+        days => format!("{} days ago", -days),
+    }
+}
+
+/// Due date of the card, `None` when it has no due date or it cannot be read
+pub fn card_due_date(card: &Card) -> Option<NaiveDate> {
+    let date_format = date_format_finder(&card.date_due).ok()?;
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        NaiveDateTime::parse_from_str(&card.date_due, date_format.to_parser_string())
+            .map(|date_time| date_time.date())
+            .ok()
+    } else {
+        NaiveDate::parse_from_str(&card.date_due, date_format.to_parser_string()).ok()
+    }
+}
+
+/// Sorts the cards by due date, cards without a readable due date always come last and cards
+/// due on the same day are ordered by the tie breaker
+pub fn sort_cards_by_due_date(cards: &mut [Card], tie_breaker: CardSortTieBreaker) {
+    cards.sort_by(|a, b| {
+        let (a_due, b_due) = (card_due_date(a), card_due_date(b));
+        (a_due.is_none(), a_due)
+            .cmp(&(b_due.is_none(), b_due))
+            .then_with(|| tie_breaker.compare(a, b))
+    });
+}
+
+/// When the card was created, read from the `date_created` written with `Utc::now()`
+pub fn card_created_at(card: &Card) -> Option<NaiveDateTime> {
+    let date_created = card.date_created.trim().trim_end_matches("UTC").trim();
+    NaiveDateTime::parse_from_str(date_created, "%Y-%m-%d %H:%M:%S%.f").ok()
+}
+
+/// When the card was last changed, read from the `date_modified` written with `Utc::now()`
+pub fn card_modified_at(card: &Card) -> Option<NaiveDateTime> {
+    let date_modified = card.date_modified.trim().trim_end_matches("UTC").trim();
+    NaiveDateTime::parse_from_str(date_modified, "%Y-%m-%d %H:%M:%S%.f").ok()
+}
+
+/// Days left before an active card goes stale, counted from its last change. None when cards
+/// never go stale, the card is not active or its modified date cannot be read
+pub fn days_until_stale(card: &Card, stale_after_days: u16, now: NaiveDateTime) -> Option<i64> {
+    if stale_after_days == 0 || card.card_status != CardStatus::Active {
+        return None;
+    }
+    let modified_at = card_modified_at(card)?;
+    Some(stale_after_days as i64 - (now - modified_at).num_days())
+}
+
+/// Board and card ids of the most recently modified cards across all boards, newest first.
+/// Cards with a modified date that cannot be read come last
+pub fn recently_modified_cards(boards: &[Board], limit: usize) -> Vec<(u128, u128)> {
+    let mut cards = boards
+        .iter()
+        .flat_map(|board| {
+            board
+                .cards
+                .iter()
+                .map(move |card| (card_modified_at(card), board.id, card.id))
+        })
+        .collect::<Vec<(Option<NaiveDateTime>, u128, u128)>>();
+    cards.sort_by(|a, b| b.0.cmp(&a.0));
+    cards
+        .into_iter()
+        .take(limit)
+        .map(|(_, board_id, card_id)| (board_id, card_id))
+        .collect()
+}
+
+/// True when moving from one position to the other is a jump worth keeping in the navigation
+/// history, that is a move to another board or past more than one card on the same board
+pub fn is_navigation_jump(
+    boards: &[Board],
+    from: (u128, Option<u128>),
+    to: (u128, Option<u128>),
+) -> bool {
+    if from.0 != to.0 {
+        return true;
+    }
+    let card_index = |card_id: Option<u128>| {
+        let card_id = card_id?;
+        boards
+            .iter()
+            .find(|board| board.id == from.0)?
+            .cards
+            .iter()
+            .position(|card| card.id == card_id)
+    };
+    match (card_index(from.1), card_index(to.1)) {
+        (Some(from_index), Some(to_index)) => from_index.abs_diff(to_index) > 1,
+        _ => false,
+    }
+}
+
+/// Days until the milestone of the board, `None` when it has no due date or it cannot be read
+pub fn board_days_until_due(board: &Board, today: NaiveDate) -> Option<i64> {
+    let due_date = board.due_date.as_ref()?;
+    let date_format = date_format_finder(due_date).ok()?;
+    days_until_due(due_date, date_format, today).ok()
+}
+
 pub fn date_format_converter(date_string: &str, date_format: DateFormat) -> Result<String, String> {
     if date_string == FIELD_NOT_SET || date_string.is_empty() {
         return Ok(date_string.to_string());
@@ -1967,8 +4766,498 @@ pub fn date_format_converter(date_string: &str, date_format: DateFormat) -> Resu
     }
 }
 
+/// Parses dates written with month names in the given locale (e.g. "15 March 2023" or "15 de marzo de 2023")
+/// and converts them to the given date format. Returns Ok(None) when the input does not contain a month name
+/// so that it can be handled by date_format_converter, and Err with a message asking for clarification when
+/// the input is ambiguous
+pub fn parse_localized_date(
+    date_string: &str,
+    locale: DateLocale,
+    date_format: DateFormat,
+) -> Result<Option<String>, String> {
+    if date_string == FIELD_NOT_SET || date_string.is_empty() {
+        return Ok(None);
+    }
+    let mut month = None;
+    let mut numbers = vec![];
+    let mut time = None;
+    let tokens = date_string
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '/' || c == '-' || c == '.')
+        .filter(|token| !token.is_empty());
+    for token in tokens {
+        if token.contains(':') {
+            let parsed_time = NaiveTime::parse_from_str(token, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(token, "%H:%M"));
+            if let Ok(parsed_time) = parsed_time {
+                time = Some(parsed_time);
+                continue;
+            }
+            return Err(format!("Could not understand the time '{}'", token));
+        }
+        let lowercase_token = token.to_lowercase();
+        // remove english ordinal suffixes like 1st, 2nd, 3rd, 4th
+        let numeric_part = lowercase_token
+            .trim_end_matches("st")
+            .trim_end_matches("nd")
+            .trim_end_matches("rd")
+            .trim_end_matches("th");
+        if !numeric_part.is_empty() && numeric_part.chars().all(|c| c.is_ascii_digit()) {
+            numbers.push(numeric_part.to_string());
+        } else if let Some(month_number) = locale.month_from_name(&lowercase_token) {
+            if month.is_some() {
+                return Err(format!(
+                    "'{}' contains more than one month, please enter only one",
+                    date_string
+                ));
+            }
+            month = Some(month_number);
+        } else if locale.filler_words().contains(&lowercase_token.as_str()) {
+            continue;
+        } else if month.is_none() && lowercase_token.chars().all(|c| c.is_alphabetic()) {
+            // could be a month name from another locale, leave it to the other parsers
+            return Ok(None);
+        } else {
+            return Err(format!(
+                "Could not understand '{}' in '{}'",
+                token, date_string
+            ));
+        }
+    }
+    let month = if let Some(month) = month {
+        month
+    } else {
+        return Ok(None);
+    };
+    let (day, year) = match numbers.len() {
+        2 => {
+            let four_digit_numbers = numbers.iter().filter(|n| n.len() == 4).count();
+            if four_digit_numbers != 1 {
+                return Err(format!(
+                    "'{}' is ambiguous, it could be either {} or {} as the day, please write the year with four digits",
+                    date_string, numbers[0], numbers[1]
+                ));
+            }
+            if numbers[0].len() == 4 {
+                (numbers[1].clone(), numbers[0].clone())
+            } else {
+                (numbers[0].clone(), numbers[1].clone())
+            }
+        }
+        1 => {
+            return Err(format!(
+                "'{}' is missing either the day or the year, please enter both",
+                date_string
+            ))
+        }
+        _ => {
+            return Err(format!(
+                "Could not understand '{}', please enter a day, month and year",
+                date_string
+            ))
+        }
+    };
+    let day = day.parse::<u32>().map_err(|e| e.to_string())?;
+    let year = year.parse::<i32>().map_err(|e| e.to_string())?;
+    let naive_date = if let Some(naive_date) = NaiveDate::from_ymd_opt(year, month, day) {
+        naive_date
+    } else {
+        return Err(format!("'{}' is not a valid date", date_string));
+    };
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        let time = time.unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        Ok(Some(
+            NaiveDateTime::new(naive_date, time)
+                .format(date_format.to_parser_string())
+                .to_string(),
+        ))
+    } else {
+        Ok(Some(
+            naive_date
+                .format(date_format.to_parser_string())
+                .to_string(),
+        ))
+    }
+}
+
 pub async fn handle_exit(app: &mut App) {
     if app.config.save_on_exit {
         app.dispatch(IoEvent::AutoSave).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_count_due_days_by_calendar_date_near_midnight() {
+        let today = date(2023, 6, 14);
+        let format = DateFormat::DayMonthYearTime;
+        assert_eq!(days_until_due("15/06/2023-00:00:00", format, today), Ok(1));
+        assert_eq!(days_until_due("14/06/2023-23:59:59", format, today), Ok(0));
+        assert_eq!(days_until_due("14/06/2023-00:00:00", format, today), Ok(0));
+        assert_eq!(days_until_due("13/06/2023-23:59:59", format, today), Ok(-1));
+    }
+
+    #[test]
+    fn should_count_due_days_by_calendar_date_over_dst_change_dates() {
+        // clocks change on the last sunday of march and october in europe, only the calendar
+        // date is compared so the missing or repeated hour does not shift the count
+        let format = DateFormat::DayMonthYear;
+        assert_eq!(
+            days_until_due("27/03/2023", format, date(2023, 3, 25)),
+            Ok(2)
+        );
+        assert_eq!(
+            days_until_due("30/10/2023", format, date(2023, 10, 28)),
+            Ok(2)
+        );
+        let format = DateFormat::YearMonthDayTime;
+        assert_eq!(
+            days_until_due("2023/03/26-02:30:00", format, date(2023, 3, 25)),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn should_reject_due_dates_in_another_format() {
+        assert!(days_until_due("2023/06/15", DateFormat::DayMonthYear, date(2023, 6, 14)).is_err());
+    }
+
+    #[test]
+    fn should_parse_localized_dates_into_the_configured_date_format() {
+        let locale = DateLocale::English;
+        assert_eq!(
+            parse_localized_date("15 March 2023", locale, DateFormat::DayMonthYear),
+            Ok(Some(String::from("15/03/2023")))
+        );
+        assert_eq!(
+            parse_localized_date("March 15th, 2023", locale, DateFormat::MonthDayYear),
+            Ok(Some(String::from("03/15/2023")))
+        );
+        assert_eq!(
+            parse_localized_date("2023 mar 4 10:30", locale, DateFormat::DayMonthYearTime),
+            Ok(Some(String::from("04/03/2023-10:30:00")))
+        );
+        assert_eq!(
+            parse_localized_date(
+                "15 de marzo de 2023",
+                DateLocale::Spanish,
+                DateFormat::DayMonthYear
+            ),
+            Ok(Some(String::from("15/03/2023")))
+        );
+    }
+
+    #[test]
+    fn should_leave_dates_without_a_month_name_to_the_other_parsers() {
+        let locale = DateLocale::English;
+        assert_eq!(
+            parse_localized_date("15/03/2023", locale, DateFormat::DayMonthYear),
+            Ok(None)
+        );
+        assert_eq!(
+            parse_localized_date(FIELD_NOT_SET, locale, DateFormat::DayMonthYear),
+            Ok(None)
+        );
+        assert_eq!(
+            parse_localized_date("15 marzo 2023", locale, DateFormat::DayMonthYear),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn should_reject_ambiguous_and_invalid_localized_dates() {
+        let locale = DateLocale::English;
+        let format = DateFormat::DayMonthYear;
+        assert!(parse_localized_date("5 March 23", locale, format).is_err());
+        assert!(parse_localized_date("March 2023", locale, format).is_err());
+        assert!(parse_localized_date("March 5 April 2023", locale, format).is_err());
+        assert!(parse_localized_date("31 February 2023", locale, format).is_err());
+        assert!(parse_localized_date("15 March 2023 25:00", locale, format).is_err());
+    }
+
+    #[test]
+    fn should_only_use_ascii_glyphs_in_ascii_only_rendering() {
+        use crate::constants::{
+            ASCII_APP_TITLE, ASCII_UNSAVED_CHANGES_SYMBOL, ASCII_VERTICAL_SCROLL_BAR_SYMBOL,
+            LIST_SELECTED_SYMBOL, SPINNER_FRAMES,
+        };
+        assert!(SPINNER_FRAMES.iter().all(|frame| frame.is_ascii()));
+        assert!(LIST_SELECTED_SYMBOL.is_ascii());
+        assert!(ASCII_VERTICAL_SCROLL_BAR_SYMBOL.is_ascii());
+        assert!(ASCII_UNSAVED_CHANGES_SYMBOL.is_ascii());
+        assert!(ASCII_APP_TITLE.is_ascii());
+        assert!(CardStatus::all()
+            .iter()
+            .all(|status| status.ascii_glyph().is_ascii()));
+        assert!(CardPriority::all()
+            .iter()
+            .all(|priority| priority.ascii_glyph().is_ascii()));
+    }
+
+    #[test]
+    fn should_give_default_keys_for_a_single_action() {
+        assert_eq!(
+            KeyBindings::default_for("new_card"),
+            KeyBindings::default().new_card
+        );
+        assert!(KeyBindings::default_for("not_an_action").is_empty());
+    }
+
+    #[test]
+    fn should_count_days_until_board_due_date() {
+        let mut board = Board::new(String::from("Release"), String::new());
+        assert_eq!(board_days_until_due(&board, date(2023, 6, 14)), None);
+        board.due_date = Some(String::from("12/06/2023"));
+        assert_eq!(board_days_until_due(&board, date(2023, 6, 14)), Some(-2));
+    }
+
+    #[test]
+    fn should_sort_tags_by_use_or_name() {
+        let tags = vec![
+            (String::from("ui"), 1),
+            (String::from("bug"), 3),
+            (String::from("api"), 1),
+        ];
+        let mut by_frequency = tags.clone();
+        TagSortOrder::Frequency.sort(&mut by_frequency);
+        let names = by_frequency
+            .iter()
+            .map(|(tag, _)| tag.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["bug", "api", "ui"]);
+        let mut alphabetical = tags;
+        TagSortOrder::Alphabetical.sort(&mut alphabetical);
+        let names = alphabetical
+            .iter()
+            .map(|(tag, _)| tag.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["api", "bug", "ui"]);
+    }
+
+    #[test]
+    fn should_describe_due_dates_relative_to_today() {
+        assert_eq!(relative_due_date_text(0), "today");
+        assert_eq!(relative_due_date_text(1), "tomorrow");
+        assert_eq!(relative_due_date_text(-1), "yesterday");
+        assert_eq!(relative_due_date_text(3), "in 3 days");
+        assert_eq!(relative_due_date_text(-2), "2 days ago");
+    }
+
+    #[test]
+    fn should_sort_undated_cards_last_by_due_date() {
+        let card = |name: &str, date_due: &str| Card {
+            name: name.to_string(),
+            date_due: date_due.to_string(),
+            ..Card::default()
+        };
+        let mut cards = vec![
+            card("undated", FIELD_NOT_SET),
+            card("later", "20/06/2023"),
+            card("unreadable", "someday"),
+            card("sooner", "15/06/2023"),
+        ];
+        sort_cards_by_due_date(&mut cards, CardSortTieBreaker::Manual);
+        let names = cards
+            .iter()
+            .map(|card| card.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["sooner", "later", "undated", "unreadable"]);
+    }
+
+    #[test]
+    fn should_break_due_date_ties_with_the_configured_tie_breaker() {
+        let card = |name: &str, date_created: &str| Card {
+            name: name.to_string(),
+            date_due: "15/06/2023".to_string(),
+            date_created: date_created.to_string(),
+            ..Card::default()
+        };
+        let cards = vec![
+            card("charlie", "2023-06-01 10:00:00.000000 UTC"),
+            card("Alpha", "unknown"),
+            card("bravo", "2023-05-01 10:00:00.000000 UTC"),
+        ];
+        let sorted_names = |tie_breaker: CardSortTieBreaker| {
+            let mut cards = cards.clone();
+            sort_cards_by_due_date(&mut cards, tie_breaker);
+            cards
+                .iter()
+                .map(|card| card.name.clone())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(
+            sorted_names(CardSortTieBreaker::Manual),
+            vec!["charlie", "Alpha", "bravo"]
+        );
+        assert_eq!(
+            sorted_names(CardSortTieBreaker::Name),
+            vec!["Alpha", "bravo", "charlie"]
+        );
+        assert_eq!(
+            sorted_names(CardSortTieBreaker::DateCreated),
+            vec!["bravo", "charlie", "Alpha"]
+        );
+    }
+
+    #[test]
+    fn should_match_only_open_cards_past_their_due_date_with_the_overdue_quick_filter() {
+        let card = |date_due: &str, card_status: CardStatus| Card {
+            date_due: date_due.to_string(),
+            card_status,
+            ..Card::default()
+        };
+        let overdue = QuickFilter::Overdue.preset();
+        assert!(overdue.matches(&card("01/01/2000", CardStatus::Active)));
+        assert!(!overdue.matches(&card("01/01/2000", CardStatus::Complete)));
+        assert!(!overdue.matches(&card("01/01/2999", CardStatus::Active)));
+        assert!(!overdue.matches(&card(FIELD_NOT_SET, CardStatus::Active)));
+        assert_eq!(
+            QuickFilter::from_preset(&overdue),
+            Some(QuickFilter::Overdue)
+        );
+        assert_eq!(
+            QuickFilter::from_preset(&FilterPreset {
+                name: String::from("Overdue Cards"),
+                tags: vec![],
+                priority: None,
+                overdue: false,
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn should_refuse_conflicting_key_chords() {
+        let keybindings = KeyBindings::default();
+        let chord = |keys: &str| keys.chars().map(Key::Char).collect::<Vec<Key>>();
+        assert!(keybindings.chord_conflict(&chord("gx"), None).is_none());
+        assert!(keybindings.chord_conflict(&chord("gm"), Some(0)).is_none());
+        assert!(keybindings.chord_conflict(&chord("g"), None).is_some());
+        assert!(keybindings.chord_conflict(&chord("gm"), Some(1)).is_some());
+        assert!(keybindings.chord_conflict(&chord("gmx"), Some(1)).is_some());
+        assert!(keybindings.chord_conflict(&chord("qm"), None).is_some());
+    }
+
+    #[test]
+    fn should_list_recently_modified_cards_newest_first() {
+        let card = |date_modified: &str| Card {
+            date_modified: date_modified.to_string(),
+            ..Card::default()
+        };
+        let mut work = Board::new(String::from("Work"), String::new());
+        let mut home = Board::new(String::from("Home"), String::new());
+        work.cards = vec![
+            card("2023-06-14 09:00:00.000000001 UTC"),
+            card("not a date"),
+        ];
+        home.cards = vec![
+            card("2023-06-15 08:30:00 UTC"),
+            card("2023-06-13 10:00:00 UTC"),
+        ];
+        let recent = recently_modified_cards(&[work.clone(), home.clone()], 3);
+        assert_eq!(
+            recent,
+            vec![
+                (home.id, home.cards[0].id),
+                (work.id, work.cards[0].id),
+                (home.id, home.cards[1].id),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_turn_custom_commands_into_keys() {
+        let keybindings = KeyBindings::default();
+        let commands = CustomCommand::list_from_string(
+            "Finish = change_card_status_to_completed, down; Menu = go_to_main_menu",
+            &keybindings,
+        )
+        .unwrap();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(
+            commands[0].keys(&keybindings),
+            Ok(vec![Key::Char('1'), Key::Down])
+        );
+        assert!(CustomCommand::list_from_string("Broken = not_an_action", &keybindings).is_err());
+    }
+
+    #[test]
+    fn should_only_treat_big_moves_as_navigation_jumps() {
+        let mut work = Board::new(String::from("Work"), String::new());
+        let home = Board::new(String::from("Home"), String::new());
+        work.cards = vec![Card::default(), Card::default(), Card::default()];
+        let card = |index: usize| (work.id, Some(work.cards[index].id));
+        let boards = [work.clone(), home.clone()];
+        assert!(!is_navigation_jump(&boards, card(0), card(1)));
+        assert!(is_navigation_jump(&boards, card(0), card(2)));
+        assert!(is_navigation_jump(&boards, card(1), (home.id, None)));
+        assert!(!is_navigation_jump(&boards, (work.id, None), card(2)));
+    }
+
+    #[test]
+    fn should_undo_and_redo_a_batch_as_one_step() {
+        let mut work = Board::new(String::from("Work"), String::new());
+        let mut home = Board::new(String::from("Home"), String::new());
+        work.cards = vec![Card::default(), Card::default()];
+        home.cards = vec![Card::default()];
+        let before = vec![work.clone(), home.clone()];
+        let added = Card::default();
+        let mut boards = vec![work.clone(), home.clone()];
+        boards[0].cards.clear();
+        boards[1].cards.clear();
+        boards[1].cards.push(added.clone());
+        let after = boards.clone();
+        let batch = ActionHistory::CompoundOperation(
+            String::from("Cleared both boards and added a card"),
+            vec![
+                ActionHistory::ClearBoard(work.cards.clone(), work.id),
+                ActionHistory::ClearBoard(home.cards.clone(), home.id),
+                ActionHistory::CreateCard(added, home.id),
+            ],
+        );
+
+        assert!(batch.undo(&mut boards).is_ok());
+        assert_eq!(boards, before);
+        assert!(batch.redo(&mut boards).is_ok());
+        assert_eq!(boards, after);
+    }
+
+    #[test]
+    fn should_stop_undoing_a_batch_when_a_part_fails() {
+        let work = Board::new(String::from("Work"), String::new());
+        let deleted = Board::new(String::from("Deleted"), String::new());
+        let batch = ActionHistory::CompoundOperation(
+            String::from("Created two cards"),
+            vec![
+                ActionHistory::CreateCard(Card::default(), deleted.id),
+                ActionHistory::CreateCard(Card::default(), work.id),
+            ],
+        );
+        let mut boards = vec![work];
+        assert!(batch.undo(&mut boards).is_err());
+    }
+
+    #[test]
+    fn should_count_stale_days_from_the_last_change() {
+        let now = date(2023, 6, 14).and_hms_opt(12, 0, 0).unwrap();
+        let card = |date_modified: &str, card_status: CardStatus| Card {
+            date_modified: date_modified.to_string(),
+            card_status,
+            ..Card::default()
+        };
+        let untouched = card("2023-06-01 12:00:00 UTC", CardStatus::Active);
+        assert_eq!(days_until_stale(&untouched, 14, now), Some(1));
+        assert_eq!(days_until_stale(&untouched, 10, now), Some(-3));
+        assert_eq!(days_until_stale(&untouched, 0, now), None);
+        let modified = card("2023-06-14 09:00:00 UTC", CardStatus::Active);
+        assert_eq!(days_until_stale(&modified, 14, now), Some(14));
+        let completed = card("2023-06-01 12:00:00 UTC", CardStatus::Complete);
+        assert_eq!(days_until_stale(&completed, 14, now), None);
+    }
+}
@@ -35,6 +35,7 @@ pub enum AppStatus {
     Initialized,
     UserInput,
     KeyBindMode,
+    CardJumpMode,
 }
 
 #[derive(Clone, PartialEq, Debug, Copy, Default)]
@@ -72,18 +73,52 @@ pub enum Focus {
     TextInput,
     CardPriority,
     CardStatus,
+    CardPoints,
     CardTags,
     CardComments,
     ChangeCardPriorityPopup,
+    ChangeCardPointsPopup,
     ChangeDateFormatPopup,
     FilterByTagPopup,
+    FilterPresetPopup,
+    CardRecurrencePopup,
+    CardLinkPopup,
+    RecentCardsPopup,
     #[default]
     NoFocus,
     ExtraFocus, // Used in cases where defining a new focus is not necessary
 }
 
+/// Keys pressed one after the other that run an action, like `g` then `m`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KeyChord {
+    /// Name of the action as used by the single key bindings
+    pub action: String,
+    pub keys: Vec<Key>,
+}
+
+impl KeyChord {
+    pub fn new(action: &str, keys: Vec<Key>) -> Self {
+        Self {
+            action: action.to_string(),
+            keys,
+        }
+    }
+
+    /// The keys of the chord as shown in the keybinding editor and help, like `<g> then <m>`
+    pub fn keys_to_string(keys: &[Key]) -> String {
+        keys.iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<String>>()
+            .join(" then ")
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct KeyBindings {
+    /// Multi key chords, the first key of a chord can not also be bound on its own
+    pub chords: Vec<KeyChord>,
     pub quit: Vec<Key>,
     pub open_config_menu: Vec<Key>,
     pub up: Vec<Key>,
@@ -109,6 +144,36 @@ pub struct KeyBindings {
     pub clear_all_toasts: Vec<Key>,
     pub undo: Vec<Key>,
     pub redo: Vec<Key>,
+    pub jump_to_card: Vec<Key>,
+    pub dismiss_top_toast: Vec<Key>,
+    pub toggle_board_focus_mode: Vec<Key>,
+    pub cycle_card_status: Vec<Key>,
+    pub reset_board_view: Vec<Key>,
+    pub toggle_card_selection: Vec<Key>,
+    pub show_quick_help: Vec<Key>,
+    pub move_card_to_top: Vec<Key>,
+    pub move_card_to_bottom: Vec<Key>,
+    pub toggle_split_view: Vec<Key>,
+    pub switch_split_view_pane: Vec<Key>,
+    pub clear_board: Vec<Key>,
+    pub toggle_focus_timer: Vec<Key>,
+    pub toggle_log_panel: Vec<Key>,
+    pub change_card_recurrence: Vec<Key>,
+    pub grow_panel: Vec<Key>,
+    pub shrink_panel: Vec<Key>,
+    pub export_board_and_copy_path: Vec<Key>,
+    pub toggle_expanded_card: Vec<Key>,
+    pub toggle_hide_completed_cards: Vec<Key>,
+    pub toggle_relative_due_dates: Vec<Key>,
+    pub next_theme: Vec<Key>,
+    pub prv_theme: Vec<Key>,
+    pub navigate_back: Vec<Key>,
+    pub navigate_forward: Vec<Key>,
+    pub toggle_board_hide_completed_cards: Vec<Key>,
+    pub toggle_overdue_quick_filter: Vec<Key>,
+    pub toggle_watched_quick_filter: Vec<Key>,
+    pub toggle_blocked_quick_filter: Vec<Key>,
+    pub toggle_high_priority_quick_filter: Vec<Key>,
 }
 
 impl UiMode {
@@ -208,6 +273,34 @@ impl UiMode {
             UiMode::TitleBodyHelpLog,
         ]
     }
+
+    /// The view with the same layout plus the log panel, view modes that already show the log
+    /// return themselves and modes that are not views return None
+    pub fn with_log_panel(&self) -> Option<UiMode> {
+        match self {
+            UiMode::Zen | UiMode::BodyLog => Some(UiMode::BodyLog),
+            UiMode::TitleBody | UiMode::TitleBodyLog => Some(UiMode::TitleBodyLog),
+            UiMode::BodyHelp | UiMode::BodyHelpLog => Some(UiMode::BodyHelpLog),
+            UiMode::TitleBodyHelp | UiMode::TitleBodyHelpLog => Some(UiMode::TitleBodyHelpLog),
+            _ => None,
+        }
+    }
+
+    pub fn all_modes() -> Vec<UiMode> {
+        let mut modes = UiMode::view_modes();
+        modes.extend(vec![
+            UiMode::ConfigMenu,
+            UiMode::EditKeybindings,
+            UiMode::MainMenu,
+            UiMode::HelpMenu,
+            UiMode::LogsOnly,
+            UiMode::NewBoard,
+            UiMode::NewCard,
+            UiMode::LoadSave,
+            UiMode::CreateTheme,
+        ]);
+        modes
+    }
 }
 
 impl fmt::Display for UiMode {
@@ -280,21 +373,32 @@ impl Focus {
             Self::TextInput => "Text Input",
             Self::CardPriority => "Card Priority",
             Self::CardStatus => "Card Status",
+            Self::CardPoints => "Card Points",
             Self::CardTags => "Card Tags",
             Self::CardComments => "Card Comments",
             Self::ChangeCardPriorityPopup => "Change Card Priority Popup",
+            Self::ChangeCardPointsPopup => "Change Card Points Popup",
             Self::ChangeDateFormatPopup => "Change Date Format Popup",
             Self::FilterByTagPopup => "Filter By Tag Popup",
+            Self::FilterPresetPopup => "Filter Preset Popup",
+            Self::CardRecurrencePopup => "Card Recurrence Popup",
+            Self::CardLinkPopup => "Card Link Popup",
+            Self::RecentCardsPopup => "Recent Cards Popup",
             Self::NoFocus => "No Focus",
             Self::ExtraFocus => "Extra Focus",
         }
     }
-    pub fn next(&self, available_tabs: &Vec<Focus>) -> Self {
+    /// Returns the next focus in available_tabs, when wrap is false the last tab stays focused
+    pub fn next(&self, available_tabs: &Vec<Focus>, wrap: bool) -> Self {
         // check if current_focus is in available_tabs if not set to first available tab other wise find next tab
         if available_tabs.contains(self) {
             let index = available_tabs.iter().position(|x| x == self).unwrap();
             if index == available_tabs.len() - 1 {
-                available_tabs[0]
+                if wrap {
+                    available_tabs[0]
+                } else {
+                    *self
+                }
             } else {
                 available_tabs[index + 1]
             }
@@ -302,12 +406,17 @@ impl Focus {
             available_tabs[0]
         }
     }
-    pub fn prev(&self, available_tabs: &Vec<Focus>) -> Self {
+    /// Returns the previous focus in available_tabs, when wrap is false the first tab stays focused
+    pub fn prev(&self, available_tabs: &Vec<Focus>, wrap: bool) -> Self {
         // check if current_focus is in available_tabs if not set to first available tab other wise find next tab
         if available_tabs.contains(self) {
             let index = available_tabs.iter().position(|x| x == self).unwrap();
             if index == 0 {
-                available_tabs[available_tabs.len() - 1]
+                if wrap {
+                    available_tabs[available_tabs.len() - 1]
+                } else {
+                    *self
+                }
             } else {
                 available_tabs[index - 1]
             }
@@ -354,10 +463,16 @@ impl FromStr for Focus {
             "Text Input" => Ok(Self::TextInput),
             "Card Priority" => Ok(Self::CardPriority),
             "Card Status" => Ok(Self::CardStatus),
+            "Card Points" => Ok(Self::CardPoints),
             "Card Tags" => Ok(Self::CardTags),
             "Card Comments" => Ok(Self::CardComments),
             "Change Card Priority Popup" => Ok(Self::ChangeCardPriorityPopup),
+            "Change Card Points Popup" => Ok(Self::ChangeCardPointsPopup),
             "Filter By Tag Popup" => Ok(Self::FilterByTagPopup),
+            "Filter Preset Popup" => Ok(Self::FilterPresetPopup),
+            "Card Recurrence Popup" => Ok(Self::CardRecurrencePopup),
+            "Card Link Popup" => Ok(Self::CardLinkPopup),
+            "Recent Cards Popup" => Ok(Self::RecentCardsPopup),
             "Submit Button" => Ok(Self::SubmitButton),
             "Extra Focus" => Ok(Self::ExtraFocus),
             _ => Ok(Self::NoFocus),
@@ -366,6 +481,57 @@ impl FromStr for Focus {
 }
 
 impl KeyBindings {
+    /// Default keys for a single action by its name in the config, empty for an unknown action
+    pub fn default_for(action: &str) -> Vec<Key> {
+        let default_keybindings = Self::default();
+        default_keybindings
+            .iter()
+            .find(|(name, _)| *name == action)
+            .map(|(_, keys)| keys.clone())
+            .unwrap_or_default()
+    }
+
+    /// Default chord keys for an action by its name in the config, `None` when it has no chord
+    pub fn default_chord_for(action: &str) -> Option<Vec<Key>> {
+        Self::default()
+            .chords
+            .into_iter()
+            .find(|chord| chord.action == action)
+            .map(|chord| chord.keys)
+    }
+
+    /// Rows in the keybinding editor, the single key bindings come first and the chords after
+    pub fn editor_rows(&self) -> usize {
+        self.iter().count() + self.chords.len()
+    }
+
+    /// Explains why the keys can not be used as a chord, `skip` is the index of the chord they
+    /// replace. A chord needs two keys, can not start with a key that is bound on its own and can
+    /// not be the start of another chord as it would never wait for the rest
+    pub fn chord_conflict(&self, keys: &[Key], skip: Option<usize>) -> Option<String> {
+        if keys.len() < 2 {
+            return Some(String::from("A chord needs at least two keys"));
+        }
+        if let Some((action, _)) = self.iter().find(|(_, bound)| bound.contains(&keys[0])) {
+            return Some(format!(
+                "Value {} is already assigned to {}",
+                keys[0], action
+            ));
+        }
+        self.chords
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != skip)
+            .find(|(_, chord)| chord.keys.starts_with(keys) || keys.starts_with(&chord.keys))
+            .map(|(_, chord)| {
+                format!(
+                    "Chord {} overlaps the chord for {}",
+                    KeyChord::keys_to_string(keys),
+                    chord.action
+                )
+            })
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&str, &Vec<Key>)> {
         vec![
             ("quit", &self.quit),
@@ -402,6 +568,57 @@ impl KeyBindings {
             ("clear_all_toasts", &self.clear_all_toasts),
             ("undo", &self.undo),
             ("redo", &self.redo),
+            ("jump_to_card", &self.jump_to_card),
+            ("dismiss_top_toast", &self.dismiss_top_toast),
+            ("toggle_board_focus_mode", &self.toggle_board_focus_mode),
+            ("cycle_card_status", &self.cycle_card_status),
+            ("reset_board_view", &self.reset_board_view),
+            ("toggle_card_selection", &self.toggle_card_selection),
+            ("show_quick_help", &self.show_quick_help),
+            ("move_card_to_top", &self.move_card_to_top),
+            ("move_card_to_bottom", &self.move_card_to_bottom),
+            ("toggle_split_view", &self.toggle_split_view),
+            ("switch_split_view_pane", &self.switch_split_view_pane),
+            ("clear_board", &self.clear_board),
+            ("toggle_focus_timer", &self.toggle_focus_timer),
+            ("toggle_log_panel", &self.toggle_log_panel),
+            ("change_card_recurrence", &self.change_card_recurrence),
+            ("grow_panel", &self.grow_panel),
+            ("shrink_panel", &self.shrink_panel),
+            (
+                "export_board_and_copy_path",
+                &self.export_board_and_copy_path,
+            ),
+            ("toggle_expanded_card", &self.toggle_expanded_card),
+            (
+                "toggle_hide_completed_cards",
+                &self.toggle_hide_completed_cards,
+            ),
+            ("toggle_relative_due_dates", &self.toggle_relative_due_dates),
+            ("next_theme", &self.next_theme),
+            ("prv_theme", &self.prv_theme),
+            ("navigate_back", &self.navigate_back),
+            ("navigate_forward", &self.navigate_forward),
+            (
+                "toggle_board_hide_completed_cards",
+                &self.toggle_board_hide_completed_cards,
+            ),
+            (
+                "toggle_overdue_quick_filter",
+                &self.toggle_overdue_quick_filter,
+            ),
+            (
+                "toggle_watched_quick_filter",
+                &self.toggle_watched_quick_filter,
+            ),
+            (
+                "toggle_blocked_quick_filter",
+                &self.toggle_blocked_quick_filter,
+            ),
+            (
+                "toggle_high_priority_quick_filter",
+                &self.toggle_high_priority_quick_filter,
+            ),
         ]
         .into_iter()
     }
@@ -439,6 +656,48 @@ impl KeyBindings {
                     "clear_all_toasts" => return Some(&Action::ClearAllToasts),
                     "undo" => return Some(&Action::Undo),
                     "redo" => return Some(&Action::Redo),
+                    "jump_to_card" => return Some(&Action::JumpToCard),
+                    "dismiss_top_toast" => return Some(&Action::DismissTopToast),
+                    "toggle_board_focus_mode" => return Some(&Action::ToggleBoardFocusMode),
+                    "cycle_card_status" => return Some(&Action::CycleCardStatus),
+                    "reset_board_view" => return Some(&Action::ResetBoardView),
+                    "toggle_card_selection" => return Some(&Action::ToggleCardSelection),
+                    "show_quick_help" => return Some(&Action::ShowQuickHelp),
+                    "move_card_to_top" => return Some(&Action::MoveCardToTop),
+                    "move_card_to_bottom" => return Some(&Action::MoveCardToBottom),
+                    "toggle_split_view" => return Some(&Action::ToggleSplitView),
+                    "switch_split_view_pane" => return Some(&Action::SwitchSplitViewPane),
+                    "clear_board" => return Some(&Action::ClearBoard),
+                    "toggle_focus_timer" => return Some(&Action::ToggleFocusTimer),
+                    "toggle_log_panel" => return Some(&Action::ToggleLogPanel),
+                    "change_card_recurrence" => return Some(&Action::ChangeCardRecurrence),
+                    "grow_panel" => return Some(&Action::GrowPanel),
+                    "shrink_panel" => return Some(&Action::ShrinkPanel),
+                    "export_board_and_copy_path" => return Some(&Action::ExportBoardAndCopyPath),
+                    "toggle_expanded_card" => return Some(&Action::ToggleExpandedCard),
+                    "toggle_hide_completed_cards" => {
+                        return Some(&Action::ToggleHideCompletedCards)
+                    }
+                    "toggle_relative_due_dates" => return Some(&Action::ToggleRelativeDueDates),
+                    "next_theme" => return Some(&Action::NextTheme),
+                    "prv_theme" => return Some(&Action::PrvTheme),
+                    "navigate_back" => return Some(&Action::NavigateBack),
+                    "navigate_forward" => return Some(&Action::NavigateForward),
+                    "toggle_board_hide_completed_cards" => {
+                        return Some(&Action::ToggleBoardHideCompletedCards)
+                    }
+                    "toggle_overdue_quick_filter" => {
+                        return Some(&Action::ToggleOverdueQuickFilter)
+                    }
+                    "toggle_watched_quick_filter" => {
+                        return Some(&Action::ToggleWatchedQuickFilter)
+                    }
+                    "toggle_blocked_quick_filter" => {
+                        return Some(&Action::ToggleBlockedQuickFilter)
+                    }
+                    "toggle_high_priority_quick_filter" => {
+                        return Some(&Action::ToggleHighPriorityQuickFilter)
+                    }
                     _ => return None,
                 }
             }
@@ -473,6 +732,36 @@ impl KeyBindings {
             "clear_all_toasts" => Some(&Action::ClearAllToasts),
             "undo" => Some(&Action::Undo),
             "redo" => Some(&Action::Redo),
+            "jump_to_card" => Some(&Action::JumpToCard),
+            "dismiss_top_toast" => Some(&Action::DismissTopToast),
+            "toggle_board_focus_mode" => Some(&Action::ToggleBoardFocusMode),
+            "cycle_card_status" => Some(&Action::CycleCardStatus),
+            "reset_board_view" => Some(&Action::ResetBoardView),
+            "toggle_card_selection" => Some(&Action::ToggleCardSelection),
+            "show_quick_help" => Some(&Action::ShowQuickHelp),
+            "move_card_to_top" => Some(&Action::MoveCardToTop),
+            "move_card_to_bottom" => Some(&Action::MoveCardToBottom),
+            "toggle_split_view" => Some(&Action::ToggleSplitView),
+            "switch_split_view_pane" => Some(&Action::SwitchSplitViewPane),
+            "clear_board" => Some(&Action::ClearBoard),
+            "toggle_focus_timer" => Some(&Action::ToggleFocusTimer),
+            "toggle_log_panel" => Some(&Action::ToggleLogPanel),
+            "change_card_recurrence" => Some(&Action::ChangeCardRecurrence),
+            "grow_panel" => Some(&Action::GrowPanel),
+            "shrink_panel" => Some(&Action::ShrinkPanel),
+            "export_board_and_copy_path" => Some(&Action::ExportBoardAndCopyPath),
+            "toggle_expanded_card" => Some(&Action::ToggleExpandedCard),
+            "toggle_hide_completed_cards" => Some(&Action::ToggleHideCompletedCards),
+            "toggle_relative_due_dates" => Some(&Action::ToggleRelativeDueDates),
+            "next_theme" => Some(&Action::NextTheme),
+            "prv_theme" => Some(&Action::PrvTheme),
+            "navigate_back" => Some(&Action::NavigateBack),
+            "navigate_forward" => Some(&Action::NavigateForward),
+            "toggle_board_hide_completed_cards" => Some(&Action::ToggleBoardHideCompletedCards),
+            "toggle_overdue_quick_filter" => Some(&Action::ToggleOverdueQuickFilter),
+            "toggle_watched_quick_filter" => Some(&Action::ToggleWatchedQuickFilter),
+            "toggle_blocked_quick_filter" => Some(&Action::ToggleBlockedQuickFilter),
+            "toggle_high_priority_quick_filter" => Some(&Action::ToggleHighPriorityQuickFilter),
             _ => None,
         }
     }
@@ -481,6 +770,10 @@ impl KeyBindings {
 impl Default for KeyBindings {
     fn default() -> Self {
         Self {
+            chords: vec![
+                KeyChord::new("go_to_main_menu", vec![Key::Char('g'), Key::Char('m')]),
+                KeyChord::new("open_config_menu", vec![Key::Char('g'), Key::Char('c')]),
+            ],
             quit: vec![Key::Ctrl('c'), Key::Char('q')],
             next_focus: vec![Key::Tab],
             prev_focus: vec![Key::BackTab],
@@ -506,6 +799,36 @@ impl Default for KeyBindings {
             clear_all_toasts: vec![Key::Char('t')],
             undo: vec![Key::Ctrl('z')],
             redo: vec![Key::Ctrl('y')],
+            jump_to_card: vec![Key::Char('f')],
+            dismiss_top_toast: vec![Key::Char('x')],
+            toggle_board_focus_mode: vec![Key::Char('o')],
+            cycle_card_status: vec![Key::Char('s')],
+            reset_board_view: vec![Key::Char('R')],
+            toggle_card_selection: vec![Key::Char('v')],
+            show_quick_help: vec![Key::Char('?')],
+            move_card_to_top: vec![Key::Home],
+            move_card_to_bottom: vec![Key::End],
+            toggle_split_view: vec![Key::Char('|')],
+            switch_split_view_pane: vec![Key::Char('w')],
+            clear_board: vec![Key::Char('C')],
+            toggle_focus_timer: vec![Key::Char('p')],
+            toggle_log_panel: vec![Key::Char('l')],
+            change_card_recurrence: vec![Key::Char('e')],
+            grow_panel: vec![Key::Char('+')],
+            shrink_panel: vec![Key::Char('-')],
+            export_board_and_copy_path: vec![Key::Char('y')],
+            toggle_expanded_card: vec![Key::Char('z')],
+            toggle_hide_completed_cards: vec![Key::Char('H')],
+            toggle_relative_due_dates: vec![Key::Char('u')],
+            next_theme: vec![Key::Char(']')],
+            prv_theme: vec![Key::Char('[')],
+            navigate_back: vec![Key::Char('<')],
+            navigate_forward: vec![Key::Char('>')],
+            toggle_board_hide_completed_cards: vec![Key::Char('K')],
+            toggle_overdue_quick_filter: vec![Key::Char('O')],
+            toggle_watched_quick_filter: vec![Key::Char('W')],
+            toggle_blocked_quick_filter: vec![Key::Char('B')],
+            toggle_high_priority_quick_filter: vec![Key::Char('P')],
         }
     }
 }
@@ -2,10 +2,22 @@ use ratatui::style::{Color, Modifier, Style};
 
 pub const FIELD_NOT_SET: &str = "Not Set";
 pub const CONFIG_FILE_NAME: &str = "config.json";
+pub const CONFIG_EXPORT_FILE_NAME: &str = "kanban_config_export";
 pub const CONFIG_DIR_NAME: &str = "rust_kanban";
+// written to the config directory while the app runs, finding it at startup means the last
+// session did not exit cleanly
+pub const SESSION_MARKER_FILE_NAME: &str = "session.lock";
 pub const SAVE_DIR_NAME: &str = "kanban_saves";
 pub const SAVE_FILE_NAME: &str = "kanban";
+// older save files stored their per day file number as the savefile version, so the schema
+// version starts well above any realistic number of saves in a day
+pub const SAVE_FILE_SCHEMA_VERSION: u32 = 1010;
+pub const SAVE_NOTE_FILE_EXTENSION: &str = "note";
+pub const SAVE_CHECKSUM_FILE_EXTENSION: &str = "sha256";
+pub const CONFIG_FILE_VERSION: u32 = 1;
+pub const CARD_POINTS_OPTIONS: [u32; 8] = [0, 1, 2, 3, 5, 8, 13, 21];
 pub const NO_OF_BOARDS_PER_PAGE: u16 = 3;
+pub const DEFAULT_RECENT_CARDS_COUNT: u16 = 10;
 pub const MIN_NO_BOARDS_PER_PAGE: u16 = 1;
 pub const MAX_NO_BOARDS_PER_PAGE: u16 = 5;
 pub const NO_OF_CARDS_PER_BOARD: u16 = 2;
@@ -13,16 +25,64 @@ pub const MIN_NO_CARDS_PER_BOARD: u16 = 1;
 pub const MAX_NO_CARDS_PER_BOARD: u16 = 4;
 pub const DEFAULT_BOARD_TITLE_LENGTH: u16 = 20;
 pub const DEFAULT_CARD_TITLE_LENGTH: u16 = 20;
+pub const DEFAULT_TAG_DISPLAY_LENGTH: u16 = 20;
+// shortest tag length that still leaves room for a character and the ellipsis
+pub const MIN_TAG_DISPLAY_LENGTH: u16 = 4;
+// panel sizes are kept small enough that the body still fits on a MIN_TERM_HEIGHT terminal
+pub const DEFAULT_HELP_PANEL_HEIGHT: u16 = 5;
+pub const MIN_HELP_PANEL_HEIGHT: u16 = 5;
+pub const MAX_HELP_PANEL_HEIGHT: u16 = 8;
+pub const DEFAULT_LOG_PANEL_HEIGHT: u16 = 8;
+pub const MIN_LOG_PANEL_HEIGHT: u16 = 4;
+pub const MAX_LOG_PANEL_HEIGHT: u16 = 12;
+pub const MIN_BODY_PANEL_HEIGHT: u16 = 7;
+// share of the width in percent taken by the left pane of the split view
+pub const DEFAULT_SPLIT_VIEW_RATIO: u16 = 50;
+pub const MIN_SPLIT_VIEW_RATIO: u16 = 20;
+pub const MAX_SPLIT_VIEW_RATIO: u16 = 80;
+pub const SPLIT_VIEW_RATIO_STEP: u16 = 5;
 pub const APP_TITLE: &str = "Rust 🦀 Kanban";
+pub const ASCII_APP_TITLE: &str = "Rust Kanban";
 pub const MIN_TERM_WIDTH: u16 = 110;
 pub const MIN_TERM_HEIGHT: u16 = 30;
+// narrowest terminal the compact layout still draws a board in
+pub const MIN_COMPACT_TERM_WIDTH: u16 = 30;
 pub const LIST_SELECTED_SYMBOL: &str = ">> ";
 pub const VERTICAL_SCROLL_BAR_SYMBOL: &str = "█";
+pub const ASCII_VERTICAL_SCROLL_BAR_SYMBOL: &str = "#";
+pub const PROGRESS_BAR_EMPTY_SYMBOL: &str = "░";
+pub const ASCII_PROGRESS_BAR_EMPTY_SYMBOL: &str = "-";
+pub const CARD_PROGRESS_BAR_WIDTH: usize = 10;
+pub const BOARD_ACCENT_BAND_WIDTH: usize = 3;
+pub const UNSAVED_CHANGES_SYMBOL: &str = "●";
+pub const ASCII_UNSAVED_CHANGES_SYMBOL: &str = "*";
+pub const HIDING_COMPLETED_CARDS_SYMBOL: &str = "⊘";
+pub const ASCII_HIDING_COMPLETED_CARDS_SYMBOL: &str = "~";
 pub const DEFAULT_CARD_WARNING_DUE_DATE_DAYS: u16 = 3;
 pub const MAX_TOASTS_TO_DISPLAY: usize = 5;
+pub const MAX_ACTIVITY_LOG_ENTRIES: usize = 200;
+pub const MAX_NAVIGATION_HISTORY_ENTRIES: usize = 50;
 pub const SCREEN_TO_TOAST_WIDTH_RATIO: u16 = 3; // 1/3rd of the screen width
 pub const TOAST_FADE_OUT_TIME: u64 = 400;
 pub const TOAST_FADE_IN_TIME: u64 = 200;
+pub const RESET_UI_CONFIRM_WINDOW: u64 = 2000; // in milliseconds
+pub const KEY_CHORD_TIMEOUT: u64 = 1000; // in milliseconds
+pub const PRUNE_ARCHIVED_CARDS_CONFIRMATION_TEXT: &str = "delete";
+pub const CLEAR_ALL_BOARDS_CONFIRM_WINDOW: u64 = 10000; // in milliseconds
+pub const NAVIGATION_REPEAT_WINDOW: u64 = 150; // in milliseconds
+pub const RECURRENCE_CHECK_INTERVAL: u64 = 60; // in seconds
+pub const STALE_CHECK_INTERVAL: u64 = 60; // in seconds
+pub const STALE_WARNING_DAYS: i64 = 2;
+pub const PERIODIC_SAVE_TOAST_DURATION: u64 = 2; // in seconds
+pub const NAVIGATION_PRESSES_PER_STEP: usize = 5;
+pub const MAX_NAVIGATION_ACCELERATION: u16 = 5;
+pub const AUTO_SAVE_DEBOUNCE_TIME: u64 = 1000; // in milliseconds
+pub const DEFAULT_FOCUS_TIMER_WORK_MINUTES: u16 = 25;
+pub const DEFAULT_FOCUS_TIMER_BREAK_MINUTES: u16 = 5;
+pub const ARCHIVE_DATE_FORMAT: &str = "%Y-%m-%d";
+pub const TAG_FILTER_NAME: &str = "Tag Filter";
+pub const WATCHED_TAG: &str = "watched";
+pub const BLOCKED_TAG: &str = "blocked";
 pub const DEFAULT_TICKRATE: u64 = 50;
 pub const DEFAULT_TOAST_DURATION: u64 = 5;
 pub const IO_EVENT_WAIT_TIME: u64 = 5; // ms
@@ -33,6 +93,7 @@ pub const SAMPLE_TEXT: &str = "Sample Text";
 pub const THEME_DIR_NAME: &str = "themes";
 pub const THEME_FILE_NAME: &str = "kanban_theme";
 pub const RANDOM_SEARCH_TERM: &str = "iibnigivirneiivure";
+pub const CARD_JUMP_LABEL_CHARS: &str = "asdfghjklqwertyuiopzxcvbnm";
 
 // Style
 pub const GENERAL_STYLE: Style = Style {
@@ -0,0 +1,60 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    sync::Arc,
+};
+
+use crate::inputs::key::Key;
+
+/// Errors returned by the config, theme and export functions
+#[derive(Debug, Clone)]
+pub enum KanbanError {
+    /// Reading or writing a file or directory failed
+    Io(Arc<io::Error>),
+    /// A file was found but its contents could not be parsed
+    Parse(String),
+    /// The same key is bound to more than one action
+    Overlap(Vec<Key>),
+    /// A required file or directory could not be found
+    NotFound(String),
+}
+
+impl Display for KanbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KanbanError::Io(e) => write!(f, "IO error: {}", e),
+            KanbanError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            KanbanError::Overlap(keys) => write!(
+                f,
+                "Overlapped keybinds found: {}",
+                keys.iter()
+                    .map(|key| key.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            KanbanError::NotFound(msg) => write!(f, "Not found: {}", msg),
+        }
+    }
+}
+
+impl Error for KanbanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KanbanError::Io(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for KanbanError {
+    fn from(e: io::Error) -> Self {
+        KanbanError::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for KanbanError {
+    fn from(e: serde_json::Error) -> Self {
+        KanbanError::Parse(e.to_string())
+    }
+}
@@ -4,7 +4,7 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use super::{key::Key, mouse::Mouse, InputEvent};
@@ -28,9 +28,13 @@ impl Events {
         let event_tx = tx.clone();
         let event_stop_capture = stop_capture.clone();
         tokio::spawn(async move {
+            let mut last_tick = Instant::now();
             loop {
-                // poll for tick rate duration, if no event, sent tick event.
-                if crossterm::event::poll(tick_rate).unwrap() {
+                // poll until the next tick is due, input events are sent as soon as they arrive
+                // without a tick after them, so held keys are not slowed down by an extra render
+                // for every key press. Ticks keep coming at the tick rate while input streams in
+                let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+                if crossterm::event::poll(timeout).unwrap() {
                     let event = crossterm::event::read().unwrap();
                     if let crossterm::event::Event::Mouse(mouse_action) = event {
                         let mouse_action = Mouse::from(mouse_action);
@@ -45,8 +49,11 @@ impl Events {
                         }
                     }
                 }
-                if let Err(err) = event_tx.send(InputEvent::Tick).await {
-                    error!("Oops!, {}", err);
+                if last_tick.elapsed() >= tick_rate {
+                    if let Err(err) = event_tx.send(InputEvent::Tick).await {
+                        error!("Oops!, {}", err);
+                    }
+                    last_tick = Instant::now();
                 }
                 if event_stop_capture.load(Ordering::Relaxed) {
                     break;
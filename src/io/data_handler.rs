@@ -1,24 +1,52 @@
+use chrono::{NaiveDate, NaiveDateTime};
 use log::{debug, error, info};
 use regex::Regex;
 use savefile::prelude::*;
-use serde::Serialize;
-use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
 
 use super::handler::{get_config_dir, make_file_system_safe_name};
 use crate::{
-    app::{kanban::Board, state::UiMode, AppConfig},
+    app::{
+        actions::Action,
+        date_format_finder, days_until_due,
+        kanban::{ArchivedCard, Board, BoardStatistics, Card, CardPriority, CardStatus},
+        state::{KeyBindings, UiMode},
+        AppConfig, ConfirmationStyle, DateFormat,
+    },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, THEME_DIR_NAME,
-        THEME_FILE_NAME,
+        APP_TITLE, ARCHIVE_DATE_FORMAT, CONFIG_DIR_NAME, CONFIG_EXPORT_FILE_NAME, CONFIG_FILE_NAME,
+        CONFIG_FILE_VERSION, FIELD_NOT_SET, SAVE_CHECKSUM_FILE_EXTENSION, SAVE_DIR_NAME,
+        SAVE_FILE_NAME, SAVE_FILE_SCHEMA_VERSION, SAVE_NOTE_FILE_EXTENSION,
+        SESSION_MARKER_FILE_NAME, THEME_DIR_NAME, THEME_FILE_NAME,
     },
+    error::KanbanError,
     inputs::key::Key,
     io::handler::prepare_config_dir,
-    ui::Theme,
+    ui::{TerminalColorSchemeFormat, Theme},
 };
 
 extern crate savefile;
 
-pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String> {
+pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, KanbanError> {
+    get_config_with_warnings(ignore_overlapped_keybinds).map(|(config, _)| config)
+}
+
+/// Reads the config file, fields that cannot be read (for example ones written by a newer version
+/// of the app) are left at their defaults instead of resetting the whole config, the returned
+/// warnings say what was skipped
+pub fn get_config_with_warnings(
+    ignore_overlapped_keybinds: bool,
+) -> Result<(AppConfig, Vec<String>), KanbanError> {
+    let mut warnings = Vec::new();
     let config_dir_status = get_config_dir();
     let config_dir = if let Ok(config_dir) = config_dir_status {
         config_dir
@@ -26,17 +54,29 @@ pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String>
         return Err(config_dir_status.unwrap_err());
     };
     let config_path = config_dir.join(CONFIG_FILE_NAME);
-    let config = match fs::read_to_string(config_path) {
-        Ok(config) => AppConfig {
-            // if config file has been found, parse it, if an error occurs, use default config and write it to file
-            ..serde_json::from_str(&config).unwrap_or_else(|e| {
-                error!("Error parsing config file: {}", e);
-                let write_config_status = write_config(&AppConfig::default());
-                if write_config_status.is_err() {
-                    error!("{}", write_config_status.unwrap_err());
+    let mut config = match fs::read_to_string(config_path) {
+        // if config file has been found, parse it, if an error occurs keep the fields that can be
+        // read, if none can be read use default config and write it to file
+        Ok(config) => match serde_json::from_str::<AppConfig>(&config) {
+            Ok(config) => config,
+            Err(e) => match parse_config_leniently(&config) {
+                Some((config, skipped_fields)) => {
+                    debug!("Error parsing config file: {}", e);
+                    warnings.push(format!(
+                        "Could not read config settings: {}, using their defaults",
+                        skipped_fields.join(", ")
+                    ));
+                    config
+                }
+                None => {
+                    error!("Error parsing config file: {}", e);
+                    let write_config_status = write_config(&AppConfig::default());
+                    if write_config_status.is_err() {
+                        error!("{}", write_config_status.unwrap_err());
+                    }
+                    AppConfig::default()
                 }
-                AppConfig::default()
-            })
+            },
         },
         Err(_) => {
             // if config file has not been found, use default config and write it to file
@@ -48,13 +88,67 @@ pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String>
             AppConfig::default()
         }
     };
-    let config_keybinds = config.keybindings.clone();
+    // changes that cannot be undone always ask, a hand edited config may still turn them off
+    if config.permanent_change_confirmation == ConfirmationStyle::None {
+        config.permanent_change_confirmation = ConfirmationStyle::Popup;
+        warnings.push(String::from(
+            "Changes that cannot be undone always ask for confirmation, using Popup",
+        ));
+    }
+    if config.config_version > CONFIG_FILE_VERSION {
+        warnings.push(format!(
+            "Config file is from a newer version of {}, settings this version does not know are ignored",
+            APP_TITLE
+        ));
+    }
+    // the config is written back in the format of this version
+    config.config_version = CONFIG_FILE_VERSION;
+    for command in &config.custom_commands {
+        let unknown_actions = command.unknown_actions(&config.keybindings);
+        if !unknown_actions.is_empty() {
+            warnings.push(format!(
+                "Custom command '{}' uses unknown actions: {}, it can not be run until they are fixed",
+                command.name,
+                unknown_actions.join(", ")
+            ));
+        }
+    }
     // make sure there is no overlap between keybinds
     if ignore_overlapped_keybinds {
-        return Ok(config);
+        return Ok((config, warnings));
     }
+    let overlapped_keys = get_overlapped_keybinds(&config.keybindings);
+    if !overlapped_keys.is_empty() {
+        return Err(KanbanError::Overlap(overlapped_keys));
+    }
+    Ok((config, warnings))
+}
+
+/// Builds a config from the fields of the config file that can be read on their own, returns the
+/// config and the names of the fields that were skipped, `None` if the file is not a JSON object
+fn parse_config_leniently(config_str: &str) -> Option<(AppConfig, Vec<String>)> {
+    let file_fields = match serde_json::from_str::<serde_json::Value>(config_str) {
+        Ok(serde_json::Value::Object(file_fields)) => file_fields,
+        _ => return None,
+    };
+    let mut merged_config = serde_json::to_value(AppConfig::default()).ok()?;
+    let mut skipped_fields = Vec::new();
+    for (field_name, field_value) in file_fields {
+        let mut candidate_config = merged_config.clone();
+        candidate_config[field_name.as_str()] = field_value;
+        if serde_json::from_value::<AppConfig>(candidate_config.clone()).is_ok() {
+            merged_config = candidate_config;
+        } else {
+            skipped_fields.push(field_name);
+        }
+    }
+    let config = serde_json::from_value::<AppConfig>(merged_config).ok()?;
+    Some((config, skipped_fields))
+}
+
+fn get_overlapped_keybinds(keybindings: &KeyBindings) -> Vec<Key> {
     let mut key_count_map: HashMap<Key, u16> = HashMap::new();
-    for (_, value) in config_keybinds.iter() {
+    for (_, value) in keybindings.iter() {
         for key in value.iter() {
             let key_count = key_count_map.entry(*key).or_insert(0);
             *key_count += 1;
@@ -66,21 +160,23 @@ pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String>
             overlapped_keys.push(*key);
         }
     }
-    if !overlapped_keys.is_empty() {
-        let mut overlapped_keys_str = String::new();
-        for key in overlapped_keys.iter() {
-            overlapped_keys_str.push_str(&format!("{:?}, ", key));
+    for (index, chord) in keybindings.chords.iter().enumerate() {
+        if keybindings
+            .chord_conflict(&chord.keys, Some(index))
+            .is_some()
+        {
+            if let Some(first_key) = chord.keys.first() {
+                if !overlapped_keys.contains(first_key) {
+                    overlapped_keys.push(*first_key);
+                }
+            }
         }
-        return Err(format!(
-            "Overlapped keybinds found: {}",
-            overlapped_keys_str
-        ));
     }
-    Ok(config)
+    overlapped_keys
 }
 
-pub fn write_config(config: &AppConfig) -> Result<(), String> {
-    let config_str = serde_json::to_string_pretty(&config).unwrap();
+pub fn write_config(config: &AppConfig) -> Result<(), KanbanError> {
+    let config_str = serde_json::to_string_pretty(&config)?;
     prepare_config_dir()?;
     let config_dir = get_config_dir()?;
     let write_result = fs::write(config_dir.join(CONFIG_FILE_NAME), config_str);
@@ -88,7 +184,7 @@ pub fn write_config(config: &AppConfig) -> Result<(), String> {
         Ok(_) => Ok(()),
         Err(e) => {
             debug!("Error writing config file: {}", e);
-            Err("Error writing config file".to_string())
+            Err(e.into())
         }
     }
 }
@@ -115,7 +211,8 @@ pub fn reset_config() {
     }
 }
 
-pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<(), SavefileError> {
+/// Saves the boards as a new version of today's save file and returns the name of the file
+pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<String, SavefileError> {
     let get_config_status = get_config(false);
     let config = if let Ok(config) = get_config_status {
         config
@@ -127,7 +224,7 @@ pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<(), SavefileError
     // versioning style is: SAVE_FILE_NAME_27-12-2020_v1
     // if the file exists, increment the version number
     // if the file does not exist, version number is 1
-    let files = fs::read_dir(&config.save_directory)?;
+    let files = fs::read_dir(config.get_save_directory())?;
     let mut version = 1;
     for file in files {
         let file = file?;
@@ -167,12 +264,198 @@ pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<(), SavefileError
         chrono::Local::now().format("%d-%m-%Y"),
         version
     );
-    let file_path = config.save_directory.join(file_name);
-    let save_status = save_file(file_path, version, &boards);
-    match save_status {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e),
+    let temp_path = get_save_temp_path(&config.get_save_directory(), &file_name);
+    save_file(temp_path, SAVE_FILE_SCHEMA_VERSION, &boards)?;
+    finish_save_file(&config.get_save_directory(), &file_name)?;
+    if let Some(max_saves_to_keep) = config.max_saves_to_keep {
+        prune_old_saves(&config.get_save_directory(), max_saves_to_keep);
+    }
+    Ok(file_name)
+}
+
+fn get_save_note_path(save_directory: &Path, save_file_name: &str) -> PathBuf {
+    save_directory.join(format!("{}.{}", save_file_name, SAVE_NOTE_FILE_EXTENSION))
+}
+
+/// Stores a short note about a save file in a sidecar file next to it
+pub fn write_save_note(
+    save_directory: &Path,
+    save_file_name: &str,
+    note: &str,
+) -> Result<(), KanbanError> {
+    fs::write(get_save_note_path(save_directory, save_file_name), note)?;
+    Ok(())
+}
+
+/// The note stored with a save file, `None` if the save has no note
+pub fn get_save_note(save_directory: &Path, save_file_name: &str) -> Option<String> {
+    let note = fs::read_to_string(get_save_note_path(save_directory, save_file_name)).ok()?;
+    let note = note.lines().collect::<Vec<&str>>().join(" ");
+    if note.trim().is_empty() {
+        None
+    } else {
+        Some(note.trim().to_string())
+    }
+}
+
+/// Removes the note of a save file if it has one
+pub fn delete_save_note(save_directory: &Path, save_file_name: &str) {
+    let note_path = get_save_note_path(save_directory, save_file_name);
+    if note_path.exists() {
+        if let Err(e) = fs::remove_file(&note_path) {
+            debug!("Error deleting save note {:?}: {}", note_path, e);
+        }
+    }
+}
+
+fn get_save_checksum_path(save_directory: &Path, save_file_name: &str) -> PathBuf {
+    save_directory.join(format!(
+        "{}.{}",
+        save_file_name, SAVE_CHECKSUM_FILE_EXTENSION
+    ))
+}
+
+/// Saves are written to this path first and only moved into place by `finish_save_file`, so a
+/// crash while saving never leaves a partly written save behind
+pub fn get_save_temp_path(save_directory: &Path, save_file_name: &str) -> PathBuf {
+    save_directory.join(format!("{}.tmp", save_file_name))
+}
+
+/// Writes the checksum of a save written to its temp path and then moves the save into place. The
+/// temp file is removed when either step fails so no partly finished save is left behind
+pub fn finish_save_file(save_directory: &Path, save_file_name: &str) -> Result<(), io::Error> {
+    let temp_path = get_save_temp_path(save_directory, save_file_name);
+    let finish_status = fs::read(&temp_path)
+        .and_then(|save_bytes| write_save_checksum(save_directory, save_file_name, &save_bytes))
+        .and_then(|_| fs::rename(&temp_path, save_directory.join(save_file_name)));
+    if let Err(e) = finish_status {
+        error!("Cannot finish saving {}: {}", save_file_name, e);
+        remove_save_temp_file(&temp_path);
+        delete_save_checksum(save_directory, save_file_name);
+        return Err(e);
     }
+    Ok(())
+}
+
+/// Removes the temp files of saves that were never finished, e.g. when the app was killed while
+/// saving
+pub fn remove_leftover_save_temp_files(save_directory: &Path) {
+    let files = match fs::read_dir(save_directory) {
+        Ok(files) => files,
+        Err(e) => {
+            debug!("Cannot read the save directory: {}", e);
+            return;
+        }
+    };
+    for file in files.filter_map(|file| file.ok()) {
+        let path = file.path();
+        if path.is_file()
+            && path
+                .extension()
+                .map_or(false, |extension| extension == "tmp")
+        {
+            info!("Removing unfinished save {:?}", path);
+            remove_save_temp_file(&path);
+        }
+    }
+}
+
+fn remove_save_temp_file(temp_path: &Path) {
+    if temp_path.exists() {
+        if let Err(e) = fs::remove_file(temp_path) {
+            debug!("Error deleting save temp file {:?}: {}", temp_path, e);
+        }
+    }
+}
+
+/// Writes the SHA-256 of a save file to a sidecar file next to it, in the format `sha256sum -c`
+/// reads
+fn write_save_checksum(
+    save_directory: &Path,
+    save_file_name: &str,
+    save_bytes: &[u8],
+) -> Result<(), io::Error> {
+    fs::write(
+        get_save_checksum_path(save_directory, save_file_name),
+        format!("{}  {}\n", sha256_hex(save_bytes), save_file_name),
+    )
+}
+
+/// Checks a save file against its sidecar checksum so a truncated or corrupted save is reported
+/// before it is loaded. Saves without a checksum, e.g. those written before checksums were added,
+/// are loaded without verification
+fn verify_save_checksum(save_directory: &Path, save_file_name: &str) -> Result<(), SavefileError> {
+    let checksum_path = get_save_checksum_path(save_directory, save_file_name);
+    let expected = match fs::read_to_string(&checksum_path) {
+        Ok(checksum_file) => checksum_file
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_lowercase(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            debug!(
+                "No checksum found for {}, loading it without verification",
+                save_file_name
+            );
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let actual = sha256_hex(&fs::read(save_directory.join(save_file_name))?);
+    if actual != expected {
+        error!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            save_file_name, expected, actual
+        );
+        return Err(SavefileError::GeneralError {
+            msg: format!(
+                "{} does not match its checksum, the save may be truncated or corrupted",
+                save_file_name
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Removes the checksum of a save file if it has one
+pub fn delete_save_checksum(save_directory: &Path, save_file_name: &str) {
+    let checksum_path = get_save_checksum_path(save_directory, save_file_name);
+    if checksum_path.exists() {
+        if let Err(e) = fs::remove_file(&checksum_path) {
+            debug!("Error deleting save checksum {:?}: {}", checksum_path, e);
+        }
+    }
+}
+
+/// SHA-256 of the bytes as lowercase hex, the format `sha256sum` writes
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn get_session_marker_path() -> Result<PathBuf, KanbanError> {
+    Ok(get_config_dir()?.join(SESSION_MARKER_FILE_NAME))
+}
+
+/// True when the marker of an earlier session is still there, which means it crashed or was killed
+pub fn previous_session_crashed() -> bool {
+    get_session_marker_path()
+        .map(|marker_path| marker_path.exists())
+        .unwrap_or(false)
+}
+
+/// Marks the session as running, the marker is removed again on a clean exit
+pub fn write_session_marker() -> Result<(), KanbanError> {
+    prepare_config_dir()?;
+    fs::write(get_session_marker_path()?, std::process::id().to_string())?;
+    Ok(())
+}
+
+pub fn remove_session_marker() -> Result<(), KanbanError> {
+    let marker_path = get_session_marker_path()?;
+    if marker_path.exists() {
+        fs::remove_file(marker_path)?;
+    }
+    Ok(())
 }
 
 pub fn get_local_kanban_state(
@@ -187,14 +470,352 @@ pub fn get_local_kanban_state(
         debug!("Error getting config: {}", get_config_status.unwrap_err());
         AppConfig::default()
     };
-    let file_path = config.save_directory.join(file_name);
+    let file_path = config.get_save_directory().join(&file_name);
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
-    let boards = load_file(file_path, version)?;
+    verify_save_checksum(&config.get_save_directory(), &file_name)?;
+    let boards = load_file(file_path, version.max(SAVE_FILE_SCHEMA_VERSION))?;
+    Ok(boards)
+}
+
+/// Reads boards from JSON, either a list of boards or a JSON export with a `boards` field, and
+/// checks the board and card ids are unique so the boards can be used as the app state
+pub fn parse_boards_from_json(json: &str) -> Result<Vec<Board>, KanbanError> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let boards_value = match value {
+        serde_json::Value::Array(boards) => serde_json::Value::Array(boards),
+        serde_json::Value::Object(mut object) => object.remove("boards").ok_or_else(|| {
+            KanbanError::Parse(String::from(
+                "expected a \"boards\" field with a list of boards",
+            ))
+        })?,
+        _ => {
+            return Err(KanbanError::Parse(String::from(
+                "expected a list of boards or a JSON export with a \"boards\" field",
+            )))
+        }
+    };
+    let boards: Vec<Board> = serde_json::from_value(boards_value)?;
+    check_unique_ids(&boards)?;
+    Ok(boards)
+}
+
+/// Fails when two boards or two cards share an id
+fn check_unique_ids(boards: &[Board]) -> Result<(), KanbanError> {
+    let mut board_ids = Vec::new();
+    let mut card_ids = Vec::new();
+    for board in boards.iter() {
+        if board_ids.contains(&board.id) {
+            return Err(KanbanError::Parse(format!(
+                "board '{}' uses the id {} of another board",
+                board.name, board.id
+            )));
+        }
+        board_ids.push(board.id);
+        let archived_cards = board.archived_cards.iter().map(|archived| &archived.card);
+        for card in board.cards.iter().chain(archived_cards) {
+            if card_ids.contains(&card.id) {
+                return Err(KanbanError::Parse(format!(
+                    "card '{}' on board '{}' uses the id {} of another card",
+                    card.name, board.name, card.id
+                )));
+            }
+            card_ids.push(card.id);
+        }
+    }
+    Ok(())
+}
+
+/// Reads the boards of a file written by "Export to JSON", a bare list of boards is read too so
+/// hand edited files can be imported. Exports from a newer version of the app are read with a
+/// warning as they may have fields this version does not know, the returned warnings say so
+pub fn import_kanban_from_json(path: &Path) -> Result<(Vec<Board>, Vec<String>), KanbanError> {
+    let json = fs::read_to_string(path)?;
+    let value: serde_json::Value = serde_json::from_str(&json)?;
+    let mut warnings = Vec::new();
+    let boards = if value.is_array() {
+        serde_json::from_value::<Vec<Board>>(value)
+            .map_err(|e| KanbanError::Parse(format!("invalid boards: {}", e)))?
+    } else {
+        let export_struct = serde_json::from_value::<ExportStruct>(value)
+            .map_err(|e| KanbanError::Parse(format!("invalid JSON export: {}", e)))?;
+        let app_version = env!("CARGO_PKG_VERSION");
+        if is_newer_version(&export_struct.kanban_version, app_version) {
+            warnings.push(format!(
+                "{} was exported by version {} which is newer than this version ({}), some fields may be lost",
+                path.to_string_lossy(),
+                export_struct.kanban_version,
+                app_version
+            ));
+        }
+        export_struct.boards
+    };
+    check_unique_ids(&boards)?;
+    Ok((boards, warnings))
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct TrelloExport {
+    name: String,
+    lists: Vec<TrelloList>,
+    cards: Vec<TrelloCard>,
+    checklists: Vec<TrelloChecklist>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct TrelloList {
+    id: String,
+    name: String,
+    closed: bool,
+    pos: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct TrelloCard {
+    id: String,
+    name: String,
+    desc: Option<String>,
+    id_list: String,
+    closed: bool,
+    pos: f64,
+    due: Option<String>,
+    due_complete: bool,
+    labels: Vec<TrelloLabel>,
+    id_checklists: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct TrelloLabel {
+    name: Option<String>,
+    color: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct TrelloChecklist {
+    id: String,
+    id_card: String,
+    name: String,
+    check_items: Vec<TrelloCheckItem>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct TrelloCheckItem {
+    name: String,
+    state: String,
+}
+
+/// Reads a board exported from Trello as JSON, every open list becomes a board and its cards
+/// become cards. Labels become tags, checklist items become comments and due dates are written
+/// in the configured date format. Cards archived in Trello are archived here too, archived lists
+/// and fields that have no match are left out
+pub fn import_from_trello(path: &Path) -> Result<Vec<Board>, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.to_string_lossy(), e))?;
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let today = chrono::Local::now().date_naive();
+    parse_trello_export(&json, config.date_format, today, &chrono::Local)
+}
+
+/// Builds the boards of a Trello export, lists and cards keep the order they have in Trello. Due
+/// dates are stored in UTC by Trello and are written in the given time zone
+fn parse_trello_export<Tz: chrono::TimeZone>(
+    json: &str,
+    date_format: DateFormat,
+    today: NaiveDate,
+    timezone: &Tz,
+) -> Result<Vec<Board>, String> {
+    let mut trello: TrelloExport =
+        serde_json::from_str(json).map_err(|e| format!("Invalid Trello export: {}", e))?;
+    if trello.lists.is_empty() {
+        return Err(String::from("Invalid Trello export: no lists found"));
+    }
+    // the export lists them in creation order, pos is their place on the Trello board
+    trello
+        .lists
+        .sort_by(|a, b| a.pos.partial_cmp(&b.pos).unwrap_or(Ordering::Equal));
+    trello
+        .cards
+        .sort_by(|a, b| a.pos.partial_cmp(&b.pos).unwrap_or(Ordering::Equal));
+    let mut boards = Vec::new();
+    for list in trello.lists.iter().filter(|list| !list.closed) {
+        let description = if trello.name.is_empty() {
+            String::from("Imported from Trello")
+        } else {
+            format!("Imported from the Trello board '{}'", trello.name)
+        };
+        let mut board = Board::new(list.name.clone(), description);
+        for trello_card in trello.cards.iter().filter(|card| card.id_list == list.id) {
+            let tags = trello_card
+                .labels
+                .iter()
+                .filter_map(|label| {
+                    label
+                        .name
+                        .clone()
+                        .filter(|name| !name.trim().is_empty())
+                        .or_else(|| label.color.clone())
+                })
+                .map(|tag| tag.trim().to_string())
+                .collect::<Vec<String>>();
+            let mut checklists = trello
+                .checklists
+                .iter()
+                .filter(|checklist| checklist.id_card == trello_card.id)
+                .collect::<Vec<&TrelloChecklist>>();
+            // checklists are kept in the order they have on the card
+            checklists.sort_by_key(|checklist| {
+                trello_card
+                    .id_checklists
+                    .iter()
+                    .position(|id| *id == checklist.id)
+                    .unwrap_or(usize::MAX)
+            });
+            let comments = checklists
+                .iter()
+                .flat_map(|checklist| {
+                    checklist.check_items.iter().map(move |item| {
+                        let check = if item.state == "complete" { "x" } else { " " };
+                        format!("{}: [{}] {}", checklist.name, check, item.name)
+                    })
+                })
+                .collect::<Vec<String>>();
+            let date_due = trello_card
+                .due
+                .as_deref()
+                .and_then(|due| chrono::DateTime::parse_from_rfc3339(due).ok())
+                .map(|due| {
+                    due.with_timezone(timezone)
+                        .naive_local()
+                        .format(date_format.to_parser_string())
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let mut card = Card::new(
+                trello_card.name.clone(),
+                trello_card.desc.clone().unwrap_or_default(),
+                date_due,
+                CardPriority::Low,
+                tags,
+                comments,
+            );
+            if trello_card.due_complete {
+                card.card_status = CardStatus::Complete;
+                card.date_completed = chrono::Utc::now().to_string();
+            }
+            if trello_card.closed {
+                board.archived_cards.push(ArchivedCard {
+                    card,
+                    date_archived: today.format(ARCHIVE_DATE_FORMAT).to_string(),
+                });
+            } else {
+                board.cards.push(card);
+            }
+        }
+        boards.push(board);
+    }
     Ok(boards)
 }
 
+/// Compares dotted version numbers like "0.9.3", anything after a '-' is ignored and missing
+/// parts count as 0 so "1.0" and "1.0.0" are the same version
+fn is_newer_version(version: &str, than: &str) -> bool {
+    let parse = |version: &str| {
+        version
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.trim().parse::<u64>().unwrap_or(0))
+            .collect::<Vec<u64>>()
+    };
+    let mut version = parse(version);
+    let mut than = parse(than);
+    let length = version.len().max(than.len());
+    version.resize(length, 0);
+    than.resize(length, 0);
+    version > than
+}
+
+/// Keeps only the save files and orders them from oldest to newest
+fn sort_save_files(savefiles: &mut Vec<String>) {
+    // keep only the files which have follow the pattern SAVEFILE_NAME_<NaiveDate in format DD-MM-YYYY>_v<version number>
+    // example kanban_02-12-2022_v7
+    // use regex to match the pattern
+    let re = Regex::new(r"^kanban_\d{2}-\d{2}-\d{4}_v\d+$").unwrap();
+    savefiles.retain(|file| re.is_match(file));
+    // order the files by date and version
+    savefiles.sort_by(|a, b| {
+        let a_date = a.split('_').nth(1).unwrap();
+        let b_date = b.split('_').nth(1).unwrap();
+        let a_version = a.split('_').nth(2).unwrap();
+        let b_version = b.split('_').nth(2).unwrap();
+        let a_date = chrono::NaiveDate::parse_from_str(a_date, "%d-%m-%Y").unwrap();
+        let b_date = chrono::NaiveDate::parse_from_str(b_date, "%d-%m-%Y").unwrap();
+        let a_version = a_version.split('v').nth(1).unwrap().parse::<u32>().unwrap();
+        let b_version = b_version.split('v').nth(1).unwrap().parse::<u32>().unwrap();
+        if a_date > b_date {
+            std::cmp::Ordering::Greater
+        } else if a_date < b_date {
+            std::cmp::Ordering::Less
+        } else if a_version > b_version {
+            std::cmp::Ordering::Greater
+        } else if a_version < b_version {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+}
+
+/// Deletes the oldest save files (and their notes) so only the newest `max_saves_to_keep` are
+/// left, other files in the save directory are never touched. Returns the deleted file names
+pub fn prune_old_saves(save_directory: &Path, max_saves_to_keep: usize) -> Vec<String> {
+    if max_saves_to_keep == 0 {
+        return vec![];
+    }
+    let mut savefiles = match fs::read_dir(save_directory) {
+        Ok(files) => files
+            .filter_map(|file| file.ok())
+            .filter_map(|file| file.file_name().into_string().ok())
+            .collect::<Vec<String>>(),
+        Err(e) => {
+            error!("Cannot read the save directory to prune old saves: {}", e);
+            return vec![];
+        }
+    };
+    sort_save_files(&mut savefiles);
+    if savefiles.len() <= max_saves_to_keep {
+        return vec![];
+    }
+    let prune_count = savefiles.len() - max_saves_to_keep;
+    let mut deleted = Vec::new();
+    for file_name in savefiles.into_iter().take(prune_count) {
+        match fs::remove_file(save_directory.join(&file_name)) {
+            Ok(_) => {
+                info!("Deleted old save file {}", file_name);
+                delete_save_note(save_directory, &file_name);
+                delete_save_checksum(save_directory, &file_name);
+                deleted.push(file_name);
+            }
+            Err(e) => error!("Cannot delete old save file {}: {}", file_name, e),
+        }
+    }
+    deleted
+}
+
 pub fn get_available_local_savefiles() -> Option<Vec<String>> {
     let get_config_status = get_config(false);
     let config = if let Ok(config) = get_config_status {
@@ -203,7 +824,7 @@ pub fn get_available_local_savefiles() -> Option<Vec<String>> {
         debug!("Error getting config: {}", get_config_status.unwrap_err());
         AppConfig::default()
     };
-    let read_dir_status = fs::read_dir(&config.save_directory);
+    let read_dir_status = fs::read_dir(config.get_save_directory());
     match read_dir_status {
         Ok(files) => {
             let mut savefiles = Vec::new();
@@ -212,33 +833,7 @@ pub fn get_available_local_savefiles() -> Option<Vec<String>> {
                 let file_name = file.file_name().into_string().unwrap();
                 savefiles.push(file_name);
             }
-            // keep only the files which have follow the pattern SAVEFILE_NAME_<NaiveDate in format DD-MM-YYYY>_v<version number>
-            // example kanban_02-12-2022_v7
-            // use regex to match the pattern
-            let re = Regex::new(r"^kanban_\d{2}-\d{2}-\d{4}_v\d+$").unwrap();
-            savefiles.retain(|file| re.is_match(file));
-            // order the files by date and version
-            savefiles.sort_by(|a, b| {
-                let a_date = a.split('_').nth(1).unwrap();
-                let b_date = b.split('_').nth(1).unwrap();
-                let a_version = a.split('_').nth(2).unwrap();
-                let b_version = b.split('_').nth(2).unwrap();
-                let a_date = chrono::NaiveDate::parse_from_str(a_date, "%d-%m-%Y").unwrap();
-                let b_date = chrono::NaiveDate::parse_from_str(b_date, "%d-%m-%Y").unwrap();
-                let a_version = a_version.split('v').nth(1).unwrap().parse::<u32>().unwrap();
-                let b_version = b_version.split('v').nth(1).unwrap().parse::<u32>().unwrap();
-                if a_date > b_date {
-                    std::cmp::Ordering::Greater
-                } else if a_date < b_date {
-                    std::cmp::Ordering::Less
-                } else if a_version > b_version {
-                    std::cmp::Ordering::Greater
-                } else if a_version < b_version {
-                    std::cmp::Ordering::Less
-                } else {
-                    std::cmp::Ordering::Equal
-                }
-            });
+            sort_save_files(&mut savefiles);
             Some(savefiles)
         }
         Err(_) => {
@@ -261,13 +856,14 @@ pub fn get_available_local_savefiles() -> Option<Vec<String>> {
     }
 }
 
-pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, String> {
-    #[derive(Serialize)]
-    struct ExportStruct {
-        kanban_version: String,
-        export_date: String,
-        boards: Vec<Board>,
-    }
+#[derive(Serialize, Deserialize)]
+struct ExportStruct {
+    kanban_version: String,
+    export_date: String,
+    boards: Vec<Board>,
+}
+
+pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, KanbanError> {
     // use serde serialization
     let get_config_status = get_config(false);
     let config = if let Ok(config) = get_config_status {
@@ -286,31 +882,642 @@ pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, String> {
         export_date: date.to_string(),
         boards: boards.to_vec(),
     };
-    let file_path = config.save_directory.join("kanban_export.json");
-    // check if file exists if so add a number to the end of the file name with _<number>
-    let file_path = if file_path.exists() {
-        let mut i = 1;
-        let mut new_file_path = config
-            .save_directory
-            .join(format!("kanban_export_{}.json", i));
-        while new_file_path.exists() {
-            i += 1;
-            new_file_path = config
-                .save_directory
-                .join(format!("kanban_export_{}.json", i));
-        }
-        new_file_path
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_export", "json");
+    // write to file
+    let write_status = fs::write(
+        file_path.clone(),
+        serde_json::to_string_pretty(&export_struct).unwrap(),
+    );
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes the card counts and points of every board and their totals to a JSON file for reporting
+/// tools, returns the path of the file
+pub fn export_board_statistics_to_json(boards: &[Board]) -> Result<String, KanbanError> {
+    #[derive(Serialize)]
+    struct StatisticsExport {
+        kanban_version: String,
+        export_date: String,
+        total: BoardStatistics,
+        boards: Vec<BoardStatistics>,
+    }
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
     } else {
-        file_path
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
     };
-    // write to file
+    let today = chrono::Local::now().date_naive();
+    let is_overdue = |card: &Card| {
+        date_format_finder(&card.date_due)
+            .ok()
+            .and_then(|date_format| days_until_due(&card.date_due, date_format, today).ok())
+            .map_or(false, |days| days < 0)
+    };
+    let board_statistics = boards
+        .iter()
+        .map(|board| BoardStatistics::new(board, is_overdue))
+        .collect::<Vec<BoardStatistics>>();
+    let export_struct = StatisticsExport {
+        kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+        export_date: chrono::Local::now().format("%d-%m-%Y").to_string(),
+        total: BoardStatistics::total("All Boards", &board_statistics),
+        boards: board_statistics,
+    };
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_statistics", "json");
     let write_status = fs::write(
         file_path.clone(),
         serde_json::to_string_pretty(&export_struct).unwrap(),
     );
     match write_status {
         Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes the current keybindings with their descriptions to a Markdown cheatsheet in the save
+/// directory, grouped by category with the chords at the end, returns the path of the file
+pub fn export_keybinding_cheatsheet(config: &AppConfig) -> Result<String, KanbanError> {
+    let keybindings = &config.keybindings;
+    let keys_to_string = |keys: &[Key]| {
+        keys.iter()
+            .map(|key| format!("`{}`", key))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let mut rows: Vec<(Action, String)> = keybindings
+        .iter()
+        .filter_map(|(name, keys)| {
+            KeyBindings::str_to_action(keybindings.clone(), name)
+                .map(|action| (*action, keys_to_string(keys)))
+        })
+        .collect();
+    // actions that can not be rebound keep their default keys
+    for action in Action::all() {
+        if !action.keys().is_empty() && !rows.iter().any(|(bound, _)| *bound == action) {
+            rows.push((action, keys_to_string(action.keys())));
+        }
+    }
+    let mut cheatsheet = format!("# {} Keybindings\n", APP_TITLE);
+    for category in ["General", "Navigation", "Cards", "Boards", "View"] {
+        cheatsheet.push_str(&format!(
+            "\n## {}\n\n| Keys | Action |\n| --- | --- |\n",
+            category
+        ));
+        for (action, keys) in rows
+            .iter()
+            .filter(|(action, _)| action.category() == category)
+        {
+            cheatsheet.push_str(&format!("| {} | {} |\n", keys, action));
+        }
+    }
+    if !keybindings.chords.is_empty() {
+        cheatsheet.push_str("\n## Chords\n\n| Keys | Action |\n| --- | --- |\n");
+        for chord in keybindings.chords.iter() {
+            let action = KeyBindings::str_to_action(keybindings.clone(), &chord.action)
+                .map(|action| action.to_string())
+                .unwrap_or_else(|| chord.action.clone());
+            let keys = chord
+                .keys
+                .iter()
+                .map(|key| format!("`{}`", key))
+                .collect::<Vec<String>>()
+                .join(" then ");
+            cheatsheet.push_str(&format!("| {} | {} |\n", keys, action));
+        }
+    }
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_cheatsheet", "md");
+    fs::write(&file_path, cheatsheet)?;
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Copies the text to the system clipboard with the clipboard command of the platform, returns
+/// an error when none of the commands are available or they fail
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let clipboard_commands: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else if cfg!(target_os = "windows") {
+        &[("clip", &[])]
+    } else {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    };
+    for (program, args) in clipboard_commands {
+        let child = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                debug!("Could not run {}: {}", program, e);
+                continue;
+            }
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            if let Err(e) = stdin.write_all(text.as_bytes()) {
+                debug!("Could not write to {}: {}", program, e);
+                continue;
+            }
+        }
+        match child.wait() {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => debug!("{} exited with {}", program, status),
+            Err(e) => debug!("Could not wait for {}: {}", program, e),
+        }
+    }
+    Err("No clipboard command is available".to_string())
+}
+
+pub fn export_kanban_to_org(boards: &[Board]) -> Result<String, KanbanError> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let version = env!("CARGO_PKG_VERSION");
+    let date = chrono::Local::now().format("%d-%m-%Y");
+    let mut org = String::new();
+    org.push_str("#+TITLE: Kanban Export\n");
+    org.push_str(&format!("#+DATE: {}\n", date));
+    org.push_str(&format!("#+KANBAN_VERSION: {}\n", version));
+    // stale cards are still open, so they go before the | separator
+    org.push_str("#+TODO: TODO STALE | DONE\n");
+    for board in boards {
+        org.push_str(&format!("\n* {}\n", board.name));
+        org.push_str(":PROPERTIES:\n");
+        org.push_str(&format!(":ID: {}\n", board.id));
+        org.push_str(":END:\n");
+        if !board.description.is_empty() && board.description != FIELD_NOT_SET {
+            org.push_str(&format!("{}\n", board.description));
+        }
+        for card in &board.cards {
+            let keyword = match card.card_status {
+                CardStatus::Active => "TODO",
+                CardStatus::Complete => "DONE",
+                CardStatus::Stale => "STALE",
+            };
+            let priority = match card.priority {
+                CardPriority::High => "A",
+                CardPriority::Medium => "B",
+                CardPriority::Low => "C",
+            };
+            let mut headline = format!("** {} [#{}] {}", keyword, priority, card.name);
+            if !card.tags.is_empty() {
+                // org tags cannot contain whitespace or colons
+                let tags = card
+                    .tags
+                    .iter()
+                    .map(|tag| tag.trim().replace([' ', ':'], "_"))
+                    .filter(|tag| !tag.is_empty())
+                    .collect::<Vec<String>>();
+                if !tags.is_empty() {
+                    headline.push_str(&format!(" :{}:", tags.join(":")));
+                }
+            }
+            org.push_str(&format!("{}\n", headline));
+            if let Some(deadline) = get_org_timestamp(&card.date_due) {
+                org.push_str(&format!("DEADLINE: {}\n", deadline));
+            }
+            org.push_str(":PROPERTIES:\n");
+            org.push_str(&format!(":ID: {}\n", card.id));
+            org.push_str(&format!(":CREATED: {}\n", card.date_created));
+            org.push_str(&format!(":MODIFIED: {}\n", card.date_modified));
+            org.push_str(&format!(":COMPLETED: {}\n", card.date_completed));
+            if let Some(points) = card.points {
+                org.push_str(&format!(":POINTS: {}\n", points));
+            }
+            org.push_str(":END:\n");
+            if !card.description.is_empty() && card.description != FIELD_NOT_SET {
+                org.push_str(&format!("{}\n", card.description));
+            }
+            if !card.comments.is_empty() {
+                org.push_str("*** Comments\n");
+                for comment in &card.comments {
+                    org.push_str(&format!("- {}\n", comment));
+                }
+            }
+        }
+    }
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_export", "org");
+    let write_status = fs::write(file_path.clone(), org);
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes the boards as Markdown for pasting into a wiki or a pull request, boards are `##`
+/// headings and cards are `###` headings with their details. It cannot be imported again
+pub fn export_kanban_to_markdown(boards: &[Board]) -> Result<String, KanbanError> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let version = env!("CARGO_PKG_VERSION");
+    let date = chrono::Local::now().format("%d-%m-%Y");
+    let is_set = |field: &str| !field.trim().is_empty() && field != FIELD_NOT_SET;
+    let mut markdown = String::new();
+    markdown.push_str("# Kanban Export\n\n");
+    markdown.push_str(&format!(
+        "Exported on {} with kanban version {}\n",
+        date, version
+    ));
+    for board in boards {
+        markdown.push_str(&format!("\n## {}\n", escape_markdown_text(&board.name)));
+        if is_set(&board.description) {
+            markdown.push_str(&format!("\n{}\n", escape_markdown_text(&board.description)));
+        }
+        for card in &board.cards {
+            markdown.push_str(&format!("\n### {}\n", escape_markdown_text(&card.name)));
+            if is_set(&card.description) {
+                markdown.push_str(&format!("\n{}\n", escape_markdown_text(&card.description)));
+            }
+            markdown.push('\n');
+            markdown.push_str(&format!("- **Priority:** {}\n", card.priority));
+            markdown.push_str(&format!("- **Status:** {}\n", card.card_status));
+            if is_set(&card.date_due) {
+                markdown.push_str(&format!("- **Due Date:** {}\n", card.date_due));
+            }
+            let tags = card
+                .tags
+                .iter()
+                .filter(|tag| !tag.trim().is_empty())
+                .map(|tag| escape_markdown_text(tag.trim()).replace('\n', " "))
+                .collect::<Vec<String>>();
+            if !tags.is_empty() {
+                markdown.push_str(&format!("- **Tags:** {}\n", tags.join(", ")));
+            }
+            let comments = card
+                .comments
+                .iter()
+                .filter(|comment| !comment.trim().is_empty())
+                .collect::<Vec<&String>>();
+            if !comments.is_empty() {
+                markdown.push_str("\n**Comments:**\n\n");
+                for comment in comments {
+                    // later lines are indented to stay inside the list item
+                    let comment = escape_markdown_text(comment.trim()).replace('\n', "\n  ");
+                    markdown.push_str(&format!("- {}\n", comment));
+                }
+            }
+        }
+    }
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_export", "md");
+    match fs::write(&file_path, markdown) {
+        Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Escapes the markers that start a block at the start of a line, so user text is not read as a
+/// heading, a list, a quote, a table, a code fence or a rule. Ordered list numbers like `1.` or
+/// `1)` get the escape before their `.` or `)`
+fn escape_markdown_text(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+            if trimmed.starts_with(['#', '-', '+', '*', '>', '|', '`', '~', '=']) {
+                format!("{}\\{}", indent, trimmed)
+            } else if digits > 0 && trimmed[digits..].starts_with(['.', ')']) {
+                format!("{}{}\\{}", indent, &trimmed[..digits], &trimmed[digits..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Writes one row per card for spreadsheets, tags are joined with `;` and unset fields are left
+/// empty
+pub fn export_kanban_to_csv(boards: &[Board]) -> Result<String, KanbanError> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let or_empty = |field: &str| {
+        if field == FIELD_NOT_SET {
+            String::new()
+        } else {
+            field.to_string()
+        }
+    };
+    let mut csv = String::from(
+        "board_name,card_name,description,priority,status,due_date,tags,comment_count\r\n",
+    );
+    for board in boards {
+        for card in &board.cards {
+            let tags = card
+                .tags
+                .iter()
+                .map(|tag| tag.trim())
+                .filter(|tag| !tag.is_empty())
+                .collect::<Vec<&str>>()
+                .join(";");
+            let row = [
+                board.name.clone(),
+                card.name.clone(),
+                or_empty(&card.description),
+                card.priority.to_string(),
+                card.card_status.to_string(),
+                or_empty(&card.date_due),
+                tags,
+                card.comments.len().to_string(),
+            ];
+            let row = row
+                .iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<String>>()
+                .join(",");
+            csv.push_str(&row);
+            csv.push_str("\r\n");
+        }
+    }
+    let file_path = get_export_file_path(&config.get_save_directory(), "kanban_export", "csv");
+    match fs::write(&file_path, csv) {
+        Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Quotes a CSV field when it has a comma, a quote or a line break, quotes inside are doubled
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Converts a due date in any of the supported date formats to an org timestamp
+fn get_org_timestamp(date_string: &str) -> Option<String> {
+    if date_string.is_empty() || date_string == FIELD_NOT_SET {
+        return None;
+    }
+    let date_format = date_format_finder(date_string).ok()?;
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        let date_time =
+            NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()).ok()?;
+        Some(date_time.format("<%Y-%m-%d %a %H:%M>").to_string())
+    } else {
+        let date = NaiveDate::parse_from_str(date_string, date_format.to_parser_string()).ok()?;
+        Some(date.format("<%Y-%m-%d %a>").to_string())
+    }
+}
+
+/// Returns <file_stem>.<extension> in the save directory, if it already exists a number is
+/// added to the end of the file name with _<number>
+fn get_export_file_path(save_directory: &Path, file_stem: &str, extension: &str) -> PathBuf {
+    let file_path = save_directory.join(format!("{}.{}", file_stem, extension));
+    if !file_path.exists() {
+        return file_path;
+    }
+    let mut i = 1;
+    let mut new_file_path = save_directory.join(format!("{}_{}.{}", file_stem, i, extension));
+    while new_file_path.exists() {
+        i += 1;
+        new_file_path = save_directory.join(format!("{}_{}.{}", file_stem, i, extension));
+    }
+    new_file_path
+}
+
+#[derive(Serialize, Deserialize)]
+struct ConfigExport {
+    kanban_version: String,
+    export_date: String,
+    config: AppConfig,
+}
+
+/// Writes the whole config, including keybindings and the name of the default theme,
+/// to a file in the save directory that can be imported on another machine
+pub fn export_config(config: &AppConfig) -> Result<String, KanbanError> {
+    let export_struct = ConfigExport {
+        kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+        export_date: chrono::Local::now().format("%d-%m-%Y").to_string(),
+        config: config.clone(),
+    };
+    let file_path = get_export_file_path(
+        &config.get_save_directory(),
+        CONFIG_EXPORT_FILE_NAME,
+        "json",
+    );
+    let write_status = fs::write(
+        file_path.clone(),
+        serde_json::to_string_pretty(&export_struct).unwrap(),
+    );
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes the colors of the theme as a terminal color scheme in the given format to a file
+/// in the save directory
+pub fn export_theme_to_terminal_color_scheme(
+    theme: &Theme,
+    format: TerminalColorSchemeFormat,
+    config: &AppConfig,
+) -> Result<String, KanbanError> {
+    let file_stem = format!("{}_color_scheme", make_file_system_safe_name(&theme.name));
+    let file_path = get_export_file_path(
+        &config.get_save_directory(),
+        &file_stem,
+        format.file_extension(),
+    );
+    let write_status = fs::write(file_path.clone(), theme.to_terminal_color_scheme(format));
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Reads the most recently modified config export from the save directory,
+/// the import is rejected if any keybindings overlap
+pub fn import_config(save_directory: &Path) -> Result<(AppConfig, String), KanbanError> {
+    let files = fs::read_dir(save_directory)?;
+    let latest_export = files
+        .filter_map(|file| file.ok())
+        .filter(|file| {
+            let file_name = file.file_name().to_string_lossy().to_string();
+            file_name.starts_with(CONFIG_EXPORT_FILE_NAME) && file_name.ends_with(".json")
+        })
+        .filter_map(|file| {
+            let modified = file.metadata().ok()?.modified().ok()?;
+            Some((modified, file.path()))
+        })
+        .max_by_key(|(modified, _)| *modified);
+    let file_path = if let Some((_, file_path)) = latest_export {
+        file_path
+    } else {
+        return Err(KanbanError::NotFound(format!(
+            "No config export found in {}",
+            save_directory.to_string_lossy()
+        )));
+    };
+    let export_str = fs::read_to_string(&file_path)?;
+    let export_struct: ConfigExport = serde_json::from_str(&export_str)?;
+    let overlapped_keys = get_overlapped_keybinds(&export_struct.config.keybindings);
+    if !overlapped_keys.is_empty() {
+        return Err(KanbanError::Overlap(overlapped_keys));
+    }
+    Ok((
+        export_struct.config,
+        file_path.to_string_lossy().to_string(),
+    ))
+}
+
+/// What a path typed in as the save directory would do, checked before it is put in the config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveDirectoryStatus {
+    Writable,
+    Missing,
+    NotADirectory,
+    NotWritable(String),
+}
+
+impl fmt::Display for SaveDirectoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveDirectoryStatus::Writable => write!(f, "Writable"),
+            SaveDirectoryStatus::Missing => {
+                write!(f, "Does not exist, it will be created on submit")
+            }
+            SaveDirectoryStatus::NotADirectory => write!(f, "Is a file, not a directory"),
+            SaveDirectoryStatus::NotWritable(reason) => write!(f, "Not writable: {}", reason),
+        }
+    }
+}
+
+/// Checks that the save directory exists and that a file can be written in it, the test file is
+/// removed straight away
+pub fn check_save_directory(path: &Path) -> SaveDirectoryStatus {
+    if !path.exists() {
+        return SaveDirectoryStatus::Missing;
+    }
+    if !path.is_dir() {
+        return SaveDirectoryStatus::NotADirectory;
+    }
+    let test_file = path.join(".rust_kanban_write_test");
+    match fs::write(&test_file, "") {
+        Ok(_) => {
+            if let Err(e) = fs::remove_file(&test_file) {
+                debug!("Could not remove {:?}: {}", test_file, e);
+            }
+            SaveDirectoryStatus::Writable
+        }
+        Err(e) => SaveDirectoryStatus::NotWritable(e.to_string()),
+    }
+}
+
+/// Makes sure a directory can be used to save in before it is put in the config, a missing
+/// directory is created and a test file is written and deleted to check it is writable
+pub fn validate_save_directory(dir: &Path) -> Result<(), String> {
+    if check_save_directory(dir) == SaveDirectoryStatus::Missing {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create the directory: {}", e))?;
+        info!("Created save directory {:?}", dir);
+    }
+    match check_save_directory(dir) {
+        SaveDirectoryStatus::Writable => Ok(()),
+        status => Err(status.to_string()),
+    }
+}
+
+/// Expands a leading `~` and any `$VAR`, `${VAR}` or `%VAR%` environment variables in a path
+/// taken from the config, variables that cannot be expanded are kept as written
+pub fn expand_path(raw: &str) -> PathBuf {
+    let mut expanded = String::new();
+    let mut rest = raw;
+    if rest == "~" || rest.starts_with("~/") || rest.starts_with("~\\") {
+        if let Some(home_dir) = home::home_dir() {
+            expanded.push_str(&home_dir.to_string_lossy());
+            rest = &rest[1..];
+        } else {
+            debug!("Could not find home directory to expand ~ in path {}", raw);
+        }
+    }
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => {
+                let braced = chars.peek() == Some(&'{');
+                if braced {
+                    chars.next();
+                }
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if (braced && next == '}')
+                        || (!braced && !(next.is_ascii_alphanumeric() || next == '_'))
+                    {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+                let closed = braced && chars.peek() == Some(&'}');
+                if closed {
+                    chars.next();
+                }
+                let literal = if braced {
+                    format!("${{{}{}", name, if closed { "}" } else { "" })
+                } else {
+                    format!("${}", name)
+                };
+                if braced && !closed {
+                    expanded.push_str(&literal);
+                } else {
+                    push_env_var(&mut expanded, &name, &literal);
+                }
+            }
+            '%' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for next in chars.by_ref() {
+                    if next == '%' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(next);
+                }
+                if closed {
+                    push_env_var(&mut expanded, &name, &format!("%{}%", name));
+                } else {
+                    expanded.push('%');
+                    expanded.push_str(&name);
+                }
+            }
+            _ => expanded.push(c),
+        }
+    }
+    PathBuf::from(expanded)
+}
+
+fn push_env_var(expanded: &mut String, name: &str, literal: &str) {
+    match env::var(name) {
+        Ok(value) if !name.is_empty() => expanded.push_str(&value),
+        _ => {
+            debug!("Could not expand {} in path, keeping it as is", literal);
+            expanded.push_str(literal);
+        }
     }
 }
 
@@ -320,10 +1527,10 @@ pub fn get_default_save_directory() -> PathBuf {
     default_save_path
 }
 
-fn get_theme_dir() -> Result<PathBuf, String> {
+fn get_theme_dir() -> Result<PathBuf, KanbanError> {
     let home_dir = home::home_dir();
     if home_dir.is_none() {
-        return Err(String::from("Error getting home directory"));
+        return Err(KanbanError::NotFound(String::from("Home directory")));
     }
     let mut theme_dir = home_dir.unwrap();
     // check if windows or unix
@@ -372,11 +1579,11 @@ pub fn get_saved_themes() -> Option<Vec<Theme>> {
     }
 }
 
-pub fn save_theme(theme: Theme) -> Result<String, String> {
+pub fn save_theme(theme: Theme) -> Result<String, KanbanError> {
     let theme_dir = get_theme_dir()?;
     let create_dir_status = fs::create_dir_all(&theme_dir);
     if let Err(e) = create_dir_status {
-        return Err(e.to_string());
+        return Err(e.into());
     }
     // export the theme to json using serde prefix the file name with THEME_FILE_NAME and put the theme.name next then .json
     let theme_name = format!(
@@ -385,12 +1592,285 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
         make_file_system_safe_name(&theme.name)
     );
     let theme_path = theme_dir.join(theme_name);
-    let write_status = fs::write(
-        theme_path.clone(),
-        serde_json::to_string_pretty(&theme).unwrap(),
-    );
+    let write_status = fs::write(theme_path.clone(), serde_json::to_string_pretty(&theme)?);
     if let Err(write_status) = write_status {
-        return Err(write_status.to_string());
+        return Err(write_status.into());
     }
     Ok(theme_path.to_str().unwrap().to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty directory for a single test, removed first in case an earlier run left it behind
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rust_kanban_test_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn export_with_version(kanban_version: &str, boards: Vec<Board>) -> String {
+        serde_json::to_string(&ExportStruct {
+            kanban_version: kanban_version.to_string(),
+            export_date: String::from("01/01/2023"),
+            boards,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn should_keep_readable_config_fields_and_skip_the_rest() {
+        let config_str = r#"{"tickrate": "fast", "no_of_cards_to_show": 7}"#;
+        let (config, skipped_fields) = parse_config_leniently(config_str).unwrap();
+        assert_eq!(config.no_of_cards_to_show, 7);
+        assert_eq!(config.tickrate, AppConfig::default().tickrate);
+        assert_eq!(skipped_fields, vec![String::from("tickrate")]);
+    }
+
+    #[test]
+    fn should_compare_versions_with_missing_parts_as_zero() {
+        assert!(is_newer_version("1.0.1", "1.0"));
+        assert!(is_newer_version("0.10.0", "0.9.7"));
+        assert!(!is_newer_version("1.0", "1.0.0"));
+        assert!(!is_newer_version("1.0.0", "1.0"));
+        assert!(!is_newer_version("1.0.0-beta", "1.0.0"));
+        assert!(!is_newer_version("0.9", "0.9.1"));
+    }
+
+    #[test]
+    fn should_import_json_exports_and_warn_about_newer_versions() {
+        let dir = test_dir("import_json");
+        let board = Board::new(String::from("Imported"), String::new());
+        let current_path = dir.join("current.json");
+        let current_version = env!("CARGO_PKG_VERSION");
+        fs::write(
+            &current_path,
+            export_with_version(current_version, vec![board.clone()]),
+        )
+        .unwrap();
+        let (boards, warnings) = import_kanban_from_json(&current_path).unwrap();
+        assert_eq!(boards, vec![board.clone()]);
+        assert!(warnings.is_empty());
+
+        let newer_path = dir.join("newer.json");
+        fs::write(
+            &newer_path,
+            export_with_version("999.0", vec![board.clone()]),
+        )
+        .unwrap();
+        let (boards, warnings) = import_kanban_from_json(&newer_path).unwrap();
+        assert_eq!(boards.len(), 1);
+        assert_eq!(warnings.len(), 1);
+
+        let list_path = dir.join("list.json");
+        fs::write(
+            &list_path,
+            serde_json::to_string(&vec![board.clone()]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            import_kanban_from_json(&list_path).unwrap().0,
+            vec![board.clone()]
+        );
+
+        let duplicate_path = dir.join("duplicate.json");
+        fs::write(
+            &duplicate_path,
+            export_with_version(current_version, vec![board.clone(), board]),
+        )
+        .unwrap();
+        assert!(import_kanban_from_json(&duplicate_path).is_err());
+        assert!(import_kanban_from_json(&dir.join("missing.json")).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_escape_markdown_syntax_at_the_start_of_lines() {
+        let escaped = [
+            ("#42 crash on start", "\\#42 crash on start"),
+            ("- not a list", "\\- not a list"),
+            ("+ not a list", "\\+ not a list"),
+            ("* not a list", "\\* not a list"),
+            ("> not a quote", "\\> not a quote"),
+            ("| a | b |", "\\| a | b |"),
+            ("```rust", "\\```rust"),
+            ("~~~", "\\~~~"),
+            ("===", "\\==="),
+            ("1. not a list", "1\\. not a list"),
+            ("12) not a list", "12\\) not a list"),
+        ];
+        for (text, expected) in escaped {
+            assert_eq!(escape_markdown_text(text), expected);
+        }
+        assert_eq!(
+            escape_markdown_text("steps:\n  - open\n# done"),
+            "steps:\n  \\- open\n\\# done"
+        );
+        assert_eq!(
+            escape_markdown_text("fix #42 - the | pipe"),
+            "fix #42 - the | pipe"
+        );
+        assert_eq!(escape_markdown_text("2023 was a year"), "2023 was a year");
+    }
+
+    #[test]
+    fn should_quote_csv_fields_with_commas_quotes_and_line_breaks() {
+        assert_eq!(escape_csv_field("plain text"), "plain text");
+        assert_eq!(escape_csv_field("one, two"), "\"one, two\"");
+        assert_eq!(escape_csv_field("say \"hello\""), "\"say \"\"hello\"\"\"");
+        assert_eq!(escape_csv_field("first\nsecond"), "\"first\nsecond\"");
+        assert_eq!(escape_csv_field("first\r\nsecond"), "\"first\r\nsecond\"");
+        assert_eq!(escape_csv_field(""), "");
+    }
+
+    #[test]
+    fn should_prune_only_the_oldest_save_files() {
+        let dir = test_dir("prune_old_saves");
+        let saves = [
+            "kanban_31-12-2022_v3",
+            "kanban_01-01-2023_v1",
+            "kanban_01-01-2023_v2",
+        ];
+        let other_files = ["notes.txt", "kanban_backup.json"];
+        for file_name in saves.iter().chain(other_files.iter()) {
+            fs::write(dir.join(file_name), file_name).unwrap();
+        }
+        fs::write(dir.join("kanban_31-12-2022_v3.note"), "oldest").unwrap();
+        let remaining_files = || {
+            let mut files = fs::read_dir(&dir)
+                .unwrap()
+                .map(|file| file.unwrap().file_name().into_string().unwrap())
+                .collect::<Vec<String>>();
+            files.sort();
+            files
+        };
+
+        assert!(prune_old_saves(&dir, 0).is_empty());
+        assert!(prune_old_saves(&dir, 3).is_empty());
+        assert_eq!(remaining_files().len(), 6);
+
+        assert_eq!(
+            prune_old_saves(&dir, 1),
+            vec![
+                String::from("kanban_31-12-2022_v3"),
+                String::from("kanban_01-01-2023_v1"),
+            ]
+        );
+        assert_eq!(
+            remaining_files(),
+            vec!["kanban_01-01-2023_v2", "kanban_backup.json", "notes.txt"]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_import_trello_lists_and_cards_in_board_order() {
+        let export = r#"{
+            "name": "Project",
+            "lists": [
+                {"id": "doing", "name": "Doing", "closed": false, "pos": 2048},
+                {"id": "todo", "name": "To Do", "closed": false, "pos": 1024},
+                {"id": "old", "name": "Old List", "closed": true, "pos": 512}
+            ],
+            "cards": [
+                {
+                    "id": "second", "name": "Second", "desc": "", "idList": "todo",
+                    "closed": false, "pos": 300, "due": "2023-06-15T12:00:00.000Z",
+                    "dueComplete": true, "idChecklists": ["steps"],
+                    "labels": [{"name": "bug", "color": "red"}, {"name": "", "color": "green"}]
+                },
+                {"id": "first", "name": "First", "idList": "todo", "closed": false, "pos": 100},
+                {"id": "archived", "name": "Archived", "idList": "todo", "closed": true, "pos": 200},
+                {"id": "lost", "name": "Lost", "idList": "old", "closed": false, "pos": 100}
+            ],
+            "checklists": [
+                {
+                    "id": "steps", "idCard": "second", "name": "Steps",
+                    "checkItems": [
+                        {"name": "write", "state": "complete"},
+                        {"name": "test", "state": "incomplete"}
+                    ]
+                }
+            ]
+        }"#;
+        let today = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        let boards =
+            parse_trello_export(export, DateFormat::DayMonthYear, today, &chrono::Utc).unwrap();
+        let board_names = boards
+            .iter()
+            .map(|b| b.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(board_names, vec!["To Do", "Doing"]);
+        assert_eq!(
+            boards[0].description,
+            "Imported from the Trello board 'Project'"
+        );
+        let card_names = boards[0]
+            .cards
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(card_names, vec!["First", "Second"]);
+        let second = &boards[0].cards[1];
+        assert_eq!(second.tags, vec!["bug", "green"]);
+        assert_eq!(second.comments, vec!["Steps: [x] write", "Steps: [ ] test"]);
+        assert_eq!(second.date_due, "15/06/2023");
+        assert_eq!(second.card_status, CardStatus::Complete);
+        assert_eq!(boards[0].cards[0].card_status, CardStatus::Active);
+        assert_eq!(boards[0].archived_cards.len(), 1);
+        assert_eq!(boards[0].archived_cards[0].card.name, "Archived");
+        assert_eq!(
+            boards[0].archived_cards[0].date_archived,
+            today.format(ARCHIVE_DATE_FORMAT).to_string()
+        );
+        assert!(boards[1].cards.is_empty());
+        assert!(parse_trello_export(
+            r#"{"lists": []}"#,
+            DateFormat::DayMonthYear,
+            today,
+            &chrono::Utc
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_verify_saves_against_their_checksums() {
+        let dir = test_dir("save_checksums");
+        let legacy_save = "kanban_01-01-2023_v1";
+        let save = "kanban_02-01-2023_v1";
+        let newer_save = "kanban_03-01-2023_v1";
+        fs::write(dir.join(legacy_save), "legacy boards").unwrap();
+        assert!(verify_save_checksum(&dir, legacy_save).is_ok());
+
+        fs::write(get_save_temp_path(&dir, save), "boards").unwrap();
+        finish_save_file(&dir, save).unwrap();
+        assert!(!get_save_temp_path(&dir, save).exists());
+        assert_eq!(
+            fs::read_to_string(get_save_checksum_path(&dir, save)).unwrap(),
+            format!("{}  {}\n", sha256_hex(b"boards"), save)
+        );
+        assert!(verify_save_checksum(&dir, save).is_ok());
+        assert!(verify_save_checksum(&dir, legacy_save).is_ok());
+
+        fs::write(dir.join(save), "boards that were changed").unwrap();
+        assert!(verify_save_checksum(&dir, save).is_err());
+
+        fs::write(dir.join(newer_save), "boards without a checksum").unwrap();
+        assert!(verify_save_checksum(&dir, newer_save).is_ok());
+
+        fs::write(
+            get_save_temp_path(&dir, "kanban_04-01-2023_v1"),
+            "unfinished",
+        )
+        .unwrap();
+        remove_leftover_save_temp_files(&dir);
+        assert!(!get_save_temp_path(&dir, "kanban_04-01-2023_v1").exists());
+        assert!(dir.join(save).exists());
+
+        delete_save_checksum(&dir, save);
+        assert!(!get_save_checksum_path(&dir, save).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
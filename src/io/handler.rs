@@ -1,7 +1,7 @@
-use chrono::NaiveDate;
+use chrono::Local;
 use eyre::{anyhow, Result};
 use linked_hash_map::LinkedHashMap;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use ratatui::widgets::ListState;
 use savefile::{load_file, save_file};
 use std::{
@@ -14,11 +14,25 @@ use std::{
 use super::data_handler::{get_available_local_savefiles, get_local_kanban_state};
 use super::IoEvent;
 use crate::{
-    app::{kanban::Board, state::UiMode, App, AppConfig},
-    constants::{CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME},
+    app::{
+        app_helper::{
+            add_imported_boards, apply_filter_preset, refresh_hidden_completed_cards,
+            send_overdue_boards_reminder,
+        },
+        kanban::{migrate_cards_to_workflow_stages, prune_archived_cards, Board},
+        App, AppConfig, PopupMode,
+    },
+    constants::{
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, PERIODIC_SAVE_TOAST_DURATION,
+        PRUNE_ARCHIVED_CARDS_CONFIRMATION_TEXT, SAVE_DIR_NAME, SAVE_FILE_NAME,
+        SAVE_FILE_SCHEMA_VERSION,
+    },
+    error::KanbanError,
     io::data_handler::{
-        get_default_save_directory, get_saved_themes, reset_config,
-        save_kanban_state_locally,
+        delete_save_checksum, delete_save_note, finish_save_file, get_default_save_directory,
+        get_save_temp_path, get_saved_themes, import_from_trello, import_kanban_from_json,
+        prune_old_saves, remove_leftover_save_temp_files, reset_config, save_kanban_state_locally,
+        write_save_note,
     },
     ui::TextColorOptions,
 };
@@ -45,6 +59,9 @@ impl IoAsyncHandler {
             IoEvent::ResetVisibleBoardsandCards => self.refresh_visible_boards_and_cards().await,
             IoEvent::AutoSave => self.auto_save().await,
             IoEvent::LoadPreview => self.load_preview().await,
+            IoEvent::ImportJson(path) => self.import_json(path).await,
+            IoEvent::ImportTrello(path) => self.import_trello(path).await,
+            IoEvent::PeriodicSave => self.periodic_save().await,
         };
 
         let mut app = self.app.lock().await;
@@ -68,7 +85,40 @@ impl IoAsyncHandler {
             error!("Cannot create save directory");
             app.send_error_toast("Cannot create save directory", None);
         }
-        app.boards = prepare_boards(&mut app);
+        remove_leftover_save_temp_files(&app.config.get_save_directory());
+        if app.state.boards_from_stdin {
+            info!("👍 Using {} boards read from stdin", app.boards.len());
+            app.send_info_toast(
+                &format!(
+                    "Loaded {} boards from stdin, they are only saved when you save",
+                    app.boards.len()
+                ),
+                None,
+            );
+        } else if app.state.safe_mode {
+            info!("Safe mode, not loading the last save");
+            app.send_warning_toast(
+                "Started in safe mode with the default config, the last save was not loaded",
+                None,
+            );
+        } else {
+            app.boards = prepare_boards(&mut app);
+        }
+        migrate_to_workflow_stages(&mut app);
+        // repeating cards whose due date passed while the app was closed move on the next tick
+        app.state.last_recurrence_check = None;
+        app.state.last_stale_check = None;
+        if app.config.remember_boards_hiding_completed_cards {
+            app.state.boards_hiding_completed_cards =
+                app.config.boards_hiding_completed_cards.clone();
+            refresh_hidden_completed_cards(&mut app);
+        }
+        if app.config.remember_last_filter {
+            if let Some(last_filter) = app.config.last_filter.clone() {
+                info!("Reapplying the last active filter");
+                apply_filter_preset(&mut app, &last_filter);
+            }
+        }
         app.keybind_list_maker();
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         let saved_themes = get_saved_themes();
@@ -90,7 +140,32 @@ impl IoAsyncHandler {
         }
         info!("👍 Application initialized");
         app.initialized(); // we could update the app state
-        if app.config.save_directory == get_default_save_directory() {
+        send_overdue_boards_reminder(&mut app);
+        let retention_days = app.config.archive_retention_days;
+        if retention_days > 0 {
+            // only list the expired cards here, they are deleted once the user confirms
+            let expired_cards = prune_archived_cards(
+                &mut app.boards,
+                retention_days,
+                Local::now().date_naive(),
+                true,
+            );
+            if !expired_cards.is_empty() {
+                info!(
+                    "{} archived cards are older than {} days",
+                    expired_cards.len(),
+                    retention_days
+                );
+                app.state.archived_cards_to_prune = expired_cards;
+                let confirmation_style = app.config.permanent_change_confirmation;
+                app.open_confirmation(
+                    PopupMode::ConfirmPruneArchivedCards,
+                    confirmation_style,
+                    PRUNE_ARCHIVED_CARDS_CONFIRMATION_TEXT,
+                );
+            }
+        }
+        if app.config.get_save_directory() == get_default_save_directory() {
             app.send_warning_toast(
                 "Save directory is set to a temporary directory,
             your operating system may delete it at any time. Please change it in the settings.",
@@ -122,8 +197,18 @@ impl IoAsyncHandler {
         let mut app = self.app.lock().await;
         let board_data = &app.boards;
         let status = save_kanban_state_locally(board_data.to_vec());
+        let save_note = app.state.pending_save_note.take();
         match status {
-            Ok(_) => {
+            Ok(save_file_name) => {
+                app.state.boards_from_stdin = false;
+                app.action_history_manager.changes_since_save = 0;
+                if let Some(save_note) = save_note {
+                    let save_directory = app.config.get_save_directory();
+                    if let Err(e) = write_save_note(&save_directory, &save_file_name, &save_note) {
+                        error!("Cannot save note for {}: {}", save_file_name, e);
+                        app.send_error_toast("Cannot save note for the save file", None);
+                    }
+                }
                 info!("👍 Local data saved");
                 app.send_info_toast("👍 Local data saved", None);
             }
@@ -172,12 +257,17 @@ impl IoAsyncHandler {
         match board_data {
             Ok(boards) => {
                 app.set_boards(boards);
+                app.state.boards_from_stdin = false;
+                app.action_history_manager.changes_since_save = 0;
+                migrate_to_workflow_stages(&mut app);
+                app.state.last_recurrence_check = None;
+                app.state.last_stale_check = None;
                 info!("👍 Save file {:?} loaded", save_file_name);
                 app.send_info_toast(&format!("👍 Save file {:?} loaded", save_file_name), None);
             }
             Err(err) => {
-                debug!("Cannot load save file: {:?}", err);
-                app.send_error_toast("Cannot load save file", None);
+                error!("Cannot load save file: {}", err);
+                app.send_error_toast(&format!("Cannot load save file: {}", err), None);
             }
         }
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
@@ -209,7 +299,7 @@ impl IoAsyncHandler {
         }
         let file_name = file_list[selected].clone();
         info!("🚀 Deleting save file: {}", file_name);
-        let path = app.config.save_directory.join(file_name);
+        let path = app.config.get_save_directory().join(&file_name);
         // check if the file exists
         if !Path::new(&path).exists() {
             error!("Cannot delete save file: file not found");
@@ -223,6 +313,8 @@ impl IoAsyncHandler {
                 app.state.load_save_state = ListState::default();
                 return Ok(());
             } else {
+                delete_save_note(&app.config.get_save_directory(), &file_name);
+                delete_save_checksum(&app.config.get_save_directory(), &file_name);
                 info!("👍 Save file deleted");
                 app.send_info_toast("👍 Save file deleted", None);
             }
@@ -256,6 +348,68 @@ impl IoAsyncHandler {
         auto_save(&mut app).await
     }
 
+    /// Saves a new version of the boards like a manual save, so earlier saves are kept
+    async fn periodic_save(&mut self) -> Result<()> {
+        let mut app = self.app.lock().await;
+        if app.state.boards_from_stdin {
+            info!("Skipping periodic save, the boards read from stdin have not been saved yet");
+            return Ok(());
+        }
+        if app.state.safe_mode {
+            info!("Skipping periodic save in safe mode");
+            return Ok(());
+        }
+        match save_kanban_state_locally(app.boards.clone()) {
+            Ok(save_file_name) => {
+                app.action_history_manager.changes_since_save = 0;
+                info!("👍 Auto saved to {}", save_file_name);
+                app.send_info_toast(
+                    &format!("Auto saved to {}", save_file_name),
+                    Some(Duration::from_secs(PERIODIC_SAVE_TOAST_DURATION)),
+                );
+            }
+            Err(err) => {
+                error!("Cannot auto save: {:?}", err);
+                app.send_error_toast("Cannot auto save", None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn import_json(&mut self, path: PathBuf) -> Result<()> {
+        let mut app = self.app.lock().await;
+        info!("🚀 Importing boards from {:?}", path);
+        match import_kanban_from_json(&path) {
+            Ok((boards, warnings)) => {
+                for warning in warnings {
+                    warn!("{}", warning);
+                    app.send_warning_toast(&warning, None);
+                }
+                add_imported_boards(&mut app, boards, &path.to_string_lossy());
+            }
+            Err(e) => {
+                error!("Cannot import {:?}: {}", path, e);
+                app.send_error_toast(&format!("Cannot import boards: {}", e), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn import_trello(&mut self, path: PathBuf) -> Result<()> {
+        let mut app = self.app.lock().await;
+        info!("🚀 Importing Trello board from {:?}", path);
+        match import_from_trello(&path) {
+            Ok(boards) => {
+                add_imported_boards(&mut app, boards, &path.to_string_lossy());
+            }
+            Err(e) => {
+                error!("Cannot import {:?}: {}", path, e);
+                app.send_error_toast(&format!("Cannot import Trello board: {}", e), None);
+            }
+        }
+        Ok(())
+    }
+
     async fn load_preview(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
         if app.state.load_save_state.selected().is_none() {
@@ -342,10 +496,10 @@ impl IoAsyncHandler {
     }
 }
 
-pub(crate) fn get_config_dir() -> Result<PathBuf, String> {
+pub(crate) fn get_config_dir() -> Result<PathBuf, KanbanError> {
     let home_dir = home::home_dir();
     if home_dir.is_none() {
-        return Err(String::from("Error getting home directory"));
+        return Err(KanbanError::NotFound(String::from("Home directory")));
     }
     let mut config_dir = home_dir.unwrap();
     // check if windows or unix
@@ -365,32 +519,18 @@ pub(crate) fn get_save_dir() -> PathBuf {
     save_dir
 }
 
-pub fn prepare_config_dir() -> Result<(), String> {
-    let config_dir = get_config_dir();
-    if config_dir.is_err() {
-        return Err(String::from("Error getting config directory"));
-    }
-    let config_dir = config_dir.unwrap();
+pub fn prepare_config_dir() -> Result<(), KanbanError> {
+    let config_dir = get_config_dir()?;
     if !config_dir.exists() {
-        let dir_creation_status = std::fs::create_dir_all(&config_dir);
-        if dir_creation_status.is_err() {
-            return Err(String::from("Error creating config directory"));
-        }
+        std::fs::create_dir_all(&config_dir)?;
     }
     // make config file if it doesn't exist and write default config to it
     let mut config_file = config_dir;
     config_file.push(CONFIG_FILE_NAME);
     if !config_file.exists() {
         let default_config = AppConfig::default();
-        let config_json = serde_json::to_string_pretty(&default_config);
-        if let Ok(config_json) = config_json {
-            let file_creation_status = std::fs::write(&config_file, config_json);
-            if file_creation_status.is_err() {
-                return Err(String::from("Error creating config file"));
-            }
-        } else {
-            return Err(String::from("Error creating config file"));
-        }
+        let config_json = serde_json::to_string_pretty(&default_config)?;
+        std::fs::write(&config_file, config_json)?;
     }
     Ok(())
 }
@@ -404,82 +544,69 @@ fn prepare_save_dir() -> bool {
 }
 
 fn prepare_boards(app: &mut App) -> Vec<Board> {
-    if app.config.always_load_last_save {
-        let latest_save_file_info = get_latest_save_file();
-        if let Ok(latest_save_file_info) = latest_save_file_info {
-            let latest_save_file = latest_save_file_info.0;
-            let latest_version = latest_save_file_info.1;
-            let local_data =
-                get_local_kanban_state(latest_save_file.clone(), latest_version, false);
-            match local_data {
-                Ok(data) => {
-                    info!("👍 Local data loaded from {:?}", latest_save_file);
-                    app.send_info_toast(
-                        &format!("👍 Local data loaded from {:?}", latest_save_file),
-                        None,
-                    );
-                    data
-                }
-                Err(err) => {
-                    debug!("Cannot get local data: {:?}", err);
-                    error!("👎 Cannot get local data, Data might be corrupted or is not in the correct format");
-                    app.send_error_toast("👎 Cannot get local data, Data might be corrupted or is not in the correct format", None);
-                    vec![]
-                }
-            }
-        } else {
+    if !app.config.always_load_last_save {
+        info!("Auto Load Last Save is off, starting with no boards");
+        app.send_info_toast(
+            "Started with no boards, open \"Load a Save\" to pick a save file",
+            None,
+        );
+        return vec![];
+    }
+    let (latest_save_file, latest_version) = match get_latest_save_file() {
+        Ok(latest_save_file_info) => latest_save_file_info,
+        Err(err) => {
+            info!("{}, starting with no boards", err);
+            app.send_info_toast("No save files found, starting with no boards", None);
+            return vec![];
+        }
+    };
+    let local_data = get_local_kanban_state(latest_save_file.clone(), latest_version, false);
+    match local_data {
+        Ok(data) => {
+            info!("👍 Local data loaded from {:?}", latest_save_file);
+            app.send_info_toast(
+                &format!("👍 Local data loaded from {:?}", latest_save_file),
+                None,
+            );
+            data
+        }
+        Err(err) => {
+            debug!("Cannot get local data: {:?}", err);
+            error!(
+                "👎 Cannot get local data, Data might be corrupted or is not in the correct format"
+            );
+            app.send_error_toast(
+                "👎 Cannot get local data, Data might be corrupted or is not in the correct format",
+                None,
+            );
             vec![]
         }
-    } else {
-        app.set_ui_mode(UiMode::LoadSave);
-        vec![]
     }
 }
 
-// return save file name and the latest verison
+// return save file name and the latest verison, the save files are already sorted by date and version
 fn get_latest_save_file() -> Result<(String, u32)> {
-    let local_save_files = get_available_local_savefiles();
-    let local_save_files = if let Some(local_save_files) = local_save_files {
-        local_save_files
-    } else {
-        return Err(anyhow!("No local save files found"));
-    };
-    let fall_back_version = -1;
-    if local_save_files.is_empty() {
-        return Err(anyhow!("No local save files found"));
-    }
-    let latest_date = local_save_files
-        .iter()
-        .map(|file| {
-            let date = file.split('_').collect::<Vec<&str>>()[1];
-            NaiveDate::parse_from_str(date, "%d-%m-%Y").unwrap()
-        })
-        .max()
-        .unwrap();
-    let latest_version = local_save_files
-        .iter()
-        .filter(|file| {
-            let date = file.split('_').collect::<Vec<&str>>()[1];
-            NaiveDate::parse_from_str(date, "%d-%m-%Y").unwrap() == latest_date
-        })
-        .map(|file| {
-            let version = file.split("_v").collect::<Vec<&str>>()[1];
-            version.parse::<i32>().unwrap_or(fall_back_version)
-        })
-        .max()
-        .unwrap_or(fall_back_version);
+    let latest_save_file = get_available_local_savefiles()
+        .and_then(|local_save_files| local_save_files.last().cloned())
+        .ok_or_else(|| anyhow!("No local save files found"))?;
+    let latest_version = latest_save_file
+        .rsplit("_v")
+        .next()
+        .and_then(|version| version.parse::<u32>().ok())
+        .ok_or_else(|| anyhow!("Invalid save file name {}", latest_save_file))?;
+    Ok((latest_save_file, latest_version))
+}
 
-    if latest_version == fall_back_version {
-        return Err(anyhow!("No local save files found"));
+/// Puts cards that are not in a configured workflow stage into the stage closest to their status
+fn migrate_to_workflow_stages(app: &mut App) {
+    if app.config.workflow_stages.is_empty() {
+        return;
+    }
+    let workflow_stages = app.config.workflow_stages.clone();
+    let migrated_cards = migrate_cards_to_workflow_stages(&mut app.boards, &workflow_stages);
+    if migrated_cards > 0 {
+        info!("Moved {} cards to workflow stages", migrated_cards);
     }
-    let latest_version = latest_version as u32;
-
-    let latest_save_file = format!(
-        "kanban_{}_v{}",
-        latest_date.format("%d-%m-%Y"),
-        latest_version
-    );
-    Ok((latest_save_file, latest_version))
 }
 
 pub fn refresh_visible_boards_and_cards(app: &mut App) {
@@ -539,14 +666,22 @@ pub fn make_file_system_safe_name(name: &str) -> String {
 }
 
 pub async fn auto_save(app: &mut App) -> Result<()> {
+    if app.state.boards_from_stdin {
+        info!("Skipping auto save, the boards read from stdin have not been saved yet");
+        return Ok(());
+    }
+    if app.state.safe_mode {
+        info!("Skipping auto save in safe mode");
+        return Ok(());
+    }
     let mut file_version = 0;
     let latest_save_file_info = get_latest_save_file();
     let save_required = if latest_save_file_info.is_ok() {
         let latest_save_file_info = latest_save_file_info.unwrap();
         let save_file_name = latest_save_file_info.0;
         file_version = latest_save_file_info.1;
-        let file_path = app.config.save_directory.join(save_file_name);
-        let boards: Vec<Board> = load_file(file_path, file_version)?;
+        let file_path = app.config.get_save_directory().join(save_file_name);
+        let boards: Vec<Board> = load_file(file_path, file_version.max(SAVE_FILE_SCHEMA_VERSION))?;
         app.boards != boards
     } else {
         true
@@ -558,13 +693,26 @@ pub async fn auto_save(app: &mut App) -> Result<()> {
             chrono::Local::now().format("%d-%m-%Y"),
             file_version + 1
         );
-        let file_path = app.config.save_directory.join(file_name);
-        let save_status = save_file(file_path, file_version, &app.boards);
+        let temp_path = get_save_temp_path(&app.config.get_save_directory(), &file_name);
+        let save_status = save_file(temp_path, SAVE_FILE_SCHEMA_VERSION, &app.boards)
+            .map_err(|e| e.to_string())
+            .and_then(|_| {
+                finish_save_file(&app.config.get_save_directory(), &file_name)
+                    .map_err(|e| e.to_string())
+            });
         match save_status {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                app.action_history_manager.changes_since_save = 0;
+                if let Some(max_saves_to_keep) = app.config.max_saves_to_keep {
+                    prune_old_saves(&app.config.get_save_directory(), max_saves_to_keep);
+                }
+                Ok(())
+            }
             Err(e) => Err(anyhow!("Error saving file: {}", e)),
         }
     } else {
+        // the boards match the latest save so nothing is at risk
+        app.action_history_manager.changes_since_save = 0;
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 pub mod data_handler;
 pub mod handler;
 // For this dummy application we only need two IO event
@@ -12,4 +14,7 @@ pub enum IoEvent {
     ResetVisibleBoardsandCards,
     AutoSave,
     LoadPreview,
+    ImportJson(PathBuf),
+    ImportTrello(PathBuf),
+    PeriodicSave,
 }
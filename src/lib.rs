@@ -1,5 +1,10 @@
 use app::{App, AppReturn};
-use crossterm::{event::EnableMouseCapture, execute};
+use crossterm::{
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+};
 use eyre::Result;
 use inputs::{events::Events, InputEvent};
 use io::IoEvent;
@@ -9,6 +14,7 @@ use ui::ui_main;
 
 pub mod app;
 pub mod constants;
+pub mod error;
 pub mod inputs;
 pub mod io;
 pub mod ui;
@@ -16,12 +22,18 @@ pub mod ui;
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     // Configure Crossterm backend for tui
     crossterm::terminal::enable_raw_mode()?;
-    {
+    let use_alternate_screen = {
         let app = app.lock().await;
+        let use_alternate_screen =
+            app.config.use_alternate_screen && !app.state.alternate_screen_disabled;
+        if use_alternate_screen {
+            execute!(stdout(), EnterAlternateScreen)?;
+        }
         if app.config.enable_mouse_support {
             execute!(stdout(), EnableMouseCapture)?;
         }
-    }
+        use_alternate_screen
+    };
     let my_stdout = stdout();
     let backend = CrosstermBackend::new(my_stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -57,7 +69,12 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
         let result = match events.next().await {
             InputEvent::KeyBoardInput(key) => app.do_action(key).await,
             InputEvent::MouseAction(mouse_action) => app.handle_mouse(mouse_action).await,
-            InputEvent::Tick => AppReturn::Continue,
+            InputEvent::Tick => app.handle_tick().await,
+        };
+        // custom commands from the command palette queue their keys to run after it closes
+        let result = match result {
+            AppReturn::Continue => app.run_queued_keys().await,
+            AppReturn::Exit => AppReturn::Exit,
         };
         // Check if we should exit
         if result == AppReturn::Exit {
@@ -67,11 +84,21 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     }
 
     // Restore the terminal and close application
-    execute!(stdout(), crossterm::event::DisableMouseCapture)?;
-    terminal.clear()?;
-    terminal.set_cursor(0, 0)?;
+    execute!(stdout(), DisableMouseCapture)?;
+    if use_alternate_screen {
+        terminal.clear()?;
+        execute!(stdout(), LeaveAlternateScreen)?;
+        terminal.set_cursor(0, 0)?;
+    } else {
+        // keep the last frame in the scrollback and put the prompt below it
+        let size = terminal.size()?;
+        execute!(stdout(), cursor::MoveTo(0, size.height.saturating_sub(1)))?;
+    }
     terminal.show_cursor()?;
     crossterm::terminal::disable_raw_mode()?;
+    if !use_alternate_screen {
+        println!();
+    }
 
     Ok(())
 }
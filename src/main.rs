@@ -1,15 +1,31 @@
 use clap::Parser;
-use crossterm::{event::DisableMouseCapture, execute, terminal};
-use eyre::Result;
+use crossterm::{
+    cursor,
+    event::DisableMouseCapture,
+    execute,
+    terminal::{self, LeaveAlternateScreen},
+    tty::IsTty,
+};
+use eyre::{eyre, Result};
 use log::LevelFilter;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use rust_kanban::{
-    app::App,
+    app::{state::UiMode, App},
     constants::APP_TITLE,
-    io::{handler::IoAsyncHandler, IoEvent},
+    io::{
+        data_handler::{
+            parse_boards_from_json, previous_session_crashed, remove_session_marker,
+            write_session_marker,
+        },
+        handler::IoAsyncHandler,
+        IoEvent,
+    },
     start_ui,
 };
-use std::{io::stdout, sync::Arc};
+use std::{
+    io::{stdin, stdout, BufRead, Read, Write},
+    sync::Arc,
+};
 
 extern crate savefile_derive;
 #[derive(Parser)]
@@ -18,6 +34,41 @@ struct CliArgs {
     // optional argument to reset config
     #[arg(short, long)]
     reset: Option<bool>,
+    // optional ui mode to open instead of the configured default view
+    #[arg(long, value_name = "NAME")]
+    ui_mode: Option<String>,
+    // read the boards from json piped on stdin instead of loading the last save
+    #[arg(long)]
+    from_stdin: bool,
+    // start with the default config and without loading the last save
+    #[arg(long)]
+    safe_mode: bool,
+    // leave the last frame in the terminal scrollback on exit instead of using the alternate screen
+    #[arg(long)]
+    no_alternate_screen: bool,
+    // draw with ascii characters only and without colors, for serial consoles and minimal terminals
+    #[arg(long)]
+    ascii: bool,
+}
+
+/// Asks on the terminal whether to start in safe mode after the last session did not exit
+/// cleanly, defaults to no when nothing can be asked
+fn ask_for_safe_mode() -> bool {
+    println!(
+        "{} did not exit cleanly last time, a broken config or save file may be the cause.",
+        APP_TITLE
+    );
+    if !stdin().is_tty() {
+        println!("Run with --safe-mode to start with the default config and no save loaded.");
+        return false;
+    }
+    print!("Start in safe mode with the default config and without loading the last save? [y/N] ");
+    _ = stdout().flush();
+    let mut answer = String::new();
+    if stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
 }
 
 #[tokio::main]
@@ -25,17 +76,24 @@ async fn main() -> Result<()> {
     // Handling Panic when terminal is in raw mode
     let default_panic = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
+        // restore the terminal the same way start_ui does before printing the panic
         _ = terminal::disable_raw_mode();
         let execute_result = execute!(stdout(), DisableMouseCapture);
         if let Err(e) = execute_result {
             println!("Error while disabling mouse capture: {}", e);
         }
+        let execute_result = execute!(stdout(), LeaveAlternateScreen, cursor::Show);
+        if let Err(e) = execute_result {
+            println!("Error while restoring the terminal: {}", e);
+        }
         println!();
         let stdout = stdout();
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend);
         if let Ok(mut terminal) = terminal {
-            terminal.clear().unwrap();
+            _ = terminal.clear();
+            _ = terminal.set_cursor(0, 0);
+            _ = terminal.show_cursor();
         }
         if cfg!(debug_assertions) {
             default_panic(info);
@@ -50,11 +108,68 @@ async fn main() -> Result<()> {
 
     // parse cli args
     let args = CliArgs::parse();
+    let ui_mode_override = if let Some(ui_mode_name) = &args.ui_mode {
+        match UiMode::from_string(ui_mode_name) {
+            Some(ui_mode) => Some(ui_mode),
+            None => {
+                let valid_names = UiMode::all_modes()
+                    .iter()
+                    .map(|ui_mode| format!("\"{}\"", ui_mode))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                return Err(eyre!(
+                    "Invalid UI mode {:?}, valid names are: {}",
+                    ui_mode_name,
+                    valid_names
+                ));
+            }
+        }
+    } else {
+        None
+    };
+    let stdin_boards = if args.from_stdin {
+        if stdin().is_tty() {
+            return Err(eyre!(
+                "--from-stdin expects board JSON on stdin, for example: cat board.json | {} --from-stdin",
+                env!("CARGO_PKG_NAME")
+            ));
+        }
+        let mut input = String::new();
+        stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| eyre!("Could not read stdin: {}", e))?;
+        let boards = parse_boards_from_json(&input)
+            .map_err(|e| eyre!("Invalid board JSON on stdin: {}", e))?;
+        Some(boards)
+    } else {
+        None
+    };
+
+    let safe_mode = args.safe_mode || (previous_session_crashed() && ask_for_safe_mode());
+    // the marker stays behind if the app panics or is killed so the next start can offer safe mode
+    if let Err(e) = write_session_marker() {
+        println!("Could not write the session marker: {}", e);
+    }
 
     let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
 
     // We need to share the App between thread
-    let main_app_instance = Arc::new(tokio::sync::Mutex::new(App::new(sync_io_tx.clone())));
+    let mut app = App::with_safe_mode(sync_io_tx.clone(), safe_mode);
+    if let Some(ui_mode) = ui_mode_override {
+        // only changes the view for this launch, the configured default view is left as is
+        app.set_ui_mode(ui_mode);
+    }
+    if args.no_alternate_screen {
+        app.state.alternate_screen_disabled = true;
+    }
+    if args.ascii {
+        app.state.ascii_only_forced = true;
+    }
+    if let Some(boards) = stdin_boards {
+        app.set_boards(boards);
+        app.state.boards_from_stdin = true;
+    }
+    let main_app_instance = Arc::new(tokio::sync::Mutex::new(app));
     let app_widget_manager_instance = Arc::clone(&main_app_instance);
     let app_ui_instance = Arc::clone(&main_app_instance);
     // Configure log
@@ -86,6 +201,9 @@ async fn main() -> Result<()> {
     }
 
     start_ui(&app_ui_instance).await?;
+    if let Err(e) = remove_session_marker() {
+        println!("Could not remove the session marker: {}", e);
+    }
 
     Ok(())
 }
@@ -399,6 +399,97 @@ impl Theme {
             card_priority_high_style: Style::default().fg(Color::LightRed).bg(Color::White),
         }
     }
+    /// Uses the Okabe-Ito palette which avoids red/green pairs so that due dates, statuses and
+    /// priorities stay distinguishable for people with color vision deficiencies
+    fn color_blind_safe() -> Theme {
+        let blue = Color::Rgb(0, 114, 178);
+        let sky_blue = Color::Rgb(86, 180, 233);
+        let orange = Color::Rgb(230, 159, 0);
+        let vermillion = Color::Rgb(213, 94, 0);
+        let yellow = Color::Rgb(240, 228, 66);
+        let reddish_purple = Color::Rgb(204, 121, 167);
+        Theme {
+            name: "Color Blind Safe".to_string(),
+            general_style: Style::default().fg(Color::White).bg(Color::Reset),
+            list_select_style: Style::default()
+                .fg(Color::Black)
+                .bg(sky_blue)
+                .add_modifier(Modifier::BOLD),
+            card_due_default_style: Style::default()
+                .fg(sky_blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_due_warning_style: Style::default()
+                .fg(orange)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_due_overdue_style: Style::default()
+                .fg(vermillion)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            card_status_active_style: Style::default()
+                .fg(sky_blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_status_completed_style: Style::default()
+                .fg(blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_status_stale_style: Style::default()
+                .fg(Color::Gray)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::ITALIC),
+            keyboard_focus_style: Style::default()
+                .fg(orange)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            mouse_focus_style: Style::default()
+                .fg(yellow)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            help_key_style: Style::default()
+                .fg(sky_blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            help_text_style: Style::default().fg(Color::White).bg(Color::Reset),
+            log_error_style: Style::default()
+                .fg(vermillion)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            log_debug_style: Style::default()
+                .fg(sky_blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            log_warn_style: Style::default()
+                .fg(orange)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            log_trace_style: Style::default()
+                .fg(Color::Gray)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            log_info_style: Style::default()
+                .fg(blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            progress_bar_style: Style::default().fg(blue).bg(Color::Reset),
+            error_text_style: Style::default().fg(Color::Black).bg(vermillion),
+            inactive_text_style: Style::default().fg(Color::DarkGray).bg(Color::Reset),
+            card_priority_low_style: Style::default()
+                .fg(sky_blue)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_priority_medium_style: Style::default()
+                .fg(orange)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+            card_priority_high_style: Style::default()
+                .fg(reddish_purple)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
     pub fn all_default_themes() -> Vec<Theme> {
         vec![
             Theme::default(),
@@ -408,6 +499,7 @@ impl Theme {
             Theme::metro(),
             Theme::matrix(),
             Theme::cyberpunk(),
+            Theme::color_blind_safe(),
         ]
     }
 
@@ -984,6 +1076,215 @@ impl Theme {
     }
 }
 
+/// Formats the current theme can be exported to as a terminal color scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalColorSchemeFormat {
+    WindowsTerminal,
+    Xresources,
+}
+
+impl Display for TerminalColorSchemeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TerminalColorSchemeFormat::WindowsTerminal => write!(f, "Windows Terminal"),
+            TerminalColorSchemeFormat::Xresources => write!(f, "Xresources"),
+        }
+    }
+}
+
+impl TerminalColorSchemeFormat {
+    pub fn file_extension(&self) -> &str {
+        match self {
+            TerminalColorSchemeFormat::WindowsTerminal => "json",
+            TerminalColorSchemeFormat::Xresources => "Xresources",
+        }
+    }
+}
+
+/// The colors of a theme mapped onto the 16 color palette used by terminals
+pub struct TerminalPalette {
+    pub foreground: (u8, u8, u8),
+    pub background: (u8, u8, u8),
+    pub cursor: (u8, u8, u8),
+    pub selection_background: (u8, u8, u8),
+    /// black, red, green, yellow, blue, magenta, cyan, white followed by their bright variants
+    pub colors: [(u8, u8, u8); 16],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowsTerminalScheme {
+    name: String,
+    foreground: String,
+    background: String,
+    cursor_color: String,
+    selection_background: String,
+    black: String,
+    red: String,
+    green: String,
+    yellow: String,
+    blue: String,
+    purple: String,
+    cyan: String,
+    white: String,
+    bright_black: String,
+    bright_red: String,
+    bright_green: String,
+    bright_yellow: String,
+    bright_blue: String,
+    bright_purple: String,
+    bright_cyan: String,
+    bright_white: String,
+}
+
+fn to_hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Parses a custom color written as `#RRGGBB` or as `r,g,b` with values from 0 to 255
+pub fn parse_custom_color(input: &str) -> Result<Color, String> {
+    let input = input.trim();
+    if let Some(hex) = input.strip_prefix('#') {
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "Invalid hex color {}, use the format #RRGGBB",
+                input
+            ));
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+        return Ok(Color::Rgb(channel(0), channel(2), channel(4)));
+    }
+    let rgb_values = input
+        .split(',')
+        .map(|x| x.trim().parse::<u8>())
+        .collect::<Result<Vec<u8>, _>>();
+    match rgb_values {
+        Ok(rgb_values) if rgb_values.len() == 3 => {
+            Ok(Color::Rgb(rgb_values[0], rgb_values[1], rgb_values[2]))
+        }
+        _ => Err(String::from(
+            "Invalid color, use #RRGGBB or r,g,b where r,g,b are numbers from 0 to 255",
+        )),
+    }
+}
+
+/// Parses a board accent color, either one of the named colors or a custom color, and returns
+/// it with the text it should be saved as
+pub fn parse_accent_color(input: &str) -> Result<(Color, String), String> {
+    let input = input.trim();
+    let normalized_input = input.replace(' ', "").to_lowercase();
+    let named_color = TextColorOptions::to_iter().find(|color| {
+        !matches!(color, TextColorOptions::RGB(..) | TextColorOptions::None)
+            && color.to_string().replace(' ', "").to_lowercase() == normalized_input
+    });
+    if let Some(named_color) = named_color {
+        if let Some(color) = named_color.to_color() {
+            return Ok((color, named_color.to_string()));
+        }
+    }
+    match parse_custom_color(input)? {
+        Color::Rgb(r, g, b) => Ok((Color::Rgb(r, g, b), to_hex((r, g, b)))),
+        color => Ok((color, input.to_string())),
+    }
+}
+
+/// Returns the rgb value of a style color, colors that are not set or can't be converted use
+/// the fallback instead
+fn terminal_color(color: Option<Color>, fallback: TextColorOptions) -> (u8, u8, u8) {
+    match color.map(TextColorOptions::from) {
+        Some(TextColorOptions::None) | None => fallback.to_rgb(),
+        Some(color) => color.to_rgb(),
+    }
+}
+
+impl Theme {
+    /// Maps the theme onto a terminal palette, each ANSI color is taken from the style that
+    /// uses that color for a similar purpose in the app
+    pub fn to_terminal_palette(&self) -> TerminalPalette {
+        let foreground = terminal_color(self.general_style.fg, TextColorOptions::Gray);
+        let background = terminal_color(self.general_style.bg, TextColorOptions::Black);
+        TerminalPalette {
+            foreground,
+            background,
+            cursor: terminal_color(self.keyboard_focus_style.fg, TextColorOptions::White),
+            selection_background: terminal_color(
+                self.list_select_style.bg,
+                TextColorOptions::DarkGray,
+            ),
+            colors: [
+                background,
+                terminal_color(self.card_due_overdue_style.fg, TextColorOptions::Red),
+                terminal_color(self.card_status_completed_style.fg, TextColorOptions::Green),
+                terminal_color(self.card_due_warning_style.fg, TextColorOptions::Yellow),
+                terminal_color(self.keyboard_focus_style.fg, TextColorOptions::Blue),
+                terminal_color(self.mouse_focus_style.fg, TextColorOptions::Magenta),
+                terminal_color(self.help_key_style.fg, TextColorOptions::Cyan),
+                foreground,
+                terminal_color(self.inactive_text_style.fg, TextColorOptions::DarkGray),
+                terminal_color(self.error_text_style.fg, TextColorOptions::LightRed),
+                terminal_color(
+                    self.card_priority_low_style.fg,
+                    TextColorOptions::LightGreen,
+                ),
+                terminal_color(
+                    self.card_priority_medium_style.fg,
+                    TextColorOptions::LightYellow,
+                ),
+                terminal_color(self.progress_bar_style.fg, TextColorOptions::LightBlue),
+                terminal_color(self.log_debug_style.fg, TextColorOptions::LightMagenta),
+                terminal_color(
+                    self.card_status_active_style.fg,
+                    TextColorOptions::LightCyan,
+                ),
+                terminal_color(self.help_text_style.fg, TextColorOptions::White),
+            ],
+        }
+    }
+
+    pub fn to_terminal_color_scheme(&self, format: TerminalColorSchemeFormat) -> String {
+        let palette = self.to_terminal_palette();
+        let colors = palette.colors.map(to_hex);
+        match format {
+            TerminalColorSchemeFormat::WindowsTerminal => {
+                let scheme = WindowsTerminalScheme {
+                    name: self.name.clone(),
+                    foreground: to_hex(palette.foreground),
+                    background: to_hex(palette.background),
+                    cursor_color: to_hex(palette.cursor),
+                    selection_background: to_hex(palette.selection_background),
+                    black: colors[0].clone(),
+                    red: colors[1].clone(),
+                    green: colors[2].clone(),
+                    yellow: colors[3].clone(),
+                    blue: colors[4].clone(),
+                    purple: colors[5].clone(),
+                    cyan: colors[6].clone(),
+                    white: colors[7].clone(),
+                    bright_black: colors[8].clone(),
+                    bright_red: colors[9].clone(),
+                    bright_green: colors[10].clone(),
+                    bright_yellow: colors[11].clone(),
+                    bright_blue: colors[12].clone(),
+                    bright_purple: colors[13].clone(),
+                    bright_cyan: colors[14].clone(),
+                    bright_white: colors[15].clone(),
+                };
+                serde_json::to_string_pretty(&scheme).unwrap()
+            }
+            TerminalColorSchemeFormat::Xresources => {
+                let mut xresources = format!("! {} (exported from rust_kanban)\n", self.name);
+                xresources.push_str(&format!("*.foreground: {}\n", to_hex(palette.foreground)));
+                xresources.push_str(&format!("*.background: {}\n", to_hex(palette.background)));
+                xresources.push_str(&format!("*.cursorColor: {}\n", to_hex(palette.cursor)));
+                for (index, color) in colors.iter().enumerate() {
+                    xresources.push_str(&format!("*.color{}: {}\n", index, color));
+                }
+                xresources
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TextColorOptions {
     Black,
@@ -1115,8 +1416,8 @@ impl TextColorOptions {
             TextColorOptions::Gray => (192, 192, 192),
             TextColorOptions::DarkGray => (128, 128, 128),
             TextColorOptions::LightRed => (255, 0, 0),
-            TextColorOptions::LightGreen => (255, 255, 0),
-            TextColorOptions::LightYellow => (0, 255, 0),
+            TextColorOptions::LightGreen => (0, 255, 0),
+            TextColorOptions::LightYellow => (255, 255, 0),
             TextColorOptions::LightBlue => (0, 0, 255),
             TextColorOptions::LightMagenta => (255, 0, 255),
             TextColorOptions::LightCyan => (0, 255, 255),
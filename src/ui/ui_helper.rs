@@ -1,12 +1,13 @@
-use chrono::{Local, NaiveDate, NaiveDateTime};
+use chrono::{Local, Utc};
 use log::debug;
 use ratatui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
-    text::{Span, Line},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{
-         BorderType, Borders, Cell, Clear, Gauge, List, ListItem, Paragraph, Row, Table, Block, 
+        Block, BorderType, Borders, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row,
+        Table, Wrap,
     },
     Frame,
 };
@@ -15,21 +16,34 @@ use tui_logger::TuiLoggerWidget;
 
 use crate::{
     app::{
-        date_format_converter, date_format_finder,
-        kanban::{CardPriority, CardStatus},
-        state::{AppStatus, Focus, UiMode},
-        App, DateFormat, MainMenu, PopupMode,
+        actions::Action,
+        app_helper::get_status_columns,
+        board_days_until_due, card_modified_at, date_format_converter, date_format_finder,
+        days_until_due, days_until_stale,
+        kanban::{Card, CardLink, CardPriority, CardRecurrence, CardStatus},
+        parse_localized_date, relative_due_date_text,
+        state::{AppStatus, Focus, KeyChord, UiMode},
+        App, CardPreviewField, DateFormat, MainMenu, PopupMode, QuickFilter,
     },
     calculate_cursor_position,
     constants::{
-        APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
-        LIST_SELECTED_SYMBOL, MAX_TOASTS_TO_DISPLAY, MIN_TERM_HEIGHT, MIN_TERM_WIDTH,
-        SCREEN_TO_TOAST_WIDTH_RATIO, SPINNER_FRAMES, VERTICAL_SCROLL_BAR_SYMBOL,
+        APP_TITLE, ASCII_APP_TITLE, ASCII_HIDING_COMPLETED_CARDS_SYMBOL,
+        ASCII_PROGRESS_BAR_EMPTY_SYMBOL, ASCII_UNSAVED_CHANGES_SYMBOL,
+        ASCII_VERTICAL_SCROLL_BAR_SYMBOL, BOARD_ACCENT_BAND_WIDTH, CARD_POINTS_OPTIONS,
+        CARD_PROGRESS_BAR_WIDTH, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH,
+        FIELD_NOT_SET, HIDING_COMPLETED_CARDS_SYMBOL, LIST_SELECTED_SYMBOL, MAX_TOASTS_TO_DISPLAY,
+        MIN_BODY_PANEL_HEIGHT, MIN_TERM_HEIGHT, MIN_TERM_WIDTH, PROGRESS_BAR_EMPTY_SYMBOL,
+        SCREEN_TO_TOAST_WIDTH_RATIO, SPINNER_FRAMES, STALE_WARNING_DAYS, UNSAVED_CHANGES_SYMBOL,
+        VERTICAL_SCROLL_BAR_SYMBOL,
+    },
+    io::data_handler::{
+        check_save_directory, expand_path, get_available_local_savefiles, get_save_note,
+        SaveDirectoryStatus,
     },
-    io::data_handler::get_available_local_savefiles,
 };
 
 use super::{
+    parse_accent_color, parse_custom_color,
     widgets::{ToastType, ToastWidget},
     TextColorOptions, TextModifierOptions,
 };
@@ -88,7 +102,13 @@ where
     };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(85), Constraint::Length(5)].as_ref())
+        .constraints(
+            [
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.help_panel_height),
+            ]
+            .as_ref(),
+        )
         .split(rect.size());
 
     let help_chunks = Layout::default()
@@ -126,7 +146,13 @@ where
 {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(80), Constraint::Length(8)].as_ref())
+        .constraints(
+            [
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.log_panel_height),
+            ]
+            .as_ref(),
+        )
         .split(rect.size());
 
     render_body(rect, chunks[0], app, false);
@@ -153,8 +179,8 @@ where
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Percentage(75),
-                Constraint::Length(5),
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.help_panel_height),
             ]
             .as_ref(),
         )
@@ -208,8 +234,8 @@ where
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Percentage(75),
-                Constraint::Length(8),
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.log_panel_height),
             ]
             .as_ref(),
         )
@@ -248,9 +274,9 @@ where
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Percentage(70),
-                Constraint::Length(5),
-                Constraint::Length(8),
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.help_panel_height),
+                Constraint::Length(app.config.log_panel_height),
             ]
             .as_ref(),
         )
@@ -302,9 +328,9 @@ where
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Percentage(60),
-                Constraint::Length(5),
-                Constraint::Length(8),
+                Constraint::Min(MIN_BODY_PANEL_HEIGHT),
+                Constraint::Length(app.config.help_panel_height),
+                Constraint::Length(app.config.log_panel_height),
             ]
             .as_ref(),
         )
@@ -452,11 +478,11 @@ where
     let percentage = ((current_index + 1) as f32 / total_rows as f32) * 100.0;
     let blocks_to_render = (percentage / 100.0 * visible_rows as f32) as usize;
 
-    // render blocks VERTICAL_SCROLL_BAR_SYMBOL
+    // render blocks of the scroll bar symbol
     for i in 0..blocks_to_render {
         let block_x = table_chunks[1].right() - 2;
         let block_y = table_chunks[1].top() + i as u16;
-        let block = Paragraph::new(VERTICAL_SCROLL_BAR_SYMBOL)
+        let block = Paragraph::new(scroll_bar_symbol(app))
             .style(progress_bar_style)
             .block(Block::default().borders(Borders::NONE));
         rect.render_widget(block, Rect::new(block_x, block_y, 1, 1));
@@ -596,6 +622,24 @@ where
     let paragraph_text = format!("Current Value is {}\n\n{}",config_item_value,
         "Press 'i' to edit, or 'Esc' to cancel, Press 'Ins' to stop editing and press 'Enter' on Submit to save");
     let paragraph_title = Line::from(vec![Span::raw(config_item_name)]);
+    let mut edit_title = vec![Span::raw("Edit")];
+    let typed_path = app.state.current_user_input.trim().to_string();
+    if config_item_name == "Save Directory" && !typed_path.is_empty() {
+        let status = match &app.state.save_directory_check {
+            Some((checked_path, status)) if *checked_path == typed_path => status.clone(),
+            _ => {
+                let status = check_save_directory(&expand_path(&typed_path));
+                app.state.save_directory_check = Some((typed_path, status.clone()));
+                status
+            }
+        };
+        let status_style = match status {
+            SaveDirectoryStatus::Writable => app.theme.log_info_style,
+            SaveDirectoryStatus::Missing => app.theme.log_warn_style,
+            _ => app.theme.error_text_style,
+        };
+        edit_title.push(Span::styled(format!(" - {} ", status), status_style));
+    }
     let config_item = Paragraph::new(paragraph_text)
         .block(
             Block::default()
@@ -608,7 +652,7 @@ where
     let edit_item = Paragraph::new(app.state.current_user_input.clone())
         .block(
             Block::default()
-                .title("Edit")
+                .title(Line::from(edit_title))
                 .style(app.theme.general_style)
                 .borders(Borders::ALL)
                 .border_style(edit_box_style)
@@ -659,134 +703,727 @@ where
     }
 }
 
-pub fn render_select_default_view<B>(rect: &mut Frame<B>, app: &mut App)
+pub fn render_edit_board_description<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
-    let render_area = centered_rect(70, 70, rect.size());
-    let mouse_coordinates = app.state.current_mouse_coordinates;
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    render_text_input_popup(
+        rect,
+        app,
+        &format!("Edit Description of {}", board_name),
+        "Description",
+        "Press 'i' to edit, or 'Esc' to cancel, Press 'Ins' to stop editing and press 'Enter' to save. Save an empty description to remove it",
+    );
+}
+
+pub fn render_save_note_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    render_text_input_popup(
+        rect,
+        app,
+        "Save Note",
+        "Note",
+        "Describe this save (optional). Press 'Ins' to stop editing and press 'Enter' to save, an empty note saves without one. Press 'Esc' to cancel the save",
+    );
+}
+
+pub fn render_import_markdown_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    render_text_input_popup(
+        rect,
+        app,
+        "Import Markdown Checklist",
+        "Path",
+        "Path of a Markdown file, '## ' headings become boards and '- [ ]' or '- [x]' items become cards. Press 'Ins' to stop editing and press 'Enter' to import, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_import_json_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    render_text_input_popup(
+        rect,
+        app,
+        "Import from JSON",
+        "Path",
+        "Path of a file made with 'Export to JSON' or a list of boards, the boards are added next to the current ones. Press 'Ins' to stop editing and press 'Enter' to import, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_import_trello_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    render_text_input_popup(
+        rect,
+        app,
+        "Import from Trello",
+        "Path",
+        "Path of a board exported from Trello as JSON, every list becomes a board next to the current ones. Press 'Ins' to stop editing and press 'Enter' to import, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_edit_board_card_limit_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    render_text_input_popup(
+        rect,
+        app,
+        &format!("Card Limit of {}", board_name),
+        "Card Limit",
+        "Number of cards before the board header shows a warning, 0 turns it off for this board and an empty limit uses the one from the config. Press 'Ins' to stop editing and press 'Enter' to save, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_edit_board_due_date_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    render_text_input_popup(
+        rect,
+        app,
+        &format!("Due Date of {}", board_name),
+        "Due Date",
+        "Milestone for the whole board, written like a card due date. Press 'Ins' to stop editing and press 'Enter' to save, an empty date removes it, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_edit_board_accent_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    render_text_input_popup(
+        rect,
+        app,
+        &format!("Accent Color of {}", board_name),
+        "Accent Color",
+        "Color of the band on the board header, a color name like 'Light Blue', #RRGGBB or r,g,b. Press 'Ins' to stop editing and press 'Enter' to save, an empty color removes it, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_edit_card_progress_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let card_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .and_then(|board| {
+            board
+                .cards
+                .iter()
+                .find(|card| Some(card.id) == app.state.current_card_id)
+        })
+        .map(|card| card.name.clone())
+        .unwrap_or_default();
+    render_text_input_popup(
+        rect,
+        app,
+        &format!("Progress of {}", card_name),
+        "Progress",
+        "How far along the card is, from 0 to 100 percent, an empty progress stops tracking it. Press 'Ins' to stop editing and press 'Enter' to save, or 'Esc' to cancel",
+    );
+}
+
+pub fn render_new_card_on_all_boards_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    render_text_input_popup(
+        rect,
+        app,
+        "New Card on Every Board",
+        "Card Name",
+        "Name of the card to add to every board, words starting with '#' become tags. Press 'Ins' to stop editing and press 'Enter' to add, or 'Esc' to cancel",
+    );
+}
+
+/// Popup with a free text field that is submitted with 'Enter'
+fn render_text_input_popup<B>(
+    rect: &mut Frame<B>,
+    app: &mut App,
+    title: &str,
+    field_title: &str,
+    help_text: &str,
+) where
+    B: Backend,
+{
+    let area = centered_rect(70, 70, rect.size());
     let clear_area = centered_rect(80, 80, rect.size());
     let clear_area_border = Block::default()
-        .title("Default HomeScreen Editor")
+        .title(title.to_string())
         .style(app.theme.general_style)
         .borders(Borders::ALL)
         .border_style(app.theme.keyboard_focus_style)
         .border_type(BorderType::Rounded);
     render_blank_styled_canvas(rect, app, clear_area, false);
     rect.render_widget(clear_area_border, clear_area);
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(8), Constraint::Length(5)].as_ref())
-        .split(render_area);
 
-    let list_items = UiMode::all();
-    let list_items: Vec<ListItem> = list_items
-        .iter()
-        .map(|s| ListItem::new(s.to_string()))
-        .collect();
+    let chunks = if app.config.enable_mouse_support {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(4),
+                    Constraint::Min(6),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Min(6)].as_ref())
+            .split(area)
+    };
 
-    if check_if_mouse_is_in_area(mouse_coordinates, render_area) {
-        app.state.mouse_focus = Some(Focus::SelectDefaultView);
-        app.state.focus = Focus::SelectDefaultView;
-        let top_of_list = render_area.top() + 1;
-        let mut bottom_of_list = top_of_list + list_items.len() as u16;
-        if bottom_of_list > render_area.bottom() {
-            bottom_of_list = render_area.bottom();
-        }
-        let mouse_y = mouse_coordinates.1;
-        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
-            app.state
-                .default_view_state
-                .select(Some((mouse_y - top_of_list) as usize));
-        }
-    }
+    let edit_box_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1]) {
+            app.state.mouse_focus = Some(Focus::EditGeneralConfigPopup);
+            app.state.focus = Focus::EditGeneralConfigPopup;
+            app.theme.mouse_focus_style
+        } else if app.state.app_status == AppStatus::UserInput {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
 
-    let default_view_list = List::new(list_items)
+    let help_paragraph = Paragraph::new(help_text.to_string())
         .block(
             Block::default()
+                .style(app.theme.general_style)
                 .borders(Borders::ALL)
-                .border_style(app.theme.general_style)
                 .border_type(BorderType::Rounded),
         )
-        .highlight_style(app.theme.list_select_style)
-        .highlight_symbol(LIST_SELECTED_SYMBOL);
-
-    let up_key = app
-        .state
-        .keybind_store
-        .iter()
-        .find(|x| x[1] == "Go up")
-        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
-        .clone();
-    let down_key = app
-        .state
-        .keybind_store
-        .iter()
-        .find(|x| x[1] == "Go down")
-        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
-        .clone();
-
-    let help_spans = Line::from(vec![
-        Span::styled("Use ", app.theme.help_text_style),
-        Span::styled(up_key, app.theme.help_key_style),
-        Span::styled("or ", app.theme.help_text_style),
-        Span::styled(down_key, app.theme.help_key_style),
-        Span::styled("to navigate. Press ", app.theme.help_text_style),
-        Span::styled("<Enter>", app.theme.help_key_style),
-        Span::styled(" or ", app.theme.help_text_style),
-        Span::styled("<Mouse Left Click>", app.theme.help_key_style),
-        Span::styled(
-            " To select a Default View. Press ",
-            app.theme.help_text_style,
-        ),
-        Span::styled("<Esc>", app.theme.help_key_style),
-        Span::styled(" to cancel", app.theme.help_text_style),
-    ]);
-
-    let config_help = Paragraph::new(help_spans)
-        .alignment(Alignment::Left)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    let edit_item = Paragraph::new(app.state.current_user_input.clone())
         .block(
             Block::default()
-                .title("Help")
-                .borders(Borders::ALL)
+                .title(field_title.to_string())
                 .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_style(edit_box_style)
                 .border_type(BorderType::Rounded),
         )
-        .alignment(Alignment::Center)
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(ratatui::widgets::Wrap { trim: false });
 
-    rect.render_stateful_widget(
-        default_view_list,
-        chunks[0],
-        &mut app.state.default_view_state,
-    );
-    rect.render_widget(config_help, chunks[1]);
+    if app.state.app_status == AppStatus::UserInput {
+        let text_before_cursor =
+            if let Some(current_cursor_position) = app.state.current_cursor_position {
+                app.state
+                    .current_user_input
+                    .get(..current_cursor_position)
+                    .unwrap_or(&app.state.current_user_input)
+            } else {
+                &app.state.current_user_input
+            };
+        let line_width = chunks[1].width.saturating_sub(2).max(1);
+        let mut x_offset = 0;
+        let mut y_offset = 0;
+        for line in text_before_cursor.split('\n') {
+            let line_length = line.chars().count() as u16;
+            y_offset += line_length / line_width;
+            x_offset = line_length % line_width;
+            y_offset += 1;
+        }
+        y_offset = y_offset.saturating_sub(1);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + y_offset + 1);
+    }
+    rect.render_widget(help_paragraph, chunks[0]);
+    rect.render_widget(edit_item, chunks[1]);
 
     if app.config.enable_mouse_support {
+        let submit_button_style =
+            if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+                app.state.mouse_focus = Some(Focus::SubmitButton);
+                app.state.focus = Focus::SubmitButton;
+                app.theme.mouse_focus_style
+            } else {
+                app.theme.general_style
+            };
+        let submit_button = Paragraph::new("Submit")
+            .block(
+                Block::default()
+                    .style(app.theme.general_style)
+                    .borders(Borders::ALL)
+                    .border_style(submit_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        rect.render_widget(submit_button, chunks[2]);
         render_close_button(rect, app)
     }
 }
 
-pub fn render_edit_keybindings<B>(rect: &mut Frame<B>, app: &mut App)
+/// Compact overlay with the keybindings that are most useful in the current context, the keys
+/// are read from the live keybindings so custom bindings are shown
+pub fn render_quick_help<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
-    let popup_mode = app.state.popup_mode.is_some();
-    let chunks = Layout::default()
+    let entries = get_quick_help_actions(app)
+        .iter()
+        .filter_map(|action| {
+            let action_name = action.to_string();
+            app.state
+                .keybind_store
+                .iter()
+                .find(|keybind| keybind[1] == action_name)
+                .map(|keybind| (keybind[0].trim().to_string(), action_name))
+        })
+        .collect::<Vec<(String, String)>>();
+
+    let area = centered_rect(80, 60, rect.size());
+    let block = Block::default()
+        .title("Quick Help (press any key to close)")
+        .style(app.theme.general_style)
+        .borders(Borders::ALL)
+        .border_style(app.theme.keyboard_focus_style)
+        .border_type(BorderType::Rounded);
+    render_blank_styled_canvas(rect, app, area, false);
+    rect.render_widget(block, area);
+
+    let inner_area = Rect::new(
+        area.x + 2,
+        area.y + 1,
+        area.width.saturating_sub(4),
+        area.height.saturating_sub(2),
+    );
+    if entries.is_empty() || inner_area.height == 0 {
+        return;
+    }
+    let key_width = entries
+        .iter()
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or(0);
+    // use as many columns as needed to fit the entries in the available height
+    let no_of_columns = ((entries.len() as u16 + inner_area.height - 1) / inner_area.height).max(2);
+    let rows_per_column = (entries.len() as u16 + no_of_columns - 1) / no_of_columns;
+    let column_chunks = Layout::default()
+        .direction(Direction::Horizontal)
         .constraints(
-            [
-                Constraint::Length(3),
-                Constraint::Min(8),
-                Constraint::Length(5),
-                Constraint::Length(3),
-            ]
-            .as_ref(),
+            (0..no_of_columns)
+                .map(|_| Constraint::Ratio(1, no_of_columns as u32))
+                .collect::<Vec<Constraint>>(),
         )
-        .split(rect.size());
-    let table_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(95), Constraint::Length(1)].as_ref())
-        .split(chunks[1]);
+        .split(inner_area);
+    for (column_index, column_entries) in entries.chunks(rows_per_column as usize).enumerate() {
+        if column_index >= column_chunks.len() {
+            break;
+        }
+        let lines = column_entries
+            .iter()
+            .map(|(keys, action_name)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:width$}  ", keys, width = key_width),
+                        app.theme.help_key_style,
+                    ),
+                    Span::styled(action_name.clone(), app.theme.help_text_style),
+                ])
+            })
+            .collect::<Vec<Line>>();
+        rect.render_widget(Paragraph::new(lines), column_chunks[column_index]);
+    }
+}
+
+/// Shows every field of the focused card with its comments and this session's changes to it,
+/// the keys shown at the bottom jump to editing a field
+pub fn render_expanded_card<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board_and_card = app.state.current_board_id.and_then(|board_id| {
+        boards
+            .iter()
+            .find(|board| board.id == board_id)
+            .and_then(|board| {
+                app.state.current_card_id.and_then(|card_id| {
+                    board
+                        .cards
+                        .iter()
+                        .find(|card| card.id == card_id)
+                        .map(|card| (board.name.clone(), card.clone()))
+                })
+            })
+    });
+    let (board_name, card) = if let Some(board_and_card) = board_and_card {
+        board_and_card
+    } else {
+        app.state.popup_mode = None;
+        return;
+    };
+
+    let heading_style = app.theme.general_style.add_modifier(Modifier::BOLD);
+    let or_not_set = |value: &str| {
+        if value.is_empty() {
+            FIELD_NOT_SET.to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    let field_line = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{}: ", label), app.theme.inactive_text_style),
+            Span::styled(value, app.theme.general_style),
+        ])
+    };
+    let mut lines = vec![
+        field_line("Board", board_name),
+        field_line("Status", card.card_status.to_string()),
+        field_line("Priority", card.priority.to_string()),
+        field_line("Due", or_not_set(&card.date_due)),
+        field_line(
+            "Points",
+            card.points
+                .map(|points| points.to_string())
+                .unwrap_or_else(|| FIELD_NOT_SET.to_string()),
+        ),
+        field_line(
+            "Progress",
+            card.progress
+                .map(|progress| card_progress_bar(app, progress))
+                .unwrap_or_else(|| FIELD_NOT_SET.to_string()),
+        ),
+        field_line(
+            "Time Spent",
+            format!("{}h {}m", card.minutes_spent / 60, card.minutes_spent % 60),
+        ),
+        field_line("Tags", or_not_set(&card.tags.join(", "))),
+        Line::from(""),
+        Line::from(Span::styled("Description", heading_style)),
+    ];
+    lines.extend(
+        or_not_set(&card.description)
+            .lines()
+            .map(|line| Line::from(Span::styled(line.to_string(), app.theme.general_style))),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Comments ({})", card.comments.len()),
+        heading_style,
+    )));
+    lines.extend(card.comments.iter().map(|comment| {
+        Line::from(Span::styled(
+            format!("- {}", comment),
+            app.theme.general_style,
+        ))
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "History (this session)",
+        heading_style,
+    )));
+    let card_reference = format!("'{}'", card.name);
+    let history_lines = app
+        .action_history_manager
+        .activity_log
+        .iter()
+        .filter(|entry| entry.description.contains(&card_reference))
+        .map(|entry| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", entry.time.format("%H:%M:%S")),
+                    app.theme.inactive_text_style,
+                ),
+                Span::styled(entry.description.clone(), app.theme.general_style),
+            ])
+        })
+        .collect::<Vec<Line>>();
+    if history_lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No changes made this session",
+            app.theme.inactive_text_style,
+        )));
+    } else {
+        lines.extend(history_lines);
+    }
+
+    let mut help_spans = vec![];
+    for (key, field) in [
+        ("n", "name"),
+        ("d", "description"),
+        ("u", "due date"),
+        ("t", "tags"),
+        ("c", "comments"),
+        ("p", "priority"),
+        ("s", "status"),
+    ] {
+        help_spans.push(Span::styled(key, app.theme.help_key_style));
+        help_spans.push(Span::styled(
+            format!(" {}  ", field),
+            app.theme.help_text_style,
+        ));
+    }
+    help_spans.push(Span::styled(
+        "any other key closes",
+        app.theme.help_text_style,
+    ));
+
+    let area = centered_rect(80, 80, rect.size());
+    render_blank_styled_canvas(rect, app, area, false);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)].as_ref())
+        .split(area);
+    let card_paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!("Card: {}", card.name))
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_style(app.theme.keyboard_focus_style)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: false });
+    rect.render_widget(card_paragraph, chunks[0]);
+    let help_paragraph = Paragraph::new(Line::from(help_spans)).block(
+        Block::default()
+            .title("Edit")
+            .style(app.theme.general_style)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    );
+    rect.render_widget(help_paragraph, chunks[1]);
+}
+
+/// Actions listed in the quick help overlay for the current view and focus
+fn get_quick_help_actions(app: &App) -> Vec<Action> {
+    if UiMode::view_modes().contains(&app.state.ui_mode) {
+        if app.state.focus == Focus::Body {
+            vec![
+                Action::Up,
+                Action::Down,
+                Action::Left,
+                Action::Right,
+                Action::Enter,
+                Action::ToggleExpandedCard,
+                Action::NewCard,
+                Action::DeleteCard,
+                Action::MoveCardUp,
+                Action::MoveCardDown,
+                Action::MoveCardToTop,
+                Action::MoveCardToBottom,
+                Action::MoveCardLeft,
+                Action::MoveCardRight,
+                Action::CycleCardStatus,
+                Action::ChangeCardStatusToCompleted,
+                Action::ToggleCardSelection,
+                Action::JumpToCard,
+                Action::ToggleBoardFocusMode,
+                Action::ToggleHideCompletedCards,
+                Action::ToggleSplitView,
+                Action::SwitchSplitViewPane,
+                Action::GrowPanel,
+                Action::ShrinkPanel,
+                Action::NewBoard,
+                Action::DeleteBoard,
+                Action::Undo,
+                Action::Redo,
+                Action::ToggleCommandPalette,
+                Action::Quit,
+            ]
+        } else {
+            vec![
+                Action::NextFocus,
+                Action::PrvFocus,
+                Action::Enter,
+                Action::GrowPanel,
+                Action::ShrinkPanel,
+                Action::NewBoard,
+                Action::NewCard,
+                Action::HideUiElement,
+                Action::SaveState,
+                Action::OpenConfigMenu,
+                Action::GoToMainMenu,
+                Action::Undo,
+                Action::Redo,
+                Action::ToggleCommandPalette,
+                Action::Quit,
+            ]
+        }
+    } else {
+        vec![
+            Action::Up,
+            Action::Down,
+            Action::NextFocus,
+            Action::PrvFocus,
+            Action::Enter,
+            Action::TakeUserInput,
+            Action::StopUserInput,
+            Action::GoToPreviousUIMode,
+            Action::ToggleCommandPalette,
+            Action::Quit,
+        ]
+    }
+}
+
+pub fn render_select_default_view<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let render_area = centered_rect(70, 70, rect.size());
+    let mouse_coordinates = app.state.current_mouse_coordinates;
+    let clear_area = centered_rect(80, 80, rect.size());
+    let clear_area_border = Block::default()
+        .title("Default HomeScreen Editor")
+        .style(app.theme.general_style)
+        .borders(Borders::ALL)
+        .border_style(app.theme.keyboard_focus_style)
+        .border_type(BorderType::Rounded);
+    render_blank_styled_canvas(rect, app, clear_area, false);
+    rect.render_widget(clear_area_border, clear_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(8), Constraint::Length(5)].as_ref())
+        .split(render_area);
+
+    let list_items = UiMode::all();
+    let list_items: Vec<ListItem> = list_items
+        .iter()
+        .map(|s| ListItem::new(s.to_string()))
+        .collect();
+
+    if check_if_mouse_is_in_area(mouse_coordinates, render_area) {
+        app.state.mouse_focus = Some(Focus::SelectDefaultView);
+        app.state.focus = Focus::SelectDefaultView;
+        let top_of_list = render_area.top() + 1;
+        let mut bottom_of_list = top_of_list + list_items.len() as u16;
+        if bottom_of_list > render_area.bottom() {
+            bottom_of_list = render_area.bottom();
+        }
+        let mouse_y = mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .default_view_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+
+    let default_view_list = List::new(list_items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    let up_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Go up")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
+    let down_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Go down")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
+
+    let help_spans = Line::from(vec![
+        Span::styled("Use ", app.theme.help_text_style),
+        Span::styled(up_key, app.theme.help_key_style),
+        Span::styled("or ", app.theme.help_text_style),
+        Span::styled(down_key, app.theme.help_key_style),
+        Span::styled("to navigate. Press ", app.theme.help_text_style),
+        Span::styled("<Enter>", app.theme.help_key_style),
+        Span::styled(" or ", app.theme.help_text_style),
+        Span::styled("<Mouse Left Click>", app.theme.help_key_style),
+        Span::styled(
+            " To select a Default View. Press ",
+            app.theme.help_text_style,
+        ),
+        Span::styled("<Esc>", app.theme.help_key_style),
+        Span::styled(" to cancel", app.theme.help_text_style),
+    ]);
+
+    let config_help = Paragraph::new(help_spans)
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .title("Help")
+                .borders(Borders::ALL)
+                .style(app.theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    rect.render_stateful_widget(
+        default_view_list,
+        chunks[0],
+        &mut app.state.default_view_state,
+    );
+    rect.render_widget(config_help, chunks[1]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_edit_keybindings<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_mode = app.state.popup_mode.is_some();
+    let chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(8),
+                Constraint::Length(5),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let table_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(95), Constraint::Length(1)].as_ref())
+        .split(chunks[1]);
     let default_style = if popup_mode {
         app.theme.inactive_text_style
     } else {
@@ -840,6 +1477,12 @@ where
         row.push(row_value);
         table_items.push(row);
     }
+    for chord in keybindings.chords.iter() {
+        table_items.push(vec![
+            format!("{} (chord)", chord.action),
+            KeyChord::keys_to_string(&chord.keys),
+        ]);
+    }
 
     let rows = table_items.iter().map(|item| {
         let height = item
@@ -859,11 +1502,11 @@ where
     let percentage = ((current_index + 1) as f32 / total_rows as f32) * 100.0;
     let blocks_to_render = (percentage / 100.0 * visible_rows as f32) as usize;
 
-    // render blocks VERTICAL_SCROLL_BAR_SYMBOL
+    // render blocks of the scroll bar symbol
     for i in 0..blocks_to_render {
         let block_x = table_chunks[1].right() - 2;
         let block_y = table_chunks[1].top() + i as u16;
-        let block = Paragraph::new(VERTICAL_SCROLL_BAR_SYMBOL)
+        let block = Paragraph::new(scroll_bar_symbol(app))
             .style(progress_bar_style)
             .block(Block::default().borders(Borders::NONE));
         rect.render_widget(block, Rect::new(block_x, block_y, 1, 1));
@@ -938,6 +1581,13 @@ where
         .find(|x| x[1] == "Go down")
         .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
         .clone();
+    let reset_ui_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Reset UI")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
 
     let edit_keybind_help_spans = Line::from(vec![
         Span::styled("Use ", help_text_style),
@@ -950,6 +1600,8 @@ where
         Span::styled(" or ", help_text_style),
         Span::styled("<Mouse Left Click>", help_key_style),
         Span::styled(" to edit, ", help_text_style),
+        Span::styled(reset_ui_key, help_key_style),
+        Span::styled("to reset the selected keybinding, ", help_text_style),
         Span::styled("<Esc>", help_key_style),
         Span::styled(
             " to cancel, To Reset Keybindings to Default Press ",
@@ -1053,69 +1705,73 @@ where
         key_list.push((k, v));
     }
 
-    if key_id > key_list.len() {
-        return;
-    } else {
-        let key = key_list[key_id].0;
-        let value = key_list[key_id].1;
+    let (key, key_value) = if key_id < key_list.len() {
         let mut key_value = String::new();
-        for v in value.iter() {
+        for v in key_list[key_id].1.iter() {
             key_value.push_str(&v.to_string());
             key_value.push(' ');
         }
-        let user_input_key = app
-            .state
-            .keybind_store
-            .iter()
-            .find(|x| x[1] == "Enter input mode")
-            .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
-            .clone();
-        let paragraph_text = format!("Current Value is {}\n\nPress <{}> to edit, <Esc> to cancel, <Ins> to stop editing and <Enter> to save when stopped editing",key_value,user_input_key);
-        let paragraph_title = key.to_uppercase();
-        let config_item = Paragraph::new(paragraph_text)
-            .block(
-                Block::default()
-                    .title(paragraph_title)
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded),
-            )
-            .wrap(ratatui::widgets::Wrap { trim: true });
-        let current_edited_keybinding = app.state.edited_keybinding.clone();
-        let mut current_edited_keybinding_string = String::new();
-        if let Some(current_edited_keybinding) = current_edited_keybinding {
-            for key in current_edited_keybinding {
-                current_edited_keybinding_string.push_str(&key.to_string());
-                current_edited_keybinding_string.push(' ');
-            }
+        (key_list[key_id].0.to_string(), key_value)
+    } else if let Some(chord) = current_bindings.chords.get(key_id - key_list.len()) {
+        (
+            format!("{} (chord)", chord.action),
+            KeyChord::keys_to_string(&chord.keys),
+        )
+    } else {
+        return;
+    };
+    let user_input_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Enter input mode")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
+    let paragraph_text = format!("Current Value is {}\n\nPress <{}> to edit, <Esc> to cancel, <Ins> to stop editing and <Enter> to save when stopped editing",key_value,user_input_key);
+    let paragraph_title = key.to_uppercase();
+    let config_item = Paragraph::new(paragraph_text)
+        .block(
+            Block::default()
+                .title(paragraph_title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    let current_edited_keybinding = app.state.edited_keybinding.clone();
+    let mut current_edited_keybinding_string = String::new();
+    if let Some(current_edited_keybinding) = current_edited_keybinding {
+        for key in current_edited_keybinding {
+            current_edited_keybinding_string.push_str(&key.to_string());
+            current_edited_keybinding_string.push(' ');
         }
-        let edit_item = Paragraph::new(current_edited_keybinding_string.clone())
-            .block(
-                Block::default()
-                    .title("Edit")
-                    .borders(Borders::ALL)
-                    .border_style(edit_box_style)
-                    .border_type(BorderType::Rounded),
-            )
-            .wrap(ratatui::widgets::Wrap { trim: true });
+    }
+    let edit_item = Paragraph::new(current_edited_keybinding_string.clone())
+        .block(
+            Block::default()
+                .title("Edit")
+                .borders(Borders::ALL)
+                .border_style(edit_box_style)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
 
-        let log = draw_logs(app, true, false, chunks[2]);
+    let log = draw_logs(app, true, false, chunks[2]);
 
-        if app.state.app_status == AppStatus::KeyBindMode {
-            let current_cursor_position = if app.state.current_cursor_position.is_some() {
-                app.state.current_cursor_position.unwrap() as u16
-            } else {
-                current_edited_keybinding_string.len() as u16
-            };
-            let x_offset = current_cursor_position % (chunks[1].width - 2);
-            let y_offset = current_cursor_position / (chunks[1].width - 2);
-            let x_cursor_position = chunks[1].x + x_offset + 1;
-            let y_cursor_position = chunks[1].y + y_offset + 1;
-            rect.set_cursor(x_cursor_position, y_cursor_position);
-        }
-        rect.render_widget(config_item, chunks[0]);
-        rect.render_widget(edit_item, chunks[1]);
-        rect.render_widget(log, chunks[2]);
+    if app.state.app_status == AppStatus::KeyBindMode {
+        let current_cursor_position = if app.state.current_cursor_position.is_some() {
+            app.state.current_cursor_position.unwrap() as u16
+        } else {
+            current_edited_keybinding_string.len() as u16
+        };
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        let y_offset = current_cursor_position / (chunks[1].width - 2);
+        let x_cursor_position = chunks[1].x + x_offset + 1;
+        let y_cursor_position = chunks[1].y + y_offset + 1;
+        rect.set_cursor(x_cursor_position, y_cursor_position);
     }
+    rect.render_widget(config_item, chunks[0]);
+    rect.render_widget(edit_item, chunks[1]);
+    rect.render_widget(log, chunks[2]);
 
     if app.config.enable_mouse_support {
         let submit_button_style =
@@ -1561,11 +2217,167 @@ where
     rect.render_stateful_widget(main_menu, render_area, &mut app.state.main_menu_state);
 }
 
+/// Draws all cards grouped into status columns regardless of the board they belong to
+fn render_status_columns<B>(rect: &mut Frame<B>, area: Rect, app: &mut App)
+where
+    B: Backend,
+{
+    let columns = get_status_columns(&app.boards);
+    let column_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            columns
+                .iter()
+                .map(|_| Constraint::Ratio(1, columns.len() as u32))
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(area);
+
+    for (column_index, (status, cards)) in columns.iter().enumerate() {
+        let status_style = if app.state.popup_mode.is_some() {
+            app.theme.inactive_text_style
+        } else {
+            get_card_status_style_and_glyph(app, status).0
+        };
+        let column_border_style = if app.state.popup_mode.is_some() {
+            app.theme.inactive_text_style
+        } else if check_if_mouse_is_in_area(
+            app.state.current_mouse_coordinates,
+            column_chunks[column_index],
+        ) {
+            app.state.mouse_focus = Some(Focus::Body);
+            app.state.focus = Focus::Body;
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+
+        let column_points: u32 = cards
+            .iter()
+            .filter_map(|(board_id, card_id)| {
+                let board = app.boards.iter().find(|board| board.id == *board_id)?;
+                board.get_card(*card_id)?.points
+            })
+            .sum();
+        let mut selected_index = None;
+        let list_items: Vec<ListItem> = cards
+            .iter()
+            .enumerate()
+            .filter_map(|(card_index, (board_id, card_id))| {
+                let board = app.boards.iter().find(|board| board.id == *board_id)?;
+                let card = board.get_card(*card_id)?;
+                if app.state.current_card_id == Some(*card_id) {
+                    selected_index = Some(card_index);
+                }
+                let (card_name, card_name_style) =
+                    completed_card_name(app, card, card.name.clone(), status_style);
+                Some(ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", card_priority_glyph(app, &card.priority)),
+                        status_style,
+                    ),
+                    Span::styled(card_name, card_name_style),
+                    Span::styled(format!(" ({})", board.name), app.theme.inactive_text_style),
+                ])))
+            })
+            .collect();
+
+        let highlight_style = if app.state.popup_mode.is_some() || app.state.focus != Focus::Body {
+            app.theme.inactive_text_style
+        } else {
+            app.theme.list_select_style
+        };
+        let column_list = List::new(list_items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!(
+                            "{} {} ({}) [{} pts]",
+                            card_status_glyph(app, status),
+                            status,
+                            cards.len(),
+                            column_points
+                        ),
+                        status_style,
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(column_border_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(highlight_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+        let mut column_state = ListState::default();
+        column_state.select(selected_index);
+        rect.render_stateful_widget(column_list, column_chunks[column_index], &mut column_state);
+    }
+}
+
+/// Drawn in place of the boards when there are none to show, with the key that creates a board
+fn render_empty_body<B>(rect: &mut Frame<B>, area: Rect, app: &App)
+where
+    B: Backend,
+{
+    let text_style = app.theme.inactive_text_style;
+    let key_style = if app.state.popup_mode.is_some() {
+        app.theme.inactive_text_style
+    } else {
+        app.theme.help_key_style
+    };
+    let mut lines = if app.boards.is_empty() {
+        let guidance = match app.config.keybindings.new_board.first() {
+            Some(new_board_key) => Line::from(vec![
+                Span::styled("Press ", text_style),
+                Span::styled(new_board_key.to_string(), key_style),
+                Span::styled(" to create your first board", text_style),
+            ]),
+            None => Line::from(Span::styled(
+                "Use \"New Board\" in the command palette to create your first board",
+                text_style,
+            )),
+        };
+        vec![
+            Line::from(Span::styled("There are no boards yet", text_style)),
+            Line::from(""),
+            guidance,
+        ]
+    } else {
+        vec![Line::from(Span::styled(
+            "No boards to show, clear the filter to see all boards",
+            text_style,
+        ))]
+    };
+    // center the text vertically inside the borders
+    let top_padding = area.height.saturating_sub(2 + lines.len() as u16) / 2;
+    for _ in 0..top_padding {
+        lines.insert(0, Line::from(""));
+    }
+    let border_style = if app.state.popup_mode.is_some() {
+        app.theme.inactive_text_style
+    } else {
+        app.theme.general_style
+    };
+    let empty_paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title("Boards")
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .border_type(BorderType::Rounded),
+        );
+    rect.render_widget(empty_paragraph, area);
+}
+
 /// Draws Kanban boards
 pub fn render_body<B>(rect: &mut Frame<B>, area: Rect, app: &mut App, preview_mode: bool)
 where
     B: Backend,
 {
+    if app.state.status_column_view && !preview_mode {
+        render_status_columns(rect, area, app);
+        return;
+    }
     let fallback_boards = vec![];
     let focus = app.state.focus;
     let boards = if preview_mode {
@@ -1591,14 +2403,6 @@ where
     };
     let current_board = &app.state.current_board_id.unwrap_or(0);
 
-    let add_board_key = app
-        .state
-        .keybind_store
-        .iter()
-        .find(|x| x[1] == "Create new board")
-        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
-        .clone();
-
     // check if any boards are present
     if preview_mode {
         if app.state.preview_boards_and_cards.is_none()
@@ -1622,27 +2426,39 @@ where
             return;
         }
     } else if app.visible_boards_and_cards.is_empty() {
-        let empty_paragraph = Paragraph::new(
-            [
-                "No boards found, press ".to_string(),
-                add_board_key,
-                " to add a new board".to_string(),
-            ]
-            .concat(),
-        )
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .title("Boards")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded),
-        )
-        .style(error_text_style);
-        rect.render_widget(empty_paragraph, area);
+        render_empty_body(rect, area, app);
         return;
     }
 
-    let filter_chunks = if app.filtered_boards.is_empty() {
+    let card_totals = if preview_mode {
+        None
+    } else {
+        get_card_totals_text(app)
+    };
+    let focus_timer_text = if preview_mode {
+        None
+    } else {
+        app.state
+            .focus_timer
+            .as_ref()
+            .map(|focus_timer| focus_timer.status_text())
+    };
+    let changes_since_save = app.action_history_manager.changes_since_save;
+    let unsaved_text = if preview_mode || changes_since_save == 0 {
+        None
+    } else {
+        let unsaved_symbol = if app.ascii_only() {
+            ASCII_UNSAVED_CHANGES_SYMBOL
+        } else {
+            UNSAVED_CHANGES_SYMBOL
+        };
+        Some(format!("{} {} unsaved", unsaved_symbol, changes_since_save))
+    };
+    let filter_chunks = if app.filtered_boards.is_empty()
+        && card_totals.is_none()
+        && focus_timer_text.is_none()
+        && unsaved_text.is_none()
+    {
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(0), Constraint::Percentage(100)].as_ref())
@@ -1667,18 +2483,107 @@ where
             .split(filter_chunks[1])
     };
 
-    if !app.filtered_boards.is_empty() {
-        let filtered_text = "This is a filtered view, Clear filter to see all boards and cards";
-        let filtered_paragraph = Paragraph::new(filtered_text.to_string())
+    if !app.filtered_boards.is_empty()
+        || card_totals.is_some()
+        || focus_timer_text.is_some()
+        || unsaved_text.is_some()
+    {
+        let mut status_spans = vec![];
+        if !app.filtered_boards.is_empty() {
+            // boards that hide their completed cards are marked in their header instead
+            let filtered_text = if app.state.hide_completed_cards {
+                if app.state.active_filter.is_some() {
+                    Some("This is a filtered view with completed cards hidden, Clear filter and show completed cards to see all cards")
+                } else {
+                    Some("Completed cards are hidden, show completed cards to see all cards")
+                }
+            } else if app.state.active_filter.is_some()
+                || app.state.boards_hiding_completed_cards.is_empty()
+            {
+                Some("This is a filtered view, Clear filter to see all boards and cards")
+            } else {
+                None
+            };
+            if let Some(filtered_text) = filtered_text {
+                status_spans.push(Span::styled(filtered_text, error_text_style));
+            }
+            if let Some(quick_filter) = app
+                .state
+                .active_filter
+                .as_ref()
+                .and_then(QuickFilter::from_preset)
+            {
+                if !status_spans.is_empty() {
+                    status_spans.push(Span::styled(" | ", app.theme.inactive_text_style));
+                }
+                status_spans.push(Span::styled(
+                    format!(
+                        "Quick filter: {}, press the same key again to clear",
+                        quick_filter
+                    ),
+                    app.theme.help_key_style,
+                ));
+            }
+        }
+        if let Some(card_totals) = card_totals {
+            let card_totals_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.help_key_style
+            };
+            if !status_spans.is_empty() {
+                status_spans.push(Span::styled(" | ", app.theme.inactive_text_style));
+            }
+            status_spans.push(Span::styled(card_totals, card_totals_style));
+        }
+        if let Some(focus_timer_text) = focus_timer_text {
+            let focus_timer_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.progress_bar_style
+            };
+            if !status_spans.is_empty() {
+                status_spans.push(Span::styled(" | ", app.theme.inactive_text_style));
+            }
+            status_spans.push(Span::styled(focus_timer_text, focus_timer_style));
+        }
+        if let Some(unsaved_text) = unsaved_text {
+            let unsaved_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.card_due_warning_style
+            };
+            if !status_spans.is_empty() {
+                status_spans.push(Span::styled(" | ", app.theme.inactive_text_style));
+            }
+            status_spans.push(Span::styled(unsaved_text, unsaved_style));
+        }
+        let filtered_paragraph = Paragraph::new(Line::from(status_spans))
             .alignment(Alignment::Center)
-            .block(Block::default())
-            .style(error_text_style);
+            .block(Block::default());
         rect.render_widget(filtered_paragraph, filter_chunks[0]);
     }
 
+    let split_view = if preview_mode {
+        None
+    } else {
+        app.state.split_view.clone()
+    };
+    // the mouse only focuses the active pane so navigation keeps working on the current board
+    let inactive_pane_board_id = split_view.as_ref().map(|pane| pane.board_id);
+    if !preview_mode {
+        app.state.hovered_board_card = None;
+    }
     let mut constraints = vec![];
     // check if length of boards is more than NO_OF_BOARDS_PER_PAGE
-    if boards.len() > app.config.no_of_boards_to_show.into() {
+    if split_view.is_some() {
+        constraints.push(Constraint::Percentage(app.config.split_view_ratio));
+        constraints.push(Constraint::Percentage(
+            100u16.saturating_sub(app.config.split_view_ratio),
+        ));
+    } else if app.single_board_view() && !preview_mode {
+        constraints.push(Constraint::Percentage(100));
+    } else if boards.len() > app.config.no_of_boards_to_show.into() {
         for _i in 0..app.config.no_of_boards_to_show {
             constraints.push(Constraint::Percentage(
                 100 / app.config.no_of_boards_to_show,
@@ -1696,13 +2601,33 @@ where
     // visible_boards_and_cards: Vec<LinkedHashMap<String, Vec<String>>>
     let visible_boards_and_cards = if preview_mode {
         app.state.preview_visible_boards_and_cards.clone()
+    } else if let Some(split_view) = split_view {
+        // the current board is the active pane, the inactive pane keeps its own cards
+        let active_pane = app
+            .visible_boards_and_cards
+            .iter()
+            .find(|(board_id, _)| **board_id == *current_board)
+            .map(|(board_id, card_ids)| (*board_id, card_ids.clone()));
+        let inactive_pane = Some((split_view.board_id, split_view.visible_card_ids));
+        let panes = if split_view.is_left {
+            [inactive_pane, active_pane]
+        } else {
+            [active_pane, inactive_pane]
+        };
+        panes.into_iter().flatten().collect()
+    } else if app.single_board_view() {
+        app.visible_boards_and_cards
+            .iter()
+            .filter(|(board_id, _)| **board_id == *current_board)
+            .map(|(board_id, card_ids)| (*board_id, card_ids.clone()))
+            .collect()
     } else {
         app.visible_boards_and_cards.clone()
     };
     for (board_index, board_and_card_tuple) in visible_boards_and_cards.iter().enumerate() {
         // render board with title in board chunks alongside with cards in card chunks of the board
         // break if board_index is more than NO_OF_BOARDS_PER_PAGE
-        if board_index >= app.config.no_of_boards_to_show.into() {
+        if board_index >= board_chunks.len() {
             break;
         }
         let board_id = board_and_card_tuple.0;
@@ -1725,15 +2650,33 @@ where
         let board_title = board.name.clone();
         let board_cards = board_and_card_tuple.1;
         // if board title is longer than DEFAULT_BOARD_TITLE_LENGTH, truncate it and add ... at the end
-        let board_title = if board_title.len() > DEFAULT_BOARD_TITLE_LENGTH.into() {
+        let board_title =
+            truncate_with_ellipsis(&board_title, DEFAULT_BOARD_TITLE_LENGTH as usize + 3, false);
+        let board_title = format!("{} ({})", board_title, board.cards.len());
+        // the limit is checked against every card on the board, not just the ones a filter shows
+        let limit_board = if preview_mode {
+            board
+        } else {
+            app.boards
+                .iter()
+                .find(|b| b.id == board.id)
+                .unwrap_or(board)
+        };
+        let card_limit_warning = limit_board
+            .effective_card_limit(app.config.board_card_limit)
+            .filter(|card_limit| limit_board.cards.len() > *card_limit)
+            .map(|card_limit| format!(" ! over limit {}/{} ", limit_board.cards.len(), card_limit));
+        let total_points = board.total_points();
+        let board_title = if total_points > 0 {
             format!(
-                "{}...",
-                &board_title[0..DEFAULT_BOARD_TITLE_LENGTH as usize]
+                "{} [{}/{} pts]",
+                board_title,
+                board.completed_points(),
+                total_points
             )
         } else {
             board_title
         };
-        let board_title = format!("{} ({})", board_title, board.cards.len());
         let board_title = if board_id == current_board {
             format!("{} {}", ">>", board_title)
         } else {
@@ -1764,13 +2707,18 @@ where
         };
         let board_border_style = if app.state.popup_mode.is_some() {
             app.theme.inactive_text_style
-        } else if check_if_mouse_is_in_area(
-            app.state.current_mouse_coordinates,
-            board_chunks[board_index],
-        ) {
+        } else if inactive_pane_board_id != Some(*board_id)
+            && check_if_mouse_is_in_area(
+                app.state.current_mouse_coordinates,
+                board_chunks[board_index],
+            )
+        {
             app.state.mouse_focus = Some(Focus::Body);
-            app.state.focus = Focus::Body;
-            app.state.current_board_id = Some(*board_id);
+            app.state.hovered_board_card = Some((*board_id, None));
+            if app.mouse_sets_focus() {
+                app.state.focus = Focus::Body;
+                app.state.current_board_id = Some(*board_id);
+            }
             app.theme.mouse_focus_style
         } else if *board_id == *current_board
             && matches!(focus, Focus::Body)
@@ -1781,24 +2729,137 @@ where
             app.theme.general_style
         };
 
+        let mut board_title_spans = vec![];
+        // the accent is drawn as a band in front of the title, boards without one only use the
+        // theme
+        if let Some(Ok((accent_color, _))) = board.accent.as_deref().map(parse_accent_color) {
+            let accent_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                Style::default().bg(accent_color)
+            };
+            board_title_spans.push(Span::styled(
+                " ".repeat(BOARD_ACCENT_BAND_WIDTH),
+                accent_style,
+            ));
+            board_title_spans.push(Span::raw(" "));
+        }
+        board_title_spans.push(Span::raw(board_title));
+        if let Some(due_date) = &board.due_date {
+            let due_date_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                match board_days_until_due(board, Local::now().date_naive()) {
+                    Some(days_left) if days_left < 0 => app.theme.card_due_overdue_style,
+                    Some(days_left) if days_left <= app.config.warning_delta as i64 => {
+                        app.theme.card_due_warning_style
+                    }
+                    _ => app.theme.card_due_default_style,
+                }
+            };
+            board_title_spans.push(Span::styled(format!(" Due: {} ", due_date), due_date_style));
+        }
+        if app.state.boards_hiding_completed_cards.contains(board_id) {
+            let hiding_completed_symbol = if app.ascii_only() {
+                ASCII_HIDING_COMPLETED_CARDS_SYMBOL
+            } else {
+                HIDING_COMPLETED_CARDS_SYMBOL
+            };
+            let hiding_completed_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.card_status_completed_style
+            };
+            board_title_spans.push(Span::styled(
+                format!(" {} ", hiding_completed_symbol),
+                hiding_completed_style,
+            ));
+        }
+        if let Some(average_progress) = board.average_progress() {
+            board_title_spans.push(Span::styled(
+                format!(" {}% done ", average_progress),
+                progress_bar_style,
+            ));
+        }
+        if let Some(card_limit_warning) = card_limit_warning {
+            let card_limit_style = if app.state.popup_mode.is_some() {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.card_due_warning_style
+            };
+            board_title_spans.push(Span::styled(card_limit_warning, card_limit_style));
+        }
+        let board_title = Line::from(board_title_spans);
         let board_block = Block::default()
-            .title(&*board_title)
+            .title(board_title)
             .borders(Borders::ALL)
             .style(board_style)
             .border_style(board_border_style)
             .border_type(BorderType::Rounded);
         rect.render_widget(board_block, board_chunks[board_index]);
 
+        // the description is shortened to a single line unless the board is focused
+        let board_area = board_chunks[board_index];
+        let description_width = board_area.width.saturating_sub(2) as usize;
+        let board_description = board.description.trim();
+        let description_lines = if board_description.is_empty()
+            || board_description == FIELD_NOT_SET
+            || description_width == 0
+            || board_area.height < 6
+        {
+            vec![]
+        } else if !preview_mode && *board_id == *current_board && matches!(focus, Focus::Body) {
+            let max_lines = (board_area.height / 3).max(1) as usize;
+            let wrapped_lines = textwrap::wrap(board_description, description_width);
+            let mut lines = wrapped_lines
+                .iter()
+                .take(max_lines)
+                .map(|line| line.to_string())
+                .collect::<Vec<String>>();
+            if wrapped_lines.len() > max_lines {
+                let last_line = lines.pop().unwrap_or_default();
+                lines.push(truncate_with_ellipsis(&last_line, description_width, true));
+            }
+            lines
+        } else {
+            let first_line = board_description.lines().next().unwrap_or_default();
+            vec![truncate_with_ellipsis(
+                first_line,
+                description_width,
+                board_description.lines().count() > 1,
+            )]
+        };
+        let description_height = description_lines.len() as u16;
+        if description_height > 0 {
+            let description_paragraph =
+                Paragraph::new(description_lines.join("\n")).style(app.theme.inactive_text_style);
+            rect.render_widget(
+                description_paragraph,
+                Rect::new(
+                    board_area.x + 1,
+                    board_area.y + 1,
+                    board_area.width - 2,
+                    description_height,
+                ),
+            );
+        }
+        let board_area = Rect::new(
+            board_area.x,
+            board_area.y + description_height,
+            board_area.width,
+            board_area.height - description_height,
+        );
+
         let card_area_chunks = if app.config.disable_scrollbars {
             Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Percentage(100)].as_ref())
-                .split(board_chunks[board_index])
+                .split(board_area)
         } else {
             Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Length(1), Constraint::Percentage(99)].as_ref())
-                .split(board_chunks[board_index])
+                .split(board_area)
         };
 
         let card_chunks = if app.config.disable_scrollbars {
@@ -1837,10 +2898,10 @@ where
             };
             // calculate number of blocks to render
             let blocks_to_render = (available_height * cards_scroll_percentage) as u16;
-            // render blocks VERTICAL_SCROLL_BAR_SYMBOL
+            // render blocks of the scroll bar symbol
             if !all_board_cards.is_empty() {
                 for i in 0..blocks_to_render {
-                    let block = Paragraph::new(VERTICAL_SCROLL_BAR_SYMBOL)
+                    let block = Paragraph::new(scroll_bar_symbol(app))
                         .style(progress_bar_style)
                         .block(Block::default().borders(Borders::NONE));
                     rect.render_widget(
@@ -1859,11 +2920,6 @@ where
             if card_index >= app.config.no_of_cards_to_show.into() {
                 break;
             }
-            let inner_card_chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
-                .margin(1)
-                .split(card_chunks[card_index]);
             // unwrap card if panic skip it and log it
             let card = board.get_card(*card_id);
             // check if card is None, if so skip it and log it
@@ -1872,16 +2928,50 @@ where
             }
             let card = card.unwrap();
 
-            let card_title = if card.name.len() > DEFAULT_CARD_TITLE_LENGTH.into() {
-                format!("{}...", &card.name[0..DEFAULT_CARD_TITLE_LENGTH as usize])
+            let card_name =
+                truncate_with_ellipsis(&card.name, DEFAULT_CARD_TITLE_LENGTH as usize + 3, false);
+            let card_name = if let Some(points) = card.points {
+                format!("{} [{}]", card_name, points)
             } else {
-                card.name.clone()
+                card_name
+            };
+            let (card_name, card_name_style) =
+                completed_card_name(app, card, card_name, Style::default());
+            let card_title = if app.config.show_card_ids {
+                format!("{} ", card.id_label())
+            } else {
+                String::new()
+            };
+            let (_, card_status_glyph, _) = get_card_status_display(app, card);
+            let card_title = format!("{} {}", card_status_glyph, card_title);
+            let card_title = if app.state.selected_card_ids.contains(card_id) {
+                format!("* {}", card_title)
+            } else {
+                card_title
             };
             let card_title = if app.state.current_card_id.unwrap_or(0) == *card_id {
                 format!("{} {}", ">>", card_title)
             } else {
                 card_title
             };
+            let card_title = if app.state.app_status == AppStatus::CardJumpMode {
+                if let Some((label, _, _)) = app
+                    .state
+                    .card_jump_labels
+                    .iter()
+                    .find(|(_, _, labelled_card_id)| labelled_card_id == card_id)
+                {
+                    format!("[{}] {}", label, card_title)
+                } else {
+                    card_title
+                }
+            } else {
+                card_title
+            };
+            let card_title = Line::from(vec![
+                Span::raw(card_title),
+                Span::styled(card_name, card_name_style),
+            ]);
 
             let card_description = if card.description == FIELD_NOT_SET {
                 format!("Description: {}", FIELD_NOT_SET)
@@ -1889,19 +2979,8 @@ where
                 card.description.clone()
             };
 
-            let mut card_extra_info = vec![Line::from("")];
-            if card.date_due == FIELD_NOT_SET {
-                if app.state.popup_mode.is_some() {
-                    card_extra_info.push(Line::from(Span::styled(
-                        format!("Due: {}", FIELD_NOT_SET),
-                        app.theme.inactive_text_style,
-                    )))
-                } else {
-                    card_extra_info.push(Line::from(Span::styled(
-                        format!("Due: {}", FIELD_NOT_SET),
-                        app.theme.card_due_default_style,
-                    )))
-                }
+            let card_due_date_line = if card.date_due == FIELD_NOT_SET || card.date_due.is_empty() {
+                no_due_date_line(app)
             } else {
                 let card_due_date = card.date_due.clone();
                 let parsed_due_date =
@@ -1914,66 +2993,34 @@ where
                         ))
                     } else {
                         if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
-                            Line::from(Span::styled(
-                                format!("Due: {}", parsed_due_date),
-                                app.theme.card_due_default_style,
-                            ))
+                            no_due_date_line(app)
                         } else {
                             let formatted_date_format =
                                 date_format_finder(&parsed_due_date).unwrap();
-                            let (days_left, parsed_due_date) = match formatted_date_format {
-                                DateFormat::DayMonthYear
-                                | DateFormat::MonthDayYear
-                                | DateFormat::YearMonthDay => {
-                                    let today = Local::now().date_naive();
-                                    let string_to_naive_date_format = NaiveDate::parse_from_str(
-                                        &parsed_due_date,
-                                        app.config.date_format.to_parser_string(),
-                                    )
-                                    .unwrap();
-                                    let days_left = string_to_naive_date_format
-                                        .signed_duration_since(today)
-                                        .num_days();
-                                    let parsed_due_date = string_to_naive_date_format
-                                        .format(app.config.date_format.to_parser_string())
-                                        .to_string();
-                                    (days_left, parsed_due_date)
-                                }
-                                DateFormat::DayMonthYearTime
-                                | DateFormat::MonthDayYearTime
-                                | DateFormat::YearMonthDayTime {} => {
-                                    let today = Local::now().naive_local();
-                                    let string_to_naive_date_format =
-                                        NaiveDateTime::parse_from_str(
-                                            &parsed_due_date,
-                                            app.config.date_format.to_parser_string(),
-                                        )
-                                        .unwrap();
-                                    let days_left = string_to_naive_date_format
-                                        .signed_duration_since(today)
-                                        .num_days();
-                                    let parsed_due_date = string_to_naive_date_format
-                                        .format(app.config.date_format.to_parser_string())
-                                        .to_string();
-                                    (days_left, parsed_due_date)
-                                }
+                            let days_left = days_until_due(
+                                &parsed_due_date,
+                                formatted_date_format,
+                                Local::now().date_naive(),
+                            )
+                            .unwrap();
+                            let due_text = if app.relative_due_dates() {
+                                format!("Due: {}", relative_due_date_text(days_left))
+                            } else {
+                                format!("Due: {}", parsed_due_date)
                             };
                             if days_left >= 0 {
                                 match days_left.cmp(&(app.config.warning_delta as i64)) {
                                     Ordering::Less | Ordering::Equal => Line::from(Span::styled(
-                                        format!("Due: {}", parsed_due_date),
+                                        due_text,
                                         app.theme.card_due_warning_style,
                                     )),
                                     Ordering::Greater => Line::from(Span::styled(
-                                        format!("Due: {}", parsed_due_date),
+                                        due_text,
                                         app.theme.card_due_default_style,
                                     )),
                                 }
                             } else {
-                                Line::from(Span::styled(
-                                    format!("Due: {}", parsed_due_date),
-                                    app.theme.card_due_overdue_style,
-                                ))
+                                Line::from(Span::styled(due_text, app.theme.card_due_overdue_style))
                             }
                         }
                     }
@@ -1988,54 +3035,135 @@ where
                         app.theme.card_due_default_style,
                     ))
                 };
-                card_extra_info.extend(vec![card_due_date_styled]);
-            }
+                card_due_date_styled
+            };
 
-            let card_status = format!("Status: {}", card.card_status.clone());
+            let (card_status_style, card_status_glyph, card_status_name) =
+                get_card_status_display(app, card);
+            let card_status = format!("Status: {} {}", card_status_glyph, card_status_name);
+            // active cards close to going stale show how many days they have left
+            let stale_in_days =
+                days_until_stale(card, app.config.stale_after_days, Utc::now().naive_utc())
+                    .filter(|days| (1..=STALE_WARNING_DAYS).contains(days));
+            let going_stale_warning = match stale_in_days {
+                Some(1) => String::from(", stale tomorrow"),
+                Some(days) => format!(", stale in {} days", days),
+                None => String::new(),
+            };
             let card_status = if app.state.popup_mode.is_some() {
-                Line::from(Span::styled(card_status, app.theme.inactive_text_style))
+                Line::from(Span::styled(
+                    format!("{}{}", card_status, going_stale_warning),
+                    app.theme.inactive_text_style,
+                ))
+            } else {
+                Line::from(vec![
+                    Span::styled(card_status, card_status_style),
+                    Span::styled(going_stale_warning, app.theme.card_status_stale_style),
+                ])
+            };
+
+            let card_priority = format!(
+                "Priority: {} {}",
+                card_priority_glyph(app, &card.priority),
+                card.priority
+            );
+            let card_priority = if app.state.popup_mode.is_some() {
+                Line::from(Span::styled(card_priority, app.theme.inactive_text_style))
             } else {
-                match card.card_status {
-                    CardStatus::Active => Line::from(Span::styled(
-                        card_status,
-                        app.theme.card_status_active_style,
+                match card.priority {
+                    CardPriority::Low => Line::from(Span::styled(
+                        card_priority,
+                        app.theme.card_priority_low_style,
                     )),
-                    CardStatus::Complete => Line::from(Span::styled(
-                        card_status,
-                        app.theme.card_status_completed_style,
+                    CardPriority::Medium => Line::from(Span::styled(
+                        card_priority,
+                        app.theme.card_priority_medium_style,
+                    )),
+                    CardPriority::High => Line::from(Span::styled(
+                        card_priority,
+                        app.theme.card_priority_high_style,
                     )),
-                    CardStatus::Stale => {
-                        Line::from(Span::styled(card_status, app.theme.card_status_stale_style))
-                    }
                 }
             };
-            card_extra_info.extend(vec![card_status]);
+
+            let card_tags = if card.tags.is_empty() {
+                format!("Tags: {}", FIELD_NOT_SET)
+            } else {
+                let tag_display_length = app.config.tag_display_length as usize;
+                let tags = card
+                    .tags
+                    .iter()
+                    .map(|tag| truncate_with_ellipsis(tag, tag_display_length, false))
+                    .collect::<Vec<String>>();
+                format!("Tags: {}", tags.join(", "))
+            };
+            let card_tags = if app.state.popup_mode.is_some() {
+                Line::from(Span::styled(card_tags, app.theme.inactive_text_style))
+            } else {
+                Line::from(Span::styled(card_tags, app.theme.general_style))
+            };
+
+            // only the fields configured in card_preview_fields are shown, in the configured order
+            let mut card_extra_info = vec![Line::from("")];
+            for field in app.config.card_preview_fields.iter() {
+                match field {
+                    CardPreviewField::DueDate => card_extra_info.push(card_due_date_line.clone()),
+                    CardPreviewField::Status => card_extra_info.push(card_status.clone()),
+                    CardPreviewField::Priority => card_extra_info.push(card_priority.clone()),
+                    CardPreviewField::Tags => card_extra_info.push(card_tags.clone()),
+                }
+            }
+            if let Some(progress) = card.progress {
+                card_extra_info.push(Line::from(Span::styled(
+                    format!("Progress: {}", card_progress_bar(app, progress)),
+                    progress_bar_style,
+                )));
+            }
+            let card_extra_info_height = card_extra_info.len() as u16;
 
             // if card id is same as current_card, highlight it
             let card_style = if app.state.popup_mode.is_some() {
                 app.theme.inactive_text_style
-            } else if check_if_mouse_is_in_area(
-                app.state.current_mouse_coordinates,
-                card_chunks[card_index],
-            ) {
+            } else if inactive_pane_board_id != Some(*board_id)
+                && check_if_mouse_is_in_area(
+                    app.state.current_mouse_coordinates,
+                    card_chunks[card_index],
+                )
+            {
                 app.state.mouse_focus = Some(Focus::Body);
-                app.state.focus = Focus::Body;
-                app.state.current_card_id = Some(*card_id);
+                app.state.hovered_board_card = Some((*board_id, Some(*card_id)));
+                if app.mouse_sets_focus() {
+                    app.state.focus = Focus::Body;
+                    app.state.current_card_id = Some(*card_id);
+                }
                 app.theme.mouse_focus_style
             } else if app.state.current_card_id.unwrap_or(0) == *card_id
                 && matches!(focus, Focus::Body)
                 && *board_id == *current_board
             {
                 app.theme.keyboard_focus_style
+            } else if let Some(highlight_style) = get_card_highlight_style(app, card) {
+                highlight_style
             } else {
                 app.theme.general_style
             };
             let card_block = Block::default()
-                .title(&*card_title)
+                .title(card_title)
                 .borders(Borders::ALL)
                 .border_style(card_style)
                 .border_type(BorderType::Rounded);
             rect.render_widget(card_block, card_chunks[card_index]);
+            let inner_card_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Min(0),
+                        Constraint::Length(card_extra_info_height),
+                    ]
+                    .as_ref(),
+                )
+                .margin(1)
+                .split(card_chunks[card_index]);
             let card_paragraph = Paragraph::new(card_description)
                 .alignment(Alignment::Left)
                 .block(Block::default())
@@ -2131,13 +3259,187 @@ fn top_left_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[0])[0]
 }
 
-/// Draws size error screen if the terminal is too small
-pub fn draw_size_error<B>(rect: &mut Frame<B>, size: &Rect, msg: String, app: &mut App)
-where
-    B: Backend,
-{
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
+/// Count and points of the selected cards, or of the filtered cards when nothing is selected,
+/// the points are left out when none of the cards have any
+fn get_card_totals_text(app: &App) -> Option<String> {
+    let (label, cards) = if !app.state.selected_card_ids.is_empty() {
+        (
+            "Selected",
+            app.boards
+                .iter()
+                .flat_map(|board| board.cards.iter())
+                .filter(|card| app.state.selected_card_ids.contains(&card.id))
+                .collect::<Vec<&Card>>(),
+        )
+    } else if !app.filtered_boards.is_empty() {
+        (
+            "Filtered",
+            app.filtered_boards
+                .iter()
+                .flat_map(|board| board.cards.iter())
+                .collect::<Vec<&Card>>(),
+        )
+    } else {
+        return None;
+    };
+    let mut totals = format!(
+        "{}: {} card{}",
+        label,
+        cards.len(),
+        if cards.len() == 1 { "" } else { "s" }
+    );
+    let points = cards
+        .iter()
+        .filter_map(|card| card.points)
+        .collect::<Vec<u32>>();
+    if !points.is_empty() {
+        totals.push_str(&format!(", {} pts", points.iter().sum::<u32>()));
+    }
+    Some(totals)
+}
+
+/// Due date line of a card without a due date, blank when the placeholder is turned off
+fn no_due_date_line(app: &App) -> Line<'static> {
+    if app.config.no_due_date_text.is_empty() {
+        Line::from("")
+    } else {
+        Line::from(Span::styled(
+            format!("Due: {}", app.config.no_due_date_text),
+            app.theme.inactive_text_style,
+        ))
+    }
+}
+
+/// Style from the theme and glyph from the config for a card status, status is shown with both
+/// so it can be read without relying on color
+fn get_card_status_style_and_glyph(app: &App, status: &CardStatus) -> (Style, String) {
+    let style = match status {
+        CardStatus::Active => app.theme.card_status_active_style,
+        CardStatus::Complete => app.theme.card_status_completed_style,
+        CardStatus::Stale => app.theme.card_status_stale_style,
+    };
+    (style, card_status_glyph(app, status).to_string())
+}
+
+/// Glyph from the config for a card status, configured glyphs that are not ASCII are swapped for
+/// the ASCII ones when rendering ASCII only
+fn card_status_glyph<'a>(app: &'a App, status: &CardStatus) -> &'a str {
+    let glyph = app.config.card_status_glyphs.get(status);
+    if app.ascii_only() && !glyph.is_ascii() {
+        status.ascii_glyph()
+    } else {
+        glyph
+    }
+}
+
+/// Name and style of a card with the completed look applied, when configured completed cards
+/// get the completed style of the theme and are crossed out, with ASCII only rendering they get
+/// a "[done]" prefix instead
+fn completed_card_name(app: &App, card: &Card, name: String, style: Style) -> (String, Style) {
+    if !app.config.strikethrough_completed_cards || card.card_status != CardStatus::Complete {
+        return (name, style);
+    }
+    let style = if app.state.popup_mode.is_some() {
+        style
+    } else {
+        style.patch(app.theme.card_status_completed_style)
+    };
+    if app.ascii_only() {
+        (format!("[done] {}", name), style)
+    } else {
+        (name, style.add_modifier(Modifier::CROSSED_OUT))
+    }
+}
+
+fn card_priority_glyph(app: &App, priority: &CardPriority) -> &'static str {
+    if app.ascii_only() {
+        priority.ascii_glyph()
+    } else {
+        priority.glyph()
+    }
+}
+
+/// Mini progress bar shown on cards, like `██████░░░░ 60%`
+fn card_progress_bar(app: &App, progress: u8) -> String {
+    let progress = progress.min(100);
+    let filled = (progress as usize * CARD_PROGRESS_BAR_WIDTH + 50) / 100;
+    let (filled_symbol, empty_symbol) = if app.ascii_only() {
+        (
+            ASCII_VERTICAL_SCROLL_BAR_SYMBOL,
+            ASCII_PROGRESS_BAR_EMPTY_SYMBOL,
+        )
+    } else {
+        (VERTICAL_SCROLL_BAR_SYMBOL, PROGRESS_BAR_EMPTY_SYMBOL)
+    };
+    format!(
+        "{}{} {}%",
+        filled_symbol.repeat(filled),
+        empty_symbol.repeat(CARD_PROGRESS_BAR_WIDTH - filled),
+        progress
+    )
+}
+
+fn scroll_bar_symbol(app: &App) -> &'static str {
+    if app.ascii_only() {
+        ASCII_VERTICAL_SCROLL_BAR_SYMBOL
+    } else {
+        VERTICAL_SCROLL_BAR_SYMBOL
+    }
+}
+
+/// Style, glyph and name shown for the status of a card, cards in a configured workflow stage show
+/// the stage with the style of its status
+fn get_card_status_display(app: &App, card: &Card) -> (Style, String, String) {
+    let (style, glyph) = get_card_status_style_and_glyph(app, &card.card_status);
+    match app
+        .config
+        .workflow_stages
+        .iter()
+        .find(|stage| stage.name == card.workflow_stage)
+    {
+        Some(stage) => (style, stage.glyph.clone(), stage.name.clone()),
+        None => (style, glyph, card.card_status.to_string()),
+    }
+}
+
+/// Style of the first card highlight whose filter preset matches the card
+fn get_card_highlight_style(app: &App, card: &Card) -> Option<Style> {
+    app.config.card_highlights.iter().find_map(|highlight| {
+        let (r, g, b) = highlight.color;
+        app.config
+            .filter_presets
+            .iter()
+            .find(|preset| preset.name == highlight.preset)
+            .filter(|preset| preset.matches(card))
+            .map(|_| {
+                app.theme
+                    .general_style
+                    .fg(ratatui::style::Color::Rgb(r, g, b))
+            })
+    })
+}
+
+/// Shortens the text to the given width in characters, ending it with "..." when it was cut
+/// or when `force_ellipsis` is set
+fn truncate_with_ellipsis(text: &str, width: usize, force_ellipsis: bool) -> String {
+    let char_count = text.chars().count();
+    if char_count <= width && !force_ellipsis {
+        return text.to_string();
+    }
+    if width <= 3 {
+        return ".".repeat(width);
+    }
+    let kept = text.chars().take(width - 3).collect::<String>();
+    format!("{}...", kept.trim_end())
+}
+
+/// Draws size error screen if the terminal is too small
+pub fn draw_size_error<B>(rect: &mut Frame<B>, size: &Rect, msg: String, app: &mut App)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
         .constraints([Constraint::Length(3), Constraint::Min(10)].as_ref())
         .split(*size);
 
@@ -2203,7 +3505,12 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect) -> Paragraph<'a> {
         app.theme.general_style
     };
     // check if focus is on title
-    Paragraph::new(APP_TITLE)
+    let app_title = if app.ascii_only() {
+        ASCII_APP_TITLE
+    } else {
+        APP_TITLE
+    };
+    Paragraph::new(app_title)
         .alignment(Alignment::Center)
         .block(
             Block::default()
@@ -2214,10 +3521,10 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect) -> Paragraph<'a> {
         )
 }
 
-/// Helper function to check terminal size
-pub fn check_size(rect: &Rect) -> String {
+/// Helper function to check terminal size, the compact layout only needs the height
+pub fn check_size(rect: &Rect, compact_layout: bool) -> String {
     let mut msg = String::new();
-    if rect.width < MIN_TERM_WIDTH {
+    if rect.width < MIN_TERM_WIDTH && !compact_layout {
         msg.push_str(&format!(
             "For optimal viewing experience, Terminal width should be >= {}, (current width {})",
             MIN_TERM_WIDTH, rect.width
@@ -2595,8 +3902,13 @@ where
         );
     rect.render_widget(card_description, chunks[2]);
 
-    let parsed_due_date =
-        date_format_converter(&app.state.new_card_form[2], app.config.date_format);
+    let parsed_due_date = parse_localized_date(
+        app.state.new_card_form[2].trim(),
+        app.config.date_locale,
+        app.config.date_format,
+    )
+    .transpose()
+    .unwrap_or_else(|| date_format_converter(&app.state.new_card_form[2], app.config.date_format));
     let card_due_date = Paragraph::new(card_due_date_field)
         .alignment(Alignment::Left)
         .block(
@@ -2809,9 +4121,13 @@ where
         rect.render_widget(no_saves_paragraph, chunks[1]);
     } else {
         // make a list from the Vec<string> of savefiles
+        let save_directory = app.config.get_save_directory();
         let items: Vec<ListItem> = item_list
             .iter()
-            .map(|i| ListItem::new(i.to_string()))
+            .map(|i| match get_save_note(&save_directory, i) {
+                Some(note) => ListItem::new(format!("{} — {}", i, note)),
+                None => ListItem::new(i.to_string()),
+            })
             .collect();
         let choice_list = List::new(items)
             .block(
@@ -3133,7 +4449,7 @@ where
             .constraints([
                 Constraint::Length(3),
                 Constraint::Min(3),
-                Constraint::Min(14),
+                Constraint::Min(16),
                 Constraint::Length(3),
             ])
             .margin(1)
@@ -3144,7 +4460,7 @@ where
             .constraints([
                 Constraint::Length(3),
                 Constraint::Min(5),
-                Constraint::Length(16),
+                Constraint::Length(18),
             ])
             .margin(1)
             .split(popup_area)
@@ -3215,12 +4531,31 @@ where
     let card_description = card.description.clone();
     let wrapped_description =
         textwrap::wrap(&card_description, (card_chunks[1].width - 2) as usize);
+    // card links are highlighted, links that do not point to any card are shown as errors
     let wrapped_description_spans = wrapped_description
         .iter()
-        .map(|x| Line::from(Span::styled(&**x, app.theme.general_style)))
+        .map(|x| {
+            Line::from(
+                CardLink::parse(x)
+                    .into_iter()
+                    .map(|(text, link)| match link {
+                        Some(link) if link.resolve(&app.boards).is_empty() => {
+                            Span::styled(text, app.theme.error_text_style)
+                        }
+                        Some(_) => Span::styled(text, app.theme.help_key_style),
+                        None => Span::styled(text, app.theme.general_style),
+                    })
+                    .collect::<Vec<Span>>(),
+            )
+        })
         .collect::<Vec<Line>>();
+    let main_block_title = if app.config.show_card_ids {
+        format!("{} {} >> Board({})", card.id_label(), card_name, board_name)
+    } else {
+        format!("{} >> Board({})", card_name, board_name)
+    };
     let main_block = Block::default()
-        .title(format!("{} >> Board({})", card_name, board_name))
+        .title(main_block_title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(app.theme.general_style);
@@ -3268,8 +4603,14 @@ where
         format!("Completed: {}", card.date_completed),
         app.theme.general_style,
     );
-    let card_priority = format!("Priority: {}", card.priority);
-    let card_status = format!("Status: {}", card.card_status);
+    let card_priority = format!(
+        "Priority: {} {}",
+        card_priority_glyph(app, &card.priority),
+        card.priority
+    );
+    let (card_status_style, card_status_glyph, card_status_name) =
+        get_card_status_display(app, card);
+    let card_status = format!("Status: {} {}", card_status_glyph, card_status_name);
     let card_due_date = card.date_due.clone();
     let parsed_due_date = date_format_converter(card_due_date.trim(), app.config.date_format);
     let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
@@ -3286,34 +4627,12 @@ where
                 )
             } else {
                 let formatted_date_format = date_format_finder(&parsed_due_date).unwrap();
-                let days_left = match formatted_date_format {
-                    DateFormat::DayMonthYear
-                    | DateFormat::MonthDayYear
-                    | DateFormat::YearMonthDay => {
-                        let today = Local::now().date_naive();
-                        let string_to_naive_date_format = NaiveDate::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days()
-                    }
-                    DateFormat::DayMonthYearTime
-                    | DateFormat::MonthDayYearTime
-                    | DateFormat::YearMonthDayTime {} => {
-                        let today = Local::now().naive_local();
-                        let string_to_naive_date_format = NaiveDateTime::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days()
-                    }
-                };
+                let days_left = days_until_due(
+                    &parsed_due_date,
+                    formatted_date_format,
+                    Local::now().date_naive(),
+                )
+                .unwrap();
                 if days_left <= app.config.warning_delta.into() && days_left >= 0 {
                     Span::styled(
                         format!("Due: {}", card.date_due),
@@ -3356,22 +4675,46 @@ where
     };
     let card_status_styled = if app.state.focus == Focus::CardStatus {
         Span::styled(card_status, app.theme.list_select_style)
-    } else if card.card_status == CardStatus::Complete {
-        Span::styled(card_status, app.theme.card_status_completed_style)
-    } else if card.card_status == CardStatus::Active {
-        Span::styled(card_status, app.theme.card_status_active_style)
-    } else if card.card_status == CardStatus::Stale {
-        Span::styled(card_status, app.theme.card_status_stale_style)
     } else {
-        Span::styled(card_status, app.theme.general_style)
+        Span::styled(card_status, card_status_style)
+    };
+    let card_points = match card.points {
+        Some(points) => format!("Points: {}", points),
+        None => format!("Points: {}", FIELD_NOT_SET),
+    };
+    let card_points_styled = if app.state.focus == Focus::CardPoints {
+        Span::styled(card_points, app.theme.list_select_style)
+    } else {
+        Span::styled(card_points, app.theme.general_style)
+    };
+    let mut card_due_date_spans = vec![card_due_date_styled];
+    if let Some(recurrence) = card.recurrence {
+        card_due_date_spans.push(Span::styled(
+            format!(" (repeats {})", recurrence.to_string().to_lowercase()),
+            app.theme.general_style,
+        ));
+    }
+    let card_time_spent = if card.minutes_spent > 0 {
+        format!(
+            "Time Spent: {}h {}m",
+            card.minutes_spent / 60,
+            card.minutes_spent % 60
+        )
+    } else {
+        format!("Time Spent: {}", FIELD_NOT_SET)
     };
     let card_extra_info_items = vec![
         ListItem::new(vec![Line::from(card_date_created)]),
         ListItem::new(vec![Line::from(card_date_modified)]),
-        ListItem::new(vec![Line::from(card_due_date_styled)]),
+        ListItem::new(vec![Line::from(card_due_date_spans)]),
         ListItem::new(vec![Line::from(card_date_completed)]),
         ListItem::new(vec![Line::from(card_priority_styled)]),
         ListItem::new(vec![Line::from(card_status_styled)]),
+        ListItem::new(vec![Line::from(card_points_styled)]),
+        ListItem::new(vec![Line::from(Span::styled(
+            card_time_spent,
+            app.theme.general_style,
+        ))]),
     ];
     if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, card_chunks[2]) {
         let top_of_list = card_chunks[2].y + 1;
@@ -3403,6 +4746,13 @@ where
                     app.state.card_view_tag_list_state.select(None);
                     app.state.current_cursor_position = None;
                 }
+                6 => {
+                    app.state.focus = Focus::CardPoints;
+                    app.state.mouse_focus = Some(Focus::CardPoints);
+                    app.state.card_view_comment_list_state.select(None);
+                    app.state.card_view_tag_list_state.select(None);
+                    app.state.current_cursor_position = None;
+                }
                 _ => {
                     app.state.focus = Focus::NoFocus;
                     app.state.mouse_focus = None;
@@ -3528,7 +4878,11 @@ where
     let mut collector_end = 0;
     for (i, tag) in card.tags.iter().enumerate() {
         let tag_string = format!("{}) {} ", i + 1, tag);
-        if (collector.len() + tag_string.len()) < card_chunks[2].width as usize {
+        // a tag wider than the view starts its own line instead of leaving an empty one
+        if collector.is_empty()
+            || (collector.chars().count() + tag_string.chars().count())
+                < card_chunks[2].width as usize
+        {
             collector.push_str(&tag_string);
             collector_end = i + 1;
         } else {
@@ -3553,7 +4907,10 @@ where
     let mut collector_end = 0;
     for (i, comment) in card.comments.iter().enumerate() {
         let comment_string = format!("{}) {} ", i + 1, comment);
-        if (collector.len() + comment_string.len()) < card_chunks[2].width as usize {
+        if collector.is_empty()
+            || (collector.chars().count() + comment_string.chars().count())
+                < card_chunks[2].width as usize
+        {
             collector.push_str(&comment_string);
             collector_end = i + 1;
         } else {
@@ -3604,7 +4961,7 @@ where
             .map(|span| span.content.to_string())
             .collect::<String>();
 
-        let available_height = card_chunks[2].height - 8;
+        let available_height = card_chunks[2].height - 10;
         let tags_height = if card_tags.is_empty() {
             0
         } else {
@@ -3632,7 +4989,7 @@ where
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8),
+                Constraint::Length(10),
                 Constraint::Length(tags_height),
                 Constraint::Length(comments_height),
             ])
@@ -4348,7 +5705,384 @@ where
     }
 }
 
-pub fn render_change_card_status_popup<B>(rect: &mut Frame<B>, app: &mut App)
+pub fn render_filter_preset_selector<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let all_presets = app
+        .config
+        .filter_presets
+        .iter()
+        .map(|preset| ListItem::new(vec![Line::from(preset.to_string())]))
+        .collect::<Vec<ListItem>>();
+
+    let percent_height =
+        (((all_presets.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+
+    let popup_area = centered_rect(50, percent_height, rect.size());
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::FilterPresetPopup);
+        app.state.focus = Focus::FilterPresetPopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + all_presets.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .filter_preset_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let presets = List::new(all_presets)
+        .block(
+            Block::default()
+                .title("Apply Filter Preset")
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(presets, popup_area, &mut app.state.filter_preset_list_state);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_recurrence_selector<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let card_name = app
+        .boards
+        .iter()
+        .find(|board| Some(board.id) == app.state.current_board_id)
+        .and_then(|board| board.get_card(app.state.current_card_id.unwrap_or(0)))
+        .map(|card| card.name.clone())
+        .unwrap_or_default();
+    let mut all_options = vec![ListItem::new(vec![Line::from(FIELD_NOT_SET)])];
+    all_options.extend(CardRecurrence::all().iter().map(|recurrence| {
+        ListItem::new(vec![Line::from(format!(
+            "{} (advances on the due date, not on completion)",
+            recurrence
+        ))])
+    }));
+
+    let percent_height =
+        (((all_options.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+
+    let popup_area = centered_rect(50, percent_height, rect.size());
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::CardRecurrencePopup);
+        app.state.focus = Focus::CardRecurrencePopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + all_options.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .card_recurrence_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let options = List::new(all_options)
+        .block(
+            Block::default()
+                .title(format!("Repeat \"{}\"", card_name))
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        options,
+        popup_area,
+        &mut app.state.card_recurrence_list_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_link_selector<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let candidates = app
+        .state
+        .card_link_candidates
+        .iter()
+        .filter_map(|(board_id, card_id)| {
+            let board = app.boards.iter().find(|board| board.id == *board_id)?;
+            let card = board.get_card(*card_id)?;
+            Some(ListItem::new(vec![Line::from(vec![
+                Span::styled(card.name.clone(), app.theme.general_style),
+                Span::styled(
+                    format!(" (in {})", board.name),
+                    app.theme.inactive_text_style,
+                ),
+            ])]))
+        })
+        .collect::<Vec<ListItem>>();
+
+    let percent_height =
+        (((candidates.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+
+    let popup_area = centered_rect(50, percent_height, rect.size());
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::CardLinkPopup);
+        app.state.focus = Focus::CardLinkPopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + candidates.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .card_link_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let candidates = List::new(candidates)
+        .block(
+            Block::default()
+                .title("More than one card matches, select one")
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(candidates, popup_area, &mut app.state.card_link_list_state);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_recent_cards<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let recent_cards = app
+        .state
+        .recent_cards
+        .iter()
+        .filter_map(|(board_id, card_id)| {
+            let board = app.boards.iter().find(|board| board.id == *board_id)?;
+            let card = board.get_card(*card_id)?;
+            let modified_at = card_modified_at(card)
+                .map(|modified_at| {
+                    modified_at
+                        .and_utc()
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_else(|| FIELD_NOT_SET.to_string());
+            Some(ListItem::new(vec![Line::from(vec![
+                Span::styled(card.name.clone(), app.theme.general_style),
+                Span::styled(
+                    format!(" (in {}, modified {})", board.name, modified_at),
+                    app.theme.inactive_text_style,
+                ),
+            ])]))
+        })
+        .collect::<Vec<ListItem>>();
+
+    let percent_height =
+        (((recent_cards.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+
+    let popup_area = centered_rect(60, percent_height, rect.size());
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::RecentCardsPopup);
+        app.state.focus = Focus::RecentCardsPopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + recent_cards.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .recent_cards_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let recent_cards = List::new(recent_cards)
+        .block(
+            Block::default()
+                .title("Recently Modified Cards")
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        recent_cards,
+        popup_area,
+        &mut app.state.recent_cards_list_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_activity_log_popup<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(70, 70, rect.size());
+    // newest changes are shown at the top
+    let mut activity_items = app
+        .action_history_manager
+        .activity_log
+        .iter()
+        .rev()
+        .map(|entry| {
+            ListItem::new(vec![Line::from(vec![
+                Span::styled(
+                    format!("{} ", entry.time.format("%H:%M:%S")),
+                    app.theme.inactive_text_style,
+                ),
+                Span::styled(entry.description.clone(), app.theme.general_style),
+            ])])
+        })
+        .collect::<Vec<ListItem>>();
+    if activity_items.is_empty() {
+        activity_items.push(ListItem::new(vec![Line::from(Span::styled(
+            "No changes made this session",
+            app.theme.inactive_text_style,
+        ))]));
+    }
+    let activity_log = List::new(activity_items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Activity Log ({} changes)",
+                    app.action_history_manager.activity_log.len()
+                ))
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        activity_log,
+        popup_area,
+        &mut app.state.activity_log_list_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_change_card_status_popup<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let mut card_name = String::new();
+    let mut board_name = String::new();
+    let boards = if app.filtered_boards.is_empty() {
+        app.boards.clone()
+    } else {
+        app.filtered_boards.clone()
+    };
+    if let Some(current_board_id) = app.state.current_board_id {
+        if let Some(current_board) = boards.iter().find(|b| b.id == current_board_id) {
+            if let Some(current_card_id) = app.state.current_card_id {
+                if let Some(current_card) =
+                    current_board.cards.iter().find(|c| c.id == current_card_id)
+                {
+                    card_name = current_card.name.clone();
+                    board_name = current_board.name.clone();
+                }
+            }
+        }
+    }
+    let all_statuses = CardStatus::all()
+        .iter()
+        .map(|s| ListItem::new(vec![Line::from(s.to_string())]))
+        .collect::<Vec<ListItem>>();
+    let percent_height =
+        (((all_statuses.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect(50, percent_height, rect.size());
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::ChangeCardStatusPopup);
+        app.state.focus = Focus::ChangeCardStatusPopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + all_statuses.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .card_status_selector_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let popup_title = if app.state.popup_mode == Some(PopupMode::BoardStatusSelector) {
+        let board_name = app
+            .state
+            .current_board_id
+            .and_then(|board_id| boards.iter().find(|b| b.id == board_id))
+            .map(|board| board.name.clone())
+            .unwrap_or_default();
+        format!("Changing Status of all Cards in {}", board_name)
+    } else {
+        format!("Changing Status of \"{}\" in {}", card_name, board_name)
+    };
+    let statuses = List::new(all_statuses)
+        .block(
+            Block::default()
+                .title(popup_title)
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        statuses,
+        popup_area,
+        &mut app.state.card_status_selector_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_priority_selector<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
@@ -4371,33 +6105,33 @@ where
             }
         }
     }
-    let all_statuses = CardStatus::all()
+    let all_priorities = CardPriority::all()
         .iter()
-        .map(|s| ListItem::new(vec![Line::from(s.to_string())]))
+        .map(|p| ListItem::new(vec![Line::from(p.to_string())]))
         .collect::<Vec<ListItem>>();
     let percent_height =
-        (((all_statuses.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+        (((all_priorities.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
     let popup_area = centered_rect(50, percent_height, rect.size());
     if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
-        app.state.mouse_focus = Some(Focus::ChangeCardStatusPopup);
-        app.state.focus = Focus::ChangeCardStatusPopup;
+        app.state.mouse_focus = Some(Focus::ChangeCardPriorityPopup);
+        app.state.focus = Focus::ChangeCardPriorityPopup;
         let top_of_list = popup_area.y + 1;
-        let mut bottom_of_list = popup_area.y + all_statuses.len() as u16;
+        let mut bottom_of_list = popup_area.y + all_priorities.len() as u16;
         if bottom_of_list > popup_area.bottom() {
             bottom_of_list = popup_area.bottom();
         }
         let mouse_y = app.state.current_mouse_coordinates.1;
         if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
             app.state
-                .card_status_selector_state
+                .card_priority_selector_state
                 .select(Some((mouse_y - top_of_list) as usize));
         }
     }
-    let statuses = List::new(all_statuses)
+    let priorities = List::new(all_priorities)
         .block(
             Block::default()
                 .title(format!(
-                    "Changing Status of \"{}\" in {}",
+                    "Changing Priority of \"{}\" in {}",
                     card_name, board_name
                 ))
                 .style(app.theme.general_style)
@@ -4409,9 +6143,9 @@ where
 
     render_blank_styled_canvas(rect, app, popup_area, false);
     rect.render_stateful_widget(
-        statuses,
+        priorities,
         popup_area,
-        &mut app.state.card_status_selector_state,
+        &mut app.state.card_priority_selector_state,
     );
 
     if app.config.enable_mouse_support {
@@ -4419,56 +6153,51 @@ where
     }
 }
 
-pub fn render_card_priority_selector<B>(rect: &mut Frame<B>, app: &mut App)
+pub fn render_card_points_selector<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
     let mut card_name = String::new();
     let mut board_name = String::new();
-    let boards = if app.filtered_boards.is_empty() {
-        app.boards.clone()
-    } else {
-        app.filtered_boards.clone()
-    };
     if let Some(current_board_id) = app.state.current_board_id {
-        if let Some(current_board) = boards.iter().find(|b| b.id == current_board_id) {
+        if let Some(current_board) = app.boards.iter().find(|b| b.id == current_board_id) {
             if let Some(current_card_id) = app.state.current_card_id {
-                if let Some(current_card) =
-                    current_board.cards.iter().find(|c| c.id == current_card_id)
-                {
+                if let Some(current_card) = current_board.get_card(current_card_id) {
                     card_name = current_card.name.clone();
                     board_name = current_board.name.clone();
                 }
             }
         }
     }
-    let all_priorities = CardPriority::all()
-        .iter()
-        .map(|p| ListItem::new(vec![Line::from(p.to_string())]))
-        .collect::<Vec<ListItem>>();
+    let mut all_points = vec![ListItem::new(vec![Line::from(FIELD_NOT_SET)])];
+    all_points.extend(
+        CARD_POINTS_OPTIONS
+            .iter()
+            .map(|p| ListItem::new(vec![Line::from(p.to_string())])),
+    );
     let percent_height =
-        (((all_priorities.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+        (((all_points.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
     let popup_area = centered_rect(50, percent_height, rect.size());
     if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
-        app.state.mouse_focus = Some(Focus::ChangeCardPriorityPopup);
-        app.state.focus = Focus::ChangeCardPriorityPopup;
+        app.state.mouse_focus = Some(Focus::ChangeCardPointsPopup);
+        app.state.focus = Focus::ChangeCardPointsPopup;
         let top_of_list = popup_area.y + 1;
-        let mut bottom_of_list = popup_area.y + all_priorities.len() as u16;
+        let mut bottom_of_list = popup_area.y + all_points.len() as u16;
         if bottom_of_list > popup_area.bottom() {
             bottom_of_list = popup_area.bottom();
         }
         let mouse_y = app.state.current_mouse_coordinates.1;
         if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
             app.state
-                .card_priority_selector_state
+                .card_points_selector_state
                 .select(Some((mouse_y - top_of_list) as usize));
         }
     }
-    let priorities = List::new(all_priorities)
+    let points = List::new(all_points)
         .block(
             Block::default()
                 .title(format!(
-                    "Changing Priority of \"{}\" in {}",
+                    "Changing Points of \"{}\" in {}",
                     card_name, board_name
                 ))
                 .style(app.theme.general_style)
@@ -4480,9 +6209,9 @@ where
 
     render_blank_styled_canvas(rect, app, popup_area, false);
     rect.render_stateful_widget(
-        priorities,
+        points,
         popup_area,
-        &mut app.state.card_priority_selector_state,
+        &mut app.state.card_points_selector_state,
     );
 
     if app.config.enable_mouse_support {
@@ -4544,17 +6273,19 @@ where
         };
 
         // go through all_available tags and map them to list items if the tag is in selected_tags highlight it with app.theme.list_select_style
+        let tag_display_length = app.config.tag_display_length as usize;
         let all_tags = all_available_tags
             .iter()
             .map(|tag| {
+                let tag_name = truncate_with_ellipsis(&tag.0, tag_display_length, false);
                 if selected_tags.contains(&tag.0) {
                     ListItem::new(vec![Line::from(vec![Span::styled(
-                        format!("(Selected) {} - {} occurrences", tag.0, tag.1),
+                        format!("(Selected) {} - {} occurrences", tag_name, tag.1),
                         app.theme.list_select_style,
                     )])])
                 } else {
                     ListItem::new(vec![Line::from(vec![Span::styled(
-                        format!("{} - {} occurrences", tag.0, tag.1),
+                        format!("{} - {} occurrences", tag_name, tag.1),
                         app.theme.general_style,
                     )])])
                 }
@@ -4683,11 +6414,11 @@ where
             let percentage = ((current_index + 1) as f32 / total_rows as f32) * 100.0;
             let blocks_to_render = (percentage / 100.0 * visible_rows as f32) as usize;
 
-            // render blocks VERTICAL_SCROLL_BAR_SYMBOL
+            // render blocks of the scroll bar symbol
             for i in 0..blocks_to_render {
                 let block_x = filter_list_chunks[1].right() - 1;
                 let block_y = filter_list_chunks[1].top() + i as u16;
-                let block = Paragraph::new(VERTICAL_SCROLL_BAR_SYMBOL)
+                let block = Paragraph::new(scroll_bar_symbol(app))
                     .style(app.theme.progress_bar_style)
                     .block(Block::default().borders(Borders::NONE));
                 rect.render_widget(block, Rect::new(block_x, block_y, 1, 1));
@@ -5417,13 +7148,364 @@ where
     }
 }
 
+pub fn render_confirm_load_save<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 50, rect.size());
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    let chunks = confirmation_popup_chunks(app, popup_area);
+    let button_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[chunks.len() - 1]);
+
+    let mut summary_lines = vec![Line::from(Span::styled(
+        "Loading this save will replace your current boards:",
+        app.theme.general_style,
+    ))];
+    if let Some(diff) = &app.state.load_save_diff {
+        let sections = [
+            ("Boards added", &diff.boards_added),
+            ("Boards removed", &diff.boards_removed),
+            ("Boards changed", &diff.boards_changed),
+            ("Cards added", &diff.cards_added),
+            ("Cards removed", &diff.cards_removed),
+            ("Cards changed", &diff.cards_changed),
+        ];
+        for (label, names) in sections.iter() {
+            if names.is_empty() {
+                continue;
+            }
+            summary_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ({}): ", label, names.len()),
+                    app.theme.keyboard_focus_style,
+                ),
+                Span::styled(names.join(", "), app.theme.general_style),
+            ]));
+        }
+    }
+    let summary = Paragraph::new(summary_lines)
+        .block(Block::default())
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let load_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[0]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.state.focus = Focus::SubmitButton;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::SubmitButton {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let cancel_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[1]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            app.state.focus = Focus::ExtraFocus;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::ExtraFocus {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    // the button stays disabled until the confirmation text is typed
+    let load_button_style = if app.confirmation_typed() {
+        load_button_style
+    } else {
+        app.theme.inactive_text_style
+    };
+    let load_button = Paragraph::new("Load")
+        .style(load_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(load_button_style),
+        )
+        .alignment(Alignment::Center);
+    let cancel_button = Paragraph::new("Cancel")
+        .style(cancel_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(cancel_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Load Save?")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+    rect.render_widget(summary, chunks[0]);
+    render_confirmation_input(rect, app, &chunks);
+    rect.render_widget(load_button, button_chunks[0]);
+    rect.render_widget(cancel_button, button_chunks[1]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_confirm_prune_archived_cards<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 50, rect.size());
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    let chunks = confirmation_popup_chunks(app, popup_area);
+    let button_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[chunks.len() - 1]);
+
+    let mut summary_lines = vec![Line::from(Span::styled(
+        format!(
+            "These archived cards are older than {} days and will be permanently deleted:",
+            app.config.archive_retention_days
+        ),
+        app.theme.general_style,
+    ))];
+    for (board_name, card_name) in &app.state.archived_cards_to_prune {
+        summary_lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", board_name), app.theme.keyboard_focus_style),
+            Span::styled(card_name.clone(), app.theme.general_style),
+        ]));
+    }
+    let summary = Paragraph::new(summary_lines)
+        .block(Block::default())
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let delete_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[0]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.state.focus = Focus::SubmitButton;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::SubmitButton {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let keep_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[1]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            app.state.focus = Focus::ExtraFocus;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::ExtraFocus {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    // the button stays disabled until the confirmation text is typed
+    let delete_button_style = if app.confirmation_typed() {
+        delete_button_style
+    } else {
+        app.theme.inactive_text_style
+    };
+    let delete_button = Paragraph::new("Delete")
+        .style(delete_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(delete_button_style),
+        )
+        .alignment(Alignment::Center);
+    let keep_button = Paragraph::new("Keep")
+        .style(keep_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(keep_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Delete Old Archived Cards?")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+    rect.render_widget(summary, chunks[0]);
+    render_confirmation_input(rect, app, &chunks);
+    rect.render_widget(delete_button, button_chunks[0]);
+    rect.render_widget(keep_button, button_chunks[1]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_confirm_delete_board<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 50, rect.size());
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    let chunks = confirmation_popup_chunks(app, popup_area);
+    let button_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[chunks.len() - 1]);
+
+    let board = app
+        .state
+        .board_to_delete
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id));
+    let (board_name, card_count) = board
+        .map(|board| (board.name.clone(), board.cards.len()))
+        .unwrap_or_default();
+    let undo_key = app
+        .config
+        .keybindings
+        .undo
+        .first()
+        .map_or(String::new(), |key| format!(" with {}", key));
+    let summary = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("Board ", app.theme.general_style),
+            Span::styled(board_name, app.theme.keyboard_focus_style),
+            Span::styled(
+                format!(" and its {} cards will be deleted.", card_count),
+                app.theme.general_style,
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("This can be undone{}.", undo_key),
+            app.theme.inactive_text_style,
+        )),
+    ])
+    .block(Block::default())
+    .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let delete_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[0]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.state.focus = Focus::SubmitButton;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::SubmitButton {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let keep_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[1]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            app.state.focus = Focus::ExtraFocus;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::ExtraFocus {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    // the button stays disabled until the confirmation text is typed
+    let delete_button_style = if app.confirmation_typed() {
+        delete_button_style
+    } else {
+        app.theme.inactive_text_style
+    };
+    let delete_button = Paragraph::new("Delete")
+        .style(delete_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(delete_button_style),
+        )
+        .alignment(Alignment::Center);
+    let keep_button = Paragraph::new("Keep")
+        .style(keep_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(keep_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Delete Board?")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+    rect.render_widget(summary, chunks[0]);
+    render_confirmation_input(rect, app, &chunks);
+    rect.render_widget(delete_button, button_chunks[0]);
+    rect.render_widget(keep_button, button_chunks[1]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+/// Summary, confirmation input (only when text has to be typed) and button rows of a
+/// confirmation popup
+fn confirmation_popup_chunks(app: &App, popup_area: Rect) -> Vec<Rect> {
+    let constraints = if app.state.confirmation_text.is_some() {
+        vec![
+            Constraint::Min(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ]
+    } else {
+        vec![Constraint::Min(3), Constraint::Length(3)]
+    };
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .margin(2)
+        .split(popup_area)
+        .to_vec()
+}
+
+/// Field for the text a confirmation popup asks for, drawn in the middle row from
+/// `confirmation_popup_chunks`
+fn render_confirmation_input<B>(rect: &mut Frame<B>, app: &App, chunks: &[Rect])
+where
+    B: Backend,
+{
+    let confirmation_text = if let Some(confirmation_text) = &app.state.confirmation_text {
+        confirmation_text
+    } else {
+        return;
+    };
+    let input_area = chunks[1];
+    let input_style = if app.state.app_status == AppStatus::UserInput {
+        app.theme.keyboard_focus_style
+    } else {
+        app.theme.general_style
+    };
+    let input = Paragraph::new(app.state.current_user_input.clone()).block(
+        Block::default()
+            .title(format!("Type '{}' to confirm", confirmation_text))
+            .style(app.theme.general_style)
+            .borders(Borders::ALL)
+            .border_style(input_style)
+            .border_type(BorderType::Rounded),
+    );
+    rect.render_widget(input, input_area);
+    if app.state.app_status == AppStatus::UserInput {
+        let cursor_position = app
+            .state
+            .current_cursor_position
+            .unwrap_or(app.state.current_user_input.len())
+            .min(app.state.current_user_input.len());
+        let typed_width = app
+            .state
+            .current_user_input
+            .get(..cursor_position)
+            .map_or(0, |typed| typed.chars().count()) as u16;
+        rect.set_cursor(
+            (input_area.x + 1 + typed_width).min(input_area.right().saturating_sub(2)),
+            input_area.y + 1,
+        );
+    }
+}
+
 pub fn render_custom_rgb_color_prompt<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
     // make a small popup with a text input field and a submit button
     let popup_area = centered_rect(50, 60, rect.size());
-    let prompt_text = "Enter a custom RGB color in the format: r,g,b (0-254)";
+    let prompt_text = "Enter a custom color as #RRGGBB or r,g,b (0-255)";
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -5431,6 +7513,7 @@ where
             [
                 Constraint::Length(1),
                 Constraint::Min(5),
+                Constraint::Length(1),
                 Constraint::Length(3),
                 Constraint::Length(5),
             ]
@@ -5455,7 +7538,7 @@ where
             app.theme.general_style
         };
     let submit_button_style =
-        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[3]) {
             app.state.mouse_focus = Some(Focus::SubmitButton);
             app.state.focus = Focus::SubmitButton;
             app.state.app_status = AppStatus::Initialized;
@@ -5465,6 +7548,24 @@ where
         } else {
             app.theme.general_style
         };
+    // preview the color while it is typed, or show why it can not be used
+    let preview_line = if app.state.current_user_input.trim().is_empty() {
+        Line::from(Span::styled(
+            "Type a color to preview it",
+            app.theme.inactive_text_style,
+        ))
+    } else {
+        match parse_custom_color(&app.state.current_user_input) {
+            Ok(custom_color) => Line::from(vec![
+                Span::styled("Preview: ", app.theme.general_style),
+                Span::styled("      ", Style::default().bg(custom_color)),
+                Span::styled(" ", app.theme.general_style),
+                Span::styled("Sample Text", app.theme.general_style.fg(custom_color)),
+            ]),
+            Err(e) => Line::from(Span::styled(e, app.theme.error_text_style)),
+        }
+    };
+    let preview = Paragraph::new(preview_line).alignment(Alignment::Center);
     let prompt_text = Paragraph::new(prompt_text)
         .style(app.theme.general_style)
         .block(Block::default())
@@ -5530,8 +7631,9 @@ where
     render_blank_styled_canvas(rect, app, popup_area, true);
     rect.render_widget(prompt_text, chunks[0]);
     rect.render_widget(text_input, chunks[1]);
-    rect.render_widget(submit_button, chunks[2]);
-    rect.render_widget(help_text, chunks[3]);
+    rect.render_widget(preview, chunks[2]);
+    rect.render_widget(submit_button, chunks[3]);
+    rect.render_widget(help_text, chunks[4]);
     rect.render_widget(border_block, popup_area);
 
     if app.state.app_status == AppStatus::UserInput {
@@ -2,16 +2,83 @@ use super::{
     super::app::state::{AppStatus, UiMode},
     ui_helper,
 };
-use crate::app::{App, PopupMode};
-use ratatui::{backend::Backend, Frame};
+use crate::{
+    app::{App, PopupMode},
+    constants::MIN_COMPACT_TERM_WIDTH,
+};
+use ratatui::{
+    backend::{Backend, TestBackend},
+    buffer::Buffer,
+    layout::Rect,
+    style::Color,
+    widgets::Widget,
+    Frame, Terminal,
+};
+use std::io;
+use textwrap::core::display_width;
 
 /// Main UI Drawing handler
 pub fn draw<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    draw_frame(rect, app);
+    if app.ascii_only() {
+        rect.render_widget(AsciiOnly, rect.size());
+    }
+}
+
+/// Draws the app into an in memory terminal of the given size instead of a real one, so layouts
+/// can be checked in snapshot tests
+pub fn render_to_buffer(app: &mut App, width: u16, height: u16) -> io::Result<Buffer> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+    terminal.draw(|rect| draw(rect, app))?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+/// Text content of a buffer, one line per row with trailing spaces removed. The cells covered
+/// by the right half of a wide character are skipped so every line reads like the terminal shows it
+pub fn buffer_to_string(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    if width == 0 {
+        return String::new();
+    }
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let mut line = String::new();
+            let mut cells_to_skip = 0;
+            for cell in row {
+                if cells_to_skip > 0 {
+                    cells_to_skip -= 1;
+                    continue;
+                }
+                line.push_str(&cell.symbol);
+                cells_to_skip = display_width(&cell.symbol).saturating_sub(1);
+            }
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Draws the app at a fixed size and returns what the terminal would show as text, see
+/// `render_to_buffer` and `buffer_to_string`
+pub fn render_to_string(app: &mut App, width: u16, height: u16) -> io::Result<String> {
+    render_to_buffer(app, width, height).map(|buffer| buffer_to_string(&buffer))
+}
+
+fn draw_frame<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
     ui_helper::render_blank_styled_canvas(rect, app, rect.size(), app.state.popup_mode.is_some());
-    let msg = ui_helper::check_size(&rect.size());
+    let size = rect.size();
+    app.state.compact_layout = app.config.compact_layout_width > 0
+        && size.width < app.config.compact_layout_width
+        && size.width >= MIN_COMPACT_TERM_WIDTH;
+    let msg = ui_helper::check_size(&size, app.state.compact_layout);
     if &msg != "Size OK" {
         ui_helper::draw_size_error(rect, &rect.size(), msg, app);
         return;
@@ -21,6 +88,11 @@ where
     }
 
     match &app.state.ui_mode {
+        // focus mode and the compact layout only show the current board so the rest of the
+        // layout is skipped
+        ui_mode if app.single_board_view() && UiMode::view_modes().contains(ui_mode) => {
+            ui_helper::render_zen_mode(rect, app);
+        }
         UiMode::Zen => {
             ui_helper::render_zen_mode(rect, app);
         }
@@ -76,7 +148,7 @@ where
             PopupMode::ViewCard => {
                 ui_helper::render_view_card(rect, app);
             }
-            PopupMode::CardStatusSelector => {
+            PopupMode::CardStatusSelector | PopupMode::BoardStatusSelector => {
                 ui_helper::render_change_card_status_popup(rect, app);
             }
             PopupMode::ChangeUIMode => {
@@ -88,6 +160,42 @@ where
             PopupMode::EditGeneralConfig => {
                 ui_helper::render_edit_config(rect, app);
             }
+            PopupMode::EditBoardDescription => {
+                ui_helper::render_edit_board_description(rect, app);
+            }
+            PopupMode::SaveNote => {
+                ui_helper::render_save_note_prompt(rect, app);
+            }
+            PopupMode::ImportMarkdown => {
+                ui_helper::render_import_markdown_prompt(rect, app);
+            }
+            PopupMode::ImportJson => {
+                ui_helper::render_import_json_prompt(rect, app);
+            }
+            PopupMode::ImportTrello => {
+                ui_helper::render_import_trello_prompt(rect, app);
+            }
+            PopupMode::EditBoardCardLimit => {
+                ui_helper::render_edit_board_card_limit_prompt(rect, app);
+            }
+            PopupMode::EditBoardDueDate => {
+                ui_helper::render_edit_board_due_date_prompt(rect, app);
+            }
+            PopupMode::NewCardOnAllBoards => {
+                ui_helper::render_new_card_on_all_boards_prompt(rect, app);
+            }
+            PopupMode::QuickHelp => {
+                ui_helper::render_quick_help(rect, app);
+            }
+            PopupMode::ExpandedCard => {
+                ui_helper::render_expanded_card(rect, app);
+            }
+            PopupMode::EditCardProgress => {
+                ui_helper::render_edit_card_progress_prompt(rect, app);
+            }
+            PopupMode::EditBoardAccent => {
+                ui_helper::render_edit_board_accent_prompt(rect, app);
+            }
             PopupMode::EditSpecificKeyBinding => {
                 ui_helper::render_edit_specific_keybinding(rect, app);
             }
@@ -112,12 +220,39 @@ where
             PopupMode::CardPrioritySelector => {
                 ui_helper::render_card_priority_selector(rect, app);
             }
+            PopupMode::CardPointsSelector => {
+                ui_helper::render_card_points_selector(rect, app);
+            }
             PopupMode::FilterByTag => {
                 ui_helper::render_filter_by_tag_popup(rect, app);
             }
+            PopupMode::FilterPresetSelector => {
+                ui_helper::render_filter_preset_selector(rect, app);
+            }
+            PopupMode::CardRecurrenceSelector => {
+                ui_helper::render_card_recurrence_selector(rect, app);
+            }
+            PopupMode::ActivityLog => {
+                ui_helper::render_activity_log_popup(rect, app);
+            }
+            PopupMode::RecentCards => {
+                ui_helper::render_recent_cards(rect, app);
+            }
+            PopupMode::CardLinkSelector => {
+                ui_helper::render_card_link_selector(rect, app);
+            }
             PopupMode::ChangeDateFormatPopup => {
                 ui_helper::render_change_date_format_popup(rect, app);
             }
+            PopupMode::ConfirmLoadSave => {
+                ui_helper::render_confirm_load_save(rect, app);
+            }
+            PopupMode::ConfirmPruneArchivedCards => {
+                ui_helper::render_confirm_prune_archived_cards(rect, app);
+            }
+            PopupMode::ConfirmDeleteBoard => {
+                ui_helper::render_confirm_delete_board(rect, app);
+            }
         }
     }
 
@@ -127,3 +262,56 @@ where
         ui_helper::render_debug_panel(rect, app);
     }
 }
+
+/// Rewrites everything drawn in the frame with ASCII characters only and without colors, borders
+/// and block glyphs from ratatui cannot be changed at the source so they are swapped here
+struct AsciiOnly;
+
+impl Widget for AsciiOnly {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = buf.get_mut(x, y);
+                if !cell.symbol.is_ascii() {
+                    let symbol = ascii_symbol(&cell.symbol);
+                    cell.set_symbol(symbol);
+                }
+                // modifiers like bold are kept, the selected list item is still marked by its
+                // symbol without the highlight color
+                cell.fg = Color::Reset;
+                cell.bg = Color::Reset;
+            }
+        }
+    }
+}
+
+/// ASCII stand in for a symbol drawn in a cell, symbols without a close match become '?'
+fn ascii_symbol(symbol: &str) -> &'static str {
+    match symbol.chars().next() {
+        Some('─' | '━' | '═' | '╌' | '╍' | '┄' | '┅' | '—' | '–') => "-",
+        Some('│' | '┃' | '║' | '╎' | '╏' | '┆' | '┇') => "|",
+        Some('\u{2500}'..='\u{257F}') => "+",
+        Some('\u{2580}'..='\u{259F}') => "#",
+        Some('●' | '•' | '○') => "o",
+        Some('✓' | '✔') => "x",
+        Some('▲') => "^",
+        Some('▼') => "v",
+        Some('◆') => "*",
+        Some('′') => "'",
+        _ => "?",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+
+    #[test]
+    fn should_turn_a_buffer_into_lines_of_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        buffer.set_string(0, 0, "Todo", Style::default());
+        buffer.set_string(1, 1, "任务 ok", Style::default());
+        assert_eq!(buffer_to_string(&buffer), "Todo\n 任务 ok\n");
+    }
+}
@@ -1,6 +1,7 @@
 use log::{debug, error, info};
 use ngrammatic::{Corpus, CorpusBuilder, Pad};
 use std::{
+    cmp::Reverse,
     collections::HashMap,
     fmt::{self, Display},
     sync::Arc,
@@ -10,16 +11,34 @@ use tokio::{sync::MutexGuard, time::Instant};
 
 use crate::{
     app::{
+        app_helper::{
+            clear_filters, get_boards_with_selected_cards, handle_archive_card, handle_copy_card,
+            handle_export_and_clear_all_boards, handle_follow_card_link, handle_import_config,
+            handle_paste_card, handle_save_board_order, handle_sort_boards_by_done_ratio,
+            handle_sort_cards_by_due_date, open_edit_board_accent_prompt,
+            open_edit_board_card_limit_prompt, open_edit_board_due_date_prompt,
+            open_edit_card_progress_prompt, open_import_json_prompt, open_import_markdown_prompt,
+            open_import_trello_prompt, open_new_card_on_all_boards_prompt, open_recent_cards,
+            open_save_note_prompt,
+        },
         handle_exit,
         state::{AppStatus, Focus, UiMode},
         App, AppReturn, PopupMode,
     },
-    constants::{RANDOM_SEARCH_TERM, TOAST_FADE_IN_TIME, TOAST_FADE_OUT_TIME},
-    io::{data_handler::export_kanban_to_json, handler::refresh_visible_boards_and_cards, IoEvent},
+    constants::{FIELD_NOT_SET, RANDOM_SEARCH_TERM, TOAST_FADE_IN_TIME, TOAST_FADE_OUT_TIME},
+    io::{
+        data_handler::{
+            export_board_statistics_to_json, export_config, export_kanban_to_csv,
+            export_kanban_to_json, export_kanban_to_markdown, export_kanban_to_org,
+            export_keybinding_cheatsheet, export_theme_to_terminal_color_scheme, write_config,
+        },
+        handler::refresh_visible_boards_and_cards,
+        IoEvent,
+    },
     lerp_between,
 };
 
-use super::{TextColorOptions, Theme};
+use super::{TerminalColorSchemeFormat, TextColorOptions, Theme};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ToastWidget {
@@ -224,7 +243,11 @@ impl CommandPaletteWidget {
                 None
             };
             if command.is_some() {
-                match command.unwrap() {
+                let command = command.unwrap().clone();
+                if command != CommandPaletteActions::NoCommandsFound {
+                    Self::record_command_usage(app, &command);
+                }
+                match command {
                     CommandPaletteActions::ExportToJSON => {
                         let export_result = export_kanban_to_json(&app.boards);
                         if export_result.is_ok() {
@@ -239,6 +262,190 @@ impl CommandPaletteWidget {
                         }
                         app.state.popup_mode = None;
                     }
+                    CommandPaletteActions::ExportAndClearAllBoards => {
+                        app.state.popup_mode = None;
+                        handle_export_and_clear_all_boards(app);
+                    }
+                    CommandPaletteActions::ExportStatisticsToJSON => {
+                        let export_result = export_board_statistics_to_json(&app.boards);
+                        match export_result {
+                            Ok(file_path) => {
+                                let msg = format!("Exported statistics to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export statistics: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportKeybindingCheatsheet => {
+                        match export_keybinding_cheatsheet(&app.config) {
+                            Ok(file_path) => {
+                                let msg =
+                                    format!("Exported keybinding cheatsheet to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export keybinding cheatsheet: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportToOrg => {
+                        let export_result = export_kanban_to_org(&app.boards);
+                        if export_result.is_ok() {
+                            let msg = format!("Exported Org to {}", export_result.unwrap());
+                            app.send_info_toast(&msg, None);
+                            info!("{}", msg);
+                        } else {
+                            let msg =
+                                format!("Failed to export Org: {}", export_result.unwrap_err());
+                            app.send_error_toast(&msg, None);
+                            error!("{}", msg);
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportToMarkdown => {
+                        match export_kanban_to_markdown(&app.boards) {
+                            Ok(file_path) => {
+                                let msg = format!("Exported Markdown to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export Markdown: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportToCSV => {
+                        match export_kanban_to_csv(&app.boards) {
+                            Ok(file_path) => {
+                                let msg = format!("Exported CSV to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export CSV: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportSelectionToJSON
+                    | CommandPaletteActions::ExportSelectionToOrg => {
+                        app.state.popup_mode = None;
+                        let selected_boards = get_boards_with_selected_cards(app);
+                        if selected_boards.is_empty() {
+                            app.send_warning_toast(
+                                "No cards selected to export, select cards first",
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let (export_result, format_name) =
+                            if command == CommandPaletteActions::ExportSelectionToJSON {
+                                (export_kanban_to_json(&selected_boards), "JSON")
+                            } else {
+                                (export_kanban_to_org(&selected_boards), "Org")
+                            };
+                        match export_result {
+                            Ok(file_path) => {
+                                let msg = format!(
+                                    "Exported {} selected cards as {} to {}",
+                                    selected_boards
+                                        .iter()
+                                        .map(|board| board.cards.len())
+                                        .sum::<usize>(),
+                                    format_name,
+                                    file_path
+                                );
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg =
+                                    format!("Failed to export selection as {}: {}", format_name, e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                    }
+                    CommandPaletteActions::ExportThemeToWindowsTerminal
+                    | CommandPaletteActions::ExportThemeToXresources => {
+                        app.state.popup_mode = None;
+                        let format =
+                            if command == CommandPaletteActions::ExportThemeToWindowsTerminal {
+                                TerminalColorSchemeFormat::WindowsTerminal
+                            } else {
+                                TerminalColorSchemeFormat::Xresources
+                            };
+                        match export_theme_to_terminal_color_scheme(&app.theme, format, &app.config)
+                        {
+                            Ok(file_path) => {
+                                let msg = format!(
+                                    "Exported theme '{}' as {} color scheme to {}",
+                                    app.theme.name, format, file_path
+                                );
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!(
+                                    "Failed to export theme as {} color scheme: {}",
+                                    format, e
+                                );
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                    }
+                    CommandPaletteActions::ClearSelection => {
+                        app.state.popup_mode = None;
+                        if app.state.selected_card_ids.is_empty() {
+                            app.send_warning_toast("No cards selected", None);
+                        } else {
+                            app.state.selected_card_ids.clear();
+                            app.send_info_toast("Selection cleared", None);
+                        }
+                    }
+                    CommandPaletteActions::ExportConfig => {
+                        let export_result = export_config(&app.config);
+                        if export_result.is_ok() {
+                            let msg = format!("Exported config to {}", export_result.unwrap());
+                            app.send_info_toast(&msg, None);
+                            info!("{}", msg);
+                        } else {
+                            let msg =
+                                format!("Failed to export config: {}", export_result.unwrap_err());
+                            app.send_error_toast(&msg, None);
+                            error!("{}", msg);
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ImportConfig => {
+                        app.state.popup_mode = None;
+                        handle_import_config(app);
+                    }
+                    CommandPaletteActions::ImportMarkdown => {
+                        open_import_markdown_prompt(app);
+                    }
+                    CommandPaletteActions::ImportJSON => {
+                        open_import_json_prompt(app);
+                    }
+                    CommandPaletteActions::ImportTrello => {
+                        open_import_trello_prompt(app);
+                    }
                     CommandPaletteActions::Quit => {
                         handle_exit(app).await;
                         info!("Quitting");
@@ -267,6 +474,10 @@ impl CommandPaletteWidget {
                     }
                     CommandPaletteActions::SaveKanbanState => {
                         app.state.popup_mode = None;
+                        if app.config.prompt_for_save_note {
+                            open_save_note_prompt(app);
+                            return AppReturn::Continue;
+                        }
                         app.dispatch(IoEvent::SaveLocalData).await;
                     }
                     CommandPaletteActions::NewBoard => {
@@ -332,6 +543,140 @@ impl CommandPaletteWidget {
                             app.send_error_toast("Cannot change card status in this view", None);
                         }
                     }
+                    CommandPaletteActions::ChangeAllCardsOnBoardStatus => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            if app.state.current_board_id.is_some() {
+                                app.state.popup_mode = Some(PopupMode::BoardStatusSelector);
+                                app.state.app_status = AppStatus::Initialized;
+                                app.state.card_status_selector_state.select(Some(0));
+                                return AppReturn::Continue;
+                            }
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot change card status in this view", None);
+                        }
+                    }
+                    CommandPaletteActions::SortBoardsByDoneRatio => {
+                        app.state.popup_mode = None;
+                        handle_sort_boards_by_done_ratio(app);
+                    }
+                    CommandPaletteActions::SaveBoardOrder => {
+                        app.state.popup_mode = None;
+                        handle_save_board_order(app);
+                    }
+                    CommandPaletteActions::ArchiveCard => {
+                        app.state.popup_mode = None;
+                        handle_archive_card(app);
+                    }
+                    CommandPaletteActions::RecentCards => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot go to a card in this view", None);
+                        } else {
+                            open_recent_cards(app);
+                        }
+                    }
+                    CommandPaletteActions::SortCardsByDueDate => {
+                        app.state.popup_mode = None;
+                        handle_sort_cards_by_due_date(app);
+                    }
+                    CommandPaletteActions::CopyCard => {
+                        app.state.popup_mode = None;
+                        handle_copy_card(app);
+                    }
+                    CommandPaletteActions::PasteCard => {
+                        app.state.popup_mode = None;
+                        handle_paste_card(app);
+                    }
+                    CommandPaletteActions::EditBoardDescription => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            let board = app.state.current_board_id.and_then(|board_id| {
+                                app.boards.iter().find(|board| board.id == board_id)
+                            });
+                            if let Some(board) = board {
+                                let description = if board.description == FIELD_NOT_SET {
+                                    String::new()
+                                } else {
+                                    board.description.clone()
+                                };
+                                app.state.current_cursor_position = Some(description.len());
+                                app.state.current_user_input = description;
+                                app.state.popup_mode = Some(PopupMode::EditBoardDescription);
+                                app.state.app_status = AppStatus::UserInput;
+                                return AppReturn::Continue;
+                            }
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            app.state.popup_mode = None;
+                            app.send_error_toast(
+                                "Cannot edit board description in this view",
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::EditBoardCardLimit => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot set board card limit in this view", None);
+                        } else if app.state.current_board_id.is_none() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            open_edit_board_card_limit_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::EditBoardDueDate => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot set board due date in this view", None);
+                        } else if app.state.current_board_id.is_none() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            open_edit_board_due_date_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::EditBoardAccent => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot set board accent in this view", None);
+                        } else if app.state.current_board_id.is_none() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            open_edit_board_accent_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::EditCardProgress => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot set card progress in this view", None);
+                        } else if app.state.current_card_id.is_none() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No card Selected / Available", None);
+                        } else {
+                            open_edit_card_progress_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::NewCardOnAllBoards => {
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot create a card in this view", None);
+                        } else if app.boards.is_empty() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("No boards to add the card to", None);
+                        } else {
+                            open_new_card_on_all_boards_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                    }
                     CommandPaletteActions::LoadASave => {
                         app.state.popup_mode = None;
                         app.state.prev_ui_mode = Some(app.state.ui_mode);
@@ -365,19 +710,65 @@ impl CommandPaletteWidget {
                         } else {
                             app.send_info_toast("All Filters Cleared", None);
                         }
-                        app.state.filter_tags = None;
-                        app.state.all_available_tags = None;
-                        app.state.filter_by_tag_list_state.select(None);
+                        clear_filters(app);
+                    }
+                    CommandPaletteActions::ApplyFilterPreset => {
+                        if app.config.filter_presets.is_empty() {
+                            app.send_warning_toast(
+                                "No filter presets found, add some in the config menu",
+                                None,
+                            );
+                            app.state.popup_mode = None;
+                        } else {
+                            app.state.popup_mode = Some(PopupMode::FilterPresetSelector);
+                        }
+                    }
+                    CommandPaletteActions::FollowCardLink => {
                         app.state.popup_mode = None;
-                        app.filtered_boards = vec![];
-                        refresh_visible_boards_and_cards(app);
+                        handle_follow_card_link(app);
+                    }
+                    CommandPaletteActions::ViewActivityLog => {
+                        app.state.activity_log_list_state.select(None);
+                        app.state.popup_mode = Some(PopupMode::ActivityLog);
+                    }
+                    CommandPaletteActions::ClearActivityLog => {
+                        app.state.popup_mode = None;
+                        if app.action_history_manager.activity_log.is_empty() {
+                            app.send_warning_toast("Activity log is already empty", None);
+                        } else {
+                            app.action_history_manager.activity_log.clear();
+                            app.state.activity_log_list_state.select(None);
+                            app.send_info_toast("Activity log cleared", None);
+                        }
                     }
                     CommandPaletteActions::ChangeDateFormat => {
                         app.state.popup_mode = Some(PopupMode::ChangeDateFormatPopup);
                     }
+                    CommandPaletteActions::ToggleStatusColumnView => {
+                        app.state.popup_mode = None;
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.state.status_column_view = !app.state.status_column_view;
+                            if app.state.status_column_view {
+                                app.state.focus = Focus::Body;
+                                app.send_info_toast("Showing cards by status", None);
+                            } else {
+                                refresh_visible_boards_and_cards(app);
+                                app.send_info_toast("Showing cards by board", None);
+                            }
+                        } else {
+                            app.send_error_toast(
+                                "Cannot toggle status column view in this view",
+                                None,
+                            );
+                        }
+                    }
                     CommandPaletteActions::NoCommandsFound => {
                         return AppReturn::Continue;
                     }
+                    CommandPaletteActions::Custom(name) => {
+                        app.state.popup_mode = None;
+                        Self::queue_custom_command(app, &name);
+                    }
                 }
                 app.state.current_user_input = "".to_string();
             } else {
@@ -392,6 +783,84 @@ impl CommandPaletteWidget {
         AppReturn::Continue
     }
 
+    /// Increments the usage count of a command and persists it so that frequently used
+    /// commands can be shown first in the command palette
+    fn record_command_usage(app: &mut App, command: &CommandPaletteActions) {
+        *app.config
+            .command_usage
+            .entry(command.to_string())
+            .or_insert(0) += 1;
+        let write_config_status = write_config(&app.config);
+        if let Err(error_message) = write_config_status {
+            error!("Error saving command usage: {}", error_message);
+        }
+        // force the search results to be recalculated so the new order is picked up
+        app.command_palette.last_search_string = RANDOM_SEARCH_TERM.to_string();
+    }
+
+    /// The built in commands followed by the custom commands of the config, custom commands named
+    /// like a built in command are left out
+    fn available_commands(app: &App) -> Vec<CommandPaletteActions> {
+        let mut commands = CommandPaletteActions::all();
+        commands.extend(Self::custom_commands(app));
+        commands
+    }
+
+    fn custom_commands(app: &App) -> Vec<CommandPaletteActions> {
+        app.config
+            .custom_commands
+            .iter()
+            .filter(|command| CommandPaletteActions::from_string(&command.name, true).is_none())
+            .map(|command| CommandPaletteActions::Custom(command.name.clone()))
+            .collect()
+    }
+
+    /// Queues the keys of a custom command, they are run once the command palette has closed
+    fn queue_custom_command(app: &mut App, name: &str) {
+        let command = match app
+            .config
+            .custom_commands
+            .iter()
+            .find(|command| command.name == name)
+        {
+            Some(command) => command.clone(),
+            None => {
+                error!("Could not find custom command {}", name);
+                app.send_error_toast(&format!("Could not find custom command {}", name), None);
+                return;
+            }
+        };
+        match command.keys(&app.config.keybindings) {
+            Ok(keys) => {
+                info!("Running custom command {}", command.name);
+                app.state.queued_keys = keys;
+            }
+            Err(e) => {
+                error!("Can not run custom command {}: {}", command.name, e);
+                app.send_error_toast(
+                    &format!("Can not run custom command {}: {}", command.name, e),
+                    None,
+                );
+            }
+        }
+    }
+
+    /// All commands ordered by how often they have been used, commands with the same usage
+    /// count keep their default order
+    fn commands_by_usage(app: &App) -> Vec<CommandPaletteActions> {
+        let mut commands = Self::available_commands(app);
+        commands.sort_by_key(|command| {
+            Reverse(
+                app.config
+                    .command_usage
+                    .get(&command.to_string())
+                    .copied()
+                    .unwrap_or(0),
+            )
+        });
+        commands
+    }
+
     fn update(mut app: MutexGuard<App>) {
         if app.state.popup_mode.is_some()
             && app.state.popup_mode.unwrap() == PopupMode::CommandPalette
@@ -410,12 +879,28 @@ impl CommandPaletteWidget {
             for item in result {
                 search_results.push(CommandPaletteActions::from_string(&item.text, true).unwrap());
             }
+            // custom commands change with the config so they are searched on their own
+            let custom_commands = Self::custom_commands(&app);
+            if !custom_commands.is_empty() && !current_search_string.is_empty() {
+                let mut custom_corpus = CorpusBuilder::new().arity(2).pad_full(Pad::Auto).finish();
+                for command in &custom_commands {
+                    custom_corpus.add_text(command.to_string().to_lowercase().as_str());
+                }
+                for item in custom_corpus.search(&current_search_string, 0.2) {
+                    if let Some(command) = custom_commands
+                        .iter()
+                        .find(|command| command.to_string().to_lowercase() == item.text)
+                    {
+                        search_results.push(command.clone());
+                    }
+                }
+            }
             // if the search results are empty, then show all commands
             let mut command_search_results = if search_results.is_empty() {
                 if current_search_string.is_empty() {
-                    CommandPaletteActions::all()
+                    Self::commands_by_usage(&app)
                 } else {
-                    let all_actions = CommandPaletteActions::all();
+                    let all_actions = Self::available_commands(&app);
                     let mut results = vec![];
                     // append all that start with the current search string
                     for action in all_actions {
@@ -553,13 +1038,7 @@ impl CommandPaletteWidget {
             .iter()
             .map(|(tag, count)| (tag.clone(), *count))
             .collect();
-        tags.sort_by(|a, b| {
-            if a.1 == b.1 {
-                a.0.cmp(&b.0)
-            } else {
-                b.1.cmp(&a.1)
-            }
-        });
+        app.config.tag_sort_order.sort(&mut tags);
         tags
     }
 }
@@ -567,6 +1046,22 @@ impl CommandPaletteWidget {
 #[derive(Clone, Debug, PartialEq)]
 pub enum CommandPaletteActions {
     ExportToJSON,
+    ExportToOrg,
+    ExportToMarkdown,
+    ExportToCSV,
+    ExportStatisticsToJSON,
+    ExportAndClearAllBoards,
+    ExportKeybindingCheatsheet,
+    ExportSelectionToJSON,
+    ExportSelectionToOrg,
+    ClearSelection,
+    ExportThemeToWindowsTerminal,
+    ExportThemeToXresources,
+    ExportConfig,
+    ImportConfig,
+    ImportMarkdown,
+    ImportJSON,
+    ImportTrello,
     OpenConfigMenu,
     SaveKanbanState,
     LoadASave,
@@ -577,20 +1072,57 @@ pub enum CommandPaletteActions {
     OpenHelpMenu,
     ChangeUIMode,
     ChangeCurrentCardStatus,
+    ChangeAllCardsOnBoardStatus,
+    EditBoardDescription,
+    EditBoardCardLimit,
+    EditBoardDueDate,
+    EditBoardAccent,
+    EditCardProgress,
+    RecentCards,
+    NewCardOnAllBoards,
+    ArchiveCard,
+    SortCardsByDueDate,
+    CopyCard,
+    PasteCard,
+    SortBoardsByDoneRatio,
+    SaveBoardOrder,
     DebugMenu,
     ChangeTheme,
     CreateATheme,
     FilterByTag,
     ClearFilter,
+    ApplyFilterPreset,
+    FollowCardLink,
+    ViewActivityLog,
+    ClearActivityLog,
     NoCommandsFound,
     ChangeDateFormat,
+    ToggleStatusColumnView,
     Quit,
+    /// A command from the custom commands of the config, named like it
+    Custom(String),
 }
 
 impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ExportToJSON => write!(f, "Export to JSON"),
+            Self::ExportToOrg => write!(f, "Export to Org"),
+            Self::ExportToMarkdown => write!(f, "Export to Markdown"),
+            Self::ExportToCSV => write!(f, "Export to CSV"),
+            Self::ExportStatisticsToJSON => write!(f, "Export Statistics to JSON"),
+            Self::ExportAndClearAllBoards => write!(f, "Export and Clear All Boards"),
+            Self::ExportKeybindingCheatsheet => write!(f, "Export Keybinding Cheatsheet"),
+            Self::ExportSelectionToJSON => write!(f, "Export Selection to JSON"),
+            Self::ExportSelectionToOrg => write!(f, "Export Selection to Org"),
+            Self::ClearSelection => write!(f, "Clear Selection"),
+            Self::ExportThemeToWindowsTerminal => write!(f, "Export Theme to Windows Terminal"),
+            Self::ExportThemeToXresources => write!(f, "Export Theme to Xresources"),
+            Self::ExportConfig => write!(f, "Export Config"),
+            Self::ImportConfig => write!(f, "Import Config"),
+            Self::ImportMarkdown => write!(f, "Import Markdown Checklist"),
+            Self::ImportJSON => write!(f, "Import from JSON"),
+            Self::ImportTrello => write!(f, "Import from Trello"),
             Self::OpenConfigMenu => write!(f, "Configure"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
             Self::LoadASave => write!(f, "Load a Save"),
@@ -601,14 +1133,34 @@ impl Display for CommandPaletteActions {
             Self::OpenHelpMenu => write!(f, "Open Help Menu"),
             Self::ChangeUIMode => write!(f, "Change UI Mode"),
             Self::ChangeCurrentCardStatus => write!(f, "Change Current Card Status"),
+            Self::ChangeAllCardsOnBoardStatus => write!(f, "Set All Cards on Board to Status"),
+            Self::EditBoardDescription => write!(f, "Edit Board Description"),
+            Self::EditBoardCardLimit => write!(f, "Set Board Card Limit"),
+            Self::EditBoardDueDate => write!(f, "Set Board Due Date"),
+            Self::EditBoardAccent => write!(f, "Set Board Accent Color"),
+            Self::EditCardProgress => write!(f, "Set Card Progress"),
+            Self::RecentCards => write!(f, "Recently Modified Cards"),
+            Self::NewCardOnAllBoards => write!(f, "New Card on Every Board"),
+            Self::ArchiveCard => write!(f, "Archive Card"),
+            Self::SortCardsByDueDate => write!(f, "Sort Cards by Due Date"),
+            Self::CopyCard => write!(f, "Copy Card"),
+            Self::PasteCard => write!(f, "Paste Card"),
+            Self::SortBoardsByDoneRatio => write!(f, "Sort Boards by Done Ratio"),
+            Self::SaveBoardOrder => write!(f, "Save Board Order"),
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
             Self::ChangeTheme => write!(f, "Change Theme"),
             Self::CreateATheme => write!(f, "Create a Theme"),
             Self::FilterByTag => write!(f, "Filter by Tag"),
             Self::ClearFilter => write!(f, "Clear Filter"),
+            Self::ApplyFilterPreset => write!(f, "Apply Filter Preset"),
+            Self::FollowCardLink => write!(f, "Follow Card Link"),
+            Self::ViewActivityLog => write!(f, "View Activity Log"),
+            Self::ClearActivityLog => write!(f, "Clear Activity Log"),
             Self::ChangeDateFormat => write!(f, "Change Date Format"),
+            Self::ToggleStatusColumnView => write!(f, "Toggle Status Column View"),
             Self::NoCommandsFound => write!(f, "No Commands Found"),
             Self::Quit => write!(f, "Quit"),
+            Self::Custom(name) => write!(f, "{}", name),
         }
     }
 }
@@ -617,6 +1169,22 @@ impl CommandPaletteActions {
     pub fn all() -> Vec<Self> {
         let all = vec![
             Self::ExportToJSON,
+            Self::ExportToOrg,
+            Self::ExportToMarkdown,
+            Self::ExportToCSV,
+            Self::ExportStatisticsToJSON,
+            Self::ExportAndClearAllBoards,
+            Self::ExportKeybindingCheatsheet,
+            Self::ExportSelectionToJSON,
+            Self::ExportSelectionToOrg,
+            Self::ClearSelection,
+            Self::ExportThemeToWindowsTerminal,
+            Self::ExportThemeToXresources,
+            Self::ExportConfig,
+            Self::ImportConfig,
+            Self::ImportMarkdown,
+            Self::ImportJSON,
+            Self::ImportTrello,
             Self::OpenConfigMenu,
             Self::SaveKanbanState,
             Self::LoadASave,
@@ -627,11 +1195,30 @@ impl CommandPaletteActions {
             Self::OpenHelpMenu,
             Self::ChangeUIMode,
             Self::ChangeCurrentCardStatus,
+            Self::ChangeAllCardsOnBoardStatus,
+            Self::EditBoardDescription,
+            Self::EditBoardCardLimit,
+            Self::EditBoardDueDate,
+            Self::EditBoardAccent,
+            Self::EditCardProgress,
+            Self::RecentCards,
+            Self::NewCardOnAllBoards,
+            Self::ArchiveCard,
+            Self::SortCardsByDueDate,
+            Self::CopyCard,
+            Self::PasteCard,
+            Self::SortBoardsByDoneRatio,
+            Self::SaveBoardOrder,
             Self::ChangeTheme,
             Self::CreateATheme,
             Self::FilterByTag,
             Self::ClearFilter,
+            Self::ApplyFilterPreset,
+            Self::FollowCardLink,
+            Self::ViewActivityLog,
+            Self::ClearActivityLog,
             Self::ChangeDateFormat,
+            Self::ToggleStatusColumnView,
             Self::Quit,
         ];
 
@@ -648,6 +1235,22 @@ impl CommandPaletteActions {
         if lowercase_match {
             match s.to_lowercase().as_str() {
                 "export to json" => Some(Self::ExportToJSON),
+                "export to org" => Some(Self::ExportToOrg),
+                "export to markdown" => Some(Self::ExportToMarkdown),
+                "export to csv" => Some(Self::ExportToCSV),
+                "export statistics to json" => Some(Self::ExportStatisticsToJSON),
+                "export and clear all boards" => Some(Self::ExportAndClearAllBoards),
+                "export keybinding cheatsheet" => Some(Self::ExportKeybindingCheatsheet),
+                "export selection to json" => Some(Self::ExportSelectionToJSON),
+                "export selection to org" => Some(Self::ExportSelectionToOrg),
+                "clear selection" => Some(Self::ClearSelection),
+                "export theme to windows terminal" => Some(Self::ExportThemeToWindowsTerminal),
+                "export theme to xresources" => Some(Self::ExportThemeToXresources),
+                "export config" => Some(Self::ExportConfig),
+                "import config" => Some(Self::ImportConfig),
+                "import markdown checklist" => Some(Self::ImportMarkdown),
+                "import from json" => Some(Self::ImportJSON),
+                "import from trello" => Some(Self::ImportTrello),
                 "configure" => Some(Self::OpenConfigMenu),
                 "save kanban state" => Some(Self::SaveKanbanState),
                 "load a save" => Some(Self::LoadASave),
@@ -658,18 +1261,53 @@ impl CommandPaletteActions {
                 "open help menu" => Some(Self::OpenHelpMenu),
                 "change ui mode" => Some(Self::ChangeUIMode),
                 "change current card status" => Some(Self::ChangeCurrentCardStatus),
+                "set all cards on board to status" => Some(Self::ChangeAllCardsOnBoardStatus),
+                "edit board description" => Some(Self::EditBoardDescription),
+                "set board card limit" => Some(Self::EditBoardCardLimit),
+                "set board due date" => Some(Self::EditBoardDueDate),
+                "set board accent color" => Some(Self::EditBoardAccent),
+                "set card progress" => Some(Self::EditCardProgress),
+                "recently modified cards" => Some(Self::RecentCards),
+                "new card on every board" => Some(Self::NewCardOnAllBoards),
+                "archive card" => Some(Self::ArchiveCard),
+                "sort cards by due date" => Some(Self::SortCardsByDueDate),
+                "copy card" => Some(Self::CopyCard),
+                "paste card" => Some(Self::PasteCard),
+                "sort boards by done ratio" => Some(Self::SortBoardsByDoneRatio),
+                "save board order" => Some(Self::SaveBoardOrder),
                 "toggle debug panel" => Some(Self::DebugMenu),
                 "change theme" => Some(Self::ChangeTheme),
                 "create a theme" => Some(Self::CreateATheme),
                 "filter by tag" => Some(Self::FilterByTag),
                 "clear filter" => Some(Self::ClearFilter),
+                "apply filter preset" => Some(Self::ApplyFilterPreset),
+                "follow card link" => Some(Self::FollowCardLink),
+                "view activity log" => Some(Self::ViewActivityLog),
+                "clear activity log" => Some(Self::ClearActivityLog),
                 "change date format" => Some(Self::ChangeDateFormat),
+                "toggle status column view" => Some(Self::ToggleStatusColumnView),
                 "quit" => Some(Self::Quit),
                 _ => None,
             }
         } else {
             match s {
                 "Export to JSON" => Some(Self::ExportToJSON),
+                "Export to Org" => Some(Self::ExportToOrg),
+                "Export to Markdown" => Some(Self::ExportToMarkdown),
+                "Export to CSV" => Some(Self::ExportToCSV),
+                "Export Statistics to JSON" => Some(Self::ExportStatisticsToJSON),
+                "Export and Clear All Boards" => Some(Self::ExportAndClearAllBoards),
+                "Export Keybinding Cheatsheet" => Some(Self::ExportKeybindingCheatsheet),
+                "Export Selection to JSON" => Some(Self::ExportSelectionToJSON),
+                "Export Selection to Org" => Some(Self::ExportSelectionToOrg),
+                "Clear Selection" => Some(Self::ClearSelection),
+                "Export Theme to Windows Terminal" => Some(Self::ExportThemeToWindowsTerminal),
+                "Export Theme to Xresources" => Some(Self::ExportThemeToXresources),
+                "Export Config" => Some(Self::ExportConfig),
+                "Import Config" => Some(Self::ImportConfig),
+                "Import Markdown Checklist" => Some(Self::ImportMarkdown),
+                "Import from JSON" => Some(Self::ImportJSON),
+                "Import from Trello" => Some(Self::ImportTrello),
                 "Configure" => Some(Self::OpenConfigMenu),
                 "Save Kanban State" => Some(Self::SaveKanbanState),
                 "Load a Save" => Some(Self::LoadASave),
@@ -680,12 +1318,31 @@ impl CommandPaletteActions {
                 "Open Help Menu" => Some(Self::OpenHelpMenu),
                 "Change UI Mode" => Some(Self::ChangeUIMode),
                 "Change Current Card Status" => Some(Self::ChangeCurrentCardStatus),
+                "Set All Cards on Board to Status" => Some(Self::ChangeAllCardsOnBoardStatus),
+                "Edit Board Description" => Some(Self::EditBoardDescription),
+                "Set Board Card Limit" => Some(Self::EditBoardCardLimit),
+                "Set Board Due Date" => Some(Self::EditBoardDueDate),
+                "Set Board Accent Color" => Some(Self::EditBoardAccent),
+                "Set Card Progress" => Some(Self::EditCardProgress),
+                "Recently Modified Cards" => Some(Self::RecentCards),
+                "New Card on Every Board" => Some(Self::NewCardOnAllBoards),
+                "Archive Card" => Some(Self::ArchiveCard),
+                "Sort Cards by Due Date" => Some(Self::SortCardsByDueDate),
+                "Copy Card" => Some(Self::CopyCard),
+                "Paste Card" => Some(Self::PasteCard),
+                "Sort Boards by Done Ratio" => Some(Self::SortBoardsByDoneRatio),
+                "Save Board Order" => Some(Self::SaveBoardOrder),
                 "Toggle Debug Panel" => Some(Self::DebugMenu),
                 "Change Theme" => Some(Self::ChangeTheme),
                 "Create a Theme" => Some(Self::CreateATheme),
                 "Filter by Tag" => Some(Self::FilterByTag),
                 "Clear Filter" => Some(Self::ClearFilter),
+                "Apply Filter Preset" => Some(Self::ApplyFilterPreset),
+                "Follow Card Link" => Some(Self::FollowCardLink),
+                "View Activity Log" => Some(Self::ViewActivityLog),
+                "Clear Activity Log" => Some(Self::ClearActivityLog),
                 "Change Date Format" => Some(Self::ChangeDateFormat),
+                "Toggle Status Column View" => Some(Self::ToggleStatusColumnView),
                 "Quit" => Some(Self::Quit),
                 _ => None,
             }